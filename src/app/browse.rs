@@ -0,0 +1,74 @@
+//! Query used to browse a table's rows
+
+use super::handlers::sidebar::{select_all_query, with_limit};
+use crate::db::DatabaseType;
+use crate::model::SortOrder;
+
+/// Table being browsed in the Data tab, with the filter and server-side
+/// order chosen for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrowseQuery {
+    pub database_type: DatabaseType,
+    pub schema: Option<String>,
+    pub table: String,
+    /// `WHERE` condition built with the filter modal
+    pub filter: Option<String>,
+    /// `ORDER BY` column and direction chosen with the order modal
+    pub order: Option<(String, SortOrder)>,
+}
+
+impl BrowseQuery {
+    pub fn new(database_type: DatabaseType, schema: Option<&str>, table: &str) -> Self {
+        Self {
+            database_type,
+            schema: schema.map(str::to_string),
+            table: table.to_string(),
+            filter: None,
+            order: None,
+        }
+    }
+
+    pub fn is_table(&self, schema: Option<&str>, table: &str) -> bool {
+        self.schema.as_deref() == schema && self.table == table
+    }
+
+    /// The browse query, with the row limit appended after the order
+    pub fn sql(&self, limit: Option<usize>) -> String {
+        let mut query = select_all_query(self.database_type, self.schema.as_deref(), &self.table);
+        if let Some(filter) = &self.filter {
+            query.push_str(&format!(" WHERE {}", filter));
+        }
+        if let Some((column, order)) = &self.order {
+            let column = self.database_type.quote_identifier(column);
+            query.push_str(&format!(" ORDER BY {} {}", column, order));
+        }
+        with_limit(&query, limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sql_composes_filter_order_and_limit() {
+        let mut browse = BrowseQuery::new(DatabaseType::PostgreSQL, Some("sales"), "orders");
+        assert_eq!(
+            browse.sql(Some(100)),
+            "SELECT * FROM \"sales\".\"orders\" LIMIT 100"
+        );
+
+        browse.filter = Some("\"total\" > '10'".to_string());
+        browse.order = Some(("Created At".to_string(), SortOrder::Desc));
+        assert_eq!(
+            browse.sql(Some(100)),
+            "SELECT * FROM \"sales\".\"orders\" WHERE \"total\" > '10' \
+             ORDER BY \"Created At\" DESC LIMIT 100"
+        );
+        assert_eq!(
+            browse.sql(None),
+            "SELECT * FROM \"sales\".\"orders\" WHERE \"total\" > '10' \
+             ORDER BY \"Created At\" DESC"
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Query result cache
+//!
+//! Keeps recently fetched results keyed by connection and query text so that
+//! re-selecting a table does not hit the database again.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::db::ConnectionParams;
+use crate::model::QueryResult;
+
+/// Default number of cached results
+pub const DEFAULT_CACHE_SIZE: usize = 20;
+
+/// Default time a cached result stays valid
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Key identifying a cached result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheKey {
+    /// Identifies the database the query ran against
+    pub connection: String,
+    /// Query text as sent to the database
+    pub query: String,
+}
+
+impl CacheKey {
+    pub fn new(connection: &ConnectionParams, query: &str) -> Self {
+        Self {
+            connection: connection_key(connection),
+            query: query.trim().to_string(),
+        }
+    }
+}
+
+/// Cache key component for a connection (user@host:port/database).
+///
+/// A session `search_path` is part of the key, since it changes what
+/// unqualified names in a query refer to.
+pub fn connection_key(connection: &ConnectionParams) -> String {
+    let key = format!(
+        "{}@{}:{}/{}",
+        connection.username, connection.host, connection.port, connection.database
+    );
+    match &connection.search_path {
+        Some(path) => format!("{}?search_path={}", key, path),
+        None => key,
+    }
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    key: CacheKey,
+    result: QueryResult,
+    stored_at: Instant,
+}
+
+/// LRU cache of query results with a time-to-live.
+///
+/// The most recently used entry is kept at the front. A capacity of 0
+/// disables caching.
+#[derive(Debug)]
+pub struct ResultCache {
+    entries: VecDeque<CacheEntry>,
+    capacity: usize,
+    ttl: Duration,
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_SIZE, DEFAULT_CACHE_TTL)
+    }
+}
+
+impl ResultCache {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            ttl,
+        }
+    }
+
+    /// Change the capacity and TTL, dropping entries that no longer fit
+    pub fn configure(&mut self, capacity: usize, ttl: Duration) {
+        self.capacity = capacity;
+        self.ttl = ttl;
+        self.entries.truncate(capacity);
+    }
+
+    /// Number of cached results (including expired ones not yet evicted)
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Look up a fresh result, marking it as most recently used
+    pub fn get(&mut self, key: &CacheKey) -> Option<&QueryResult> {
+        self.get_at(key, Instant::now())
+    }
+
+    fn get_at(&mut self, key: &CacheKey, now: Instant) -> Option<&QueryResult> {
+        let idx = self.entries.iter().position(|e| &e.key == key)?;
+        let entry = self.entries.remove(idx)?;
+        if now.duration_since(entry.stored_at) > self.ttl {
+            return None;
+        }
+        self.entries.push_front(entry);
+        self.entries.front().map(|e| &e.result)
+    }
+
+    /// Store a result, evicting the least recently used entry when full
+    pub fn insert(&mut self, key: CacheKey, result: QueryResult) {
+        self.insert_at(key, result, Instant::now());
+    }
+
+    fn insert_at(&mut self, key: CacheKey, result: QueryResult, now: Instant) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.remove(&key);
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_back();
+        }
+        self.entries.push_front(CacheEntry {
+            key,
+            result,
+            stored_at: now,
+        });
+    }
+
+    /// Drop a single cached result
+    pub fn remove(&mut self, key: &CacheKey) {
+        self.entries.retain(|e| &e.key != key);
+    }
+
+    /// Drop every cached result for a connection
+    pub fn invalidate_connection(&mut self, connection: &str) {
+        self.entries.retain(|e| e.key.connection != connection);
+    }
+}
+
+/// Whether a statement may modify data or schema.
+///
+/// Anything that is not clearly read-only is treated as mutating, so the
+/// cache errs on the side of re-fetching.
+pub fn is_mutating_statement(query: &str) -> bool {
+    let normalized = query.trim_start_matches(|c: char| c.is_whitespace() || c == '(');
+    let first_word = normalized
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or("")
+        .to_ascii_uppercase();
+
+    match first_word.as_str() {
+        "SHOW" | "VALUES" | "TABLE" => false,
+        // SELECT ... INTO creates a table
+        "SELECT" => contains_word(normalized, &["INTO"]),
+        // Only EXPLAIN ANALYZE runs the statement it explains
+        "EXPLAIN" => {
+            let (analyze, statement) = explain_target(&normalized[first_word.len()..]);
+            analyze && is_mutating_statement(statement)
+        }
+        // CTEs may wrap data-modifying statements
+        "WITH" => contains_word(normalized, &["INSERT", "UPDATE", "DELETE", "MERGE", "INTO"]),
+        _ => true,
+    }
+}
+
+/// Whether any word of `sql` is one of `keywords` (upper case)
+fn contains_word(sql: &str, keywords: &[&str]) -> bool {
+    sql.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|word| keywords.contains(&word.to_ascii_uppercase().as_str()))
+}
+
+/// Split the options off an `EXPLAIN`, returning whether they include
+/// `ANALYZE` and the statement being explained
+fn explain_target(rest: &str) -> (bool, &str) {
+    let is_analyze =
+        |word: &str| matches!(word.to_ascii_uppercase().as_str(), "ANALYZE" | "ANALYSE");
+    let mut rest = rest.trim_start();
+
+    // EXPLAIN (ANALYZE, BUFFERS) ...; an option may be switched off
+    if let Some(options) = rest.strip_prefix('(') {
+        let Some(end) = options.find(')') else {
+            return (false, "");
+        };
+        let analyze = options[..end].split(',').any(|option| {
+            let mut words = option.split_whitespace();
+            words.next().is_some_and(is_analyze)
+                && !words.next().is_some_and(|value| {
+                    matches!(value.to_ascii_uppercase().as_str(), "FALSE" | "OFF" | "0")
+                })
+        });
+        return (analyze, &options[end + 1..]);
+    }
+
+    // EXPLAIN ANALYZE VERBOSE ...
+    let mut analyze = false;
+    loop {
+        let word_end = rest
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+        let word = &rest[..word_end];
+        if is_analyze(word) {
+            analyze = true;
+        } else if !word.eq_ignore_ascii_case("VERBOSE") {
+            return (analyze, rest);
+        }
+        rest = rest[word_end..].trim_start();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(connection: &str, query: &str) -> CacheKey {
+        CacheKey {
+            connection: connection.to_string(),
+            query: query.to_string(),
+        }
+    }
+
+    fn result(rows: usize) -> QueryResult {
+        QueryResult {
+            columns: vec!["id".to_string()],
+            column_types: Vec::new(),
+            rows: (0..rows).map(|i| vec![i.to_string()]).collect(),
+            total_rows: rows,
+            rows_affected: None,
+            execution_time_ms: 1,
+        }
+    }
+
+    #[test]
+    fn test_get_returns_inserted_result() {
+        let mut cache = ResultCache::new(2, Duration::from_secs(60));
+        cache.insert(key("db", "SELECT 1"), result(3));
+        assert_eq!(cache.get(&key("db", "SELECT 1")).unwrap().total_rows, 3);
+        assert!(cache.get(&key("other", "SELECT 1")).is_none());
+    }
+
+    #[test]
+    fn test_expired_entry_is_evicted() {
+        let mut cache = ResultCache::new(2, Duration::from_secs(60));
+        let now = Instant::now();
+        cache.insert_at(key("db", "SELECT 1"), result(1), now);
+        let later = now + Duration::from_secs(61);
+        assert!(cache.get_at(&key("db", "SELECT 1"), later).is_none());
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let mut cache = ResultCache::new(2, Duration::from_secs(60));
+        cache.insert(key("db", "a"), result(1));
+        cache.insert(key("db", "b"), result(1));
+        // Touch "a" so "b" becomes least recently used
+        cache.get(&key("db", "a"));
+        cache.insert(key("db", "c"), result(1));
+
+        assert!(cache.get(&key("db", "a")).is_some());
+        assert!(cache.get(&key("db", "b")).is_none());
+        assert!(cache.get(&key("db", "c")).is_some());
+    }
+
+    #[test]
+    fn test_zero_capacity_disables_cache() {
+        let mut cache = ResultCache::new(0, Duration::from_secs(60));
+        cache.insert(key("db", "a"), result(1));
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_invalidate_connection() {
+        let mut cache = ResultCache::new(4, Duration::from_secs(60));
+        cache.insert(key("db1", "a"), result(1));
+        cache.insert(key("db1", "b"), result(1));
+        cache.insert(key("db2", "a"), result(1));
+        cache.invalidate_connection("db1");
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&key("db2", "a")).is_some());
+    }
+
+    #[test]
+    fn test_is_mutating_statement() {
+        assert!(!is_mutating_statement("SELECT * FROM users"));
+        assert!(!is_mutating_statement("  select 1"));
+        assert!(!is_mutating_statement("(SELECT 1) UNION (SELECT 2)"));
+        assert!(!is_mutating_statement(
+            "WITH t AS (SELECT 1) SELECT * FROM t"
+        ));
+        assert!(!is_mutating_statement("EXPLAIN SELECT 1"));
+        assert!(!is_mutating_statement("EXPLAIN DELETE FROM users"));
+        assert!(!is_mutating_statement("EXPLAIN ANALYZE SELECT 1"));
+        assert!(is_mutating_statement("EXPLAIN ANALYZE DELETE FROM users"));
+        assert!(is_mutating_statement(
+            "explain analyze verbose update users set name = 'x'"
+        ));
+        assert!(is_mutating_statement(
+            "EXPLAIN (ANALYZE, BUFFERS) INSERT INTO users VALUES (1)"
+        ));
+        assert!(!is_mutating_statement(
+            "EXPLAIN (ANALYZE false) DELETE FROM users"
+        ));
+        assert!(is_mutating_statement("SELECT * INTO backup FROM users"));
+        assert!(is_mutating_statement(
+            "WITH t AS (SELECT 1) SELECT * INTO backup FROM t"
+        ));
+        assert!(is_mutating_statement("UPDATE users SET name = 'x'"));
+        assert!(is_mutating_statement("insert into users values (1)"));
+        assert!(is_mutating_statement("DROP TABLE users"));
+        assert!(is_mutating_statement(
+            "WITH d AS (DELETE FROM users RETURNING *) SELECT * FROM d"
+        ));
+    }
+}
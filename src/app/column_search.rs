@@ -0,0 +1,86 @@
+//! Incremental column filter for the Columns schema sub-tab
+
+use crate::model::schema::Column;
+
+/// Name filter for the Columns sub-tab
+#[derive(Debug, Clone, Default)]
+pub struct ColumnSearch {
+    pub query: String,
+    /// Whether keystrokes currently go to the filter input
+    pub editing: bool,
+}
+
+impl ColumnSearch {
+    /// Whether the filter line should be shown
+    pub fn is_active(&self) -> bool {
+        self.editing || !self.query.is_empty()
+    }
+
+    pub fn start(&mut self) {
+        self.editing = true;
+    }
+
+    /// Stop editing and show every column again
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.editing = false;
+    }
+
+    /// Indices of columns whose name contains the query (case-insensitive)
+    pub fn filtered_indices(&self, columns: &[Column]) -> Vec<usize> {
+        let needle = self.query.to_lowercase();
+        columns
+            .iter()
+            .enumerate()
+            .filter(|(_, col)| col.name.to_lowercase().contains(&needle))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str) -> Column {
+        Column {
+            name: name.to_string(),
+            data_type: "text".to_string(),
+            is_nullable: true,
+            default_value: None,
+            is_primary_key: false,
+            is_unique: false,
+            is_auto_increment: false,
+            comment: None,
+            ordinal_position: 0,
+            planner_stats: None,
+        }
+    }
+
+    #[test]
+    fn test_filter_by_name_substring() {
+        let columns = vec![
+            column("id"),
+            column("user_id"),
+            column("email"),
+            column("UserName"),
+        ];
+        let mut search = ColumnSearch::default();
+        assert_eq!(search.filtered_indices(&columns), vec![0, 1, 2, 3]);
+
+        search.query = "user".to_string();
+        assert_eq!(search.filtered_indices(&columns), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_clear_resets_filter() {
+        let mut search = ColumnSearch::default();
+        search.start();
+        search.query.push('x');
+        assert!(search.is_active());
+
+        search.clear();
+        assert!(!search.is_active());
+        assert!(search.query.is_empty());
+    }
+}
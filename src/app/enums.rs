@@ -1,5 +1,7 @@
 //! Core enumeration types for app state management
 
+use crate::db::ProviderCapabilities;
+
 /// Focus areas in the application
 #[derive(Debug, Clone, PartialEq)]
 pub enum Focus {
@@ -30,9 +32,70 @@ pub enum SchemaSubTab {
     Definition,
 }
 
+impl SchemaSubTab {
+    /// Tab label including its shortcut key
+    pub fn title(&self) -> &'static str {
+        match self {
+            SchemaSubTab::Columns => "Columns [1]",
+            SchemaSubTab::Indexes => "Indexes [2]",
+            SchemaSubTab::ForeignKeys => "Foreign Keys [3]",
+            SchemaSubTab::Constraints => "Constraints [4]",
+            SchemaSubTab::Triggers => "Triggers [5]",
+            SchemaSubTab::Definition => "Definition [6]",
+        }
+    }
+
+    /// Sub-tabs to show for a table, given what the database supports.
+    ///
+    /// Definition is only shown for views; Triggers only if the database has them.
+    pub fn visible_tabs(is_view: bool, caps: &ProviderCapabilities) -> Vec<SchemaSubTab> {
+        [
+            SchemaSubTab::Columns,
+            SchemaSubTab::Indexes,
+            SchemaSubTab::ForeignKeys,
+            SchemaSubTab::Constraints,
+            SchemaSubTab::Triggers,
+            SchemaSubTab::Definition,
+        ]
+        .into_iter()
+        .filter(|tab| match tab {
+            SchemaSubTab::Triggers => caps.supports_triggers,
+            SchemaSubTab::Definition => is_view,
+            _ => true,
+        })
+        .collect()
+    }
+}
+
 /// Sidebar display mode - switches between Projects list and Connections list
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SidebarMode {
     Projects,
     Connections(usize), // project index
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_tabs_for_table() {
+        let tabs = SchemaSubTab::visible_tabs(false, &ProviderCapabilities::all());
+        assert_eq!(tabs.len(), 5);
+        assert!(!tabs.contains(&SchemaSubTab::Definition));
+    }
+
+    #[test]
+    fn test_visible_tabs_for_view() {
+        let tabs = SchemaSubTab::visible_tabs(true, &ProviderCapabilities::all());
+        assert_eq!(tabs.last(), Some(&SchemaSubTab::Definition));
+    }
+
+    #[test]
+    fn test_visible_tabs_hide_unsupported_triggers() {
+        let caps = ProviderCapabilities::default();
+        let tabs = SchemaSubTab::visible_tabs(false, &caps);
+        assert!(!tabs.contains(&SchemaSubTab::Triggers));
+        assert_eq!(tabs.len(), 4);
+    }
+}
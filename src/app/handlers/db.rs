@@ -1,8 +1,35 @@
 //! Database worker handlers (async operations)
 
-use crate::app::App;
-use crate::db::{ConnectionParams, DbCommand, DbResponse, DbWorkerHandle};
-use crate::model::{Connection, HistoryEntry, Pagination, QueryResult, Table};
+use std::sync::mpsc::TryRecvError;
+use std::time::{Duration, Instant};
+
+use crate::app::cache::{connection_key, is_mutating_statement, CacheKey};
+use crate::app::loading::BulkRefresh;
+use crate::app::preview::{PreviewKey, PREVIEW_ROWS};
+use crate::app::{
+    ActiveSessionsModal, App, DatabaseStatsModal, ModalState, RolesModal, ServerSettingsModal,
+    SidebarMode,
+};
+use crate::config::Settings;
+use crate::db::{
+    spawn_db_worker, ConnectRetry, ConnectionParams, DbCommand, DbResponse, DbWorkerHandle,
+};
+use crate::export::{ExportConfig, ExportOutput, ExportTarget};
+use crate::model::{
+    resolve_statement_timeout, ActiveSession, Connection, DatabaseRole, DatabaseStats,
+    HistoryEntry, Pagination, QueryResult, ServerSetting, Table,
+};
+use crate::ui::utils::format_size;
+
+/// Number of rows fetched per batch when browsing a table
+const BROWSE_FETCH_SIZE: usize = 500;
+
+/// Most browsed rows held in memory; rows paged out of this window are
+/// dropped and fetched again from the cursor when paging back
+const BROWSE_WINDOW_ROWS: usize = 4 * BROWSE_FETCH_SIZE;
+
+/// Number of tables listed in the database size summary
+const LARGEST_TABLES_LIMIT: usize = 20;
 
 impl App {
     /// Set the DB worker handle for async operations
@@ -10,6 +37,139 @@ impl App {
         self.db_worker = Some(worker);
     }
 
+    /// Parameters for the sessions of `conn`, with the statement timeout
+    /// resolved from the connection and the settings
+    pub(crate) fn connection_params(&self, conn: &Connection) -> ConnectionParams {
+        ConnectionParams {
+            statement_timeout_secs: resolve_statement_timeout(
+                conn.query_timeout_secs,
+                self.statement_timeout_secs,
+            ),
+            ..ConnectionParams::from_connection(conn)
+        }
+    }
+
+    /// Apply global settings loaded from the config file
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        self.result_cache.configure(
+            settings.result_cache_size,
+            Duration::from_secs(settings.result_cache_ttl_secs),
+        );
+        self.show_system_objects = settings.show_system_objects;
+        self.exact_table_row_counts = settings.exact_table_row_counts;
+        self.connect_retry = ConnectRetry::new(
+            settings.connect_retries,
+            Duration::from_millis(settings.connect_retry_backoff_ms),
+        );
+        self.clipboard_confirm_rows = settings.clipboard_confirm_rows;
+        self.request_timeout = (settings.request_timeout_secs > 0)
+            .then(|| Duration::from_secs(settings.request_timeout_secs));
+        self.statement_timeout_secs = settings.statement_timeout_secs;
+        self.browse_limit = settings.default_browse_limit;
+        self.table_icons = settings.table_icons;
+        self.ascii_mode = settings.ascii_mode;
+        self.pagination_wrap = settings.pagination_wrap;
+        self.pagination.wrap = settings.pagination_wrap;
+        self.wrap_row_height = settings.wrap_row_height.max(1);
+        self.relative_history_times = settings.relative_history_times;
+        self.history_save_interval = Duration::from_secs(settings.history_save_interval_secs);
+        self.csv_delimiter = settings.csv_delimiter;
+        self.csv_quote_style = settings.csv_quote_style;
+        self.csv_with_bom = settings.csv_with_bom;
+    }
+
+    /// Show or hide system schemas, reloading the tables of every connection
+    pub(crate) fn toggle_system_objects(&mut self) {
+        self.show_system_objects = !self.show_system_objects;
+
+        let mut expanded = Vec::new();
+        for (proj_idx, project) in self.projects.iter_mut().enumerate() {
+            for (conn_idx, conn) in project.connections.iter_mut().enumerate() {
+                conn.tables.clear();
+                if conn.expanded {
+                    expanded.push((proj_idx, conn_idx, conn.clone()));
+                }
+            }
+        }
+        self.selected_table_idx = None;
+
+        // Collapsed connections load their tables again when expanded
+        for (proj_idx, conn_idx, conn) in expanded {
+            self.send_fetch_tables(&conn, proj_idx, conn_idx);
+        }
+
+        // Set after sending so a worker error stays visible
+        if self.db_worker.is_some() {
+            self.status_message = if self.show_system_objects {
+                "Showing system schemas".to_string()
+            } else {
+                "Hiding system schemas".to_string()
+            };
+        }
+    }
+
+    /// Re-fetch the tables of every expanded connection in all projects.
+    ///
+    /// The requests are queued on the worker together; each connection is
+    /// updated as its response arrives and the status bar counts them.
+    pub(crate) fn refresh_all_tables(&mut self) {
+        let expanded: Vec<(usize, usize, Connection)> = self
+            .projects
+            .iter()
+            .enumerate()
+            .flat_map(|(proj_idx, project)| {
+                project
+                    .connections
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, conn)| conn.expanded)
+                    .map(move |(conn_idx, conn)| (proj_idx, conn_idx, conn.clone()))
+            })
+            .collect();
+        if expanded.is_empty() {
+            self.status_message = "No expanded connections to refresh".to_string();
+            return;
+        }
+
+        let mut request_ids = Vec::new();
+        for (proj_idx, conn_idx, conn) in expanded {
+            if let Some(request_id) = self.send_fetch_tables(&conn, proj_idx, conn_idx) {
+                request_ids.push(request_id);
+            }
+        }
+        if request_ids.is_empty() {
+            // send_fetch_tables already reported why
+            return;
+        }
+
+        let refresh = BulkRefresh::new(request_ids);
+        self.status_message = refresh.status();
+        self.loading.message = Some(refresh.status());
+        self.bulk_refresh = Some(refresh);
+    }
+
+    /// Count a table list response towards a running "refresh all"
+    fn track_bulk_refresh(&mut self, request_id: u64, ok: bool) {
+        let Some(refresh) = self.bulk_refresh.as_mut() else {
+            return;
+        };
+        if !refresh.finish(request_id, ok) {
+            return;
+        }
+        // Keep the reason a connection failed next to the progress
+        self.status_message = if ok {
+            refresh.status()
+        } else {
+            format!("{} | {}", self.status_message, refresh.status())
+        };
+        if refresh.is_done() {
+            self.bulk_refresh = None;
+            self.loading.message = None;
+        } else {
+            self.loading.message = Some(refresh.status());
+        }
+    }
+
     /// Get the next unique request ID
     pub(crate) fn next_request_id(&mut self) -> u64 {
         let id = self.next_request_id;
@@ -21,38 +181,204 @@ impl App {
     /// This should be called regularly from the event loop.
     pub fn process_db_responses(&mut self) {
         // Collect responses first to avoid borrow issues
-        let responses: Vec<DbResponse> = {
+        let (responses, stopped) = {
             let Some(ref worker) = self.db_worker else {
                 return;
             };
             let mut responses = Vec::new();
-            while let Ok(response) = worker.try_recv() {
-                responses.push(response);
-            }
-            responses
+            let stopped = loop {
+                match worker.try_recv() {
+                    Ok(response) => responses.push(response),
+                    Err(TryRecvError::Empty) => break !worker.is_alive(),
+                    Err(TryRecvError::Disconnected) => break true,
+                }
+            };
+            (responses, stopped)
         };
 
         // Process all collected responses
         for response in responses {
             self.handle_db_response(response);
         }
+
+        if stopped {
+            self.restart_db_worker();
+        }
+        self.expire_stuck_requests(Instant::now());
+    }
+
+    /// Stop waiting for requests that got no response within the request timeout
+    pub(crate) fn expire_stuck_requests(&mut self, now: Instant) {
+        let Some(timeout) = self.request_timeout else {
+            return;
+        };
+        let expired = self.loading.expire(now, timeout);
+        if expired.is_empty() {
+            return;
+        }
+
+        // Drop the late responses of timed-out table list / details requests
+        if self.loading.fetching_tables.is_none() && self.loading.fetching_details.is_none() {
+            self.cancelled_requests
+                .extend(self.metadata_requests.drain(..));
+            self.bulk_refresh = None;
+        }
+        // Drop the late response of a timed-out query or cursor fetch
+        if !self.loading.executing_query {
+            self.cancelled_requests.extend(self.query_request.take());
+            self.pending_query_info = None;
+            self.pending_cache_key = None;
+            self.pending_fetch_start = None;
+        }
+        self.status_message = format!(
+            "Timed out after {}s waiting for the database ({})",
+            timeout.as_secs(),
+            expired.join(", ")
+        );
+    }
+
+    /// Replace a worker thread that stopped (e.g. after a panic).
+    ///
+    /// Requests sent to the old worker will never be answered, so their
+    /// loading state is dropped instead of waiting forever.
+    fn restart_db_worker(&mut self) {
+        self.loading.clear();
+        self.metadata_requests.clear();
+        self.bulk_refresh = None;
+        self.pending_query_info = None;
+        self.pending_cache_key = None;
+        self.pending_stats_info = None;
+        self.pending_sessions_info = None;
+        self.pending_roles_info = None;
+        self.pending_settings_info = None;
+        self.table_previews.clear();
+        self.query_request = None;
+        self.pending_fetch_start = None;
+        self.cursor_open = false;
+        self.cursor_has_more = false;
+        self.db_worker = Some(spawn_db_worker(self.connect_retry));
+        self.status_message =
+            "Database worker stopped and was restarted; pending requests were cancelled"
+                .to_string();
     }
 
     /// Handle a single DB response
-    fn handle_db_response(&mut self, response: DbResponse) {
+    pub(super) fn handle_db_response(&mut self, response: DbResponse) {
         match response {
-            DbResponse::TablesLoaded { result, target, .. } => {
-                self.handle_tables_loaded(result, target);
+            DbResponse::TablesLoaded {
+                request_id,
+                result,
+                target,
+            } => {
+                if self.finish_metadata_request(request_id) {
+                    let ok = result.is_ok();
+                    self.handle_tables_loaded(result, target);
+                    self.track_bulk_refresh(request_id, ok);
+                }
             }
-            DbResponse::TableDetailsLoaded { result, target, .. } => {
-                self.handle_table_details_loaded(result, target);
+            DbResponse::TableDetailsLoaded {
+                request_id,
+                result,
+                target,
+            } => {
+                if self.finish_metadata_request(request_id) {
+                    self.handle_table_details_loaded(result, target);
+                }
             }
             DbResponse::QueryExecuted {
+                request_id,
                 result,
+                notices,
                 project_idx,
-                ..
             } => {
+                if !self.finish_query_request(request_id) {
+                    return;
+                }
+                // Cached results may be stale once the connection's data changed
+                if let Some(key) = self.pending_cache_key.take() {
+                    if result.is_ok() && is_mutating_statement(&key.query) {
+                        self.result_cache.invalidate_connection(&key.connection);
+                    }
+                }
+                self.current_cache_key = None;
                 self.handle_query_executed(result, project_idx);
+                if let Some(summary) = notice_summary(&notices) {
+                    self.status_message = format!("{} | {}", self.status_message, summary);
+                }
+            }
+            DbResponse::TablePreviewLoaded { request_id, result } => {
+                if self.table_previews.finish(request_id, result) {
+                    // The selection may have moved on while this one loaded
+                    self.request_table_preview();
+                }
+            }
+            DbResponse::QueryValidated { result, .. } => {
+                self.loading.message = None;
+                self.status_message = match result {
+                    Ok(()) => "Query is valid".to_string(),
+                    Err(e) => format!("Query is invalid: {}", e),
+                };
+            }
+            DbResponse::DatabaseStatsLoaded { result, .. } => {
+                self.handle_database_stats_loaded(result);
+            }
+            DbResponse::ActiveSessionsLoaded { result, .. } => {
+                self.handle_active_sessions_loaded(result);
+            }
+            DbResponse::RolesLoaded { result, .. } => {
+                self.handle_roles_loaded(result);
+            }
+            DbResponse::SettingsLoaded { result, .. } => {
+                self.handle_settings_loaded(result);
+            }
+            DbResponse::BackendTerminated { pid, result, .. } => {
+                self.handle_backend_terminated(pid, result);
+            }
+            DbResponse::RowsFetched {
+                request_id,
+                result,
+                first_batch,
+                has_more,
+            } => {
+                if !self.finish_query_request(request_id) {
+                    return;
+                }
+                // A failed batch also ends the cursor, but its rows are incomplete
+                let ok = result.is_ok();
+                if first_batch {
+                    // The worker keeps the cursor only while rows remain
+                    self.cursor_has_more = has_more;
+                    self.cursor_open = has_more;
+                    self.current_cache_key = self.pending_cache_key.take();
+                    self.handle_query_executed(result, 0);
+                } else {
+                    self.handle_more_rows_fetched(result, has_more);
+                }
+                // Only fully loaded results are cached
+                if ok && !self.cursor_has_more && self.result_offset == 0 {
+                    self.cache_current_result();
+                }
+            }
+            DbResponse::Progress { bytes, .. } => {
+                self.handle_progress(bytes);
+            }
+            DbResponse::RowProgress { rows, .. } => {
+                self.handle_row_progress(rows);
+            }
+            DbResponse::ExportCompleted { result, target, .. } => {
+                self.handle_export_completed(result, &target);
+            }
+            DbResponse::Reconnected {
+                request_id,
+                result,
+                target,
+            } => {
+                if self.reconnect_request == Some(request_id) {
+                    self.reconnect_request = None;
+                }
+                if !self.cancelled_requests.remove(&request_id) {
+                    self.handle_reconnected(result, target);
+                }
             }
         }
     }
@@ -63,6 +389,7 @@ impl App {
 
         // Clear loading state
         self.loading.fetching_tables = None;
+        let restore_table = self.restore_table.take_if(|(t, _)| *t == target);
 
         match result {
             Ok(tables) => {
@@ -70,24 +397,49 @@ impl App {
                     if let Some(conn) = project.connections.get_mut(conn_idx) {
                         let table_count = tables.len();
                         conn.tables = tables;
+                        self.table_previews.clear_connection(proj_idx, conn_idx);
                         self.status_message = format!("Loaded {} tables", table_count);
                         self.loading.message = None;
                     }
                 }
+                if let Some((_, table)) = restore_table {
+                    self.select_restored_table(proj_idx, conn_idx, &table);
+                }
             }
             Err(e) => {
-                // Collapse the connection on error
-                if let Some(project) = self.projects.get_mut(proj_idx) {
-                    if let Some(conn) = project.connections.get_mut(conn_idx) {
+                // Collapse the connection on error. Connection failures arrive
+                // already explained by `ProviderError`'s diagnosis.
+                let conn = self
+                    .projects
+                    .get_mut(proj_idx)
+                    .and_then(|p| p.connections.get_mut(conn_idx));
+                self.status_message = match conn {
+                    Some(conn) => {
                         conn.expanded = false;
+                        format!("Cannot open {}: {}", conn.name, e)
                     }
-                }
-                self.status_message = format!("Failed to get tables: {}", e);
+                    None => format!("Failed to get tables: {}", e),
+                };
                 self.loading.message = None;
             }
         }
     }
 
+    /// Select the table the previous session left selected, unless the user moved on
+    fn select_restored_table(&mut self, proj_idx: usize, conn_idx: usize, table: &str) {
+        if self.sidebar_mode != SidebarMode::Connections(proj_idx)
+            || self.selected_connection_idx != conn_idx
+            || self.selected_table_idx.is_some()
+        {
+            return;
+        }
+        self.selected_table_idx = self.projects[proj_idx].connections[conn_idx]
+            .tables
+            .iter()
+            .position(|t| t.name == table);
+        self.fetch_table_details_if_needed(proj_idx);
+    }
+
     /// Handle table details loaded response
     fn handle_table_details_loaded(
         &mut self,
@@ -116,8 +468,25 @@ impl App {
                     }
                 }
                 self.loading.message = None;
+
+                // Finish an action that was waiting for the columns, unless
+                // the user has moved on to another table
+                let selected = (
+                    proj_idx,
+                    self.selected_connection_idx,
+                    self.selected_table_idx,
+                );
+                if let Some((pending, message)) = self.pending_table_action.take() {
+                    if pending == target
+                        && selected == (proj_idx, conn_idx, Some(table_idx))
+                        && self.sidebar_mode == SidebarMode::Connections(proj_idx)
+                    {
+                        self.update(message);
+                    }
+                }
             }
             Err(e) => {
+                self.pending_table_action = None;
                 self.status_message = format!("Failed to get table details: {}", e);
                 self.loading.message = None;
             }
@@ -138,6 +507,7 @@ impl App {
                 let execution_time_ms = query_result.execution_time_ms;
 
                 // Add to history if we have query info
+                self.executed_query = query_info.as_ref().map(|(_, _, query)| query.clone());
                 if let Some((conn_name, database, query)) = query_info {
                     self.query_history.add(HistoryEntry::success(
                         &query,
@@ -147,12 +517,21 @@ impl App {
                         row_count,
                     ));
                     self.history_dirty = true;
-                    self.status_message = format!("Fetched {} rows from {}", row_count, database);
+                    self.status_message = if query_result.rows_affected.is_some() {
+                        format!("Statement ran on {}", database)
+                    } else {
+                        format!("Fetched {} rows from {}", row_count, database)
+                    };
                 }
 
                 // Update result
-                self.pagination = Pagination::new(row_count);
+                self.pagination = Pagination::new(row_count).with_wrap(self.pagination_wrap);
                 self.result = Some(query_result);
+                self.result_offset = 0;
+                self.result_truncated = false;
+                self.column_order.clear();
+                self.hidden_columns.clear();
+                self.query_error = None;
                 self.loading.message = None;
             }
             Err(e) => {
@@ -165,42 +544,416 @@ impl App {
                         e.clone(),
                     ));
                     self.history_dirty = true;
+                    // The error itself is shown by the query outcome in the status bar
+                    self.status_message = format!("Query failed on {}", database);
+                } else {
+                    self.status_message = "Query failed".to_string();
                 }
 
                 self.result = None;
-                self.pagination = Pagination::default();
-                self.status_message = format!("Query failed: {}", e);
+                self.result_offset = 0;
+                self.result_truncated = false;
+                self.executed_query = None;
+                self.query_error = Some(e);
+                self.pagination = Pagination::default().with_wrap(self.pagination_wrap);
                 self.loading.message = None;
             }
         }
     }
 
-    /// Send a command to fetch tables asynchronously
+    /// Handle database stats loaded response
+    fn handle_database_stats_loaded(&mut self, result: Result<Option<DatabaseStats>, String>) {
+        // Clear loading state
+        self.loading.message = None;
+        let info = self.pending_stats_info.take();
+
+        match result {
+            Ok(None) => {
+                self.status_message =
+                    "Database size summary is not supported for this connection".to_string();
+            }
+            Ok(Some(stats)) => {
+                let (connection_name, database) = info.unwrap_or_default();
+                self.status_message = format!("Loaded size summary for {}", database);
+                self.modal_state = ModalState::DatabaseStats(DatabaseStatsModal {
+                    connection_name,
+                    database,
+                    stats,
+                });
+            }
+            Err(e) => {
+                self.status_message = format!("Failed to get database size: {}", e);
+            }
+        }
+    }
+
+    /// Handle session list loaded response.
+    ///
+    /// Refreshes the open sessions modal, or opens it for a new list.
+    fn handle_active_sessions_loaded(
+        &mut self,
+        result: Result<Option<Vec<ActiveSession>>, String>,
+    ) {
+        // Clear loading state
+        self.loading.message = None;
+        let info = self.pending_sessions_info.take();
+
+        match result {
+            Ok(None) => {
+                self.status_message =
+                    "Session list is not supported for this connection".to_string();
+            }
+            Ok(Some(sessions)) => {
+                let (connection_name, database) = info.unwrap_or_default();
+                self.status_message =
+                    format!("Loaded {} sessions for {}", sessions.len(), database);
+                if let ModalState::ActiveSessions(modal) = &mut self.modal_state {
+                    modal.set_sessions(sessions);
+                } else {
+                    self.modal_state = ModalState::ActiveSessions(ActiveSessionsModal::new(
+                        connection_name,
+                        database,
+                        sessions,
+                    ));
+                }
+            }
+            Err(e) => {
+                self.status_message = format!("Failed to list sessions: {}", e);
+            }
+        }
+    }
+
+    /// Handle role list loaded response
+    fn handle_roles_loaded(&mut self, result: Result<Option<Vec<DatabaseRole>>, String>) {
+        self.loading.message = None;
+        let connection_name = self.pending_roles_info.take().unwrap_or_default();
+
+        match result {
+            Ok(None) => {
+                self.status_message = "Role list is not supported for this connection".to_string();
+            }
+            Ok(Some(roles)) => {
+                let modal = RolesModal::new(connection_name, roles);
+                self.status_message = if modal.is_limited() {
+                    "Role attributes are hidden for this user; showing what is visible".to_string()
+                } else {
+                    format!("Loaded {} roles", modal.roles.len())
+                };
+                self.modal_state = ModalState::Roles(modal);
+            }
+            Err(e) => {
+                self.status_message = format!("Failed to list roles: {}", e);
+            }
+        }
+    }
+
+    /// Handle server settings loaded response
+    fn handle_settings_loaded(&mut self, result: Result<Option<Vec<ServerSetting>>, String>) {
+        self.loading.message = None;
+        let connection_name = self.pending_settings_info.take().unwrap_or_default();
+
+        match result {
+            Ok(None) => {
+                self.status_message =
+                    "Server settings are not supported for this connection".to_string();
+            }
+            Ok(Some(settings)) => {
+                self.status_message = format!("Loaded {} settings", settings.len());
+                self.modal_state =
+                    ModalState::ServerSettings(ServerSettingsModal::new(connection_name, settings));
+            }
+            Err(e) => {
+                self.status_message = format!("Failed to load settings: {}", e);
+            }
+        }
+    }
+
+    /// Handle the result of terminating a session, reloading the list on success
+    fn handle_backend_terminated(&mut self, pid: i32, result: Result<bool, String>) {
+        self.loading.message = None;
+
+        match result {
+            Ok(true) => {
+                if matches!(self.modal_state, ModalState::ActiveSessions(_)) {
+                    if let Some(conn) = self.selected_connection_info().cloned() {
+                        self.send_fetch_active_sessions(&conn);
+                    }
+                }
+                self.status_message = format!("Terminated session {}", pid);
+            }
+            Ok(false) => {
+                self.status_message =
+                    format!("Session {} was not terminated (already ended?)", pid);
+            }
+            Err(e) => {
+                self.status_message = format!("Failed to terminate session {}: {}", pid, e);
+            }
+        }
+    }
+
+    /// Handle a batch of rows from the browsing cursor.
+    ///
+    /// Rows after the window are appended and the oldest rows dropped;
+    /// rows fetched again before the window are put in front and the last
+    /// rows dropped. The page moves to the first page of the new rows.
+    fn handle_more_rows_fetched(&mut self, result: Result<QueryResult, String>, has_more: bool) {
+        // Clear loading state
+        self.loading.executing_query = false;
+        self.loading.message = None;
+        let start = self.pending_fetch_start.take();
+
+        match result {
+            Ok(batch) => {
+                let (Some(current), Some(start)) = (self.result.as_mut(), start) else {
+                    return;
+                };
+                let fetched = batch.rows.len();
+                current.execution_time_ms += batch.execution_time_ms;
+
+                if start < self.result_offset {
+                    let mut rows = batch.rows;
+                    rows.append(&mut current.rows);
+                    current.rows = rows;
+                    self.result_offset = start;
+                    if current.rows.len() > BROWSE_WINDOW_ROWS {
+                        current.rows.truncate(BROWSE_WINDOW_ROWS);
+                        self.cursor_has_more = true;
+                    }
+                } else {
+                    self.cursor_has_more = has_more;
+                    current.rows.extend(batch.rows);
+                    let excess = current.rows.len().saturating_sub(BROWSE_WINDOW_ROWS);
+                    current.rows.drain(..excess);
+                    self.result_offset += excess;
+                }
+                // Rows seen so far, including those dropped from the window
+                current.total_rows = current
+                    .total_rows
+                    .max(self.result_offset + current.rows.len());
+
+                self.pagination.set_total_rows(current.rows.len());
+                let page = start.saturating_sub(self.result_offset) / self.pagination.page_size;
+                self.pagination.current_page = page.min(self.pagination.total_pages() - 1);
+                self.data_table_state
+                    .select(Some(self.pagination.start_index()));
+                self.status_message = if fetched == 0 {
+                    "No more rows".to_string()
+                } else {
+                    format!(
+                        "Fetched rows {}-{}{}",
+                        start + 1,
+                        start + fetched,
+                        if self.cursor_has_more {
+                            ", more available"
+                        } else {
+                            ""
+                        }
+                    )
+                };
+            }
+            Err(e) => {
+                // The worker closes a cursor that failed
+                self.cursor_open = false;
+                self.cursor_has_more = false;
+                self.status_message = format!("Failed to fetch more rows: {}", e);
+            }
+        }
+    }
+
+    /// Whether some rows of the current result are not held in memory
+    pub(crate) fn result_is_partial(&self) -> bool {
+        self.cursor_has_more || self.result_offset > 0 || self.result_truncated
+    }
+
+    /// Store the current result in the result cache if it came from browsing
+    fn cache_current_result(&mut self) {
+        if let (Some(key), Some(result)) = (&self.current_cache_key, &self.result) {
+            self.result_cache.insert(key.clone(), result.clone());
+        }
+    }
+
+    /// Show a result served from the result cache
+    fn show_cached_result(&mut self, key: CacheKey, result: QueryResult, database: &str) {
+        let row_count = result.rows.len();
+        self.pagination = Pagination::new(row_count).with_wrap(self.pagination_wrap);
+        self.result = Some(result);
+        self.result_offset = 0;
+        self.result_truncated = false;
+        self.column_order.clear();
+        self.hidden_columns.clear();
+        self.executed_query = Some(key.query.clone());
+        self.current_cache_key = Some(key);
+        self.status_message = format!("Loaded {} rows from {} (cached)", row_count, database);
+    }
+
+    /// Close the browsing cursor if the worker still holds it
+    pub(crate) fn close_browse_cursor(&mut self) {
+        if self.cursor_open {
+            if let Some(worker) = self.db_worker.as_ref() {
+                let _ = worker.send(DbCommand::CloseCursor);
+            }
+        }
+        self.cursor_open = false;
+        self.cursor_has_more = false;
+    }
+
+    /// Handle progress report for a running export
+    fn handle_progress(&mut self, bytes: u64) {
+        if self.loading.exporting {
+            self.loading.export_heartbeat();
+            let message = format!("Exporting... {}", format_size(bytes));
+            self.status_message = message.clone();
+            self.loading.message = Some(message);
+        }
+    }
+
+    /// Handle row count progress of a client-side export
+    fn handle_row_progress(&mut self, rows: u64) {
+        if self.loading.exporting {
+            self.loading.export_heartbeat();
+            let message = format!("Exporting... {} rows", rows);
+            self.status_message = message.clone();
+            self.loading.message = Some(message);
+        }
+    }
+
+    /// Handle export completed response
+    fn handle_export_completed(
+        &mut self,
+        result: Result<ExportOutput, String>,
+        target: &ExportTarget,
+    ) {
+        // Clear loading state
+        self.loading.exporting = false;
+        self.loading.message = None;
+
+        match result {
+            Ok(ExportOutput::File(bytes)) => {
+                if let ExportTarget::File(path) = target {
+                    self.status_message =
+                        format!("Exported {} to {}", format_size(bytes), path.display());
+                }
+            }
+            Ok(ExportOutput::Clipboard(text)) => {
+                self.status_message =
+                    format!("Copied {} to clipboard", format_size(text.len() as u64));
+                self.pending_clipboard = Some(text);
+            }
+            Err(e) => {
+                self.status_message = format!("Export failed: {}", e);
+            }
+        }
+    }
+
+    /// Handle reconnect response
+    fn handle_reconnected(&mut self, result: Result<(), String>, target: (usize, usize)) {
+        self.loading.message = None;
+        let name = self
+            .projects
+            .get(target.0)
+            .and_then(|p| p.connections.get(target.1))
+            .map(|c| c.name.clone())
+            .unwrap_or_default();
+
+        self.status_message = match result {
+            Ok(()) => format!("Reconnected to {}", name),
+            Err(e) => format!("Reconnect to {} failed: {}", name, e),
+        };
+    }
+
+    /// Drop everything loaded for the selected connection and ask the worker
+    /// to verify a fresh connection
+    pub(crate) fn reconnect_selected_connection(&mut self) {
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return;
+        };
+        let conn_idx = self.selected_connection_idx;
+        if self.loading.is_fetching_tables_for(conn_idx) {
+            return;
+        }
+        let Some(conn) = self
+            .projects
+            .get_mut(proj_idx)
+            .and_then(|p| p.connections.get_mut(conn_idx))
+        else {
+            return;
+        };
+
+        // Tables are listed again when the connection is next expanded
+        conn.tables.clear();
+        conn.expanded = false;
+        let conn = conn.clone();
+        self.selected_table_idx = None;
+
+        let connection = self.connection_params(&conn);
+        let name = conn.name.clone();
+        let key = connection_key(&connection);
+        self.result_cache.invalidate_connection(&key);
+        if self
+            .current_cache_key
+            .as_ref()
+            .is_some_and(|k| k.connection == key)
+        {
+            // The worker closes this cursor while reconnecting
+            self.cursor_open = false;
+            self.cursor_has_more = false;
+        }
+
+        let request_id = self.next_request_id();
+        let cmd = DbCommand::Reconnect {
+            request_id,
+            connection,
+            target: (proj_idx, conn_idx),
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.reconnect_request = Some(request_id);
+                let message = format!("Reconnecting to {}...", name);
+                self.loading.message = Some(message.clone());
+                self.status_message = message;
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+        }
+    }
+
+    /// Send a command to fetch tables asynchronously, returning its request ID once sent
     pub(crate) fn send_fetch_tables(
         &mut self,
         conn: &Connection,
         proj_idx: usize,
         conn_idx: usize,
-    ) {
+    ) -> Option<u64> {
         let request_id = self.next_request_id();
-        let connection = ConnectionParams::from_connection(conn);
+        let connection = self.connection_params(conn);
 
         let cmd = DbCommand::FetchTables {
             request_id,
             connection,
-            schema: Some("public".to_string()),
+            // With a session search_path, the server resolves the schema
+            schema: match conn.search_path {
+                Some(_) => None,
+                None => conn.default_schema.clone(),
+            },
+            include_system: self.show_system_objects,
+            exact_row_counts: self.exact_table_row_counts,
             target: (proj_idx, conn_idx),
         };
 
         if let Some(worker) = self.db_worker.as_ref() {
             if worker.send(cmd).is_ok() {
+                self.metadata_requests.push(request_id);
                 self.loading.start_fetching_tables(conn_idx);
-            } else {
-                self.status_message = "Failed to send command to DB worker".to_string();
+                return Some(request_id);
             }
+            self.status_message = "Failed to send command to DB worker".to_string();
         } else {
             self.status_message = "DB worker not initialized".to_string();
         }
+        None
     }
 
     /// Send a command to fetch table details asynchronously
@@ -214,7 +967,7 @@ impl App {
         table_idx: usize,
     ) {
         let request_id = self.next_request_id();
-        let connection = ConnectionParams::from_connection(conn);
+        let connection = self.connection_params(conn);
 
         let cmd = DbCommand::FetchTableDetails {
             request_id,
@@ -226,6 +979,7 @@ impl App {
 
         if let Some(worker) = self.db_worker.as_ref() {
             if worker.send(cmd).is_ok() {
+                self.metadata_requests.push(request_id);
                 self.loading
                     .start_fetching_details(proj_idx, conn_idx, table_idx);
             } else {
@@ -236,14 +990,44 @@ impl App {
         }
     }
 
-    /// Send a command to execute a query asynchronously
+    /// Send a command to fetch the first rows of a table for its preview.
+    ///
+    /// Previews are fetched in passing, so a missing worker is not reported.
+    pub(crate) fn send_preview_table(
+        &mut self,
+        conn: &Connection,
+        table_name: &str,
+        schema: Option<&str>,
+        key: PreviewKey,
+    ) {
+        let request_id = self.next_request_id();
+        let cmd = DbCommand::PreviewTable {
+            request_id,
+            connection: self.connection_params(conn),
+            table_name: table_name.to_string(),
+            schema: schema.map(str::to_string),
+            limit: PREVIEW_ROWS,
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.table_previews.start(request_id, key);
+            }
+        }
+    }
+
+    /// Send a command to execute a query asynchronously, fetching all rows
     pub(crate) fn send_execute_query(&mut self, conn: &Connection, query: &str, proj_idx: usize) {
         let request_id = self.next_request_id();
-        let connection = ConnectionParams::from_connection(conn);
+        let connection = self.connection_params(conn);
+
+        // An explicit query replaces any browsing session
+        self.close_browse_cursor();
 
         // Store query info for history
         self.pending_query_info =
             Some((conn.name.clone(), conn.database.clone(), query.to_string()));
+        self.pending_cache_key = Some(CacheKey::new(&connection, query));
 
         let cmd = DbCommand::ExecuteQuery {
             request_id,
@@ -254,19 +1038,337 @@ impl App {
 
         if let Some(worker) = self.db_worker.as_ref() {
             if worker.send(cmd).is_ok() {
+                self.query_request = Some(request_id);
                 self.loading.start_executing_query();
             } else {
                 self.status_message = "Failed to send command to DB worker".to_string();
                 self.pending_query_info = None;
+                self.pending_cache_key = None;
             }
         } else {
             self.status_message = "DB worker not initialized".to_string();
             self.pending_query_info = None;
+            self.pending_cache_key = None;
         }
     }
 
-    /// Fetch table details (columns, indexes, foreign keys, constraints) if not already loaded
-    pub(crate) fn fetch_table_details_if_needed(&mut self, proj_idx: usize) {
+    /// Send a command to fetch the database size summary asynchronously
+    pub(crate) fn send_fetch_database_stats(&mut self, conn: &Connection) {
+        if self.pending_stats_info.is_some() {
+            return;
+        }
+
+        let request_id = self.next_request_id();
+        let connection = self.connection_params(conn);
+
+        let cmd = DbCommand::FetchDatabaseStats {
+            request_id,
+            connection,
+            limit: LARGEST_TABLES_LIMIT,
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.pending_stats_info = Some((conn.name.clone(), conn.database.clone()));
+                self.loading.message = Some("Loading database size...".to_string());
+                self.status_message = "Loading database size...".to_string();
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+        }
+    }
+
+    /// Send a command to check `query` on the server without running it
+    pub(crate) fn send_validate_query(&mut self, conn: &Connection, query: &str) {
+        let request_id = self.next_request_id();
+        let cmd = DbCommand::ValidateQuery {
+            request_id,
+            connection: self.connection_params(conn),
+            query: query.to_string(),
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.loading.message = Some("Validating query...".to_string());
+                self.status_message = "Validating query...".to_string();
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+        }
+    }
+
+    /// Send a command to list the sessions connected to the database asynchronously
+    pub(crate) fn send_fetch_active_sessions(&mut self, conn: &Connection) {
+        if self.pending_sessions_info.is_some() {
+            return;
+        }
+
+        let request_id = self.next_request_id();
+        let cmd = DbCommand::FetchActiveSessions {
+            request_id,
+            connection: self.connection_params(conn),
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.pending_sessions_info = Some((conn.name.clone(), conn.database.clone()));
+                self.loading.message = Some("Loading sessions...".to_string());
+                self.status_message = "Loading sessions...".to_string();
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+        }
+    }
+
+    /// Send a command to list the roles defined on the server asynchronously
+    pub(crate) fn send_fetch_roles(&mut self, conn: &Connection) {
+        if self.pending_roles_info.is_some() {
+            return;
+        }
+
+        let request_id = self.next_request_id();
+        let cmd = DbCommand::FetchRoles {
+            request_id,
+            connection: self.connection_params(conn),
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.pending_roles_info = Some(conn.name.clone());
+                self.loading.message = Some("Loading roles...".to_string());
+                self.status_message = "Loading roles...".to_string();
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+        }
+    }
+
+    /// Send a command to list the session's run-time parameters asynchronously
+    pub(crate) fn send_fetch_settings(&mut self, conn: &Connection) {
+        if self.pending_settings_info.is_some() {
+            return;
+        }
+
+        let request_id = self.next_request_id();
+        let cmd = DbCommand::FetchSettings {
+            request_id,
+            connection: self.connection_params(conn),
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.pending_settings_info = Some(conn.name.clone());
+                self.loading.message = Some("Loading settings...".to_string());
+                self.status_message = "Loading settings...".to_string();
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+        }
+    }
+
+    /// Send a command to terminate the session served by backend `pid`
+    pub(crate) fn send_terminate_backend(&mut self, pid: i32) {
+        if let ModalState::ActiveSessions(modal) = &mut self.modal_state {
+            modal.confirm_pid = None;
+        }
+        let Some(conn) = self.selected_connection_info().cloned() else {
+            return;
+        };
+
+        let request_id = self.next_request_id();
+        let cmd = DbCommand::TerminateBackend {
+            request_id,
+            connection: self.connection_params(&conn),
+            pid,
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.loading.message = Some(format!("Terminating session {}...", pid));
+                self.status_message = format!("Terminating session {}...", pid);
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+        }
+    }
+
+    /// Open a server-side cursor for browsing `query` and fetch its first batch.
+    ///
+    /// Only a batch of rows is transferred up front; further batches are
+    /// fetched on demand as the user pages past the loaded rows. A fresh
+    /// cached result for the same query is shown without touching the database.
+    pub(crate) fn send_open_cursor(&mut self, conn: &Connection, query: &str) {
+        let connection = self.connection_params(conn);
+        let cache_key = CacheKey::new(&connection, query);
+        if let Some(cached) = self.result_cache.get(&cache_key).cloned() {
+            self.close_browse_cursor();
+            self.show_cached_result(cache_key, cached, &conn.database);
+            return;
+        }
+
+        let request_id = self.next_request_id();
+
+        // Store query info for history
+        self.pending_query_info =
+            Some((conn.name.clone(), conn.database.clone(), query.to_string()));
+        self.pending_cache_key = Some(cache_key);
+        // Opening a cursor replaces the previous one in the worker
+        self.cursor_open = false;
+        self.cursor_has_more = false;
+
+        let cmd = DbCommand::OpenCursor {
+            request_id,
+            connection,
+            query: query.to_string(),
+            fetch_size: BROWSE_FETCH_SIZE,
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.query_request = Some(request_id);
+                self.loading.start_executing_query();
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+                self.pending_query_info = None;
+                self.pending_cache_key = None;
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+            self.pending_query_info = None;
+            self.pending_cache_key = None;
+        }
+    }
+
+    /// Fetch the batch of cursor rows that follows the window
+    pub(crate) fn fetch_rows_after_window(&mut self) {
+        let held = self.result.as_ref().map_or(0, |r| r.rows.len());
+        self.send_fetch_cursor(self.result_offset + held, BROWSE_FETCH_SIZE);
+    }
+
+    /// Fetch again the batch of cursor rows before the window
+    pub(crate) fn fetch_rows_before_window(&mut self) {
+        let start = self.result_offset.saturating_sub(BROWSE_FETCH_SIZE);
+        self.send_fetch_cursor(start, self.result_offset - start);
+    }
+
+    /// Fetch `fetch_size` rows of the browsing cursor from row `start`
+    fn send_fetch_cursor(&mut self, start: usize, fetch_size: usize) {
+        if self.loading.executing_query {
+            return;
+        }
+
+        let request_id = self.next_request_id();
+        let cmd = DbCommand::FetchCursor {
+            request_id,
+            start,
+            fetch_size,
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.pending_fetch_start = Some(start);
+                self.query_request = Some(request_id);
+                self.loading.start_executing_query();
+                self.loading.message = Some("Fetching more rows...".to_string());
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+        }
+    }
+
+    /// Send a command to export a query's result to a file or the clipboard asynchronously
+    pub(crate) fn send_export(
+        &mut self,
+        conn: &Connection,
+        query: &str,
+        target: ExportTarget,
+        config: ExportConfig,
+    ) {
+        let request_id = self.next_request_id();
+        let connection = self.connection_params(conn);
+
+        let cmd = DbCommand::ExportQuery {
+            request_id,
+            connection,
+            query: query.to_string(),
+            target,
+            config,
+        };
+
+        if let Some(worker) = self.db_worker.as_ref() {
+            if worker.send(cmd).is_ok() {
+                self.loading.start_exporting();
+            } else {
+                self.status_message = "Failed to send command to DB worker".to_string();
+            }
+        } else {
+            self.status_message = "DB worker not initialized".to_string();
+        }
+    }
+
+    /// Forget the query request being answered; false if it timed out
+    fn finish_query_request(&mut self, request_id: u64) -> bool {
+        if self.query_request == Some(request_id) {
+            self.query_request = None;
+        }
+        !self.cancelled_requests.remove(&request_id)
+    }
+
+    /// Forget a table list / details request; false if it was cancelled
+    fn finish_metadata_request(&mut self, request_id: u64) -> bool {
+        self.metadata_requests.retain(|&id| id != request_id);
+        !self.cancelled_requests.remove(&request_id)
+    }
+
+    /// Stop waiting for table lists and table details (Esc while loading).
+    ///
+    /// The worker still finishes the queries, but their responses are dropped.
+    /// A connection that was being expanded is collapsed again so expanding it
+    /// retries the fetch.
+    pub(crate) fn cancel_metadata_fetch(&mut self) {
+        if self.metadata_requests.is_empty() {
+            return;
+        }
+        self.cancelled_requests
+            .extend(self.metadata_requests.drain(..));
+        self.bulk_refresh = None;
+
+        if let (Some(conn_idx), SidebarMode::Connections(proj_idx)) =
+            (self.loading.fetching_tables, self.sidebar_mode)
+        {
+            let conn = self
+                .projects
+                .get_mut(proj_idx)
+                .and_then(|p| p.connections.get_mut(conn_idx));
+            if let Some(conn) = conn.filter(|c| c.tables.is_empty()) {
+                conn.expanded = false;
+                if self.selected_connection_idx == conn_idx {
+                    self.selected_table_idx = None;
+                }
+            }
+        }
+        self.loading.fetching_tables = None;
+        self.loading.fetching_details = None;
+        self.loading.message = None;
+        self.status_message = "Cancelled loading".to_string();
+    }
+
+    /// Fetch table details (columns, indexes, foreign keys, constraints) if not already loaded
+    pub(crate) fn fetch_table_details_if_needed(&mut self, proj_idx: usize) {
         // Capture indices at the start to avoid race conditions
         let conn_idx = self.selected_connection_idx;
         let Some(table_idx) = self.selected_table_idx else {
@@ -289,17 +1391,679 @@ impl App {
         }
 
         let table_name = table.name.clone();
-        let schema = table.schema.clone();
+        let schema = table.schema.clone().unwrap_or_else(|| conn.schema());
         let conn_clone = conn.clone();
 
         // Send async command to fetch table details
         self.send_fetch_table_details(
             &conn_clone,
             &table_name,
-            schema.as_deref(),
+            Some(&schema),
             proj_idx,
             conn_idx,
             table_idx,
         );
     }
 }
+
+/// Summarize server notices for the status bar, e.g. `1 notice: hi`.
+///
+/// Only the first notice is shown; the rest are counted.
+fn notice_summary(notices: &[String]) -> Option<String> {
+    let first = notices.first()?;
+    Some(match notices.len() {
+        1 => format!("1 notice: {}", first),
+        n => format!("{} notices: {} (+{} more)", n, first, n - 1),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::TablePreview;
+    use crate::message::Message;
+    use crate::model::{Project, QueryOutcome};
+
+    fn app_expanding_connection() -> App {
+        let mut project = Project::new("main");
+        project.connections = vec![Connection {
+            name: "local".to_string(),
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "user".to_string(),
+            password: String::new(),
+            password_env: None,
+            database: "db".to_string(),
+            tables: vec![],
+            expanded: true,
+            default_schema: None,
+            color: None,
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
+            search_path: None,
+        }];
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.metadata_requests.push(7);
+        app.loading.start_fetching_tables(0);
+        app
+    }
+
+    #[test]
+    fn test_connection_params_statement_timeout() {
+        let mut app = app_expanding_connection();
+        app.apply_settings(&Settings {
+            statement_timeout_secs: 30,
+            ..Settings::default()
+        });
+        let mut conn = app.projects[0].connections[0].clone();
+        assert_eq!(
+            app.connection_params(&conn).statement_timeout_secs,
+            Some(30)
+        );
+
+        conn.query_timeout_secs = Some(5);
+        assert_eq!(app.connection_params(&conn).statement_timeout_secs, Some(5));
+        conn.query_timeout_secs = Some(0);
+        assert_eq!(app.connection_params(&conn).statement_timeout_secs, None);
+    }
+
+    fn tables_loaded(request_id: u64) -> DbResponse {
+        DbResponse::TablesLoaded {
+            request_id,
+            result: Ok(vec![Table::new("users")]),
+            target: (0, 0),
+        }
+    }
+
+    #[test]
+    fn test_stopped_worker_is_restarted() {
+        let mut worker = spawn_db_worker(ConnectRetry::default());
+        // Dropping the command sender stops the worker
+        worker.command_tx = std::sync::mpsc::channel().0;
+        while worker.is_alive() {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        let mut app = app_expanding_connection();
+        app.set_db_worker(worker);
+        app.process_db_responses();
+
+        assert!(!app.loading.is_loading());
+        assert!(app.metadata_requests.is_empty());
+        assert!(app.status_message.starts_with("Database worker stopped"));
+        assert!(app.db_worker.as_ref().is_some_and(|w| w.is_alive()));
+    }
+
+    #[test]
+    fn test_selected_table_preview_is_fetched_once() {
+        let mut app = app_expanding_connection();
+        app.handle_db_response(tables_loaded(7));
+        let mut worker = spawn_db_worker(ConnectRetry::default());
+        let (command_tx, command_rx) = std::sync::mpsc::channel();
+        worker.command_tx = command_tx;
+        app.set_db_worker(worker);
+
+        let previews = |rx: &std::sync::mpsc::Receiver<DbCommand>| {
+            rx.try_iter()
+                .filter_map(|cmd| match cmd {
+                    DbCommand::PreviewTable {
+                        request_id,
+                        table_name,
+                        limit,
+                        ..
+                    } => Some((request_id, table_name, limit)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        app.update(Message::NavigateDown);
+        let sent = previews(&command_rx);
+        let [(request_id, ref table_name, limit)] = sent[..] else {
+            panic!("expected one preview request");
+        };
+        assert_eq!((table_name.as_str(), limit), ("users", PREVIEW_ROWS));
+        assert!(matches!(
+            app.selected_table_preview(),
+            Some(TablePreview::Loading)
+        ));
+
+        app.handle_db_response(DbResponse::TablePreviewLoaded {
+            request_id,
+            result: Ok(QueryResult {
+                columns: vec!["id".to_string()],
+                column_types: Vec::new(),
+                rows: vec![vec!["1".to_string()]],
+                execution_time_ms: 0,
+                total_rows: 1,
+                rows_affected: None,
+            }),
+        });
+        assert!(matches!(
+            app.selected_table_preview(),
+            Some(TablePreview::Loaded(_))
+        ));
+
+        // Coming back to the table uses the cached preview
+        app.update(Message::NavigateUp);
+        app.update(Message::NavigateDown);
+        assert!(previews(&command_rx).is_empty());
+    }
+
+    #[test]
+    fn test_stuck_request_times_out() {
+        let mut app = app_expanding_connection();
+        let now = Instant::now();
+
+        app.expire_stuck_requests(now);
+        assert!(app.loading.is_loading());
+
+        let timeout = app.request_timeout.unwrap();
+        app.expire_stuck_requests(now + timeout + Duration::from_secs(1));
+        assert!(!app.loading.is_loading());
+        assert!(app.status_message.starts_with("Timed out"));
+        assert!(app.status_message.ends_with("(table list)"));
+
+        // A response arriving after the timeout is dropped
+        app.handle_db_response(tables_loaded(7));
+        assert!(app.projects[0].connections[0].tables.is_empty());
+    }
+
+    #[test]
+    fn test_failed_cursor_batch_is_not_cached() {
+        let mut app = App::new(vec![]);
+        let key = CacheKey {
+            connection: "user@localhost:5432/db".to_string(),
+            query: "SELECT * FROM \"users\"".to_string(),
+        };
+        app.pending_cache_key = Some(key.clone());
+        app.handle_db_response(DbResponse::RowsFetched {
+            request_id: 1,
+            result: Ok(browse_batch(0..BROWSE_FETCH_SIZE)),
+            first_batch: true,
+            has_more: true,
+        });
+        assert_eq!(app.current_cache_key, Some(key.clone()));
+
+        app.pending_fetch_start = Some(BROWSE_FETCH_SIZE);
+        app.handle_db_response(DbResponse::RowsFetched {
+            request_id: 2,
+            result: Err("connection reset".to_string()),
+            first_batch: false,
+            has_more: false,
+        });
+        assert!(!app.cursor_has_more);
+        assert!(app.result_cache.get(&key).is_none());
+    }
+
+    #[test]
+    fn test_timed_out_query_response_is_ignored() {
+        let mut app = App::new(vec![]);
+        let sent = Instant::now();
+        app.pending_query_info = Some(("local".into(), "db".into(), "SELECT 1".into()));
+        app.query_request = Some(3);
+        app.loading.start_executing_query();
+
+        let timeout = app.request_timeout.unwrap();
+        app.expire_stuck_requests(sent + timeout + Duration::from_secs(1));
+        assert!(!app.loading.is_loading());
+        assert!(app.status_message.ends_with("(query)"));
+
+        // The query finishing later does not replace the result
+        app.handle_db_response(DbResponse::QueryExecuted {
+            request_id: 3,
+            result: Ok(browse_batch(0..1)),
+            notices: Vec::new(),
+            project_idx: 0,
+        });
+        assert!(app.result.is_none());
+        assert!(app.cancelled_requests.is_empty());
+        assert!(app.query_request.is_none());
+    }
+
+    #[test]
+    fn test_cancelled_table_fetch_is_ignored() {
+        let mut app = app_expanding_connection();
+
+        app.cancel_metadata_fetch();
+        assert!(!app.loading.is_loading());
+        assert!(!app.projects[0].connections[0].expanded);
+        assert_eq!(app.status_message, "Cancelled loading");
+
+        // The late response does not fill in the collapsed connection
+        app.handle_db_response(tables_loaded(7));
+        assert!(app.projects[0].connections[0].tables.is_empty());
+        assert!(app.cancelled_requests.is_empty());
+    }
+
+    #[test]
+    fn test_table_fetch_applies_without_cancel() {
+        let mut app = app_expanding_connection();
+
+        app.handle_db_response(tables_loaded(7));
+        assert_eq!(app.projects[0].connections[0].tables.len(), 1);
+        assert!(app.metadata_requests.is_empty());
+        assert!(app.loading.fetching_tables.is_none());
+    }
+
+    #[test]
+    fn test_query_notices_are_shown_in_status() {
+        let mut app = App::new(vec![]);
+        app.pending_query_info = Some(("local".into(), "db".into(), "DO $$ ... $$".into()));
+        app.handle_db_response(DbResponse::QueryExecuted {
+            request_id: 1,
+            result: Ok(QueryResult {
+                columns: vec![],
+                column_types: Vec::new(),
+                rows: vec![],
+                execution_time_ms: 1,
+                total_rows: 0,
+                rows_affected: None,
+            }),
+            notices: vec!["hi".to_string()],
+            project_idx: 0,
+        });
+        assert_eq!(app.status_message, "Fetched 0 rows from db | 1 notice: hi");
+
+        app.handle_db_response(DbResponse::QueryExecuted {
+            request_id: 2,
+            result: Err("boom".to_string()),
+            notices: vec!["a".to_string(), "WARNING: b".to_string()],
+            project_idx: 0,
+        });
+        assert_eq!(app.status_message, "Query failed | 2 notices: a (+1 more)");
+    }
+
+    #[test]
+    fn test_query_outcome_follows_last_query() {
+        let mut app = App::new(vec![]);
+        assert_eq!(app.query_outcome(), None);
+
+        app.pending_query_info = Some(("local".into(), "db".into(), "SELECT x".into()));
+        app.handle_db_response(DbResponse::QueryExecuted {
+            request_id: 1,
+            result: Err("column \"x\" does not exist".to_string()),
+            notices: Vec::new(),
+            project_idx: 0,
+        });
+        assert_eq!(
+            app.query_outcome(),
+            Some(QueryOutcome::Failed(
+                "column \"x\" does not exist".to_string()
+            ))
+        );
+
+        app.pending_query_info = Some(("local".into(), "db".into(), "DELETE FROM t".into()));
+        app.handle_db_response(DbResponse::QueryExecuted {
+            request_id: 2,
+            result: Ok(QueryResult {
+                columns: vec![],
+                column_types: Vec::new(),
+                rows: vec![],
+                execution_time_ms: 3,
+                total_rows: 0,
+                rows_affected: Some(2),
+            }),
+            notices: Vec::new(),
+            project_idx: 0,
+        });
+        assert_eq!(
+            app.query_outcome(),
+            Some(QueryOutcome::Affected {
+                rows: 2,
+                execution_time_ms: 3
+            })
+        );
+        assert!(app.query_error.is_none());
+        assert_eq!(app.status_message, "Statement ran on db");
+    }
+
+    #[test]
+    fn test_new_result_keeps_its_query_and_column_order() {
+        let mut app = App::new(vec![]);
+        // Columns moved and hidden in the previous result
+        app.column_order = vec![1, 0];
+        app.hidden_columns.insert(0);
+        let sql = "SELECT * FROM \"users\" WHERE \"id\" > 10 LIMIT 1000";
+        app.pending_query_info = Some(("local".into(), "db".into(), sql.into()));
+        app.handle_db_response(DbResponse::QueryExecuted {
+            request_id: 1,
+            result: Ok(QueryResult {
+                columns: vec!["id".to_string()],
+                column_types: Vec::new(),
+                rows: vec![vec!["11".to_string()]],
+                execution_time_ms: 1,
+                total_rows: 1,
+                rows_affected: None,
+            }),
+            notices: Vec::new(),
+            project_idx: 0,
+        });
+        assert_eq!(app.executed_query.as_deref(), Some(sql));
+        assert!(app.column_order.is_empty());
+        assert!(app.hidden_columns.is_empty());
+
+        app.update(Message::ToggleExecutedQuery);
+        assert!(app.show_executed_query);
+        assert_eq!(app.status_message, "Executed SQL shown");
+
+        app.pending_query_info = Some(("local".into(), "db".into(), "SELECT x".into()));
+        app.handle_db_response(DbResponse::QueryExecuted {
+            request_id: 2,
+            result: Err("column \"x\" does not exist".to_string()),
+            notices: Vec::new(),
+            project_idx: 0,
+        });
+        assert_eq!(app.executed_query, None);
+    }
+
+    fn browse_batch(rows: std::ops::Range<usize>) -> QueryResult {
+        QueryResult {
+            columns: vec!["id".to_string()],
+            column_types: Vec::new(),
+            rows: rows.map(|i| vec![i.to_string()]).collect(),
+            execution_time_ms: 1,
+            total_rows: 0,
+            rows_affected: None,
+        }
+    }
+
+    #[test]
+    fn test_browsed_rows_stay_within_window() {
+        let mut app = App::new(vec![]);
+        app.result = Some(browse_batch(0..BROWSE_WINDOW_ROWS));
+        app.pagination.set_total_rows(BROWSE_WINDOW_ROWS);
+        app.cursor_open = true;
+        app.cursor_has_more = true;
+
+        // Paging past the window drops its first rows
+        app.pending_fetch_start = Some(BROWSE_WINDOW_ROWS);
+        app.handle_db_response(DbResponse::RowsFetched {
+            request_id: 1,
+            result: Ok(browse_batch(
+                BROWSE_WINDOW_ROWS..BROWSE_WINDOW_ROWS + BROWSE_FETCH_SIZE,
+            )),
+            first_batch: false,
+            has_more: true,
+        });
+        let result = app.result.as_ref().unwrap();
+        assert_eq!(result.rows.len(), BROWSE_WINDOW_ROWS);
+        assert_eq!(result.rows[0][0], BROWSE_FETCH_SIZE.to_string());
+        assert_eq!(result.total_rows, BROWSE_WINDOW_ROWS + BROWSE_FETCH_SIZE);
+        assert_eq!(app.result_offset, BROWSE_FETCH_SIZE);
+        assert!(app.result_is_partial());
+        let first_new = (BROWSE_WINDOW_ROWS - BROWSE_FETCH_SIZE) / app.pagination.page_size;
+        assert_eq!(app.pagination.current_page, first_new);
+
+        // Dropped rows are fetched again and the window's last rows dropped
+        app.pending_fetch_start = Some(0);
+        app.handle_db_response(DbResponse::RowsFetched {
+            request_id: 2,
+            result: Ok(browse_batch(0..BROWSE_FETCH_SIZE)),
+            first_batch: false,
+            has_more: true,
+        });
+        let result = app.result.as_ref().unwrap();
+        assert_eq!(result.rows.len(), BROWSE_WINDOW_ROWS);
+        assert_eq!(result.rows[0][0], "0");
+        assert_eq!(
+            result.rows[BROWSE_WINDOW_ROWS - 1][0],
+            (BROWSE_WINDOW_ROWS - 1).to_string()
+        );
+        assert_eq!(app.result_offset, 0);
+        assert!(app.cursor_has_more);
+        assert_eq!(app.pagination.current_page, 0);
+    }
+
+    #[test]
+    fn test_generate_select_waits_for_columns() {
+        use crate::model::schema::Column;
+
+        let mut app = app_expanding_connection();
+        app.loading.clear();
+        app.projects[0].connections[0].tables = vec![Table::new("users")];
+        app.selected_table_idx = Some(0);
+
+        app.generate_select();
+        assert_eq!(
+            app.pending_table_action,
+            Some(((0, 0, 0), Message::GenerateSelect))
+        );
+        assert!(app.query.is_empty());
+
+        let details = Table::new("users").with_columns(vec![
+            Column::new("id", "integer"),
+            Column::new("email", "text"),
+        ]);
+        app.handle_table_details_loaded(Ok(details), (0, 0, 0));
+        assert_eq!(app.query, "SELECT \"id\", \"email\" FROM \"users\";");
+        assert!(app.pending_table_action.is_none());
+    }
+
+    #[test]
+    fn test_filter_modal_builds_where_clause() {
+        use crate::model::schema::Column;
+
+        let mut app = app_expanding_connection();
+        app.loading.clear();
+        app.projects[0].connections[0].tables = vec![Table::new("users")];
+        app.selected_table_idx = Some(0);
+
+        // The modal opens once the columns have loaded
+        app.update(Message::OpenFilterModal);
+        assert!(matches!(app.modal_state, ModalState::None));
+        let details = Table::new("users").with_columns(vec![
+            Column::new("id", "integer"),
+            Column::new("name", "text"),
+        ]);
+        app.handle_table_details_loaded(Ok(details), (0, 0, 0));
+        assert!(matches!(app.modal_state, ModalState::Filter(_)));
+
+        app.update(Message::ModalOptionNext);
+        app.update(Message::ModalNextField);
+        app.update(Message::ModalNextField);
+        for c in "O'Hara".chars() {
+            app.update(Message::ModalInputChar(c));
+        }
+        app.update(Message::ModalConfirm);
+
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(
+            app.query,
+            "SELECT * FROM \"users\" WHERE \"name\" = 'O''Hara' LIMIT 1000;"
+        );
+    }
+
+    #[test]
+    fn test_order_modal_composes_with_filter() {
+        use crate::model::schema::Column;
+
+        let mut app = app_expanding_connection();
+        app.loading.clear();
+        app.projects[0].connections[0].tables = vec![Table::new("users").with_columns(vec![
+            Column::new("id", "integer"),
+            Column::new("name", "text"),
+        ])];
+        app.projects[0].connections[0].tables[0].details_loaded = true;
+        app.selected_table_idx = Some(0);
+        app.activate();
+
+        app.update(Message::OpenFilterModal);
+        app.update(Message::ModalNextField);
+        app.update(Message::ModalNextField);
+        app.update(Message::ModalInputChar('1'));
+        app.update(Message::ModalConfirm);
+        app.loading.clear();
+
+        // Order by name descending
+        app.update(Message::OpenOrderModal);
+        app.update(Message::ModalOptionNext);
+        app.update(Message::ModalOptionNext);
+        app.update(Message::ModalNextField);
+        app.update(Message::ModalOptionNext);
+        app.update(Message::ModalConfirm);
+        assert_eq!(
+            app.query,
+            "SELECT * FROM \"users\" WHERE \"id\" = '1' ORDER BY \"name\" DESC LIMIT 1000;"
+        );
+
+        // Reopening the picker starts from the active order
+        app.update(Message::OpenOrderModal);
+        let ModalState::Order(modal) = &app.modal_state else {
+            panic!("expected the order modal");
+        };
+        assert_eq!(modal.selected_column(), Some("name"));
+
+        // Opening the table again drops the filter and order
+        app.modal_state = ModalState::None;
+        app.loading.clear();
+        app.activate();
+        assert_eq!(app.query, "SELECT * FROM \"users\" LIMIT 1000;");
+    }
+
+    #[test]
+    fn test_restored_table_is_selected_when_tables_load() {
+        let mut app = app_expanding_connection();
+        app.restore_table = Some(((0, 0), "users".to_string()));
+
+        app.handle_db_response(tables_loaded(7));
+
+        assert_eq!(app.selected_table_idx, Some(0));
+        assert!(app.restore_table.is_none());
+    }
+
+    #[test]
+    fn test_refresh_all_reports_progress() {
+        let mut app = app_expanding_connection();
+        app.metadata_requests.push(8);
+        app.bulk_refresh = Some(BulkRefresh::new(vec![7, 8]));
+
+        app.handle_db_response(tables_loaded(7));
+        assert_eq!(app.status_message, "Refreshing tables 1/2");
+        assert_eq!(app.projects[0].connections[0].tables.len(), 1);
+
+        app.handle_db_response(DbResponse::TablesLoaded {
+            request_id: 8,
+            result: Err("connection refused".to_string()),
+            target: (0, 0),
+        });
+        assert!(app.bulk_refresh.is_none());
+        assert!(app
+            .status_message
+            .ends_with("| Refreshed tables 1/2 (1 failed)"));
+    }
+
+    #[test]
+    fn test_refresh_all_without_expanded_connections() {
+        let mut app = app_expanding_connection();
+        app.projects[0].connections[0].expanded = false;
+        app.update(Message::RefreshAll);
+        assert!(app.bulk_refresh.is_none());
+        assert_eq!(app.status_message, "No expanded connections to refresh");
+    }
+
+    #[test]
+    fn test_failed_expand_explains_connection_error() {
+        use crate::db::ProviderError;
+
+        let mut app = app_expanding_connection();
+        let error = ProviderError::ConnectionFailed(
+            "error connecting to server: Connection refused (os error 111)".to_string(),
+        );
+        app.handle_db_response(DbResponse::TablesLoaded {
+            request_id: 7,
+            result: Err(error.to_string()),
+            target: (0, 0),
+        });
+
+        assert!(!app.projects[0].connections[0].expanded);
+        assert!(
+            app.status_message
+                .starts_with("Cannot open local: Connection refused: "),
+            "{}",
+            app.status_message
+        );
+        assert!(app.status_message.contains("server is running"));
+    }
+
+    #[test]
+    fn test_roles_modal_reports_hidden_attributes() {
+        use crate::model::DatabaseRole;
+
+        let mut app = app_expanding_connection();
+        app.pending_roles_info = Some("local".to_string());
+        app.handle_db_response(DbResponse::RolesLoaded {
+            request_id: 8,
+            result: Ok(Some(vec![DatabaseRole::name_only("app")])),
+        });
+
+        let ModalState::Roles(modal) = &app.modal_state else {
+            panic!("expected the roles modal");
+        };
+        assert_eq!(modal.connection_name, "local");
+        assert_eq!(modal.roles[0].name, "app");
+        assert!(app.pending_roles_info.is_none());
+        assert!(app.status_message.starts_with("Role attributes are hidden"));
+
+        app.modal_state = ModalState::None;
+        app.handle_db_response(DbResponse::RolesLoaded {
+            request_id: 9,
+            result: Ok(None),
+        });
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(
+            app.status_message,
+            "Role list is not supported for this connection"
+        );
+    }
+
+    #[test]
+    fn test_server_settings_open_searchable_modal() {
+        use crate::model::ServerSetting;
+
+        let mut app = app_expanding_connection();
+        app.pending_settings_info = Some("local".to_string());
+        app.handle_db_response(DbResponse::SettingsLoaded {
+            request_id: 8,
+            result: Ok(Some(vec![ServerSetting {
+                name: "TimeZone".to_string(),
+                setting: "UTC".to_string(),
+                description: "Sets the time zone for displaying and interpreting time stamps."
+                    .to_string(),
+            }])),
+        });
+        assert_eq!(app.status_message, "Loaded 1 settings");
+
+        app.update(Message::ModalInputChar('z'));
+        let ModalState::ServerSettings(modal) = &app.modal_state else {
+            panic!("expected the server settings modal");
+        };
+        assert_eq!(modal.connection_name, "local");
+        assert_eq!(modal.query, "z");
+        assert_eq!(
+            modal.selected_setting().map(|s| s.setting.as_str()),
+            Some("UTC")
+        );
+    }
+
+    #[test]
+    fn test_query_validation_reports_result() {
+        let mut app = app_expanding_connection();
+        app.handle_db_response(DbResponse::QueryValidated {
+            request_id: 8,
+            result: Ok(()),
+        });
+        assert_eq!(app.status_message, "Query is valid");
+
+        app.handle_db_response(DbResponse::QueryValidated {
+            request_id: 9,
+            result: Err("column \"nme\" does not exist".to_string()),
+        });
+        assert_eq!(
+            app.status_message,
+            "Query is invalid: column \"nme\" does not exist"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Export handlers (write the current result to a file or the clipboard)
+
+use std::path::PathBuf;
+
+use crate::app::cache::is_mutating_statement;
+use crate::app::{App, ConfirmCopyModal, CopyKind, ModalState};
+use crate::db::trim_statement;
+use crate::export::{format_text, ExportConfig, ExportFormat, ExportTarget};
+use crate::ui::utils::format_number;
+
+impl App {
+    /// Export the result of the current query to a file in the working directory.
+    ///
+    /// The query is re-run by the DB worker so the whole result is exported,
+    /// not just the rows held in memory.
+    pub(crate) fn export_result(&mut self, format: ExportFormat) {
+        self.start_export(format, false);
+    }
+
+    /// Copy the result of the current query to the clipboard in `format`.
+    ///
+    /// The whole result goes through the terminal, so results above
+    /// `clipboard_confirm_rows`, or not fully fetched yet, ask first unless
+    /// `confirmed` is set.
+    pub(crate) fn copy_result(&mut self, format: ExportFormat, confirmed: bool) {
+        if let Some(result) = &self.result {
+            let rows = result.total_rows;
+            let more_rows = self.cursor_has_more;
+            if !confirmed && (more_rows || rows > self.clipboard_confirm_rows) {
+                self.modal_state =
+                    ModalState::ConfirmCopy(ConfirmCopyModal::new(CopyKind::Csv, rows, more_rows));
+                return;
+            }
+        }
+        self.start_export(format, true);
+    }
+
+    /// Copy the rows held in memory to the clipboard as a JSON array.
+    ///
+    /// Unlike [`Self::copy_result`] the query is not re-run, so this is instant
+    /// but only covers the rows held in memory. Results above
+    /// `clipboard_confirm_rows` ask first unless `confirmed` is set.
+    pub(crate) fn copy_result_json(&mut self, confirmed: bool) {
+        let Some(result) = &self.result else {
+            self.status_message = "Nothing to copy".to_string();
+            return;
+        };
+        let rows = result.rows.len();
+        if !confirmed && rows > self.clipboard_confirm_rows {
+            self.modal_state =
+                ModalState::ConfirmCopy(ConfirmCopyModal::new(CopyKind::Json, rows, false));
+            return;
+        }
+
+        let config = ExportConfig {
+            format: ExportFormat::Json,
+            ..ExportConfig::default()
+        };
+        match format_text(result, &config) {
+            Ok(text) => {
+                self.pending_clipboard = Some(text);
+                let noun = if rows == 1 { "row" } else { "rows" };
+                self.status_message = format!("Copied {} {} as JSON", format_number(rows), noun);
+                if self.result_is_partial() {
+                    self.status_message.push_str(" (rows in memory only)");
+                }
+            }
+            Err(e) => self.status_message = format!("Copy failed: {}", e),
+        }
+    }
+
+    fn start_export(&mut self, format: ExportFormat, to_clipboard: bool) {
+        if self.loading.exporting {
+            self.status_message = "An export is already running".to_string();
+            return;
+        }
+
+        // Export the query behind the shown result, not the editor's current text
+        let query = match (&self.result, &self.executed_query) {
+            (Some(_), Some(query)) => trim_statement(query).to_string(),
+            _ => String::new(),
+        };
+        if query.is_empty() {
+            self.status_message = "Nothing to export".to_string();
+            return;
+        }
+        // The export runs the query again, which must not change data a second time
+        if is_mutating_statement(&query) {
+            self.status_message =
+                "Only read-only queries can be exported; this one would run again".to_string();
+            return;
+        }
+
+        let Some(conn) = self.selected_connection_info().cloned() else {
+            self.status_message = "Select a connection to export from".to_string();
+            return;
+        };
+
+        let stem = self
+            .selected_table_info()
+            .map(|t| t.name.clone())
+            .unwrap_or_else(|| "query".to_string());
+        let config = ExportConfig {
+            format,
+            delimiter: self.csv_delimiter,
+            quote_style: self.csv_quote_style,
+            with_bom: self.csv_with_bom,
+            ..ExportConfig::default()
+        };
+        let target = if to_clipboard {
+            ExportTarget::Clipboard
+        } else {
+            ExportTarget::File(export_file_name(
+                &stem,
+                config.extension(),
+                chrono::Local::now(),
+            ))
+        };
+
+        self.send_export(&conn, &query, target, config);
+    }
+}
+
+/// Build a timestamped export file name such as `users_20240101_120000.csv`
+fn export_file_name(stem: &str, extension: &str, now: chrono::DateTime<chrono::Local>) -> PathBuf {
+    // Keep the file name portable: replace path separators and other awkward characters
+    let stem: String = stem
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    PathBuf::from(format!(
+        "{}_{}.{}",
+        stem,
+        now.format("%Y%m%d_%H%M%S"),
+        extension
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_export_file_name() {
+        let now = chrono::Local.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        assert_eq!(
+            export_file_name("users", "csv", now),
+            PathBuf::from("users_20240102_030405.csv")
+        );
+        assert_eq!(
+            export_file_name("my table/x", "json", now),
+            PathBuf::from("my_table_x_20240102_030405.json")
+        );
+    }
+
+    #[test]
+    fn test_export_result_without_result() {
+        let mut app = App::new(vec![]);
+        app.export_result(ExportFormat::Csv);
+        assert_eq!(app.status_message, "Nothing to export");
+        assert!(!app.loading.exporting);
+
+        app.copy_result(ExportFormat::Csv, false);
+        assert_eq!(app.status_message, "Nothing to export");
+        assert!(!app.loading.exporting);
+    }
+
+    #[test]
+    fn test_export_uses_the_query_behind_the_result() {
+        let mut app = App::new(vec![]);
+        app.result = Some(crate::model::QueryResult {
+            columns: vec!["id".to_string()],
+            column_types: Vec::new(),
+            rows: vec![vec!["1".to_string()]],
+            total_rows: 1,
+            rows_affected: None,
+            execution_time_ms: 1,
+        });
+
+        // A statement that changed data is not run again
+        app.executed_query = Some("DELETE FROM users RETURNING *;".to_string());
+        app.query = "SELECT * FROM users".to_string();
+        app.export_result(ExportFormat::Csv);
+        assert_eq!(
+            app.status_message,
+            "Only read-only queries can be exported; this one would run again"
+        );
+        assert!(!app.loading.exporting);
+
+        // Text edited in the editor since the query ran does not matter
+        app.executed_query = Some("SELECT * FROM users".to_string());
+        app.query = "DELETE FROM users".to_string();
+        app.copy_result(ExportFormat::Csv, false);
+        assert_eq!(app.status_message, "Select a connection to export from");
+    }
+}
@@ -4,6 +4,11 @@
 //! categories of messages (navigation, modals, database operations, etc.).
 
 pub mod db;
+pub mod export;
 pub mod modal;
 pub mod navigation;
+pub mod query_tabs;
+pub mod reload;
+pub mod session;
+pub mod share;
 pub mod sidebar;
@@ -1,37 +1,62 @@
 //! Modal input and action handlers
 
 use crate::app::enums::{MainPanelTab, SchemaSubTab, SidebarMode};
-use crate::app::modal_fields::{ConfirmModalField, ConnectionModalField, ProjectModalField};
+use crate::app::modal_fields::{
+    ConfirmModalField, ConnectionModalField, FilterModalField, ProjectModalField,
+};
 use crate::app::modals::{
-    AddConnectionModal, ColumnVisibilityModal, DeleteProjectModal, HistoryModal, ModalState,
-    ProjectModal, SearchConnectionModal, SearchProjectModal, SearchTableModal, UnifiedSearchModal,
-    UnifiedSearchSection,
+    parse_port, AddConnectionModal, ColumnVisibilityModal, DeleteProjectModal, FieldError,
+    HistoryModal, ImportConnectionsModal, ModalState, ProjectModal, RecentTablesModal,
+    SearchConnectionModal, SearchPathModal, SearchProjectModal, SearchTableModal, SnippetsModal,
+    UnifiedSearchModal, UnifiedSearchSection,
 };
-use crate::app::App;
-use crate::model::{Connection, Project};
+use crate::app::{App, TextCursor};
+use crate::config::{import, ConnectionConfig};
+use crate::db::DatabaseType;
+use crate::event::keymap::help_line_count;
+use crate::model::{copy_name, normalize_search_path, unique_name, Connection, Project};
 
 impl App {
     /// Handle character input for modals
     pub(crate) fn handle_modal_input_char(&mut self, c: char) {
         match &mut self.modal_state {
-            ModalState::AddConnection(modal) => match modal.focused_field {
-                ConnectionModalField::Name => modal.name.push(c),
-                ConnectionModalField::Host => modal.host.push(c),
-                ConnectionModalField::Port => {
-                    if c.is_ascii_digit() && modal.port.len() < 5 {
-                        modal.port.push(c);
-                    }
+            ModalState::AddConnection(modal) | ModalState::CloneConnection(modal)
+                if modal.focused_field == ConnectionModalField::Port =>
+            {
+                // Refuse keys that would leave an impossible port
+                if !c.is_ascii_digit() {
+                    return;
                 }
-                ConnectionModalField::User => modal.user.push(c),
-                ConnectionModalField::Password => modal.password.push(c),
-                ConnectionModalField::Database => modal.database.push(c),
-                ConnectionModalField::ButtonOk | ConnectionModalField::ButtonCancel => {}
-            },
-            ModalState::AddProject(modal) | ModalState::EditProject(_, modal) => {
-                if modal.focused_field == ProjectModalField::Name {
-                    modal.name.push(c);
+                let mut port = modal.port.clone();
+                let mut cursor = modal.cursor;
+                cursor.insert(&mut port, c);
+                match parse_port(&port) {
+                    Ok(_) => (modal.port, modal.cursor) = (port, cursor),
+                    Err(hint) => self.status_message = format!("Port {}", hint),
+                }
+            }
+            ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) => {
+                if let Some((text, cursor)) = modal.focused_text() {
+                    cursor.insert(text, c);
                 }
             }
+            ModalState::AddProject(modal) | ModalState::EditProject(_, modal)
+                if modal.focused_field == ProjectModalField::Name =>
+            {
+                modal.cursor.insert(&mut modal.name, c);
+            }
+            ModalState::ExportProject(_, modal) | ModalState::ImportProject(modal) => {
+                modal.path.push(c);
+            }
+            ModalState::ImportPassword(modal) => {
+                modal.password.push(c);
+            }
+            ModalState::SearchPath(modal) => {
+                modal.value.push(c);
+            }
+            ModalState::Filter(modal) if modal.focused_field == FilterModalField::Value => {
+                modal.value.push(c);
+            }
             ModalState::SearchProject(modal) => {
                 modal.query.push(c);
                 modal.update_filter(&self.projects);
@@ -74,6 +99,18 @@ impl App {
                     }
                 }
             }
+            ModalState::GlobalSearch(modal) => {
+                modal.query.push(c);
+                modal.update_filter();
+            }
+            ModalState::GoToColumn(modal) => {
+                modal.query.push(c);
+                modal.update_filter();
+            }
+            ModalState::ServerSettings(modal) => {
+                modal.query.push(c);
+                modal.update_filter();
+            }
             _ => {}
         }
     }
@@ -81,32 +118,28 @@ impl App {
     /// Handle backspace for modals
     pub(crate) fn handle_modal_backspace(&mut self) {
         match &mut self.modal_state {
-            ModalState::AddConnection(modal) => match modal.focused_field {
-                ConnectionModalField::Name => {
-                    modal.name.pop();
-                }
-                ConnectionModalField::Host => {
-                    modal.host.pop();
-                }
-                ConnectionModalField::Port => {
-                    modal.port.pop();
-                }
-                ConnectionModalField::User => {
-                    modal.user.pop();
-                }
-                ConnectionModalField::Password => {
-                    modal.password.pop();
-                }
-                ConnectionModalField::Database => {
-                    modal.database.pop();
-                }
-                ConnectionModalField::ButtonOk | ConnectionModalField::ButtonCancel => {}
-            },
-            ModalState::AddProject(modal) | ModalState::EditProject(_, modal) => {
-                if modal.focused_field == ProjectModalField::Name {
-                    modal.name.pop();
+            ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) => {
+                if let Some((text, cursor)) = modal.focused_text() {
+                    cursor.backspace(text);
                 }
             }
+            ModalState::AddProject(modal) | ModalState::EditProject(_, modal)
+                if modal.focused_field == ProjectModalField::Name =>
+            {
+                modal.cursor.backspace(&mut modal.name);
+            }
+            ModalState::ExportProject(_, modal) | ModalState::ImportProject(modal) => {
+                modal.path.pop();
+            }
+            ModalState::ImportPassword(modal) => {
+                modal.password.pop();
+            }
+            ModalState::SearchPath(modal) => {
+                modal.value.pop();
+            }
+            ModalState::Filter(modal) if modal.focused_field == FilterModalField::Value => {
+                modal.value.pop();
+            }
             ModalState::SearchProject(modal) => {
                 modal.query.pop();
                 modal.update_filter(&self.projects);
@@ -149,6 +182,18 @@ impl App {
                     }
                 }
             }
+            ModalState::GlobalSearch(modal) => {
+                modal.query.pop();
+                modal.update_filter();
+            }
+            ModalState::GoToColumn(modal) => {
+                modal.query.pop();
+                modal.update_filter();
+            }
+            ModalState::ServerSettings(modal) => {
+                modal.query.pop();
+                modal.update_filter();
+            }
             _ => {}
         }
     }
@@ -156,15 +201,50 @@ impl App {
     /// Handle modal next field navigation
     pub(crate) fn handle_modal_next_field(&mut self) {
         match &mut self.modal_state {
-            ModalState::AddConnection(modal) => {
+            ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) => {
+                // Leaving a filled-in DSN field prefills the rest of the form
+                if modal.focused_field == ConnectionModalField::Dsn && !modal.dsn.trim().is_empty()
+                {
+                    if let Err(e) = modal.apply_dsn() {
+                        self.status_message = format!("Invalid DSN: {}", e);
+                        return;
+                    }
+                    self.status_message = "Filled connection fields from DSN".to_string();
+                }
                 modal.focused_field = modal.focused_field.next();
+                modal.cursor.end();
             }
             ModalState::AddProject(modal) | ModalState::EditProject(_, modal) => {
                 modal.focused_field = modal.focused_field.next();
+                modal.cursor.end();
             }
             ModalState::DeleteProject(modal) => {
                 modal.focused_field = modal.focused_field.next();
             }
+            ModalState::ConfirmQuit(modal) => {
+                modal.focused_field = modal.focused_field.next();
+            }
+            ModalState::ConfirmCopy(modal) => {
+                modal.focused_field = modal.focused_field.next();
+            }
+            ModalState::ConfirmQuery(modal) => {
+                modal.focused_field = modal.focused_field.next();
+            }
+            ModalState::Filter(modal) => {
+                modal.focused_field = modal.focused_field.next();
+            }
+            ModalState::Order(modal) => {
+                modal.focused_field = modal.focused_field.next();
+            }
+            ModalState::Help(modal) => {
+                modal.scroll_down(help_line_count());
+            }
+            ModalState::ConstraintDetail(modal) => {
+                modal.scroll_down();
+            }
+            ModalState::LoadErrors(modal) => {
+                modal.scroll_down();
+            }
             ModalState::SearchProject(modal) => {
                 modal.navigate_down();
             }
@@ -177,25 +257,148 @@ impl App {
             ModalState::UnifiedSearch(modal) => {
                 modal.navigate_down();
             }
+            ModalState::GlobalSearch(modal) => {
+                modal.navigate_down();
+            }
+            ModalState::GoToColumn(modal) => {
+                modal.navigate_down();
+            }
             ModalState::ColumnVisibility(modal) => {
                 modal.navigate_down();
             }
-            ModalState::None | ModalState::History(_) => {}
+            ModalState::ActiveSessions(modal) => {
+                modal.navigate_down();
+            }
+            ModalState::Roles(modal) => {
+                modal.navigate_down();
+            }
+            ModalState::Snippets(modal) => {
+                modal.navigate_down();
+            }
+            ModalState::ServerSettings(modal) => {
+                modal.navigate_down();
+            }
+            ModalState::None
+            | ModalState::History(_)
+            | ModalState::RecentTables(_)
+            | ModalState::DatabaseStats(_)
+            | ModalState::ColumnStats(_)
+            | ModalState::ImportConnections(_)
+            | ModalState::ExportProject(..)
+            | ModalState::ImportProject(_)
+            | ModalState::ImportPassword(_)
+            | ModalState::SearchPath(_) => {}
         }
     }
 
+    /// Cycle the focused selector of the filter or order modal
+    pub(crate) fn cycle_modal_option(&mut self, forward: bool) {
+        match &mut self.modal_state {
+            ModalState::Filter(modal) => modal.cycle(forward),
+            ModalState::Order(modal) => modal.cycle(forward),
+            _ => {}
+        }
+    }
+
+    /// Cycle the database type in the connection modal
+    pub(crate) fn cycle_connection_database_type(&mut self, forward: bool) {
+        if let ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) =
+            &mut self.modal_state
+        {
+            let next = if forward {
+                modal.database_type.next()
+            } else {
+                modal.database_type.prev()
+            };
+            modal.set_database_type(next);
+        }
+    }
+
+    /// Flip the production flag of the connection being edited
+    pub(crate) fn toggle_connection_production(&mut self) {
+        if let ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) =
+            &mut self.modal_state
+        {
+            modal.is_production = !modal.is_production;
+        }
+    }
+
+    /// Show or mask the password typed into the connection modal
+    pub(crate) fn toggle_password_reveal(&mut self) {
+        if let ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) =
+            &mut self.modal_state
+        {
+            modal.reveal_password = !modal.reveal_password;
+        }
+    }
+
+    /// Insert pasted text into the focused modal field as if it was typed,
+    /// so each field keeps its own limits (e.g. digits only for the port).
+    /// Fields are single-line, so line breaks are dropped.
+    pub(crate) fn handle_modal_paste(&mut self, text: &str) {
+        for c in text.chars().filter(|c| !c.is_control()) {
+            self.handle_modal_input_char(c);
+        }
+    }
+
+    /// Move the cursor or delete a word in the focused single-line field of
+    /// the connection or project modal
+    pub(crate) fn edit_modal_text(&mut self, edit: fn(&mut TextCursor, &mut String)) {
+        let (text, cursor) = match &mut self.modal_state {
+            ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) => {
+                let Some(field) = modal.focused_text() else {
+                    return;
+                };
+                field
+            }
+            ModalState::AddProject(modal) | ModalState::EditProject(_, modal)
+                if modal.focused_field == ProjectModalField::Name =>
+            {
+                (&mut modal.name, &mut modal.cursor)
+            }
+            _ => return,
+        };
+        edit(cursor, text);
+    }
+
     /// Handle modal prev field navigation
     pub(crate) fn handle_modal_prev_field(&mut self) {
         match &mut self.modal_state {
-            ModalState::AddConnection(modal) => {
+            ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) => {
                 modal.focused_field = modal.focused_field.prev();
+                modal.cursor.end();
             }
             ModalState::AddProject(modal) | ModalState::EditProject(_, modal) => {
                 modal.focused_field = modal.focused_field.prev();
+                modal.cursor.end();
             }
             ModalState::DeleteProject(modal) => {
                 modal.focused_field = modal.focused_field.prev();
             }
+            ModalState::ConfirmQuit(modal) => {
+                modal.focused_field = modal.focused_field.prev();
+            }
+            ModalState::ConfirmCopy(modal) => {
+                modal.focused_field = modal.focused_field.prev();
+            }
+            ModalState::ConfirmQuery(modal) => {
+                modal.focused_field = modal.focused_field.prev();
+            }
+            ModalState::Filter(modal) => {
+                modal.focused_field = modal.focused_field.prev();
+            }
+            ModalState::Order(modal) => {
+                modal.focused_field = modal.focused_field.prev();
+            }
+            ModalState::Help(modal) => {
+                modal.scroll_up();
+            }
+            ModalState::ConstraintDetail(modal) => {
+                modal.scroll_up();
+            }
+            ModalState::LoadErrors(modal) => {
+                modal.scroll_up();
+            }
             ModalState::SearchProject(modal) => {
                 modal.navigate_up();
             }
@@ -208,31 +411,79 @@ impl App {
             ModalState::UnifiedSearch(modal) => {
                 modal.navigate_up();
             }
+            ModalState::GlobalSearch(modal) => {
+                modal.navigate_up();
+            }
+            ModalState::GoToColumn(modal) => {
+                modal.navigate_up();
+            }
             ModalState::ColumnVisibility(modal) => {
                 modal.navigate_up();
             }
-            ModalState::None | ModalState::History(_) => {}
+            ModalState::ActiveSessions(modal) => {
+                modal.navigate_up();
+            }
+            ModalState::Roles(modal) => {
+                modal.navigate_up();
+            }
+            ModalState::Snippets(modal) => {
+                modal.navigate_up();
+            }
+            ModalState::ServerSettings(modal) => {
+                modal.navigate_up();
+            }
+            ModalState::None
+            | ModalState::History(_)
+            | ModalState::RecentTables(_)
+            | ModalState::DatabaseStats(_)
+            | ModalState::ColumnStats(_)
+            | ModalState::ImportConnections(_)
+            | ModalState::ExportProject(..)
+            | ModalState::ImportProject(_)
+            | ModalState::ImportPassword(_)
+            | ModalState::SearchPath(_) => {}
         }
     }
 
     /// Handle modal confirm action
     pub(crate) fn handle_modal_confirm(&mut self) {
         match &self.modal_state {
-            ModalState::AddConnection(modal) => {
-                if let Some(conn) = self.create_connection_from_modal(modal) {
-                    // Add connection to current project if in Connections mode
-                    if let SidebarMode::Connections(proj_idx) = self.sidebar_mode {
-                        if let Some(project) = self.projects.get_mut(proj_idx) {
-                            project.connections.push(conn);
-                            self.status_message = "Connection added".to_string();
+            ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) => {
+                let is_clone = matches!(self.modal_state, ModalState::CloneConnection(_));
+                if modal.database_type != DatabaseType::PostgreSQL {
+                    self.status_message =
+                        format!("{} connections are not supported yet", modal.database_type);
+                    return;
+                }
+                match self.create_connection_from_modal(modal) {
+                    Ok(conn) => {
+                        // Add connection to current project if in Connections mode
+                        if let SidebarMode::Connections(proj_idx) = self.sidebar_mode {
+                            if let Some(project) = self.projects.get_mut(proj_idx) {
+                                project.connections.push(conn);
+                                self.status_message = if is_clone {
+                                    "Connection cloned".to_string()
+                                } else {
+                                    "Connection added".to_string()
+                                };
+                            }
+                        }
+                        self.modal_state = ModalState::None;
+                    }
+                    Err(errors) => {
+                        // Keep modal open with the failing fields highlighted
+                        self.status_message = format!(
+                            "Invalid connection: fix {} highlighted field(s)",
+                            errors.len()
+                        );
+                        if let ModalState::AddConnection(modal)
+                        | ModalState::CloneConnection(modal) = &mut self.modal_state
+                        {
+                            modal.show_errors = true;
+                            modal.focused_field = errors[0].field;
+                            modal.cursor.end();
                         }
                     }
-                    self.modal_state = ModalState::None;
-                } else {
-                    self.status_message =
-                        "Invalid: fill name, host, user, database and valid port (1-65535)"
-                            .to_string();
-                    // Keep modal open for user to correct input
                 }
             }
             ModalState::AddProject(modal) => {
@@ -284,45 +535,177 @@ impl App {
             ModalState::UnifiedSearch(_) => {
                 // UnifiedSearch uses UnifiedSearchConfirm instead of ModalConfirm
             }
+            ModalState::GlobalSearch(_) => {
+                // GlobalSearch uses GlobalSearchConfirm instead of ModalConfirm
+            }
+            ModalState::GoToColumn(_) => {
+                // GoToColumn uses GoToColumnConfirm instead of ModalConfirm
+            }
             ModalState::ColumnVisibility(_) => {
                 // ColumnVisibility uses ToggleColumnVisibility, just close on confirm
                 self.modal_state = ModalState::None;
             }
-            ModalState::None | ModalState::History(_) => {}
+            ModalState::ImportConnections(modal) => {
+                let imported = modal.connections.clone();
+                if let SidebarMode::Connections(proj_idx) = self.sidebar_mode {
+                    if let Some(project) = self.projects.get_mut(proj_idx) {
+                        let count = imported.len();
+                        project.connections.extend(imported);
+                        self.status_message = format!("Imported {} connections", count);
+                    }
+                }
+                self.modal_state = ModalState::None;
+            }
+            ModalState::SearchPath(modal) => {
+                let (target, value) = (modal.target, modal.value.clone());
+                self.apply_search_path(target, &value);
+            }
+            ModalState::ExportProject(proj_idx, modal) => {
+                let proj_idx = *proj_idx;
+                let path = modal.path.trim().to_string();
+                self.export_project(proj_idx, &path);
+            }
+            ModalState::ImportProject(modal) => {
+                let path = modal.path.trim().to_string();
+                self.import_project(&path);
+            }
+            ModalState::ImportPassword(_) => {
+                self.confirm_import_password();
+            }
+            ModalState::Filter(_) => {
+                self.apply_filter();
+            }
+            ModalState::Order(_) => {
+                self.apply_order();
+            }
+            ModalState::ActiveSessions(modal) => {
+                if let Some(pid) = modal.confirm_pid {
+                    self.send_terminate_backend(pid);
+                }
+            }
+            ModalState::Snippets(_) => {
+                self.insert_selected_snippet();
+            }
+            ModalState::None
+            | ModalState::History(_)
+            | ModalState::RecentTables(_)
+            | ModalState::DatabaseStats(_)
+            | ModalState::ColumnStats(_)
+            | ModalState::ConstraintDetail(_)
+            | ModalState::LoadErrors(_)
+            | ModalState::ConfirmQuit(_)
+            | ModalState::ConfirmCopy(_)
+            | ModalState::ConfirmQuery(_)
+            | ModalState::Roles(_)
+            | ModalState::ServerSettings(_)
+            | ModalState::Help(_) => {}
         }
     }
 
-    /// Create connection from modal data
+    /// Create connection from modal data, or the fields that need fixing
     pub(crate) fn create_connection_from_modal(
         &self,
         modal: &AddConnectionModal,
-    ) -> Option<Connection> {
-        // Parse port as u16, which constrains the upper bound to 65535
-        let port: u16 = modal.port.parse().ok()?;
-        // Validate port range: reject port 0 (u16 already ensures <= 65535)
-        if port == 0 {
-            return None;
-        }
-        // Validate required fields: name, host, database, and user
-        if modal.name.is_empty()
-            || modal.host.is_empty()
-            || modal.database.is_empty()
-            || modal.user.is_empty()
-        {
-            return None;
-        }
-        Some(Connection {
+    ) -> Result<Connection, Vec<FieldError>> {
+        let port = modal.validate()?;
+        Ok(Connection {
             name: modal.name.clone(),
             host: modal.host.clone(),
             port,
             database: modal.database.clone(),
             username: modal.user.clone(),
             password: modal.password.clone(),
+            password_env: modal.password_env.clone(),
             expanded: false,
             tables: vec![],
+            default_schema: modal.default_schema.clone(),
+            color: modal.color.clone(),
+            is_production: modal.is_production,
+            query_timeout_secs: modal.query_timeout_secs,
+            snippets: modal.snippets.clone(),
+            search_path: None,
         })
     }
 
+    /// Preview connections from `.pgpass` / `pg_service.conf` for import.
+    ///
+    /// Connections already present in the current project are left out.
+    pub(crate) fn open_import_connections_modal(&mut self) {
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return;
+        };
+        let Some(project) = self.projects.get(proj_idx) else {
+            return;
+        };
+
+        let (configs, sources) = import::load_default_sources();
+        let mut connections: Vec<Connection> = Vec::new();
+        for conn in configs.into_iter().map(Connection::from) {
+            let is_known = project
+                .connections
+                .iter()
+                .chain(connections.iter())
+                .any(|c| is_same_target(c, &conn));
+            if !is_known {
+                connections.push(conn);
+            }
+        }
+
+        if connections.is_empty() {
+            self.status_message = if sources.is_empty() {
+                "No .pgpass or pg_service.conf found".to_string()
+            } else {
+                "No new connections to import".to_string()
+            };
+            return;
+        }
+
+        self.modal_state = ModalState::ImportConnections(ImportConnectionsModal {
+            connections,
+            sources: sources.iter().map(|p| p.display().to_string()).collect(),
+        });
+    }
+
+    /// Add a project with one connection to a local PostgreSQL server, as a
+    /// starting point that can be edited in its project file.
+    pub(crate) fn create_sample_project(&mut self) {
+        let name = unique_name("sample", self.projects.iter().map(|p| p.name.as_str()));
+        let mut project = Project::new(name);
+        project.connections.push(Connection::from(ConnectionConfig {
+            name: "local".to_string(),
+            host: "localhost".to_string(),
+            port: 5432,
+            database: "postgres".to_string(),
+            username: Some("postgres".to_string()),
+            password: None,
+            password_env: Some("PGPASSWORD".to_string()),
+            default_schema: None,
+            color: None,
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
+        }));
+        self.projects.push(project);
+        self.selected_project_idx = self.projects.len() - 1;
+        self.pending_project_save = Some(self.selected_project_idx);
+    }
+
+    /// Duplicate the selected project (with its connections) under a new name.
+    ///
+    /// The copy is written to its own project file by the main loop.
+    pub(crate) fn clone_selected_project(&mut self) {
+        if let SidebarMode::Projects = self.sidebar_mode {
+            if let Some(project) = self.projects.get(self.selected_project_idx) {
+                let name = copy_name(&project.name, self.projects.iter().map(|p| p.name.as_str()));
+                let copy = project.duplicate(name);
+                self.projects.push(copy);
+                self.selected_project_idx = self.projects.len() - 1;
+                self.pending_project_save = Some(self.selected_project_idx);
+                self.status_message = "Project cloned".to_string();
+            }
+        }
+    }
+
     // ========================================================================
     // Modal Opening Handlers
     // ========================================================================
@@ -333,6 +716,19 @@ impl App {
         self.modal_state = ModalState::AddConnection(AddConnectionModal::default());
     }
 
+    /// Open the connection modal pre-filled with a copy of the selected connection
+    pub(crate) fn open_clone_connection_modal(&mut self) {
+        if let SidebarMode::Connections(proj_idx) = self.sidebar_mode {
+            if let Some(conn) = self
+                .projects
+                .get(proj_idx)
+                .and_then(|p| p.connections.get(self.selected_connection_idx))
+            {
+                self.modal_state = ModalState::CloneConnection(AddConnectionModal::copy_of(conn));
+            }
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn open_add_project_modal(&mut self) {
         self.modal_state = ModalState::AddProject(ProjectModal::default());
@@ -527,6 +923,36 @@ impl App {
         self.modal_state = ModalState::None;
     }
 
+    /// Jump to the selected global search result, switching project if needed
+    pub(crate) fn handle_global_search_confirm(&mut self) {
+        if let ModalState::GlobalSearch(modal) = &self.modal_state {
+            if let Some(entry) = modal.selected_entry() {
+                let target = entry.target;
+                let label = format!("{} / {}", entry.path, entry.name);
+
+                self.sidebar_mode = SidebarMode::Connections(target.project_idx);
+                self.selected_project_idx = target.project_idx;
+                self.selected_connection_idx = target.connection_idx;
+                self.selected_table_idx = target.table_idx;
+                self.focus = crate::app::Focus::Sidebar;
+                self.status_message = format!("Selected: {}", label);
+
+                if target.table_idx.is_some() {
+                    // Tables are only shown under an expanded connection
+                    if let Some(conn) = self
+                        .projects
+                        .get_mut(target.project_idx)
+                        .and_then(|p| p.connections.get_mut(target.connection_idx))
+                    {
+                        conn.expanded = true;
+                    }
+                    self.fetch_table_details_if_needed(target.project_idx);
+                }
+            }
+        }
+        self.modal_state = ModalState::None;
+    }
+
     // ========================================================================
     // Column Visibility Handler
     // ========================================================================
@@ -558,23 +984,57 @@ impl App {
         }
     }
 
+    // ========================================================================
+    // Snippet Handlers
+    // ========================================================================
+
+    /// List the snippets configured on the selected connection
+    pub(crate) fn open_snippets_modal(&mut self) {
+        let Some(conn) = self.selected_connection_info() else {
+            self.status_message = "Select a connection to list its snippets".to_string();
+            return;
+        };
+        if conn.snippets.is_empty() {
+            self.status_message = format!("No snippets configured for {}", conn.name);
+            return;
+        }
+        self.modal_state =
+            ModalState::Snippets(SnippetsModal::new(conn.name.clone(), conn.snippets.clone()));
+    }
+
+    /// Put the selected snippet into the query editor without running it
+    fn insert_selected_snippet(&mut self) {
+        if let ModalState::Snippets(modal) = &self.modal_state {
+            if let Some(snippet) = modal.selected_snippet() {
+                self.query = snippet.query.clone();
+                self.status_message = format!(
+                    "Loaded snippet \"{}\" ({})",
+                    snippet.name, modal.connection_name
+                );
+            }
+            self.modal_state = ModalState::None;
+        }
+    }
+
     // ========================================================================
     // History Handlers
     // ========================================================================
 
     pub(crate) fn handle_history_navigate_up(&mut self) {
         if let ModalState::History(modal) = &mut self.modal_state {
+            let count = modal.filtered_indices(&self.query_history).len();
             if modal.selected_idx > 0 {
                 modal.selected_idx -= 1;
-            } else if !self.query_history.is_empty() {
-                modal.selected_idx = self.query_history.len() - 1;
+            } else if count > 0 {
+                modal.selected_idx = count - 1;
             }
         }
     }
 
     pub(crate) fn handle_history_navigate_down(&mut self) {
         if let ModalState::History(modal) = &mut self.modal_state {
-            if modal.selected_idx + 1 < self.query_history.len() {
+            let count = modal.filtered_indices(&self.query_history).len();
+            if modal.selected_idx + 1 < count {
                 modal.selected_idx += 1;
             } else {
                 modal.selected_idx = 0;
@@ -584,7 +1044,8 @@ impl App {
 
     pub(crate) fn handle_history_select_entry(&mut self) {
         if let ModalState::History(modal) = &self.modal_state {
-            if let Some(entry) = self.query_history.get(modal.selected_idx) {
+            let entry_idx = modal.selected_entry_idx(&self.query_history);
+            if let Some(entry) = entry_idx.and_then(|idx| self.query_history.get(idx)) {
                 self.query = entry.query.clone();
                 self.status_message =
                     format!("Loaded query from history ({})", entry.connection_name);
@@ -593,6 +1054,65 @@ impl App {
         }
     }
 
+    /// Run the selected entry again on the connection it ran on, leaving the
+    /// editor as it is
+    pub(crate) fn handle_history_rerun(&mut self) {
+        let ModalState::History(modal) = &self.modal_state else {
+            return;
+        };
+        let Some(entry) = modal
+            .selected_entry_idx(&self.query_history)
+            .and_then(|idx| self.query_history.get(idx))
+        else {
+            return;
+        };
+        let (conn_name, query) = (entry.connection_name.clone(), entry.query.clone());
+        self.modal_state = ModalState::None;
+
+        match self.find_connection_by_name(&conn_name) {
+            Some(target) => self.execute_query_on(target, query, false),
+            None => {
+                self.status_message = format!("Connection '{}' no longer exists", conn_name);
+            }
+        }
+    }
+
+    /// Project and connection index of the connection named `name`, looking
+    /// in the open project first
+    fn find_connection_by_name(&self, name: &str) -> Option<(usize, usize)> {
+        let open_project = match self.sidebar_mode {
+            SidebarMode::Connections(proj_idx) => Some(proj_idx),
+            SidebarMode::Projects => None,
+        };
+        let projects = open_project
+            .into_iter()
+            .chain((0..self.projects.len()).filter(|&idx| Some(idx) != open_project));
+        projects.into_iter().find_map(|proj_idx| {
+            let conn_idx = self
+                .projects
+                .get(proj_idx)?
+                .connections
+                .iter()
+                .position(|c| c.name == name)?;
+            Some((proj_idx, conn_idx))
+        })
+    }
+
+    /// Toggle the detail pane with the full query of the selected entry
+    pub(crate) fn handle_history_show_detail(&mut self) {
+        if let ModalState::History(modal) = &mut self.modal_state {
+            modal.show_detail = !modal.show_detail;
+        }
+    }
+
+    /// Cycle between all, only failed and only successful entries
+    pub(crate) fn handle_history_toggle_filter(&mut self) {
+        if let ModalState::History(modal) = &mut self.modal_state {
+            modal.filter = modal.filter.next();
+            modal.selected_idx = 0;
+        }
+    }
+
     pub(crate) fn handle_clear_history(&mut self) {
         self.query_history.clear();
         self.history_dirty = true;
@@ -603,4 +1123,141 @@ impl App {
         self.modal_state = ModalState::None;
         self.status_message = "Query history cleared".to_string();
     }
+
+    // ========================================================================
+    // Recent Tables Handlers
+    // ========================================================================
+
+    pub(crate) fn open_recent_tables_modal(&mut self) {
+        if self.recent_tables.is_empty() {
+            self.status_message = "No recently opened tables".to_string();
+        } else {
+            self.modal_state = ModalState::RecentTables(RecentTablesModal::default());
+        }
+    }
+
+    pub(crate) fn handle_recent_tables_navigate_up(&mut self) {
+        if let ModalState::RecentTables(modal) = &mut self.modal_state {
+            if modal.selected_idx > 0 {
+                modal.selected_idx -= 1;
+            } else if !self.recent_tables.is_empty() {
+                modal.selected_idx = self.recent_tables.len() - 1;
+            }
+        }
+    }
+
+    pub(crate) fn handle_recent_tables_navigate_down(&mut self) {
+        if let ModalState::RecentTables(modal) = &mut self.modal_state {
+            if modal.selected_idx + 1 < self.recent_tables.len() {
+                modal.selected_idx += 1;
+            } else {
+                modal.selected_idx = 0;
+            }
+        }
+    }
+
+    /// Jump to the selected recent table and browse its rows
+    pub(crate) fn handle_recent_tables_select(&mut self) {
+        let ModalState::RecentTables(modal) = &self.modal_state else {
+            return;
+        };
+        let Some(entry) = self.recent_tables.get(modal.selected_idx).cloned() else {
+            return;
+        };
+        self.modal_state = ModalState::None;
+
+        if self.loading.executing_query {
+            self.status_message = "A query is already running".to_string();
+            return;
+        }
+
+        let Some(proj_idx) = self.projects.iter().position(|p| p.name == entry.project) else {
+            self.status_message = format!("Project '{}' no longer exists", entry.project);
+            return;
+        };
+        let Some(conn_idx) = self.projects[proj_idx]
+            .connections
+            .iter()
+            .position(|c| c.name == entry.connection)
+        else {
+            self.status_message = format!("Connection '{}' no longer exists", entry.connection);
+            return;
+        };
+
+        self.sidebar_mode = SidebarMode::Connections(proj_idx);
+        self.selected_project_idx = proj_idx;
+        self.selected_connection_idx = conn_idx;
+
+        let conn = &mut self.projects[proj_idx].connections[conn_idx];
+        conn.expanded = true;
+        let table_idx = conn.tables.iter().position(|t| t.name == entry.table);
+        let needs_tables = conn.tables.is_empty();
+        self.selected_table_idx = table_idx;
+
+        if table_idx.is_some() {
+            self.fetch_table_details_if_needed(proj_idx);
+        } else if needs_tables && !self.loading.is_fetching_tables_for(conn_idx) {
+            // Tables are not loaded yet; list them so the table shows up in the sidebar
+            let conn = self.projects[proj_idx].connections[conn_idx].clone();
+            self.send_fetch_tables(&conn, proj_idx, conn_idx);
+        }
+
+        self.open_table(proj_idx, conn_idx, &entry.table);
+    }
+
+    /// Ask for the `search_path` of the selected connection's sessions
+    pub(crate) fn open_search_path_modal(&mut self) {
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return;
+        };
+        let Some(conn) = self.selected_connection_info() else {
+            self.status_message = "Select a connection to set its search_path".to_string();
+            return;
+        };
+        let current = conn.search_path.clone().unwrap_or_default();
+        self.modal_state = ModalState::SearchPath(SearchPathModal::new(
+            (proj_idx, self.selected_connection_idx),
+            current,
+        ));
+    }
+
+    /// Apply the `search_path` typed in the modal and reload the table list.
+    ///
+    /// An empty value goes back to the server's default.
+    fn apply_search_path(&mut self, (proj_idx, conn_idx): (usize, usize), value: &str) {
+        let search_path = if value.trim().is_empty() {
+            None
+        } else {
+            match normalize_search_path(value) {
+                Ok(path) => Some(path),
+                Err(e) => {
+                    self.status_message = e;
+                    return;
+                }
+            }
+        };
+        self.modal_state = ModalState::None;
+
+        let Some(conn) = self
+            .projects
+            .get_mut(proj_idx)
+            .and_then(|p| p.connections.get_mut(conn_idx))
+        else {
+            return;
+        };
+        conn.search_path = search_path;
+        let conn = conn.clone();
+        self.status_message = match &conn.search_path {
+            Some(path) => format!("search_path set to {} for {}", path, conn.name),
+            None => format!("search_path reset for {}", conn.name),
+        };
+        if conn.expanded {
+            self.send_fetch_tables(&conn, proj_idx, conn_idx);
+        }
+    }
+}
+
+/// Whether two connections point at the same database as the same user
+fn is_same_target(a: &Connection, b: &Connection) -> bool {
+    a.host == b.host && a.port == b.port && a.database == b.database && a.username == b.username
 }
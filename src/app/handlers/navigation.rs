@@ -1,7 +1,7 @@
 //! Navigation handlers for sidebar and data table
 
 use crate::app::enums::SidebarMode;
-use crate::app::App;
+use crate::app::{App, GoToColumnModal, ModalState};
 
 impl App {
     /// Navigate up based on current sidebar mode
@@ -16,6 +16,7 @@ impl App {
             }
             SidebarMode::Connections(proj_idx) => {
                 self.navigate_connections_up(proj_idx);
+                self.request_table_preview();
             }
         }
     }
@@ -32,6 +33,7 @@ impl App {
             }
             SidebarMode::Connections(proj_idx) => {
                 self.navigate_connections_down(proj_idx);
+                self.request_table_preview();
             }
         }
     }
@@ -152,4 +154,146 @@ impl App {
             self.data_table_state.select(Some(new_idx));
         }
     }
+
+    /// Result column index at each grid position, hidden columns included.
+    ///
+    /// Follows `column_order`, or the result's own order until a column is moved.
+    pub(crate) fn data_column_order(&self) -> Vec<usize> {
+        let column_count = self.result.as_ref().map_or(0, |r| r.columns.len());
+        if self.column_order.len() == column_count {
+            self.column_order.clone()
+        } else {
+            (0..column_count).collect()
+        }
+    }
+
+    /// Result column index shown at each grid position
+    pub(crate) fn visible_data_columns(&self) -> Vec<usize> {
+        self.data_column_order()
+            .into_iter()
+            .filter(|column| !self.hidden_columns.contains(column))
+            .collect()
+    }
+
+    /// Grid position of the selected column, clamped to the shown columns
+    pub(crate) fn selected_data_position(&self) -> Option<usize> {
+        let column_count = self.visible_data_columns().len();
+        let selected = self.data_table_state.selected_column()?;
+        (column_count > 0).then(|| selected.min(column_count - 1))
+    }
+
+    /// Index in the result of the selected column
+    pub(crate) fn selected_data_column(&self) -> Option<usize> {
+        let position = self.selected_data_position()?;
+        self.visible_data_columns().get(position).copied()
+    }
+
+    /// Move the selected column in the data table by `delta`, staying within the result
+    pub(crate) fn navigate_data_table_column(&mut self, delta: i32) {
+        let Some(last) = self.visible_data_columns().len().checked_sub(1) else {
+            return;
+        };
+        let new_idx = match self.selected_data_position() {
+            // The first move only selects a column
+            None => 0,
+            Some(current) if delta < 0 => current.saturating_sub((-delta) as usize),
+            Some(current) => (current + delta as usize).min(last),
+        };
+        self.data_table_state.select_column(Some(new_idx));
+    }
+
+    /// Swap the selected column with its neighbour on the left (`-1`) or right (`1`).
+    ///
+    /// Only the grid changes; the result and its exports keep the query's order.
+    pub(crate) fn move_data_column(&mut self, delta: i32) {
+        let Some(position) = self.selected_data_position() else {
+            self.status_message = "Select a column with h / l to move it".to_string();
+            return;
+        };
+        let visible = self.visible_data_columns();
+        let target = if delta < 0 {
+            position.checked_sub(1)
+        } else {
+            Some(position + 1).filter(|&target| target < visible.len())
+        };
+        let Some(target) = target else {
+            return;
+        };
+        // Hidden columns in between keep their place
+        let mut order = self.data_column_order();
+        let index_of = |column: usize| order.iter().position(|&c| c == column);
+        if let (Some(from), Some(to)) = (index_of(visible[position]), index_of(visible[target])) {
+            order.swap(from, to);
+        }
+        self.column_order = order;
+        self.data_table_state.select_column(Some(target));
+        if let Some(name) = self
+            .selected_data_column()
+            .and_then(|column| self.result.as_ref()?.columns.get(column))
+        {
+            self.status_message = format!("Moved column {}", name);
+        }
+    }
+
+    /// Hide the selected column from the grid; the result itself is unchanged
+    pub(crate) fn hide_data_column(&mut self) {
+        let Some(column) = self.selected_data_column() else {
+            self.status_message = "Select a column with h / l to hide it".to_string();
+            return;
+        };
+        let visible = self.visible_data_columns().len();
+        if visible <= 1 {
+            self.status_message = "Cannot hide the last shown column".to_string();
+            return;
+        }
+        self.hidden_columns.insert(column);
+        let position = self.selected_data_position().unwrap_or(0);
+        self.data_table_state
+            .select_column(Some(position.min(visible - 2)));
+        if let Some(name) = self.result.as_ref().and_then(|r| r.columns.get(column)) {
+            self.status_message = format!("Hid column {} (+ to show all)", name);
+        }
+    }
+
+    /// Show the columns hidden with `-` again
+    pub(crate) fn show_all_data_columns(&mut self) {
+        if self.hidden_columns.is_empty() {
+            self.status_message = "No hidden columns".to_string();
+            return;
+        }
+        let count = self.hidden_columns.len();
+        self.hidden_columns.clear();
+        let noun = if count == 1 { "column" } else { "columns" };
+        self.status_message = format!("Showing {} hidden {} again", count, noun);
+    }
+
+    /// Open the go-to-column list over the current result's columns
+    pub(crate) fn open_go_to_column(&mut self) {
+        match &self.result {
+            Some(result) if !result.columns.is_empty() => {
+                self.modal_state =
+                    ModalState::GoToColumn(GoToColumnModal::new(result.columns.clone()));
+            }
+            _ => self.status_message = "No result columns to jump to".to_string(),
+        }
+    }
+
+    /// Select the column picked in the go-to-column list
+    pub(crate) fn handle_go_to_column_confirm(&mut self) {
+        let ModalState::GoToColumn(modal) = &self.modal_state else {
+            return;
+        };
+        let Some(column) = modal.selected_column_idx() else {
+            return;
+        };
+        self.modal_state = ModalState::None;
+        // Every column is laid out within the panel width, so selecting it is enough to
+        // show it, once it is no longer hidden
+        self.hidden_columns.remove(&column);
+        let position = self
+            .visible_data_columns()
+            .iter()
+            .position(|&c| c == column);
+        self.data_table_state.select_column(position);
+    }
 }
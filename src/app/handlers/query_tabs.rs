@@ -0,0 +1,112 @@
+//! Opening, switching and closing query editor tabs
+
+use crate::app::{App, QueryTab};
+
+impl App {
+    /// Open a new tab holding a copy of the current query and make it active
+    pub(crate) fn new_query_tab(&mut self) {
+        if !self.can_switch_query_tab() {
+            return;
+        }
+        let query = self.query.clone();
+        let index = self.active_query_tab + 1;
+        self.query_tabs.insert(index, QueryTab::default());
+        self.activate_query_tab(index);
+        self.query = query;
+        self.status_message = format!("Opened query tab {}", index + 1);
+    }
+
+    /// Activate the tab to the right of the current one, wrapping around
+    pub(crate) fn next_query_tab(&mut self) {
+        if self.query_tabs.len() < 2 || !self.can_switch_query_tab() {
+            return;
+        }
+        let index = (self.active_query_tab + 1) % self.query_tabs.len();
+        self.activate_query_tab(index);
+        self.status_message = format!("Query tab {}/{}", index + 1, self.query_tabs.len());
+    }
+
+    /// Close the active tab and activate its left neighbour
+    pub(crate) fn close_query_tab(&mut self) {
+        if self.query_tabs.len() < 2 {
+            self.status_message = "Cannot close the last query tab".to_string();
+            return;
+        }
+        if !self.can_switch_query_tab() {
+            return;
+        }
+        let closed = self.active_query_tab;
+        let index = if closed == 0 { 1 } else { closed - 1 };
+        self.activate_query_tab(index);
+        self.query_tabs.remove(closed);
+        if index > closed {
+            self.active_query_tab -= 1;
+        }
+        self.status_message = format!("Closed query tab {}", closed + 1);
+    }
+
+    /// Titles of all tabs for the tab strip, in order
+    pub fn query_tab_titles(&self) -> Vec<String> {
+        self.query_tabs
+            .iter()
+            .enumerate()
+            .map(|(idx, tab)| {
+                let query = if idx == self.active_query_tab {
+                    &self.query
+                } else {
+                    &tab.query
+                };
+                QueryTab::title(idx, query)
+            })
+            .collect()
+    }
+
+    /// A running query writes its result into the active tab, so tabs
+    /// stay put until it finishes
+    fn can_switch_query_tab(&mut self) -> bool {
+        if self.loading.executing_query {
+            self.status_message = "Wait for the running query before switching tabs".to_string();
+            return false;
+        }
+        true
+    }
+
+    /// Store the active tab in its slot and load the tab at `index`
+    fn activate_query_tab(&mut self, index: usize) {
+        // The cursor belongs to the result being put away, which keeps only
+        // the rows fetched so far
+        let result_truncated = self.result_is_partial();
+        self.close_browse_cursor();
+        self.current_cache_key = None;
+        self.query_error = None;
+
+        let outgoing = QueryTab {
+            query: std::mem::take(&mut self.query),
+            result: self.result.take(),
+            result_offset: std::mem::take(&mut self.result_offset),
+            result_truncated,
+            executed_query: self.executed_query.take(),
+            pagination: std::mem::take(&mut self.pagination),
+            data_table_state: std::mem::take(&mut self.data_table_state),
+            column_order: std::mem::take(&mut self.column_order),
+            hidden_columns: std::mem::take(&mut self.hidden_columns),
+            browse: self.browse.take(),
+            result_limit: self.result_limit.take(),
+        };
+        self.query_tabs[self.active_query_tab] = outgoing;
+
+        let incoming = std::mem::take(&mut self.query_tabs[index]);
+        self.query = incoming.query;
+        self.result = incoming.result;
+        self.result_offset = incoming.result_offset;
+        self.result_truncated = incoming.result_truncated;
+        self.executed_query = incoming.executed_query;
+        self.pagination = incoming.pagination.with_wrap(self.pagination_wrap);
+        self.data_table_state = incoming.data_table_state;
+        self.column_order = incoming.column_order;
+        self.hidden_columns = incoming.hidden_columns;
+        self.browse = incoming.browse;
+        self.result_limit = incoming.result_limit;
+        self.active_query_tab = index;
+    }
+}
@@ -0,0 +1,264 @@
+//! Reloading projects edited outside lazydb (`F5`)
+
+use std::time::Instant;
+
+use crate::app::{App, SidebarMode};
+use crate::config::ProjectLoadError;
+use crate::model::{Connection, Project};
+
+impl App {
+    /// Ask the main loop to read `config.yaml` and the project files again
+    pub(crate) fn request_config_reload(&mut self) {
+        self.pending_config_reload = true;
+        self.status_message = "Reloading config...".to_string();
+    }
+
+    /// Check the config watcher; a change that has settled prompts for a reload
+    pub fn process_config_changes(&mut self, now: Instant) {
+        let Some(watcher) = self.config_watcher.as_mut() else {
+            return;
+        };
+        if watcher.changed(now) {
+            self.config_changed_on_disk = true;
+            self.status_message = "Config files changed on disk (F5 to reload)".to_string();
+        }
+    }
+
+    /// Replace the projects with ones read again from disk.
+    ///
+    /// Projects and connections are matched by name. A connection whose
+    /// host, port, database and user are unchanged keeps its expansion,
+    /// loaded tables and session `search_path`; the selection follows the
+    /// same names where they still exist.
+    pub fn reload_projects(
+        &mut self,
+        mut projects: Vec<Project>,
+        warnings: Vec<String>,
+        errors: Vec<ProjectLoadError>,
+    ) {
+        // Responses to table list, details and reconnect requests name their
+        // connection by index into the old projects, so they are dropped
+        self.cancel_metadata_fetch();
+        if let Some(request_id) = self.reconnect_request.take() {
+            self.cancelled_requests.insert(request_id);
+            self.loading.message = None;
+        }
+
+        let open_project = match self.sidebar_mode {
+            SidebarMode::Connections(idx) => self.projects.get(idx).map(|p| p.name.clone()),
+            SidebarMode::Projects => None,
+        };
+        let selected_project = self
+            .projects
+            .get(self.selected_project_idx)
+            .map(|p| p.name.clone());
+        let selected = self.selected_connection_info().map(|conn| {
+            let table = self
+                .selected_table_idx
+                .and_then(|idx| conn.tables.get(idx))
+                .map(|t| t.name.clone());
+            (conn.name.clone(), table)
+        });
+
+        for project in &mut projects {
+            let Some(old) = self.projects.iter_mut().find(|p| p.name == project.name) else {
+                continue;
+            };
+            for conn in &mut project.connections {
+                if let Some(old_conn) = old
+                    .connections
+                    .iter_mut()
+                    .find(|c| c.name == conn.name && same_server(c, conn))
+                {
+                    conn.expanded = old_conn.expanded;
+                    conn.tables = std::mem::take(&mut old_conn.tables);
+                    conn.search_path = old_conn.search_path.take();
+                }
+            }
+        }
+        self.projects = projects;
+        self.config_changed_on_disk = false;
+
+        // Loaded state is keyed by index, which may now point elsewhere
+        self.table_previews.clear();
+        self.restore_table = None;
+        self.pending_table_action = None;
+
+        let position = |name: Option<String>| {
+            name.and_then(|name| self.projects.iter().position(|p| p.name == name))
+        };
+        self.selected_project_idx = position(selected_project)
+            .unwrap_or(0)
+            .min(self.projects.len().saturating_sub(1));
+        self.sidebar_mode = match position(open_project) {
+            Some(idx) => SidebarMode::Connections(idx),
+            None => SidebarMode::Projects,
+        };
+
+        self.selected_connection_idx = 0;
+        self.selected_table_idx = None;
+        if let SidebarMode::Connections(proj_idx) = self.sidebar_mode {
+            self.selected_project_idx = proj_idx;
+            let connections = &self.projects[proj_idx].connections;
+            if let Some((conn_name, table)) = selected {
+                if let Some(conn_idx) = connections.iter().position(|c| c.name == conn_name) {
+                    self.selected_connection_idx = conn_idx;
+                    self.selected_table_idx = table.and_then(|table| {
+                        connections[conn_idx]
+                            .tables
+                            .iter()
+                            .position(|t| t.name == table)
+                    });
+                }
+            }
+        }
+
+        self.status_message = match warnings.first() {
+            Some(first) if warnings.len() > 1 => {
+                format!("{} (and {} more warnings)", first, warnings.len() - 1)
+            }
+            Some(first) => first.clone(),
+            None => format!("Reloaded {} projects", self.projects.len()),
+        };
+        self.report_project_load_errors(errors);
+    }
+}
+
+/// Whether two connections point at the same database as the same user
+fn same_server(a: &Connection, b: &Connection) -> bool {
+    a.host == b.host && a.port == b.port && a.database == b.database && a.username == b.username
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::DbResponse;
+    use crate::model::Table;
+
+    fn project(name: &str, conns: &[(&str, &str)]) -> Project {
+        let mut project = Project::new(name);
+        project.connections = conns
+            .iter()
+            .map(|(conn_name, host)| Connection {
+                name: conn_name.to_string(),
+                host: host.to_string(),
+                port: 5432,
+                username: "user".to_string(),
+                password: String::new(),
+                password_env: None,
+                database: "db".to_string(),
+                tables: vec![],
+                expanded: false,
+                default_schema: None,
+                color: None,
+                is_production: false,
+                query_timeout_secs: None,
+                snippets: Vec::new(),
+                search_path: None,
+            })
+            .collect();
+        project
+    }
+
+    #[test]
+    fn test_reload_keeps_selection_and_loaded_tables() {
+        let mut app = App::new(vec![
+            project("alpha", &[]),
+            project("beta", &[("local", "localhost"), ("stage", "stage.db")]),
+        ]);
+        app.sidebar_mode = SidebarMode::Connections(1);
+        app.selected_project_idx = 1;
+        app.selected_connection_idx = 1;
+        for conn in &mut app.projects[1].connections {
+            conn.expanded = true;
+            conn.tables = vec![Table::new("users"), Table::new("orders")];
+        }
+        app.selected_table_idx = Some(1);
+
+        // A project was added in front, and `local` moved to another host
+        app.reload_projects(
+            vec![
+                project("new", &[]),
+                project("alpha", &[]),
+                project("beta", &[("local", "db.internal"), ("stage", "stage.db")]),
+            ],
+            vec![],
+            vec![],
+        );
+
+        assert_eq!(app.sidebar_mode, SidebarMode::Connections(2));
+        assert_eq!(app.selected_project_idx, 2);
+        assert_eq!(app.selected_connection_idx, 1);
+        assert_eq!(app.selected_table_idx, Some(1));
+        let conns = &app.projects[2].connections;
+        assert!(conns[1].expanded);
+        assert_eq!(conns[1].tables.len(), 2);
+        assert!(!conns[0].expanded);
+        assert!(conns[0].tables.is_empty());
+        assert_eq!(app.status_message, "Reloaded 3 projects");
+    }
+
+    #[test]
+    fn test_reload_drops_table_fetch_in_flight() {
+        let mut app = App::new(vec![project(
+            "beta",
+            &[("local", "localhost"), ("stage", "stage.db")],
+        )]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_connection_idx = 1;
+        app.projects[0].connections[1].expanded = true;
+        app.metadata_requests.push(7);
+        app.loading.start_fetching_tables(1);
+
+        // The connections swap places in the edited file
+        app.reload_projects(
+            vec![project(
+                "beta",
+                &[("stage", "stage.db"), ("local", "localhost")],
+            )],
+            vec![],
+            vec![],
+        );
+        assert!(!app.loading.is_loading());
+        assert!(!app.projects[0].connections[0].expanded);
+
+        // The late table list does not land on the connection now at its index
+        app.handle_db_response(DbResponse::TablesLoaded {
+            request_id: 7,
+            result: Ok(vec![Table::new("users")]),
+            target: (0, 1),
+        });
+        assert!(app.projects[0]
+            .connections
+            .iter()
+            .all(|c| c.tables.is_empty()));
+        assert!(app.cancelled_requests.is_empty());
+    }
+
+    #[test]
+    fn test_reload_falls_back_when_the_project_is_gone() {
+        let mut app = App::new(vec![project("alpha", &[]), project("beta", &[])]);
+        app.sidebar_mode = SidebarMode::Connections(1);
+        app.selected_project_idx = 1;
+
+        app.reload_projects(
+            vec![project("alpha", &[])],
+            vec![],
+            vec![ProjectLoadError {
+                path: "projects/beta.yaml".to_string(),
+                message: "mapping values are not allowed in this context".to_string(),
+            }],
+        );
+
+        assert_eq!(app.sidebar_mode, SidebarMode::Projects);
+        assert_eq!(app.selected_project_idx, 0);
+        assert_eq!(
+            app.status_message,
+            "1 project file failed to load (Shift+w to view)"
+        );
+        assert!(matches!(
+            app.modal_state,
+            crate::app::ModalState::LoadErrors(_)
+        ));
+    }
+}
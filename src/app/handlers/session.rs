@@ -0,0 +1,164 @@
+//! Saving and restoring where the user left off (`restore_session` setting)
+
+use crate::app::{App, MainPanelTab, SidebarMode};
+use crate::model::SessionSnapshot;
+
+impl App {
+    /// Snapshot of the open project, expanded connections and query for the next launch
+    pub fn session_snapshot(&self) -> SessionSnapshot {
+        let mut snapshot = SessionSnapshot {
+            query: self.query.clone(),
+            tab: tab_name(self.panel_tab).to_string(),
+            ..Default::default()
+        };
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return snapshot;
+        };
+        let Some(project) = self.projects.get(proj_idx) else {
+            return snapshot;
+        };
+
+        let conn = project.connections.get(self.selected_connection_idx);
+        snapshot.project = Some(project.name.clone());
+        snapshot.connection = conn.map(|c| c.name.clone());
+        snapshot.table = conn
+            .zip(self.selected_table_idx)
+            .and_then(|(c, idx)| c.tables.get(idx))
+            .map(|t| t.name.clone());
+        snapshot.expanded = project
+            .connections
+            .iter()
+            .filter(|c| c.expanded)
+            .map(|c| c.name.clone())
+            .collect();
+        snapshot
+    }
+
+    /// Reopen a snapshot saved by a previous run.
+    ///
+    /// Expanded connections list their tables again through the worker, so
+    /// this must run after the worker is set. Names that no longer exist are skipped.
+    pub fn restore_session(&mut self, snapshot: &SessionSnapshot) {
+        self.query = snapshot.query.clone();
+        self.panel_tab = match snapshot.tab.as_str() {
+            "data" => MainPanelTab::Data,
+            "relations" => MainPanelTab::Relations,
+            _ => MainPanelTab::Schema,
+        };
+
+        let Some(proj_idx) = snapshot
+            .project
+            .as_ref()
+            .and_then(|name| self.projects.iter().position(|p| &p.name == name))
+        else {
+            return;
+        };
+        self.sidebar_mode = SidebarMode::Connections(proj_idx);
+        self.selected_project_idx = proj_idx;
+
+        let project = &mut self.projects[proj_idx];
+        if let Some(conn_idx) = snapshot
+            .connection
+            .as_ref()
+            .and_then(|name| project.connections.iter().position(|c| &c.name == name))
+        {
+            self.selected_connection_idx = conn_idx;
+            self.restore_table = snapshot
+                .table
+                .clone()
+                .map(|table| ((proj_idx, conn_idx), table));
+        }
+
+        let mut expanded = Vec::new();
+        for (conn_idx, conn) in project.connections.iter_mut().enumerate() {
+            if snapshot.expanded.contains(&conn.name) {
+                conn.expanded = true;
+                expanded.push((conn_idx, conn.clone()));
+            }
+        }
+        for (conn_idx, conn) in expanded {
+            self.send_fetch_tables(&conn, proj_idx, conn_idx);
+        }
+    }
+}
+
+/// Name of a main panel tab in the session file
+fn tab_name(tab: MainPanelTab) -> &'static str {
+    match tab {
+        MainPanelTab::Schema => "schema",
+        MainPanelTab::Data => "data",
+        MainPanelTab::Relations => "relations",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Connection, Project, Table};
+
+    fn connection(name: &str) -> Connection {
+        Connection {
+            name: name.to_string(),
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "user".to_string(),
+            password: String::new(),
+            password_env: None,
+            database: "db".to_string(),
+            tables: vec![],
+            expanded: false,
+            default_schema: None,
+            color: None,
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
+            search_path: None,
+        }
+    }
+
+    fn app() -> App {
+        let mut project = Project::new("main");
+        project.connections = vec![connection("local"), connection("staging")];
+        App::new(vec![Project::new("other"), project])
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut before = app();
+        before.sidebar_mode = SidebarMode::Connections(1);
+        before.selected_connection_idx = 1;
+        before.projects[1].connections[1].expanded = true;
+        before.projects[1].connections[1].tables = vec![Table::new("users")];
+        before.selected_table_idx = Some(0);
+        before.query = "SELECT 1;".to_string();
+        before.panel_tab = MainPanelTab::Data;
+
+        let snapshot = before.session_snapshot();
+        assert_eq!(snapshot.project.as_deref(), Some("main"));
+        assert_eq!(snapshot.table.as_deref(), Some("users"));
+        assert_eq!(snapshot.expanded, vec!["staging"]);
+
+        let mut after = app();
+        after.restore_session(&snapshot);
+        assert_eq!(after.sidebar_mode, SidebarMode::Connections(1));
+        assert_eq!(after.selected_connection_idx, 1);
+        assert!(!after.projects[1].connections[0].expanded);
+        assert!(after.projects[1].connections[1].expanded);
+        assert_eq!(after.query, "SELECT 1;");
+        assert_eq!(after.panel_tab, MainPanelTab::Data);
+        // The table is selected once the connection's tables have loaded
+        assert_eq!(after.restore_table, Some(((1, 1), "users".to_string())));
+    }
+
+    #[test]
+    fn test_restore_skips_missing_project() {
+        let mut app = app();
+        app.restore_session(&SessionSnapshot {
+            project: Some("gone".to_string()),
+            query: "SELECT 2;".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(app.sidebar_mode, SidebarMode::Projects);
+        assert_eq!(app.query, "SELECT 2;");
+    }
+}
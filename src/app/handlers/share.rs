@@ -0,0 +1,138 @@
+//! Project export/import handlers (shareable project files)
+
+use std::path::PathBuf;
+
+use crate::app::enums::SidebarMode;
+use crate::app::modals::{FilePathModal, ImportPasswordModal, ModalState};
+use crate::app::App;
+use crate::config::{project_file_slug, share, ProjectFile};
+use crate::model::{unique_name, Project};
+
+impl App {
+    /// Ask where to export the selected project
+    pub(crate) fn open_export_project_modal(&mut self) {
+        if let SidebarMode::Projects = self.sidebar_mode {
+            if let Some(project) = self.projects.get(self.selected_project_idx) {
+                let default_path = format!("{}.yaml", project_file_slug(&project.name));
+                self.modal_state = ModalState::ExportProject(
+                    self.selected_project_idx,
+                    FilePathModal::with_path(default_path),
+                );
+            }
+        }
+    }
+
+    /// Ask which project file to import
+    pub(crate) fn open_import_project_modal(&mut self) {
+        if let SidebarMode::Projects = self.sidebar_mode {
+            self.modal_state = ModalState::ImportProject(FilePathModal::default());
+        }
+    }
+
+    /// Write a project to `path` for sharing, leaving out passwords
+    pub(crate) fn export_project(&mut self, proj_idx: usize, path: &str) {
+        if path.is_empty() {
+            self.status_message = "Path cannot be empty".to_string();
+            return;
+        }
+        let Some(project) = self.projects.get(proj_idx) else {
+            self.modal_state = ModalState::None;
+            return;
+        };
+
+        match share::export_project_file(&expand_path(path), &ProjectFile::from(project)) {
+            Ok(()) => {
+                self.status_message = format!("Exported project to {} (passwords omitted)", path);
+                self.modal_state = ModalState::None;
+            }
+            Err(e) => {
+                // Keep modal open for user to correct the path
+                self.status_message = format!("Export failed: {}", e);
+            }
+        }
+    }
+
+    /// Load a shared project file and add it as a new project.
+    ///
+    /// The project is renamed if its name is taken, and the user is asked for
+    /// the password of every connection that has none.
+    pub(crate) fn import_project(&mut self, path: &str) {
+        if path.is_empty() {
+            self.status_message = "Path cannot be empty".to_string();
+            return;
+        }
+
+        let project_file = match share::import_project_file(&expand_path(path)) {
+            Ok(project_file) => project_file,
+            Err(e) => {
+                // Keep modal open for user to correct the path
+                self.status_message = format!("Import failed: {}", e);
+                return;
+            }
+        };
+
+        let mut project = Project::from(project_file);
+        project.name = unique_name(&project.name, self.projects.iter().map(|p| p.name.as_str()));
+        let pending: Vec<usize> = project
+            .connections
+            .iter()
+            .enumerate()
+            .filter(|(_, conn)| conn.password.is_empty() && conn.password_env.is_none())
+            .map(|(idx, _)| idx)
+            .collect();
+
+        self.projects.push(project);
+        let proj_idx = self.projects.len() - 1;
+        self.selected_project_idx = proj_idx;
+
+        if pending.is_empty() {
+            self.modal_state = ModalState::None;
+            self.finish_project_import(proj_idx);
+        } else {
+            self.modal_state = ModalState::ImportPassword(ImportPasswordModal {
+                project_idx: proj_idx,
+                pending,
+                password: String::new(),
+            });
+        }
+    }
+
+    /// Store the entered password and move on to the next connection
+    pub(crate) fn confirm_import_password(&mut self) {
+        let ModalState::ImportPassword(modal) = &mut self.modal_state else {
+            return;
+        };
+        let proj_idx = modal.project_idx;
+        let password = std::mem::take(&mut modal.password);
+        if let Some(conn_idx) = modal.current() {
+            modal.pending.remove(0);
+            if let Some(conn) = self
+                .projects
+                .get_mut(proj_idx)
+                .and_then(|p| p.connections.get_mut(conn_idx))
+            {
+                conn.password = password;
+            }
+        }
+
+        let done =
+            matches!(&self.modal_state, ModalState::ImportPassword(m) if m.pending.is_empty());
+        if done {
+            self.modal_state = ModalState::None;
+            self.finish_project_import(proj_idx);
+        }
+    }
+
+    /// Mark an imported project to be saved into the config directory
+    pub(crate) fn finish_project_import(&mut self, proj_idx: usize) {
+        if let Some(project) = self.projects.get(proj_idx) {
+            self.status_message = format!("Imported project {}", project.name);
+            self.pending_project_save = Some(proj_idx);
+        }
+    }
+}
+
+/// Expand a leading `~` in a user-entered path
+fn expand_path(path: &str) -> PathBuf {
+    PathBuf::from(shellexpand::tilde(path).as_ref())
+}
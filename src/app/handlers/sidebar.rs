@@ -1,7 +1,12 @@
 //! Sidebar action handlers (activate, go back, toggle expand)
 
-use crate::app::enums::SidebarMode;
-use crate::app::App;
+use crate::app::cache::is_mutating_statement;
+use crate::app::enums::{MainPanelTab, SidebarMode};
+use crate::app::{App, BrowseQuery, ConfirmQueryModal, FilterModal, ModalState, OrderModal};
+use crate::db::{trim_statement, DatabaseType};
+use crate::message::Message;
+use crate::model::schema::Column;
+use crate::model::{RecentTable, Table};
 
 impl App {
     /// Activate current selection (Enter key)
@@ -80,6 +85,51 @@ impl App {
         }
     }
 
+    /// Collapse every connection of the current project (`zM`)
+    pub(crate) fn collapse_all_connections(&mut self) {
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return;
+        };
+        let Some(project) = self.projects.get_mut(proj_idx) else {
+            return;
+        };
+        for conn in &mut project.connections {
+            conn.expanded = false;
+        }
+        // Tables are hidden now; keep the cursor on their connection
+        self.selected_table_idx = None;
+        self.status_message = "Collapsed all connections".to_string();
+    }
+
+    /// Expand every connection of the current project (`zR`).
+    ///
+    /// Only connections whose tables were never listed are fetched, all at once
+    /// through the worker; each fills in as its response arrives.
+    pub(crate) fn expand_all_connections(&mut self) {
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return;
+        };
+        let Some(project) = self.projects.get_mut(proj_idx) else {
+            return;
+        };
+        let mut to_fetch = Vec::new();
+        for (conn_idx, conn) in project.connections.iter_mut().enumerate() {
+            if !conn.expanded
+                && conn.tables.is_empty()
+                && !self.loading.is_fetching_tables_for(conn_idx)
+            {
+                to_fetch.push((conn_idx, conn.clone()));
+            }
+            conn.expanded = true;
+        }
+
+        // Set before sending so a worker error stays visible
+        self.status_message = "Expanded all connections".to_string();
+        for (conn_idx, conn) in to_fetch {
+            self.send_fetch_tables(&conn, proj_idx, conn_idx);
+        }
+    }
+
     pub(crate) fn activate_table(&mut self, proj_idx: usize) {
         // Skip if a query is already executing
         if self.loading.executing_query {
@@ -91,28 +141,639 @@ impl App {
             return;
         };
 
-        let Some(project) = self.projects.get(proj_idx) else {
+        let Some(table_name) = self
+            .projects
+            .get(proj_idx)
+            .and_then(|p| p.connections.get(conn_idx))
+            .and_then(|c| c.tables.get(table_idx))
+            .map(|t| t.name.clone())
+        else {
             return;
         };
-        let Some(conn) = project.connections.get(conn_idx) else {
+
+        self.open_table(proj_idx, conn_idx, &table_name);
+    }
+
+    /// Fetch the row preview of the table selected in the sidebar unless it
+    /// is cached or another preview is still loading
+    pub(crate) fn request_table_preview(&mut self) {
+        if self.table_previews.is_busy() {
+            return;
+        }
+        let Some(key) = self.selected_preview_key() else {
             return;
         };
-        let Some(table) = conn.tables.get(table_idx) else {
+        if self.table_previews.get(&key).is_some() {
+            return;
+        }
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return;
+        };
+        let Some(conn) = self
+            .projects
+            .get(proj_idx)
+            .and_then(|p| p.connections.get(self.selected_connection_idx))
+        else {
+            return;
+        };
+        let Some(table) = self.selected_table_idx.and_then(|idx| conn.tables.get(idx)) else {
             return;
         };
 
-        // Safely quote the table name as a SQL identifier, escaping any embedded double quotes
-        let escaped_table_name = table.name.replace('"', "\"\"");
-        let query = format!("SELECT * FROM \"{}\"", escaped_table_name);
+        let (conn, table_name, schema) = (conn.clone(), table.name.clone(), table.schema.clone());
+        self.send_preview_table(&conn, &table_name, schema.as_deref(), key);
+    }
+
+    /// Browse a table's rows and record it in the recently opened tables
+    pub(crate) fn open_table(&mut self, proj_idx: usize, conn_idx: usize, table_name: &str) {
+        let Some(project) = self.projects.get(proj_idx) else {
+            return;
+        };
+        let Some(conn) = project.connections.get(conn_idx) else {
+            return;
+        };
+
+        self.recent_tables
+            .touch(RecentTable::new(&project.name, &conn.name, table_name));
+        self.recent_tables_dirty = true;
+        self.schema_states = Default::default();
+
+        let schema = conn
+            .tables
+            .iter()
+            .find(|t| t.name == table_name)
+            .and_then(|t| t.schema.as_deref());
+        let browse = BrowseQuery::new(self.database_type(), schema, table_name);
+        let query = browse.sql(self.browse_limit);
+        self.browse = Some(browse);
         self.query = format!("{};", query);
+        self.result_limit = self.browse_limit;
 
         // Clone connection for async operation
         let conn_clone = conn.clone();
 
-        // Send async command to execute query
-        self.send_execute_query(&conn_clone, &query, proj_idx);
+        // Browse through a server-side cursor so large tables load incrementally
+        self.send_open_cursor(&conn_clone, &query);
 
         // Move focus to main panel after selecting a table
         self.focus = crate::app::Focus::MainPanel;
     }
+
+    /// Put a `SELECT` naming the selected table's columns into the query editor.
+    ///
+    /// Only the columns matching the Columns tab filter are listed. When the
+    /// columns are not loaded yet they are fetched first and the query is
+    /// generated once they arrive.
+    pub(crate) fn generate_select(&mut self) {
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return;
+        };
+        let (Some(table), Some(table_idx)) = (self.selected_table_info(), self.selected_table_idx)
+        else {
+            self.status_message = "Select a table to generate a SELECT for".to_string();
+            return;
+        };
+
+        if !table.details_loaded {
+            self.status_message = format!("Loading columns of {}...", table.name);
+            self.load_details_then(proj_idx, table_idx, Message::GenerateSelect);
+            return;
+        }
+
+        let columns: Vec<&Column> = self
+            .column_search
+            .filtered_indices(&table.columns)
+            .into_iter()
+            .map(|idx| &table.columns[idx])
+            .collect();
+        if columns.is_empty() {
+            self.status_message = if table.columns.is_empty() {
+                format!("{} has no columns", table.name)
+            } else {
+                "No columns match the filter".to_string()
+            };
+            return;
+        }
+
+        let count = columns.len();
+        let name = table.name.clone();
+        self.query = format!(
+            "{};",
+            select_columns_query(self.database_type(), table, &columns)
+        );
+        self.status_message = format!("Generated SELECT for {} ({} columns)", name, count);
+    }
+
+    /// Copy the selected table's name, qualified with its schema and quoted,
+    /// to the clipboard
+    pub(crate) fn copy_table_name(&mut self) {
+        let Some(table) = self.selected_table_info() else {
+            self.status_message = "Select a table to copy its name".to_string();
+            return;
+        };
+        let name = full_table_name(self.database_type(), table);
+        self.status_message = format!("Copied {}", name);
+        self.pending_clipboard = Some(name);
+    }
+
+    /// Fetch the selected table's details and replay `message` once they arrive
+    fn load_details_then(&mut self, proj_idx: usize, table_idx: usize, message: Message) {
+        let target = (proj_idx, self.selected_connection_idx, table_idx);
+        self.pending_table_action = Some((target, message));
+        self.fetch_table_details_if_needed(proj_idx);
+    }
+
+    /// Name, schema and column names of the selected table for the filter
+    /// and order modals.
+    ///
+    /// When the columns are not loaded yet they are fetched and `action` is
+    /// replayed once they arrive.
+    fn selected_table_columns(
+        &mut self,
+        action: Message,
+    ) -> Option<(String, Option<String>, Vec<String>)> {
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return None;
+        };
+        let (Some(table), Some(table_idx)) = (self.selected_table_info(), self.selected_table_idx)
+        else {
+            self.status_message = "Select a table to browse".to_string();
+            return None;
+        };
+
+        if !table.details_loaded {
+            self.status_message = format!("Loading columns of {}...", table.name);
+            self.load_details_then(proj_idx, table_idx, action);
+            return None;
+        }
+        if table.columns.is_empty() {
+            self.status_message = format!("{} has no columns", table.name);
+            return None;
+        }
+
+        let columns = table.columns.iter().map(|c| c.name.clone()).collect();
+        Some((table.name.clone(), table.schema.clone(), columns))
+    }
+
+    /// Open the WHERE clause builder for the selected table
+    pub(crate) fn open_filter_modal(&mut self) {
+        let Some((name, schema, columns)) = self.selected_table_columns(Message::OpenFilterModal)
+        else {
+            return;
+        };
+        let mut modal = FilterModal::new(self.database_type(), name, columns);
+        modal.schema = schema;
+        self.modal_state = ModalState::Filter(modal);
+    }
+
+    /// Open the `ORDER BY` picker for the selected table, starting from the
+    /// order it is browsed with
+    pub(crate) fn open_order_modal(&mut self) {
+        let Some((name, schema, columns)) = self.selected_table_columns(Message::OpenOrderModal)
+        else {
+            return;
+        };
+        let active = self
+            .browse
+            .as_ref()
+            .filter(|b| b.is_table(schema.as_deref(), &name))
+            .and_then(|b| b.order.as_ref());
+        let mut modal = OrderModal::new(self.database_type(), &name, columns, active);
+        modal.schema = schema;
+        self.modal_state = ModalState::Order(modal);
+    }
+
+    /// Browse the table with the condition built in the filter modal
+    pub(crate) fn apply_filter(&mut self) {
+        let ModalState::Filter(modal) = &self.modal_state else {
+            return;
+        };
+        let clause = match modal.clause() {
+            Ok(clause) => clause,
+            Err(e) => {
+                self.status_message = e.to_string();
+                return;
+            }
+        };
+        let (schema, table) = (modal.schema.clone(), modal.table_name.clone());
+        self.rebrowse(schema.as_deref(), &table, |browse| {
+            browse.filter = Some(clause)
+        });
+    }
+
+    /// Browse the table with the order chosen in the order modal
+    pub(crate) fn apply_order(&mut self) {
+        let ModalState::Order(modal) = &self.modal_state else {
+            return;
+        };
+        let order = modal.order();
+        let (schema, table) = (modal.schema.clone(), modal.table_name.clone());
+        self.rebrowse(schema.as_deref(), &table, |browse| browse.order = order);
+    }
+
+    /// Re-run the browse query of a table after changing its filter or order.
+    ///
+    /// The filter and order already applied to the table are kept, so the
+    /// two compose; browsing another table starts from neither.
+    fn rebrowse(
+        &mut self,
+        schema: Option<&str>,
+        table: &str,
+        update: impl FnOnce(&mut BrowseQuery),
+    ) {
+        if self.loading.executing_query {
+            return;
+        }
+        let Some(conn) = self.selected_connection_info().cloned() else {
+            return;
+        };
+
+        let mut browse = match self.browse.take() {
+            Some(browse) if browse.is_table(schema, table) => browse,
+            _ => BrowseQuery::new(self.database_type(), schema, table),
+        };
+        update(&mut browse);
+        let query = browse.sql(self.browse_limit);
+        self.browse = Some(browse);
+
+        self.modal_state = ModalState::None;
+        self.query = format!("{};", query);
+        self.result_limit = self.browse_limit;
+        self.panel_tab = MainPanelTab::Data;
+        self.send_open_cursor(&conn, &query);
+    }
+
+    /// Execute the query in the editor against the selected connection (all rows)
+    pub(crate) fn execute_editor_query(&mut self, confirmed: bool) {
+        // Skip if a query is already executing
+        if self.loading.executing_query {
+            return;
+        }
+
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            self.status_message = "Select a connection to run the query on".to_string();
+            return;
+        };
+        let query = self.query.trim().to_string();
+        if query.is_empty() {
+            self.status_message = "Query is empty".to_string();
+            return;
+        }
+        let Some(conn) = self.selected_connection_info().cloned() else {
+            self.status_message = "Select a connection to run the query on".to_string();
+            return;
+        };
+        if !confirmed && conn.is_production && is_mutating_statement(&query) {
+            self.modal_state = ModalState::ConfirmQuery(ConfirmQueryModal::new(&conn.name, query));
+            return;
+        }
+
+        // Queries typed by the user run as they are
+        self.result_limit = None;
+        self.browse = None;
+        self.send_execute_query(&conn, &query, proj_idx);
+    }
+
+    /// Run `query` on the connection at `target` without touching the editor
+    /// or the sidebar selection (all rows)
+    pub(crate) fn execute_query_on(
+        &mut self,
+        target: (usize, usize),
+        query: String,
+        confirmed: bool,
+    ) {
+        if self.loading.executing_query {
+            self.status_message = "A query is already running".to_string();
+            return;
+        }
+
+        let (proj_idx, conn_idx) = target;
+        let Some(conn) = self
+            .projects
+            .get(proj_idx)
+            .and_then(|p| p.connections.get(conn_idx))
+            .cloned()
+        else {
+            return;
+        };
+        if !confirmed && conn.is_production && is_mutating_statement(&query) {
+            self.modal_state = ModalState::ConfirmQuery(
+                ConfirmQueryModal::new(&conn.name, query).with_target(target),
+            );
+            return;
+        }
+
+        self.result_limit = None;
+        self.browse = None;
+        self.status_message = format!("Running query on {}", conn.name);
+        self.send_execute_query(&conn, &query, proj_idx);
+    }
+
+    /// Check the query in the editor on the selected connection without running it
+    pub(crate) fn validate_editor_query(&mut self) {
+        let query = self.query.trim().to_string();
+        if query.is_empty() {
+            self.status_message = "Query is empty".to_string();
+            return;
+        }
+        let Some(conn) = self.selected_connection_info().cloned() else {
+            self.status_message = "Select a connection to validate the query on".to_string();
+            return;
+        };
+        self.send_validate_query(&conn, &query);
+    }
+
+    /// Re-fetch the current result from the database, bypassing the result cache
+    pub(crate) fn refresh_result(&mut self) {
+        // Skip if a query is already executing
+        if self.loading.executing_query {
+            return;
+        }
+
+        match self.current_cache_key.take() {
+            Some(key) => {
+                let Some(conn) = self.selected_connection_info().cloned() else {
+                    return;
+                };
+                self.result_cache.remove(&key);
+                self.send_open_cursor(&conn, &key.query);
+            }
+            // A result cut short by switching query tabs is fetched again
+            None if self.result_truncated => {
+                let (Some(conn), Some(query)) = (
+                    self.selected_connection_info().cloned(),
+                    self.executed_query.clone(),
+                ) else {
+                    return;
+                };
+                self.send_open_cursor(&conn, &query);
+            }
+            None => self.execute_editor_query(false),
+        }
+    }
+}
+
+/// `SELECT *` for a table, qualified with its schema unless that is `public`.
+///
+/// Only `public` is reliably on the search path, so tables in other schemas
+/// (including system schemas) would otherwise fail or hit a same-named table.
+/// Identifiers are quoted the way `database_type` expects.
+pub(crate) fn select_all_query(
+    database_type: DatabaseType,
+    schema: Option<&str>,
+    table_name: &str,
+) -> String {
+    format!(
+        "SELECT * FROM {}",
+        qualified_table_name(database_type, schema, table_name)
+    )
+}
+
+/// `SELECT` listing `columns` explicitly, qualified like [`select_all_query`].
+///
+/// Lists every column of the table when `columns` is empty.
+pub(crate) fn select_columns_query(
+    database_type: DatabaseType,
+    table: &Table,
+    columns: &[&Column],
+) -> String {
+    let quote = |c: &Column| database_type.quote_identifier(&c.name);
+    let names: Vec<String> = if columns.is_empty() {
+        table.columns.iter().map(quote).collect()
+    } else {
+        columns.iter().map(|c| quote(c)).collect()
+    };
+    format!(
+        "SELECT {} FROM {}",
+        names.join(", "),
+        qualified_table_name(database_type, table.schema.as_deref(), &table.name)
+    )
+}
+
+/// Quoted table name, always prefixed with its schema when it is known, for
+/// use outside the current session's search path
+pub(crate) fn full_table_name(database_type: DatabaseType, table: &Table) -> String {
+    let name = database_type.quote_identifier(&table.name);
+    match &table.schema {
+        Some(schema) => format!("{}.{}", database_type.quote_identifier(schema), name),
+        None => name,
+    }
+}
+
+/// Quoted table name, prefixed with its schema unless that is `public`
+fn qualified_table_name(
+    database_type: DatabaseType,
+    schema: Option<&str>,
+    table_name: &str,
+) -> String {
+    let table = database_type.quote_identifier(table_name);
+    match schema.filter(|schema| *schema != "public") {
+        Some(schema) => format!("{}.{}", database_type.quote_identifier(schema), table),
+        None => table,
+    }
+}
+
+/// Append `LIMIT n` to a generated query unless it already has a LIMIT clause
+pub(crate) fn with_limit(query: &str, limit: Option<usize>) -> String {
+    let query = trim_statement(query);
+    match limit {
+        Some(limit) if !has_limit_clause(query) => format!("{} LIMIT {}", query, limit),
+        _ => query.to_string(),
+    }
+}
+
+/// Whether the query contains a LIMIT keyword outside of quotes
+fn has_limit_clause(query: &str) -> bool {
+    let mut word = String::new();
+    let mut quote: Option<char> = None;
+    for c in query.chars().chain(std::iter::once(' ')) {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                word.clear();
+            }
+            None if c.is_alphanumeric() || c == '_' => word.push(c),
+            None => {
+                if word.eq_ignore_ascii_case("limit") {
+                    return true;
+                }
+                word.clear();
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PG: DatabaseType = DatabaseType::PostgreSQL;
+
+    #[test]
+    fn test_select_all_query_without_schema() {
+        assert_eq!(
+            select_all_query(PG, None, "users"),
+            "SELECT * FROM \"users\""
+        );
+        assert_eq!(
+            select_all_query(PG, Some("public"), "users"),
+            "SELECT * FROM \"users\""
+        );
+    }
+
+    #[test]
+    fn test_select_all_query_with_schema() {
+        assert_eq!(
+            select_all_query(PG, Some("sales"), "orders"),
+            "SELECT * FROM \"sales\".\"orders\""
+        );
+        assert_eq!(
+            select_all_query(PG, Some("My Schema"), "odd\"name"),
+            "SELECT * FROM \"My Schema\".\"odd\"\"name\""
+        );
+    }
+
+    #[test]
+    fn test_with_limit_appends_limit() {
+        assert_eq!(
+            with_limit("SELECT * FROM \"users\"", Some(1000)),
+            "SELECT * FROM \"users\" LIMIT 1000"
+        );
+        assert_eq!(
+            with_limit("SELECT * FROM \"users\";", None),
+            "SELECT * FROM \"users\""
+        );
+    }
+
+    #[test]
+    fn test_with_limit_does_not_double_append() {
+        assert_eq!(
+            with_limit("SELECT * FROM users LIMIT 10;", Some(1000)),
+            "SELECT * FROM users LIMIT 10"
+        );
+        assert_eq!(
+            with_limit("select * from users limit 10", Some(1000)),
+            "select * from users limit 10"
+        );
+        // Identifiers merely containing or quoting the word are not a LIMIT clause
+        assert_eq!(
+            with_limit("SELECT * FROM \"limit\"", Some(5)),
+            "SELECT * FROM \"limit\" LIMIT 5"
+        );
+        assert_eq!(
+            with_limit("SELECT * FROM rate_limits", Some(5)),
+            "SELECT * FROM rate_limits LIMIT 5"
+        );
+    }
+
+    #[test]
+    fn test_select_all_query_quotes_reserved_and_spaced_names() {
+        // Reserved words and mixed case only resolve when quoted
+        assert_eq!(
+            select_all_query(PG, None, "Order"),
+            "SELECT * FROM \"Order\""
+        );
+        assert_eq!(
+            select_all_query(PG, Some("public"), "user data"),
+            "SELECT * FROM \"user data\""
+        );
+        assert_eq!(
+            select_all_query(PG, Some("Sales"), "Order"),
+            "SELECT * FROM \"Sales\".\"Order\""
+        );
+    }
+
+    #[test]
+    fn test_generated_queries_quote_per_database_type() {
+        let table = Table::new("order items")
+            .with_schema("shop")
+            .with_columns(vec![Column::new("key", "int")]);
+        assert_eq!(
+            select_columns_query(DatabaseType::MySQL, &table, &[]),
+            "SELECT `key` FROM `shop`.`order items`"
+        );
+        assert_eq!(
+            select_all_query(DatabaseType::MySQL, None, "a`b"),
+            "SELECT * FROM `a``b`"
+        );
+    }
+
+    #[test]
+    fn test_full_table_name_keeps_public_schema() {
+        assert_eq!(
+            full_table_name(PG, &Table::new("users").with_schema("public")),
+            "\"public\".\"users\""
+        );
+        assert_eq!(full_table_name(PG, &Table::new("Users")), "\"Users\"");
+        assert_eq!(
+            full_table_name(DatabaseType::MySQL, &Table::new("t").with_schema("shop")),
+            "`shop`.`t`"
+        );
+    }
+
+    #[test]
+    fn test_copy_table_name_uses_the_clipboard() {
+        use crate::model::{Connection, Project};
+
+        let mut project = Project::new("main");
+        project.connections = vec![Connection {
+            name: "local".to_string(),
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "user".to_string(),
+            password: String::new(),
+            password_env: None,
+            database: "db".to_string(),
+            tables: vec![Table::new("orders").with_schema("sales")],
+            expanded: true,
+            default_schema: None,
+            color: None,
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
+            search_path: None,
+        }];
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+
+        app.copy_table_name();
+        assert!(app.pending_clipboard.is_none());
+
+        app.selected_table_idx = Some(0);
+        app.copy_table_name();
+        assert_eq!(
+            app.pending_clipboard.as_deref(),
+            Some("\"sales\".\"orders\"")
+        );
+        assert_eq!(app.status_message, "Copied \"sales\".\"orders\"");
+    }
+
+    #[test]
+    fn test_select_columns_query_lists_given_columns() {
+        let table = Table::new("orders").with_schema("sales").with_columns(vec![
+            Column::new("id", "integer"),
+            Column::new("CustomerId", "integer"),
+            Column::new("note", "text"),
+        ]);
+        let chosen = [&table.columns[0], &table.columns[1]];
+        assert_eq!(
+            select_columns_query(PG, &table, &chosen),
+            "SELECT \"id\", \"CustomerId\" FROM \"sales\".\"orders\""
+        );
+        assert_eq!(
+            select_columns_query(PG, &table, &[]),
+            "SELECT \"id\", \"CustomerId\", \"note\" FROM \"sales\".\"orders\""
+        );
+
+        let public = Table::new("users")
+            .with_schema("public")
+            .with_columns(vec![Column::new("id", "integer")]);
+        assert_eq!(
+            select_columns_query(PG, &public, &[]),
+            "SELECT \"id\" FROM \"users\""
+        );
+    }
 }
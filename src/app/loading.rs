@@ -2,6 +2,17 @@
 
 #![allow(dead_code)] // Methods will be used for UI loading indicators
 
+use std::time::{Duration, Instant};
+
+/// When each kind of operation was started (or last reported progress)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StartTimes {
+    pub tables: Option<Instant>,
+    pub details: Option<Instant>,
+    pub query: Option<Instant>,
+    pub export: Option<Instant>,
+}
+
 /// Tracks loading states for various async database operations
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct LoadingState {
@@ -11,14 +22,21 @@ pub struct LoadingState {
     pub fetching_details: Option<(usize, usize, usize)>,
     /// Whether a query is currently executing
     pub executing_query: bool,
+    /// Whether an export to file is in progress
+    pub exporting: bool,
     /// Status message to display
     pub message: Option<String>,
+    /// Start time of each operation, for timing out stuck requests
+    pub started_at: StartTimes,
 }
 
 impl LoadingState {
     /// Returns true if any loading operation is in progress
     pub fn is_loading(&self) -> bool {
-        self.fetching_tables.is_some() || self.fetching_details.is_some() || self.executing_query
+        self.fetching_tables.is_some()
+            || self.fetching_details.is_some()
+            || self.executing_query
+            || self.exporting
     }
 
     /// Clear all loading states
@@ -26,27 +44,76 @@ impl LoadingState {
         self.fetching_tables = None;
         self.fetching_details = None;
         self.executing_query = false;
+        self.exporting = false;
         self.message = None;
+        self.started_at = StartTimes::default();
     }
 
     /// Set tables fetching state for a connection
     pub fn start_fetching_tables(&mut self, conn_idx: usize) {
         self.fetching_tables = Some(conn_idx);
+        self.started_at.tables = Some(Instant::now());
         self.message = Some("Loading tables...".to_string());
     }
 
     /// Set table details fetching state
     pub fn start_fetching_details(&mut self, proj_idx: usize, conn_idx: usize, table_idx: usize) {
         self.fetching_details = Some((proj_idx, conn_idx, table_idx));
+        self.started_at.details = Some(Instant::now());
         self.message = Some("Loading table details...".to_string());
     }
 
     /// Set query executing state
     pub fn start_executing_query(&mut self) {
         self.executing_query = true;
+        self.started_at.query = Some(Instant::now());
         self.message = Some("Executing query...".to_string());
     }
 
+    /// Set exporting state
+    pub fn start_exporting(&mut self) {
+        self.exporting = true;
+        self.started_at.export = Some(Instant::now());
+        self.message = Some("Exporting...".to_string());
+    }
+
+    /// Restart the export deadline after the worker reported progress
+    pub fn export_heartbeat(&mut self) {
+        if self.exporting {
+            self.started_at.export = Some(Instant::now());
+        }
+    }
+
+    /// Clear operations that started more than `timeout` before `now`.
+    ///
+    /// Returns the names of the operations that timed out.
+    pub fn expire(&mut self, now: Instant, timeout: Duration) -> Vec<&'static str> {
+        let overdue =
+            |started: Option<Instant>| started.is_some_and(|at| now.duration_since(at) > timeout);
+
+        let mut expired = Vec::new();
+        if self.fetching_tables.is_some() && overdue(self.started_at.tables) {
+            self.fetching_tables = None;
+            expired.push("table list");
+        }
+        if self.fetching_details.is_some() && overdue(self.started_at.details) {
+            self.fetching_details = None;
+            expired.push("table details");
+        }
+        if self.executing_query && overdue(self.started_at.query) {
+            self.executing_query = false;
+            expired.push("query");
+        }
+        if self.exporting && overdue(self.started_at.export) {
+            self.exporting = false;
+            expired.push("export");
+        }
+        if !expired.is_empty() && !self.is_loading() {
+            self.message = None;
+        }
+        expired
+    }
+
     /// Check if any table details fetch is in progress
     pub fn is_fetching_details(&self) -> bool {
         self.fetching_details.is_some()
@@ -68,6 +135,68 @@ impl LoadingState {
     }
 }
 
+/// Progress of re-fetching the table lists of several connections at once
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BulkRefresh {
+    /// Table list requests still waiting for a response
+    pub pending: Vec<u64>,
+    /// Number of connections being refreshed
+    pub total: usize,
+    /// Number of connections whose tables could not be fetched
+    pub failed: usize,
+}
+
+impl BulkRefresh {
+    pub fn new(request_ids: Vec<u64>) -> Self {
+        Self {
+            total: request_ids.len(),
+            pending: request_ids,
+            failed: 0,
+        }
+    }
+
+    /// Record the response to `request_id`; returns false if it is not part of this refresh
+    pub fn finish(&mut self, request_id: u64, ok: bool) -> bool {
+        let before = self.pending.len();
+        self.pending.retain(|&id| id != request_id);
+        if self.pending.len() == before {
+            return false;
+        }
+        if !ok {
+            self.failed += 1;
+        }
+        true
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Status bar text, e.g. `Refreshing tables 2/5` or `Refreshed tables 5/5`
+    pub fn status(&self) -> String {
+        let failed = if self.failed > 0 {
+            format!(" ({} failed)", self.failed)
+        } else {
+            String::new()
+        };
+        if self.is_done() {
+            format!(
+                "Refreshed tables {}/{}{}",
+                self.total - self.failed,
+                self.total,
+                failed
+            )
+        } else {
+            format!(
+                "Refreshing tables {}/{}{}",
+                self.total - self.pending.len(),
+                self.total,
+                failed
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,7 +247,9 @@ mod tests {
             fetching_tables: Some(0),
             fetching_details: Some((0, 1, 2)),
             executing_query: true,
+            exporting: true,
             message: Some("test".to_string()),
+            ..Default::default()
         };
 
         state.clear();
@@ -127,9 +258,20 @@ mod tests {
         assert!(state.fetching_tables.is_none());
         assert!(state.fetching_details.is_none());
         assert!(!state.executing_query);
+        assert!(!state.exporting);
         assert!(state.message.is_none());
     }
 
+    #[test]
+    fn test_is_loading_when_exporting() {
+        let mut state = LoadingState::default();
+        state.start_exporting();
+
+        assert!(state.is_loading());
+        assert!(state.exporting);
+        assert!(state.message.is_some());
+    }
+
     #[test]
     fn test_is_fetching_tables_for() {
         let mut state = LoadingState::default();
@@ -151,6 +293,47 @@ mod tests {
         assert!(!state.is_fetching_details_for(1, 2, 0));
     }
 
+    #[test]
+    fn test_expire_clears_overdue_operations_only() {
+        let mut state = LoadingState::default();
+        state.start_executing_query();
+        state.start_fetching_tables(0);
+        let timeout = Duration::from_secs(5);
+
+        // Nothing is overdue before the deadline
+        let now = Instant::now();
+        assert!(state.expire(now, timeout).is_empty());
+        assert!(state.executing_query);
+
+        // Only the query is overdue once the table fetch restarted later
+        state.started_at.query = Some(now - Duration::from_secs(10));
+        assert_eq!(state.expire(now, timeout), vec!["query"]);
+        assert!(!state.executing_query);
+        assert_eq!(state.fetching_tables, Some(0));
+        assert!(state.message.is_some());
+
+        assert_eq!(
+            state.expire(now + Duration::from_secs(10), timeout),
+            vec!["table list"]
+        );
+        assert!(!state.is_loading());
+        assert!(state.message.is_none());
+    }
+
+    #[test]
+    fn test_export_heartbeat_extends_deadline() {
+        let mut state = LoadingState::default();
+        state.start_exporting();
+        let started = Instant::now() - Duration::from_secs(10);
+        state.started_at.export = Some(started);
+
+        state.export_heartbeat();
+        assert!(state
+            .expire(Instant::now(), Duration::from_secs(5))
+            .is_empty());
+        assert!(state.exporting);
+    }
+
     #[test]
     fn test_multiple_loading_states() {
         // Start with multiple operations in progress
@@ -169,4 +352,20 @@ mod tests {
         state.executing_query = false;
         assert!(!state.is_loading());
     }
+
+    #[test]
+    fn test_bulk_refresh_counts_responses() {
+        let mut refresh = BulkRefresh::new(vec![3, 4, 5]);
+        assert_eq!(refresh.status(), "Refreshing tables 0/3");
+
+        assert!(refresh.finish(4, true));
+        assert!(!refresh.finish(4, true));
+        assert!(!refresh.finish(9, true));
+        assert!(refresh.finish(3, false));
+        assert_eq!(refresh.status(), "Refreshing tables 2/3 (1 failed)");
+
+        assert!(refresh.finish(5, true));
+        assert!(refresh.is_done());
+        assert_eq!(refresh.status(), "Refreshed tables 2/3 (1 failed)");
+    }
 }
@@ -3,25 +3,43 @@
 //! This module contains the core application state, modal dialogs,
 //! and related types organized into submodules.
 
+mod browse;
+mod cache;
+mod column_search;
 mod enums;
 mod handlers;
 mod loading;
 mod modal_fields;
 mod modals;
+mod preview;
+mod query_tabs;
+mod schema_state;
 mod state;
+mod text_cursor;
 mod visibility;
 
 // Re-export all public types for external use
+pub use browse::BrowseQuery;
 pub use enums::{Focus, MainPanelTab, SchemaSubTab, SidebarMode};
 #[allow(unused_imports)]
 pub use loading::LoadingState;
-pub use modal_fields::{ConfirmModalField, ConnectionModalField, ProjectModalField};
+pub use modal_fields::{
+    ConfirmModalField, ConnectionModalField, FilterModalField, OrderModalField, ProjectModalField,
+};
 pub use modals::{
-    AddConnectionModal, ColumnVisibilityModal, DeleteProjectModal, HistoryModal, ModalState,
-    ProjectModal, SearchConnectionModal, SearchProjectModal, SearchTableModal, UnifiedSearchModal,
-    UnifiedSearchSection,
+    ActiveSessionsModal, AddConnectionModal, ColumnStatsModal, ColumnVisibilityModal,
+    ConfirmCopyModal, ConfirmQueryModal, ConfirmQuitModal, ConstraintDetailModal, CopyKind,
+    DatabaseStatsModal, DeleteProjectModal, FilePathModal, FilterModal, GlobalSearchModal,
+    GoToColumnModal, HelpModal, HistoryModal, ImportConnectionsModal, ImportPasswordModal,
+    LoadErrorsModal, ModalState, OrderModal, ProjectModal, RecentTablesModal, RolesModal,
+    SearchConnectionModal, SearchPathModal, SearchProjectModal, SearchTableModal,
+    ServerSettingsModal, SnippetsModal, UnifiedSearchModal, UnifiedSearchSection,
+    DEFINITION_WRAP_WIDTH,
 };
+pub use preview::TablePreview;
+pub use query_tabs::QueryTab;
 pub use state::App;
+pub use text_cursor::TextCursor;
 pub use visibility::{
     ColumnVisibilitySettings, ColumnsVisibility, ConstraintsVisibility, ForeignKeysVisibility,
     IndexesVisibility, TriggersVisibility,
@@ -3,12 +3,15 @@
 /// Field identifiers for Connection modal
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ConnectionModalField {
+    Dsn,
+    DatabaseType,
     Name,
     Host,
     Port,
     User,
     Password,
     Database,
+    Production,
     ButtonOk,
     ButtonCancel,
 }
@@ -16,26 +19,32 @@ pub enum ConnectionModalField {
 impl ConnectionModalField {
     pub fn next(self) -> Self {
         match self {
+            ConnectionModalField::Dsn => ConnectionModalField::DatabaseType,
+            ConnectionModalField::DatabaseType => ConnectionModalField::Name,
             ConnectionModalField::Name => ConnectionModalField::Host,
             ConnectionModalField::Host => ConnectionModalField::Port,
             ConnectionModalField::Port => ConnectionModalField::User,
             ConnectionModalField::User => ConnectionModalField::Password,
             ConnectionModalField::Password => ConnectionModalField::Database,
-            ConnectionModalField::Database => ConnectionModalField::ButtonOk,
+            ConnectionModalField::Database => ConnectionModalField::Production,
+            ConnectionModalField::Production => ConnectionModalField::ButtonOk,
             ConnectionModalField::ButtonOk => ConnectionModalField::ButtonCancel,
-            ConnectionModalField::ButtonCancel => ConnectionModalField::Name,
+            ConnectionModalField::ButtonCancel => ConnectionModalField::Dsn,
         }
     }
 
     pub fn prev(self) -> Self {
         match self {
-            ConnectionModalField::Name => ConnectionModalField::ButtonCancel,
+            ConnectionModalField::Dsn => ConnectionModalField::ButtonCancel,
+            ConnectionModalField::DatabaseType => ConnectionModalField::Dsn,
+            ConnectionModalField::Name => ConnectionModalField::DatabaseType,
             ConnectionModalField::Host => ConnectionModalField::Name,
             ConnectionModalField::Port => ConnectionModalField::Host,
             ConnectionModalField::User => ConnectionModalField::Port,
             ConnectionModalField::Password => ConnectionModalField::User,
             ConnectionModalField::Database => ConnectionModalField::Password,
-            ConnectionModalField::ButtonOk => ConnectionModalField::Database,
+            ConnectionModalField::Production => ConnectionModalField::Database,
+            ConnectionModalField::ButtonOk => ConnectionModalField::Production,
             ConnectionModalField::ButtonCancel => ConnectionModalField::ButtonOk,
         }
     }
@@ -86,3 +95,64 @@ impl ConfirmModalField {
         self.next()
     }
 }
+
+/// Field identifiers for the WHERE clause builder modal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterModalField {
+    Column,
+    Operator,
+    Value,
+    ButtonOk,
+    ButtonCancel,
+}
+
+impl FilterModalField {
+    pub fn next(self) -> Self {
+        match self {
+            FilterModalField::Column => FilterModalField::Operator,
+            FilterModalField::Operator => FilterModalField::Value,
+            FilterModalField::Value => FilterModalField::ButtonOk,
+            FilterModalField::ButtonOk => FilterModalField::ButtonCancel,
+            FilterModalField::ButtonCancel => FilterModalField::Column,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            FilterModalField::Column => FilterModalField::ButtonCancel,
+            FilterModalField::Operator => FilterModalField::Column,
+            FilterModalField::Value => FilterModalField::Operator,
+            FilterModalField::ButtonOk => FilterModalField::Value,
+            FilterModalField::ButtonCancel => FilterModalField::ButtonOk,
+        }
+    }
+}
+
+/// Field identifiers for the server-side order modal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrderModalField {
+    Column,
+    Direction,
+    ButtonOk,
+    ButtonCancel,
+}
+
+impl OrderModalField {
+    pub fn next(self) -> Self {
+        match self {
+            OrderModalField::Column => OrderModalField::Direction,
+            OrderModalField::Direction => OrderModalField::ButtonOk,
+            OrderModalField::ButtonOk => OrderModalField::ButtonCancel,
+            OrderModalField::ButtonCancel => OrderModalField::Column,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            OrderModalField::Column => OrderModalField::ButtonCancel,
+            OrderModalField::Direction => OrderModalField::Column,
+            OrderModalField::ButtonOk => OrderModalField::Direction,
+            OrderModalField::ButtonCancel => OrderModalField::ButtonOk,
+        }
+    }
+}
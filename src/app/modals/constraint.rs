@@ -0,0 +1,139 @@
+//! Constraint detail modal state
+
+use crate::model::schema::{Constraint, ConstraintType};
+
+/// Width the definition is word-wrapped to
+pub const DEFINITION_WRAP_WIDTH: usize = 72;
+
+/// Scrollable popup with the full definition of one constraint
+#[derive(Debug, Clone)]
+pub struct ConstraintDetailModal {
+    pub name: String,
+    pub constraint_type: ConstraintType,
+    pub columns: Vec<String>,
+    /// Definition word-wrapped to [`DEFINITION_WRAP_WIDTH`]
+    pub lines: Vec<String>,
+    /// First visible definition line
+    pub scroll: u16,
+}
+
+impl ConstraintDetailModal {
+    pub fn new(constraint: &Constraint) -> Self {
+        let definition = constraint.definition.as_deref().unwrap_or("-");
+        Self {
+            name: constraint.name.clone(),
+            constraint_type: constraint.constraint_type.clone(),
+            columns: constraint.columns.clone(),
+            lines: wrap_words(definition, DEFINITION_WRAP_WIDTH),
+            scroll: 0,
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        if (self.scroll as usize) + 1 < self.lines.len() {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
+
+/// Word-wrap `text` to `width` characters.
+///
+/// Existing line breaks and their indentation are kept; continuation lines
+/// reuse the indentation of the line they belong to. Words longer than the
+/// available width are split.
+pub(super) fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for raw in text.lines() {
+        let indent: String = raw.chars().take_while(|c| c.is_whitespace()).collect();
+        let indent_len = indent.chars().count();
+        // Keep at least a few columns for the content of deeply indented lines
+        let available = width.saturating_sub(indent_len).max(8);
+
+        let mut current = String::new();
+        let mut current_len = 0;
+        for word in raw.split_whitespace() {
+            let mut word: Vec<char> = word.chars().collect();
+            if current_len > 0 && current_len + 1 + word.len() > available {
+                lines.push(format!("{}{}", indent, current));
+                current.clear();
+                current_len = 0;
+            }
+            while word.len() > available {
+                if current_len > 0 {
+                    lines.push(format!("{}{}", indent, current));
+                    current.clear();
+                    current_len = 0;
+                }
+                let rest = word.split_off(available);
+                lines.push(format!("{}{}", indent, word.iter().collect::<String>()));
+                word = rest;
+            }
+            if word.is_empty() {
+                continue;
+            }
+            if current_len > 0 {
+                current.push(' ');
+                current_len += 1;
+            }
+            current.extend(word.iter());
+            current_len += word.len();
+        }
+        lines.push(format!("{}{}", indent, current).trim_end().to_string());
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_words_breaks_at_word_boundaries() {
+        let lines = wrap_words("CHECK ((price > 0) AND (price < 1000))", 20);
+        assert_eq!(lines, vec!["CHECK ((price > 0)", "AND (price < 1000))"]);
+        assert!(lines.iter().all(|l| l.chars().count() <= 20));
+    }
+
+    #[test]
+    fn test_wrap_words_keeps_line_breaks_and_indentation() {
+        let lines = wrap_words("CHECK (\n    a > 0 AND b > 0 AND c > 0\n)", 16);
+        assert_eq!(
+            lines,
+            vec![
+                "CHECK (",
+                "    a > 0 AND b",
+                "    > 0 AND c >",
+                "    0",
+                ")"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrap_words_splits_long_words() {
+        let lines = wrap_words("x abcdefghijklmnopqrst", 8);
+        assert_eq!(lines, vec!["x", "abcdefgh", "ijklmnop", "qrst"]);
+    }
+
+    #[test]
+    fn test_scroll_stops_at_last_line() {
+        let mut constraint = Constraint::new("price_check", ConstraintType::Check);
+        constraint.definition = Some("CHECK (a)\nAND (b)".to_string());
+        let mut modal = ConstraintDetailModal::new(&constraint);
+        assert_eq!(modal.lines.len(), 2);
+
+        modal.scroll_down();
+        modal.scroll_down();
+        assert_eq!(modal.scroll, 1);
+        modal.scroll_up();
+        modal.scroll_up();
+        assert_eq!(modal.scroll, 0);
+    }
+}
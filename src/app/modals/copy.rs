@@ -0,0 +1,34 @@
+//! Clipboard copy confirmation modal state
+
+use super::super::modal_fields::ConfirmModalField;
+
+/// What is copied once confirmed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyKind {
+    /// The rows held in memory, as JSON
+    Json,
+    /// The whole result, re-run by the DB worker, as CSV
+    Csv,
+}
+
+/// Asks before copying a large result to the clipboard
+#[derive(Debug, Clone)]
+pub struct ConfirmCopyModal {
+    pub kind: CopyKind,
+    /// Number of rows that would be copied
+    pub rows: usize,
+    /// Whether the result has more rows than `rows` on the server
+    pub more_rows: bool,
+    pub focused_field: ConfirmModalField,
+}
+
+impl ConfirmCopyModal {
+    pub fn new(kind: CopyKind, rows: usize, more_rows: bool) -> Self {
+        Self {
+            kind,
+            rows,
+            more_rows,
+            focused_field: ConfirmModalField::ButtonCancel,
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! WHERE clause builder modal state
+
+use super::super::modal_fields::FilterModalField;
+use crate::db::DatabaseType;
+
+/// Comparison offered by the WHERE clause builder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOperator {
+    Eq,
+    NotEq,
+    Lt,
+    Gt,
+    Like,
+    IsNull,
+}
+
+impl FilterOperator {
+    pub const ALL: [FilterOperator; 6] = [
+        FilterOperator::Eq,
+        FilterOperator::NotEq,
+        FilterOperator::Lt,
+        FilterOperator::Gt,
+        FilterOperator::Like,
+        FilterOperator::IsNull,
+    ];
+
+    /// SQL spelling of the operator
+    pub fn sql(self) -> &'static str {
+        match self {
+            FilterOperator::Eq => "=",
+            FilterOperator::NotEq => "!=",
+            FilterOperator::Lt => "<",
+            FilterOperator::Gt => ">",
+            FilterOperator::Like => "LIKE",
+            FilterOperator::IsNull => "IS NULL",
+        }
+    }
+
+    /// Whether the operator compares against a value
+    pub fn takes_value(self) -> bool {
+        self != FilterOperator::IsNull
+    }
+}
+
+/// Build a WHERE condition comparing `column` with `value`.
+///
+/// The column is quoted for `database_type` and the value is written as an
+/// escaped string literal, which the database coerces to the column's type.
+/// `LIKE` compares the column's text form so it also works on numbers and
+/// dates.
+pub fn where_clause(
+    database_type: DatabaseType,
+    column: &str,
+    operator: FilterOperator,
+    value: &str,
+) -> Result<String, &'static str> {
+    let column = database_type.quote_identifier(column);
+    if !operator.takes_value() {
+        return Ok(format!("{} {}", column, operator.sql()));
+    }
+    if value.is_empty() {
+        return Err("Enter a value to compare with");
+    }
+    if value.contains('\0') {
+        return Err("The value cannot contain NUL characters");
+    }
+    let escaped = match database_type {
+        // Backslashes start escape sequences in MySQL string literals
+        DatabaseType::MySQL | DatabaseType::MariaDB => value.replace('\\', "\\\\"),
+        DatabaseType::PostgreSQL | DatabaseType::SQLite => value.to_string(),
+    };
+    let literal = format!("'{}'", escaped.replace('\'', "''"));
+    Ok(match operator {
+        FilterOperator::Like if database_type == DatabaseType::PostgreSQL => {
+            format!("{}::text LIKE {}", column, literal)
+        }
+        _ => format!("{} {} {}", column, operator.sql(), literal),
+    })
+}
+
+/// Guided filter for the rows of the selected table
+#[derive(Debug, Clone)]
+pub struct FilterModal {
+    pub database_type: DatabaseType,
+    pub table_name: String,
+    pub schema: Option<String>,
+    /// Column names of the table, in table order
+    pub columns: Vec<String>,
+    pub column_idx: usize,
+    pub operator: FilterOperator,
+    pub value: String,
+    pub focused_field: FilterModalField,
+}
+
+impl FilterModal {
+    pub fn new(
+        database_type: DatabaseType,
+        table_name: impl Into<String>,
+        columns: Vec<String>,
+    ) -> Self {
+        Self {
+            database_type,
+            table_name: table_name.into(),
+            schema: None,
+            columns,
+            column_idx: 0,
+            operator: FilterOperator::Eq,
+            value: String::new(),
+            focused_field: FilterModalField::Column,
+        }
+    }
+
+    pub fn selected_column(&self) -> Option<&str> {
+        self.columns.get(self.column_idx).map(String::as_str)
+    }
+
+    /// Cycle the column or operator, whichever field is focused
+    pub fn cycle(&mut self, forward: bool) {
+        match self.focused_field {
+            FilterModalField::Column if !self.columns.is_empty() => {
+                let len = self.columns.len();
+                self.column_idx = if forward {
+                    (self.column_idx + 1) % len
+                } else {
+                    (self.column_idx + len - 1) % len
+                };
+            }
+            FilterModalField::Operator => {
+                let all = FilterOperator::ALL;
+                let pos = all.iter().position(|&op| op == self.operator).unwrap_or(0);
+                let next = if forward {
+                    (pos + 1) % all.len()
+                } else {
+                    (pos + all.len() - 1) % all.len()
+                };
+                self.operator = all[next];
+            }
+            _ => {}
+        }
+    }
+
+    /// The WHERE condition described by the modal
+    pub fn clause(&self) -> Result<String, &'static str> {
+        let column = self.selected_column().ok_or("The table has no columns")?;
+        where_clause(self.database_type, column, self.operator, &self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PG: DatabaseType = DatabaseType::PostgreSQL;
+
+    #[test]
+    fn test_where_clause_escapes_literals() {
+        assert_eq!(
+            where_clause(PG, "name", FilterOperator::Eq, "O'Brien"),
+            Ok("\"name\" = 'O''Brien'".to_string())
+        );
+        assert_eq!(
+            where_clause(PG, "age", FilterOperator::Gt, "30"),
+            Ok("\"age\" > '30'".to_string())
+        );
+        assert_eq!(
+            where_clause(PG, "x'); DROP TABLE t; --", FilterOperator::NotEq, "1"),
+            Ok("\"x'); DROP TABLE t; --\" != '1'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_where_clause_like_and_is_null() {
+        assert_eq!(
+            where_clause(PG, "id", FilterOperator::Like, "12%"),
+            Ok("\"id\"::text LIKE '12%'".to_string())
+        );
+        // IS NULL ignores the value
+        assert_eq!(
+            where_clause(PG, "deleted_at", FilterOperator::IsNull, "ignored"),
+            Ok("\"deleted_at\" IS NULL".to_string())
+        );
+    }
+
+    #[test]
+    fn test_where_clause_mysql_quoting() {
+        assert_eq!(
+            where_clause(DatabaseType::MySQL, "note", FilterOperator::Eq, "a\\'b"),
+            Ok("`note` = 'a\\\\''b'".to_string())
+        );
+        assert_eq!(
+            where_clause(DatabaseType::MySQL, "id", FilterOperator::Like, "1%"),
+            Ok("`id` LIKE '1%'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_where_clause_requires_value() {
+        assert!(where_clause(PG, "name", FilterOperator::Eq, "").is_err());
+        assert!(where_clause(PG, "name", FilterOperator::Eq, "a\0b").is_err());
+    }
+
+    #[test]
+    fn test_cycle_wraps_columns_and_operators() {
+        let mut modal = FilterModal::new(PG, "users", vec!["id".into(), "name".into()]);
+        modal.cycle(false);
+        assert_eq!(modal.selected_column(), Some("name"));
+
+        modal.focused_field = FilterModalField::Operator;
+        modal.cycle(false);
+        assert_eq!(modal.operator, FilterOperator::IsNull);
+        modal.cycle(true);
+        assert_eq!(modal.operator, FilterOperator::Eq);
+    }
+}
@@ -0,0 +1,20 @@
+//! Help overlay modal state
+
+/// Scrollable keybinding reference
+#[derive(Debug, Clone, Default)]
+pub struct HelpModal {
+    /// First visible line
+    pub scroll: u16,
+}
+
+impl HelpModal {
+    pub fn scroll_down(&mut self, line_count: usize) {
+        if (self.scroll as usize) + 1 < line_count {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
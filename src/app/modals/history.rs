@@ -1,8 +1,117 @@
-//! Query history modal state
+//! Query history and recent tables modal state
+
+use crate::model::{HistoryEntry, QueryHistory};
+
+/// Which entries the query history modal lists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HistoryFilter {
+    #[default]
+    All,
+    Failed,
+    Succeeded,
+}
+
+impl HistoryFilter {
+    /// Filter selected after this one: all, failed only, succeeded only
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Failed,
+            Self::Failed => Self::Succeeded,
+            Self::Succeeded => Self::All,
+        }
+    }
+
+    /// Label shown in the modal title; `None` when nothing is filtered out
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            Self::All => None,
+            Self::Failed => Some("failed only"),
+            Self::Succeeded => Some("succeeded only"),
+        }
+    }
+
+    pub fn matches(self, entry: &HistoryEntry) -> bool {
+        match self {
+            Self::All => true,
+            Self::Failed => !entry.is_success(),
+            Self::Succeeded => entry.is_success(),
+        }
+    }
+}
 
 /// Query history modal state
 #[derive(Debug, Clone, Default)]
 pub struct HistoryModal {
-    /// Currently selected index in the history list
+    /// Currently selected index in the filtered history list
+    pub selected_idx: usize,
+    /// Entries listed by their outcome
+    pub filter: HistoryFilter,
+    /// Whether the full query and details of the selected entry are shown
+    pub show_detail: bool,
+    /// Show execution times relative to now ("2m ago")
+    pub relative_times: bool,
+}
+
+impl HistoryModal {
+    /// Indices into the history of the entries passing the filter, in order
+    pub fn filtered_indices(&self, history: &QueryHistory) -> Vec<usize> {
+        history
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| self.filter.matches(entry))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Index into the history of the selected entry
+    pub fn selected_entry_idx(&self, history: &QueryHistory) -> Option<usize> {
+        self.filtered_indices(history)
+            .get(self.selected_idx)
+            .copied()
+    }
+}
+
+/// Recently opened tables modal state
+#[derive(Debug, Clone, Default)]
+pub struct RecentTablesModal {
+    /// Currently selected index in the recent tables list
     pub selected_idx: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_cycles_and_selects_matching_entries() {
+        let mut history = QueryHistory::new();
+        history.add(HistoryEntry::success("SELECT 1", "local", "app", 1, 1));
+        history.add(HistoryEntry::error(
+            "SELEC 2",
+            "local",
+            "app",
+            "syntax error",
+        ));
+        history.add(HistoryEntry::success("SELECT 3", "local", "app", 1, 1));
+
+        let mut modal = HistoryModal::default();
+        assert_eq!(modal.filtered_indices(&history).len(), 3);
+
+        modal.filter = modal.filter.next();
+        assert_eq!(modal.filter, HistoryFilter::Failed);
+        let failed = modal.filtered_indices(&history);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(
+            history
+                .get(modal.selected_entry_idx(&history).unwrap())
+                .unwrap()
+                .query,
+            "SELEC 2"
+        );
+
+        modal.filter = modal.filter.next();
+        assert_eq!(modal.filtered_indices(&history).len(), 2);
+        assert_eq!(modal.filter.next(), HistoryFilter::All);
+    }
+}
@@ -0,0 +1,12 @@
+//! Connection import modal state
+
+use crate::model::Connection;
+
+/// Modal previewing connections found in libpq config files before importing
+#[derive(Debug, Clone)]
+pub struct ImportConnectionsModal {
+    /// Connections that will be added to the current project
+    pub connections: Vec<Connection>,
+    /// Files the connections were read from
+    pub sources: Vec<String>,
+}
@@ -0,0 +1,47 @@
+//! Project load errors modal state
+
+use crate::config::ProjectLoadError;
+
+use super::constraint::{wrap_words, DEFINITION_WRAP_WIDTH};
+
+/// Scrollable list of the project files that failed to load, with the
+/// error of each
+#[derive(Debug, Clone)]
+pub struct LoadErrorsModal {
+    /// (file path, error word-wrapped to [`DEFINITION_WRAP_WIDTH`])
+    pub entries: Vec<(String, Vec<String>)>,
+    /// First visible line
+    pub scroll: u16,
+}
+
+impl LoadErrorsModal {
+    pub fn new(errors: &[ProjectLoadError]) -> Self {
+        let entries = errors
+            .iter()
+            .map(|error| {
+                (
+                    error.path.clone(),
+                    wrap_words(&error.message, DEFINITION_WRAP_WIDTH - 2),
+                )
+            })
+            .collect();
+        Self { entries, scroll: 0 }
+    }
+
+    /// Number of lines drawn: each path, its error lines and a blank line
+    /// between entries
+    pub fn line_count(&self) -> usize {
+        let lines: usize = self.entries.iter().map(|(_, error)| 1 + error.len()).sum();
+        lines + self.entries.len().saturating_sub(1)
+    }
+
+    pub fn scroll_down(&mut self) {
+        if (self.scroll as usize) + 1 < self.line_count() {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
@@ -1,17 +1,50 @@
 //! Modal state structures and their implementations
 
 mod connection;
+mod constraint;
+mod copy;
+mod filter;
+mod help;
 mod history;
+mod import;
+mod load_errors;
+mod order;
 mod project;
+mod query;
+mod quit;
+mod roles;
 mod search;
+mod search_path;
+mod server_settings;
+mod sessions;
+mod share;
+mod snippets;
 mod state;
+mod stats;
 mod visibility;
 
-pub use connection::AddConnectionModal;
-pub use history::HistoryModal;
+pub use connection::{parse_port, AddConnectionModal, FieldError};
+pub use constraint::{ConstraintDetailModal, DEFINITION_WRAP_WIDTH};
+pub use copy::{ConfirmCopyModal, CopyKind};
+pub use filter::FilterModal;
+pub use help::HelpModal;
+pub use history::{HistoryModal, RecentTablesModal};
+pub use import::ImportConnectionsModal;
+pub use load_errors::LoadErrorsModal;
+pub use order::OrderModal;
 pub use project::{DeleteProjectModal, ProjectModal, SearchProjectModal};
+pub use query::ConfirmQueryModal;
+pub use quit::ConfirmQuitModal;
+pub use roles::RolesModal;
 pub use search::{
-    SearchConnectionModal, SearchTableModal, UnifiedSearchModal, UnifiedSearchSection,
+    GlobalSearchModal, GoToColumnModal, SearchConnectionModal, SearchTableModal,
+    UnifiedSearchModal, UnifiedSearchSection,
 };
+pub use search_path::SearchPathModal;
+pub use server_settings::ServerSettingsModal;
+pub use sessions::ActiveSessionsModal;
+pub use share::{FilePathModal, ImportPasswordModal};
+pub use snippets::SnippetsModal;
 pub use state::ModalState;
+pub use stats::{ColumnStatsModal, DatabaseStatsModal};
 pub use visibility::ColumnVisibilityModal;
@@ -0,0 +1,121 @@
+//! Server-side order modal state
+
+use super::super::modal_fields::OrderModalField;
+use crate::db::DatabaseType;
+use crate::model::SortOrder;
+
+/// `ORDER BY` picker for the browsed table
+#[derive(Debug, Clone)]
+pub struct OrderModal {
+    pub database_type: DatabaseType,
+    pub table_name: String,
+    pub schema: Option<String>,
+    /// Column names of the table, in table order
+    pub columns: Vec<String>,
+    /// Selected column; `None` keeps the table's natural order
+    pub column_idx: Option<usize>,
+    pub direction: SortOrder,
+    pub focused_field: OrderModalField,
+}
+
+impl OrderModal {
+    /// Start from the active order, if any
+    pub fn new(
+        database_type: DatabaseType,
+        table_name: impl Into<String>,
+        columns: Vec<String>,
+        active: Option<&(String, SortOrder)>,
+    ) -> Self {
+        let column_idx = active.and_then(|(name, _)| columns.iter().position(|c| c == name));
+        let direction = match active {
+            Some((_, order)) if column_idx.is_some() => order.clone(),
+            _ => SortOrder::Asc,
+        };
+        Self {
+            database_type,
+            table_name: table_name.into(),
+            schema: None,
+            columns,
+            column_idx,
+            direction,
+            focused_field: OrderModalField::Column,
+        }
+    }
+
+    pub fn selected_column(&self) -> Option<&str> {
+        self.column_idx
+            .and_then(|idx| self.columns.get(idx))
+            .map(String::as_str)
+    }
+
+    /// Cycle the column or direction, whichever field is focused.
+    ///
+    /// The column cycle passes through "no order" between the last and the
+    /// first column.
+    pub fn cycle(&mut self, forward: bool) {
+        match self.focused_field {
+            OrderModalField::Column => {
+                let len = self.columns.len();
+                self.column_idx = match (self.column_idx, forward) {
+                    (None, true) if len > 0 => Some(0),
+                    (None, false) if len > 0 => Some(len - 1),
+                    (Some(idx), true) if idx + 1 < len => Some(idx + 1),
+                    (Some(idx), false) if idx > 0 => Some(idx - 1),
+                    _ => None,
+                };
+            }
+            OrderModalField::Direction => {
+                self.direction = match self.direction {
+                    SortOrder::Asc => SortOrder::Desc,
+                    SortOrder::Desc => SortOrder::Asc,
+                };
+            }
+            OrderModalField::ButtonOk | OrderModalField::ButtonCancel => {}
+        }
+    }
+
+    /// The order described by the modal
+    pub fn order(&self) -> Option<(String, SortOrder)> {
+        self.selected_column()
+            .map(|column| (column.to_string(), self.direction.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_from_active_order() {
+        let columns = vec!["id".to_string(), "name".to_string()];
+        let active = ("name".to_string(), SortOrder::Desc);
+        let modal = OrderModal::new(
+            DatabaseType::PostgreSQL,
+            "users",
+            columns.clone(),
+            Some(&active),
+        );
+        assert_eq!(modal.order(), Some(active));
+
+        let modal = OrderModal::new(DatabaseType::PostgreSQL, "users", columns, None);
+        assert_eq!(modal.order(), None);
+        assert_eq!(modal.direction, SortOrder::Asc);
+    }
+
+    #[test]
+    fn test_column_cycle_passes_through_no_order() {
+        let mut modal = OrderModal::new(
+            DatabaseType::PostgreSQL,
+            "users",
+            vec!["id".into(), "name".into()],
+            None,
+        );
+        modal.cycle(true);
+        assert_eq!(modal.selected_column(), Some("id"));
+        modal.cycle(true);
+        modal.cycle(true);
+        assert_eq!(modal.selected_column(), None);
+        modal.cycle(false);
+        assert_eq!(modal.selected_column(), Some("name"));
+    }
+}
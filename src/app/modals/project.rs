@@ -1,14 +1,18 @@
 //! Project modal states
 
+use crate::fuzzy;
 use crate::model::Project;
 
 use super::super::modal_fields::{ConfirmModalField, ProjectModalField};
+use super::super::text_cursor::TextCursor;
 
 /// Modal for adding/editing a project
 #[derive(Debug, Clone)]
 pub struct ProjectModal {
     pub name: String,
     pub focused_field: ProjectModalField,
+    /// Cursor in the name field
+    pub cursor: TextCursor,
 }
 
 impl Default for ProjectModal {
@@ -16,6 +20,7 @@ impl Default for ProjectModal {
         Self {
             name: String::new(),
             focused_field: ProjectModalField::Name,
+            cursor: TextCursor::default(),
         }
     }
 }
@@ -25,6 +30,7 @@ impl ProjectModal {
         Self {
             name: name.into(),
             focused_field: ProjectModalField::Name,
+            cursor: TextCursor::default(),
         }
     }
 }
@@ -55,13 +61,7 @@ impl SearchProjectModal {
     }
 
     pub fn update_filter(&mut self, projects: &[Project]) {
-        let query_lower = self.query.to_lowercase();
-        self.filtered_indices = projects
-            .iter()
-            .enumerate()
-            .filter(|(_, p)| self.query.is_empty() || p.name.to_lowercase().contains(&query_lower))
-            .map(|(idx, _)| idx)
-            .collect();
+        self.filtered_indices = fuzzy::rank(&self.query, projects.iter().map(|p| p.name.as_str()));
 
         // Adjust selected index if needed
         if self.selected_idx >= self.filtered_indices.len() {
@@ -0,0 +1,33 @@
+//! Production write confirmation modal state
+
+use super::super::modal_fields::ConfirmModalField;
+
+/// Asks before running a statement that modifies data on a production connection
+#[derive(Debug, Clone)]
+pub struct ConfirmQueryModal {
+    /// Name of the production connection the statement would run on
+    pub connection: String,
+    /// The statement waiting for confirmation
+    pub query: String,
+    /// Project and connection index of a statement re-run from history;
+    /// `None` runs the editor's query on the selected connection
+    pub target: Option<(usize, usize)>,
+    pub focused_field: ConfirmModalField,
+}
+
+impl ConfirmQueryModal {
+    pub fn new(connection: impl Into<String>, query: impl Into<String>) -> Self {
+        Self {
+            connection: connection.into(),
+            query: query.into(),
+            target: None,
+            focused_field: ConfirmModalField::ButtonCancel,
+        }
+    }
+
+    /// Run the statement on the connection at `target` once confirmed
+    pub fn with_target(mut self, target: (usize, usize)) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
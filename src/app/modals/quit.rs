@@ -0,0 +1,20 @@
+//! Quit confirmation modal state
+
+use super::super::modal_fields::ConfirmModalField;
+
+/// Asks before quitting while work would be lost
+#[derive(Debug, Clone)]
+pub struct ConfirmQuitModal {
+    /// What would be interrupted or lost, one line each
+    pub reasons: Vec<String>,
+    pub focused_field: ConfirmModalField,
+}
+
+impl ConfirmQuitModal {
+    pub fn new(reasons: Vec<String>) -> Self {
+        Self {
+            reasons,
+            focused_field: ConfirmModalField::ButtonCancel,
+        }
+    }
+}
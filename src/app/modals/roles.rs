@@ -0,0 +1,38 @@
+//! Database roles modal state
+
+use crate::model::DatabaseRole;
+
+/// Modal listing the users and roles defined on the server
+#[derive(Debug, Clone)]
+pub struct RolesModal {
+    /// Name of the connection the roles were read through
+    pub connection_name: String,
+    pub roles: Vec<DatabaseRole>,
+    /// Currently selected index in the role list
+    pub selected_idx: usize,
+}
+
+impl RolesModal {
+    pub fn new(connection_name: String, roles: Vec<DatabaseRole>) -> Self {
+        Self {
+            connection_name,
+            roles,
+            selected_idx: 0,
+        }
+    }
+
+    /// Whether some role attributes were hidden from the connected user
+    pub fn is_limited(&self) -> bool {
+        self.roles.iter().any(DatabaseRole::is_limited)
+    }
+
+    pub fn navigate_up(&mut self) {
+        self.selected_idx = self.selected_idx.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self) {
+        if self.selected_idx + 1 < self.roles.len() {
+            self.selected_idx += 1;
+        }
+    }
+}
@@ -1,6 +1,7 @@
-//! Search modal states for connections, tables, and unified search
+//! Search modal states for connections, tables, unified and global search
 
-use crate::model::{Connection, Table};
+use crate::fuzzy;
+use crate::model::{Connection, Project, Table};
 
 /// Search modal for filtering connections within a project
 #[derive(Debug, Clone, Default)]
@@ -20,13 +21,8 @@ impl SearchConnectionModal {
     }
 
     pub fn update_filter(&mut self, connections: &[Connection]) {
-        let query_lower = self.query.to_lowercase();
-        self.filtered_indices = connections
-            .iter()
-            .enumerate()
-            .filter(|(_, c)| self.query.is_empty() || c.name.to_lowercase().contains(&query_lower))
-            .map(|(idx, _)| idx)
-            .collect();
+        self.filtered_indices =
+            fuzzy::rank(&self.query, connections.iter().map(|c| c.name.as_str()));
 
         // Adjust selected index if needed
         if self.selected_idx >= self.filtered_indices.len() {
@@ -77,13 +73,7 @@ impl SearchTableModal {
     }
 
     pub fn update_filter(&mut self, tables: &[Table]) {
-        let query_lower = self.query.to_lowercase();
-        self.filtered_indices = tables
-            .iter()
-            .enumerate()
-            .filter(|(_, t)| self.query.is_empty() || t.name.to_lowercase().contains(&query_lower))
-            .map(|(idx, _)| idx)
-            .collect();
+        self.filtered_indices = fuzzy::rank(&self.query, tables.iter().map(|t| t.name.as_str()));
 
         // Adjust selected index if needed
         if self.selected_idx >= self.filtered_indices.len() {
@@ -159,23 +149,13 @@ impl UnifiedSearchModal {
 
     /// Update the filter based on the current query
     pub fn update_filter(&mut self, connections: &[Connection], tables: &[Table]) {
-        let query_lower = self.query.to_lowercase();
-
         // Filter connections
-        self.filtered_connection_indices = connections
-            .iter()
-            .enumerate()
-            .filter(|(_, c)| self.query.is_empty() || c.name.to_lowercase().contains(&query_lower))
-            .map(|(idx, _)| idx)
-            .collect();
+        self.filtered_connection_indices =
+            fuzzy::rank(&self.query, connections.iter().map(|c| c.name.as_str()));
 
         // Filter tables
-        self.filtered_table_indices = tables
-            .iter()
-            .enumerate()
-            .filter(|(_, t)| self.query.is_empty() || t.name.to_lowercase().contains(&query_lower))
-            .map(|(idx, _)| idx)
-            .collect();
+        self.filtered_table_indices =
+            fuzzy::rank(&self.query, tables.iter().map(|t| t.name.as_str()));
 
         // Adjust selected indices if needed
         if self.selected_connection_idx >= self.filtered_connection_indices.len() {
@@ -266,3 +246,158 @@ impl UnifiedSearchModal {
         self.filtered_table_indices.len()
     }
 }
+
+/// Location of a global search result in the sidebar
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchTarget {
+    pub project_idx: usize,
+    pub connection_idx: usize,
+    /// `None` for a connection result
+    pub table_idx: Option<usize>,
+}
+
+/// A connection or table found by the global search
+#[derive(Debug, Clone)]
+pub struct GlobalSearchEntry {
+    pub target: SearchTarget,
+    /// Connection or table name (what the query is matched against)
+    pub name: String,
+    /// Where the entry lives, e.g. `My Project / Production`
+    pub path: String,
+}
+
+/// Search modal across every connection of every project plus their loaded tables
+#[derive(Debug, Clone, Default)]
+pub struct GlobalSearchModal {
+    pub query: String,
+    pub entries: Vec<GlobalSearchEntry>,
+    pub filtered_indices: Vec<usize>,
+    pub selected_idx: usize,
+}
+
+impl GlobalSearchModal {
+    /// Index every connection and already-loaded table in `projects`
+    pub fn new(projects: &[Project]) -> Self {
+        let mut entries = Vec::new();
+        for (project_idx, project) in projects.iter().enumerate() {
+            for (connection_idx, conn) in project.connections.iter().enumerate() {
+                entries.push(GlobalSearchEntry {
+                    target: SearchTarget {
+                        project_idx,
+                        connection_idx,
+                        table_idx: None,
+                    },
+                    name: conn.name.clone(),
+                    path: project.name.clone(),
+                });
+                for (table_idx, table) in conn.tables.iter().enumerate() {
+                    entries.push(GlobalSearchEntry {
+                        target: SearchTarget {
+                            project_idx,
+                            connection_idx,
+                            table_idx: Some(table_idx),
+                        },
+                        name: table.name.clone(),
+                        path: format!("{} / {}", project.name, conn.name),
+                    });
+                }
+            }
+        }
+
+        Self {
+            query: String::new(),
+            filtered_indices: (0..entries.len()).collect(),
+            entries,
+            selected_idx: 0,
+        }
+    }
+
+    pub fn update_filter(&mut self) {
+        self.filtered_indices =
+            fuzzy::rank(&self.query, self.entries.iter().map(|e| e.name.as_str()));
+
+        // Adjust selected index if needed
+        if self.selected_idx >= self.filtered_indices.len() {
+            self.selected_idx = self.filtered_indices.len().saturating_sub(1);
+        }
+    }
+
+    pub fn selected_entry(&self) -> Option<&GlobalSearchEntry> {
+        self.filtered_indices
+            .get(self.selected_idx)
+            .and_then(|&idx| self.entries.get(idx))
+    }
+
+    pub fn navigate_up(&mut self) {
+        if !self.filtered_indices.is_empty() {
+            if self.selected_idx > 0 {
+                self.selected_idx -= 1;
+            } else {
+                self.selected_idx = self.filtered_indices.len() - 1;
+            }
+        }
+    }
+
+    pub fn navigate_down(&mut self) {
+        if !self.filtered_indices.is_empty() {
+            if self.selected_idx + 1 < self.filtered_indices.len() {
+                self.selected_idx += 1;
+            } else {
+                self.selected_idx = 0;
+            }
+        }
+    }
+}
+
+/// Search modal for jumping to a result column by name
+#[derive(Debug, Clone, Default)]
+pub struct GoToColumnModal {
+    pub query: String,
+    pub columns: Vec<String>,
+    pub filtered_indices: Vec<usize>,
+    pub selected_idx: usize,
+}
+
+impl GoToColumnModal {
+    pub fn new(columns: Vec<String>) -> Self {
+        Self {
+            query: String::new(),
+            filtered_indices: (0..columns.len()).collect(),
+            columns,
+            selected_idx: 0,
+        }
+    }
+
+    pub fn update_filter(&mut self) {
+        self.filtered_indices = fuzzy::rank(&self.query, self.columns.iter().map(String::as_str));
+
+        // Adjust selected index if needed
+        if self.selected_idx >= self.filtered_indices.len() {
+            self.selected_idx = self.filtered_indices.len().saturating_sub(1);
+        }
+    }
+
+    pub fn selected_column_idx(&self) -> Option<usize> {
+        self.filtered_indices.get(self.selected_idx).copied()
+    }
+
+    pub fn navigate_up(&mut self) {
+        if !self.filtered_indices.is_empty() {
+            if self.selected_idx > 0 {
+                self.selected_idx -= 1;
+            } else {
+                self.selected_idx = self.filtered_indices.len() - 1;
+            }
+        }
+    }
+
+    pub fn navigate_down(&mut self) {
+        if !self.filtered_indices.is_empty() {
+            if self.selected_idx + 1 < self.filtered_indices.len() {
+                self.selected_idx += 1;
+            } else {
+                self.selected_idx = 0;
+            }
+        }
+    }
+}
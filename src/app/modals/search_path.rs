@@ -0,0 +1,18 @@
+//! Session search_path modal state
+
+/// Modal asking for the `search_path` of a connection's sessions
+#[derive(Debug, Clone)]
+pub struct SearchPathModal {
+    /// (project index, connection index) of the connection being changed
+    pub target: (usize, usize),
+    pub value: String,
+}
+
+impl SearchPathModal {
+    pub fn new(target: (usize, usize), value: impl Into<String>) -> Self {
+        Self {
+            target,
+            value: value.into(),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Server settings modal state
+
+use crate::fuzzy;
+use crate::model::ServerSetting;
+
+/// Searchable, read-only list of the session's run-time parameters
+#[derive(Debug, Clone)]
+pub struct ServerSettingsModal {
+    /// Name of the connection the settings were read through
+    pub connection_name: String,
+    pub settings: Vec<ServerSetting>,
+    pub query: String,
+    pub filtered_indices: Vec<usize>,
+    pub selected_idx: usize,
+}
+
+impl ServerSettingsModal {
+    pub fn new(connection_name: String, settings: Vec<ServerSetting>) -> Self {
+        Self {
+            connection_name,
+            query: String::new(),
+            filtered_indices: (0..settings.len()).collect(),
+            settings,
+            selected_idx: 0,
+        }
+    }
+
+    pub fn update_filter(&mut self) {
+        self.filtered_indices =
+            fuzzy::rank(&self.query, self.settings.iter().map(|s| s.name.as_str()));
+
+        // Adjust selected index if needed
+        if self.selected_idx >= self.filtered_indices.len() {
+            self.selected_idx = self.filtered_indices.len().saturating_sub(1);
+        }
+    }
+
+    pub fn selected_setting(&self) -> Option<&ServerSetting> {
+        self.filtered_indices
+            .get(self.selected_idx)
+            .and_then(|&idx| self.settings.get(idx))
+    }
+
+    pub fn navigate_up(&mut self) {
+        if !self.filtered_indices.is_empty() {
+            if self.selected_idx > 0 {
+                self.selected_idx -= 1;
+            } else {
+                self.selected_idx = self.filtered_indices.len() - 1;
+            }
+        }
+    }
+
+    pub fn navigate_down(&mut self) {
+        if !self.filtered_indices.is_empty() {
+            if self.selected_idx + 1 < self.filtered_indices.len() {
+                self.selected_idx += 1;
+            } else {
+                self.selected_idx = 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setting(name: &str) -> ServerSetting {
+        ServerSetting {
+            name: name.to_string(),
+            setting: "on".to_string(),
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_filter_by_name() {
+        let mut modal = ServerSettingsModal::new(
+            "local".to_string(),
+            vec![
+                setting("search_path"),
+                setting("statement_timeout"),
+                setting("timezone"),
+            ],
+        );
+        modal.query = "stmt".to_string();
+        modal.update_filter();
+        assert_eq!(
+            modal.selected_setting().map(|s| s.name.as_str()),
+            Some("statement_timeout")
+        );
+
+        modal.query = "zzz".to_string();
+        modal.update_filter();
+        assert!(modal.selected_setting().is_none());
+    }
+}
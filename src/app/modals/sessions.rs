@@ -0,0 +1,90 @@
+//! Active sessions modal state
+
+use crate::model::ActiveSession;
+
+/// Modal listing the sessions connected to a database
+#[derive(Debug, Clone)]
+pub struct ActiveSessionsModal {
+    /// Name of the connection the sessions belong to
+    pub connection_name: String,
+    /// Database name
+    pub database: String,
+    pub sessions: Vec<ActiveSession>,
+    /// Currently selected index in the session list
+    pub selected_idx: usize,
+    /// Backend waiting for the user to confirm its termination
+    pub confirm_pid: Option<i32>,
+}
+
+impl ActiveSessionsModal {
+    pub fn new(connection_name: String, database: String, sessions: Vec<ActiveSession>) -> Self {
+        Self {
+            connection_name,
+            database,
+            sessions,
+            selected_idx: 0,
+            confirm_pid: None,
+        }
+    }
+
+    pub fn selected_session(&self) -> Option<&ActiveSession> {
+        self.sessions.get(self.selected_idx)
+    }
+
+    /// Replace the list after a refresh, keeping the selected backend if it is still there
+    pub fn set_sessions(&mut self, sessions: Vec<ActiveSession>) {
+        let selected_pid = self.selected_session().map(|s| s.pid);
+        self.selected_idx = selected_pid
+            .and_then(|pid| sessions.iter().position(|s| s.pid == pid))
+            .unwrap_or_else(|| self.selected_idx.min(sessions.len().saturating_sub(1)));
+        self.sessions = sessions;
+    }
+
+    pub fn navigate_up(&mut self) {
+        self.selected_idx = self.selected_idx.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self) {
+        if self.selected_idx + 1 < self.sessions.len() {
+            self.selected_idx += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(pid: i32) -> ActiveSession {
+        ActiveSession {
+            pid,
+            user: None,
+            state: Some("idle".to_string()),
+            wait_event: None,
+            duration_ms: None,
+            query: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_refresh_keeps_selected_pid() {
+        let mut modal = ActiveSessionsModal::new(
+            "local".to_string(),
+            "app".to_string(),
+            vec![session(1), session(2), session(3)],
+        );
+        modal.navigate_down();
+        modal.navigate_down();
+        assert_eq!(modal.selected_session().map(|s| s.pid), Some(3));
+
+        modal.set_sessions(vec![session(3), session(4)]);
+        assert_eq!(modal.selected_idx, 0);
+
+        // The selected backend is gone: stay at the same position, clamped
+        modal.navigate_down();
+        modal.set_sessions(vec![session(5)]);
+        assert_eq!(modal.selected_idx, 0);
+        modal.set_sessions(vec![]);
+        assert_eq!(modal.selected_session(), None);
+    }
+}
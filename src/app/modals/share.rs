@@ -0,0 +1,30 @@
+//! Project export/import modal states
+
+/// Modal asking for a file path (project export/import)
+#[derive(Debug, Clone, Default)]
+pub struct FilePathModal {
+    pub path: String,
+}
+
+impl FilePathModal {
+    pub fn with_path(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+/// Modal asking for the passwords of an imported project's connections, one at a time
+#[derive(Debug, Clone)]
+pub struct ImportPasswordModal {
+    /// Index of the imported project
+    pub project_idx: usize,
+    /// Indices of connections still waiting for a password (first is the current one)
+    pub pending: Vec<usize>,
+    pub password: String,
+}
+
+impl ImportPasswordModal {
+    /// Connection currently being asked for
+    pub fn current(&self) -> Option<usize> {
+        self.pending.first().copied()
+    }
+}
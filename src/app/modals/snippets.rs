@@ -0,0 +1,37 @@
+//! Connection snippets modal state
+
+use crate::config::SavedQuery;
+
+/// Modal listing the named queries configured on a connection
+#[derive(Debug, Clone)]
+pub struct SnippetsModal {
+    /// Name of the connection the snippets belong to
+    pub connection_name: String,
+    pub snippets: Vec<SavedQuery>,
+    /// Currently selected index in the snippet list
+    pub selected_idx: usize,
+}
+
+impl SnippetsModal {
+    pub fn new(connection_name: String, snippets: Vec<SavedQuery>) -> Self {
+        Self {
+            connection_name,
+            snippets,
+            selected_idx: 0,
+        }
+    }
+
+    pub fn selected_snippet(&self) -> Option<&SavedQuery> {
+        self.snippets.get(self.selected_idx)
+    }
+
+    pub fn navigate_up(&mut self) {
+        self.selected_idx = self.selected_idx.saturating_sub(1);
+    }
+
+    pub fn navigate_down(&mut self) {
+        if self.selected_idx + 1 < self.snippets.len() {
+            self.selected_idx += 1;
+        }
+    }
+}
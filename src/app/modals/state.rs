@@ -1,9 +1,27 @@
 //! Modal state enum
 
 use super::connection::AddConnectionModal;
-use super::history::HistoryModal;
+use super::constraint::ConstraintDetailModal;
+use super::copy::ConfirmCopyModal;
+use super::filter::FilterModal;
+use super::help::HelpModal;
+use super::history::{HistoryModal, RecentTablesModal};
+use super::import::ImportConnectionsModal;
+use super::load_errors::LoadErrorsModal;
+use super::order::OrderModal;
 use super::project::{DeleteProjectModal, ProjectModal, SearchProjectModal};
-use super::search::{SearchConnectionModal, SearchTableModal, UnifiedSearchModal};
+use super::query::ConfirmQueryModal;
+use super::quit::ConfirmQuitModal;
+use super::roles::RolesModal;
+use super::search::{
+    GlobalSearchModal, GoToColumnModal, SearchConnectionModal, SearchTableModal, UnifiedSearchModal,
+};
+use super::search_path::SearchPathModal;
+use super::server_settings::ServerSettingsModal;
+use super::sessions::ActiveSessionsModal;
+use super::share::{FilePathModal, ImportPasswordModal};
+use super::snippets::SnippetsModal;
+use super::stats::{ColumnStatsModal, DatabaseStatsModal};
 use super::visibility::ColumnVisibilityModal;
 
 /// Current modal state
@@ -11,13 +29,36 @@ use super::visibility::ColumnVisibilityModal;
 pub enum ModalState {
     None,
     AddConnection(AddConnectionModal),
+    CloneConnection(AddConnectionModal), // pre-filled copy of an existing connection
     AddProject(ProjectModal),
     EditProject(usize, ProjectModal), // (project index, modal)
     DeleteProject(DeleteProjectModal),
+    ExportProject(usize, FilePathModal), // (project index, modal)
+    ImportProject(FilePathModal),
+    ImportPassword(ImportPasswordModal),
     SearchProject(SearchProjectModal),
     SearchConnection(SearchConnectionModal),
     SearchTable(SearchTableModal),
     UnifiedSearch(UnifiedSearchModal),
+    GlobalSearch(GlobalSearchModal),
+    GoToColumn(GoToColumnModal),
     History(HistoryModal),
+    RecentTables(RecentTablesModal),
     ColumnVisibility(ColumnVisibilityModal),
+    DatabaseStats(DatabaseStatsModal),
+    ColumnStats(ColumnStatsModal),
+    ConstraintDetail(ConstraintDetailModal),
+    LoadErrors(LoadErrorsModal),
+    Filter(FilterModal),
+    Order(OrderModal),
+    ActiveSessions(ActiveSessionsModal),
+    Roles(RolesModal),
+    ServerSettings(ServerSettingsModal),
+    Snippets(SnippetsModal),
+    SearchPath(SearchPathModal),
+    ImportConnections(ImportConnectionsModal),
+    ConfirmQuit(ConfirmQuitModal),
+    ConfirmCopy(ConfirmCopyModal),
+    ConfirmQuery(ConfirmQueryModal),
+    Help(HelpModal),
 }
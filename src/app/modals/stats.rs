@@ -0,0 +1,21 @@
+//! Database and column stats modal state
+
+use crate::model::{ColumnStats, DatabaseStats};
+
+/// Modal showing the database size and its largest tables
+#[derive(Debug, Clone)]
+pub struct DatabaseStatsModal {
+    /// Name of the connection the stats belong to
+    pub connection_name: String,
+    /// Database name
+    pub database: String,
+    pub stats: DatabaseStats,
+}
+
+/// Modal showing statistics of one result column
+#[derive(Debug, Clone)]
+pub struct ColumnStatsModal {
+    pub stats: ColumnStats,
+    /// More rows remain on the server, so the stats only cover the fetched rows
+    pub partial: bool,
+}
@@ -0,0 +1,126 @@
+//! Row previews of the table selected in the sidebar
+
+use std::collections::HashMap;
+
+use crate::model::QueryResult;
+
+/// Rows fetched for a table preview
+pub const PREVIEW_ROWS: usize = 3;
+
+/// Project index, connection index and qualified name of a previewed table
+pub type PreviewKey = (usize, usize, String);
+
+/// Preview of one table
+#[derive(Debug, Clone)]
+pub enum TablePreview {
+    Loading,
+    Loaded(QueryResult),
+    Failed(String),
+}
+
+/// Previews fetched so far, one request at a time.
+///
+/// Moving through the sidebar only starts a request when none is in flight;
+/// the selection is checked again once it finishes, so holding `j` does not
+/// queue a query per table passed.
+#[derive(Debug, Default)]
+pub struct TablePreviews {
+    entries: HashMap<PreviewKey, TablePreview>,
+    /// Request in flight and the table it previews
+    in_flight: Option<(u64, PreviewKey)>,
+}
+
+impl TablePreviews {
+    pub fn get(&self, key: &PreviewKey) -> Option<&TablePreview> {
+        self.entries.get(key)
+    }
+
+    /// Whether a preview request is waiting for its response
+    pub fn is_busy(&self) -> bool {
+        self.in_flight.is_some()
+    }
+
+    /// Record that `request_id` fetches the preview of `key`
+    pub fn start(&mut self, request_id: u64, key: PreviewKey) {
+        self.entries.insert(key.clone(), TablePreview::Loading);
+        self.in_flight = Some((request_id, key));
+    }
+
+    /// Store the result of `request_id`; responses to other requests are ignored
+    pub fn finish(&mut self, request_id: u64, result: Result<QueryResult, String>) -> bool {
+        let Some((_, key)) = self.in_flight.take_if(|(id, _)| *id == request_id) else {
+            return false;
+        };
+        let preview = match result {
+            Ok(result) => TablePreview::Loaded(result),
+            Err(e) => TablePreview::Failed(e),
+        };
+        self.entries.insert(key, preview);
+        true
+    }
+
+    /// Forget the previews of one connection, e.g. after its tables were reloaded
+    pub fn clear_connection(&mut self, proj_idx: usize, conn_idx: usize) {
+        self.entries
+            .retain(|(p, c, _), _| (*p, *c) != (proj_idx, conn_idx));
+    }
+
+    /// Forget every preview and the request in flight
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.in_flight = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(conn_idx: usize, table: &str) -> PreviewKey {
+        (0, conn_idx, table.to_string())
+    }
+
+    fn result() -> QueryResult {
+        QueryResult {
+            columns: vec!["id".to_string()],
+            column_types: Vec::new(),
+            rows: vec![vec!["1".to_string()]],
+            execution_time_ms: 0,
+            total_rows: 1,
+            rows_affected: None,
+        }
+    }
+
+    #[test]
+    fn test_finish_stores_only_the_request_in_flight() {
+        let mut previews = TablePreviews::default();
+        previews.start(1, key(0, "public.users"));
+        assert!(previews.is_busy());
+        assert!(matches!(
+            previews.get(&key(0, "public.users")),
+            Some(TablePreview::Loading)
+        ));
+
+        assert!(!previews.finish(2, Ok(result())));
+        assert!(previews.finish(1, Ok(result())));
+        assert!(!previews.is_busy());
+        assert!(matches!(
+            previews.get(&key(0, "public.users")),
+            Some(TablePreview::Loaded(r)) if r.rows.len() == 1
+        ));
+    }
+
+    #[test]
+    fn test_clear_connection_keeps_other_connections() {
+        let mut previews = TablePreviews::default();
+        previews.start(1, key(0, "users"));
+        previews.finish(1, Err("denied".to_string()));
+        previews.start(2, key(1, "users"));
+        previews.finish(2, Ok(result()));
+
+        previews.clear_connection(0, 0);
+
+        assert!(previews.get(&key(0, "users")).is_none());
+        assert!(previews.get(&key(1, "users")).is_some());
+    }
+}
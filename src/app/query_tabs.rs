@@ -0,0 +1,63 @@
+//! Query editor tabs
+
+use std::collections::HashSet;
+
+use ratatui::widgets::TableState;
+
+use crate::model::{Pagination, QueryResult};
+
+use super::browse::BrowseQuery;
+
+/// Longest query excerpt shown in a tab title
+const TITLE_MAX_CHARS: usize = 20;
+
+/// Query and result of a query editor tab that is not shown.
+///
+/// The active tab lives in the `query`, `result` and related fields of
+/// [`App`](super::App); its slot in `App::query_tabs` is empty until another
+/// tab is activated and the two are swapped.
+#[derive(Debug, Clone, Default)]
+pub struct QueryTab {
+    pub query: String,
+    pub result: Option<QueryResult>,
+    pub(crate) result_offset: usize,
+    /// The result's cursor was closed before all rows were fetched
+    pub(crate) result_truncated: bool,
+    pub executed_query: Option<String>,
+    pub pagination: Pagination,
+    pub data_table_state: TableState,
+    pub(crate) column_order: Vec<usize>,
+    pub(crate) hidden_columns: HashSet<usize>,
+    pub(crate) browse: Option<BrowseQuery>,
+    pub(crate) result_limit: Option<usize>,
+}
+
+impl QueryTab {
+    /// Tab strip title: the 1-based number and the start of the first query line
+    pub fn title(index: usize, query: &str) -> String {
+        let first_line = query.lines().map(str::trim).find(|l| !l.is_empty());
+        match first_line {
+            Some(line) if line.chars().count() > TITLE_MAX_CHARS => {
+                let excerpt: String = line.chars().take(TITLE_MAX_CHARS).collect();
+                format!("{} {}…", index + 1, excerpt)
+            }
+            Some(line) => format!("{} {}", index + 1, line),
+            None => format!("{} (empty)", index + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_uses_first_non_empty_line() {
+        assert_eq!(QueryTab::title(0, ""), "1 (empty)");
+        assert_eq!(QueryTab::title(1, "\n  SELECT 1\nFROM t"), "2 SELECT 1");
+        assert_eq!(
+            QueryTab::title(2, "SELECT * FROM very_long_table_name"),
+            "3 SELECT * FROM very_l…"
+        );
+    }
+}
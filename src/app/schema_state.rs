@@ -0,0 +1,106 @@
+//! Row selection for the schema sub-tabs
+
+use ratatui::widgets::TableState;
+
+use super::enums::SchemaSubTab;
+
+/// Selected row in each list-like schema sub-tab
+///
+/// The Columns selection indexes the rows left by the column filter.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaTableStates {
+    pub columns: TableState,
+    pub indexes: TableState,
+    pub foreign_keys: TableState,
+    pub constraints: TableState,
+    pub triggers: TableState,
+}
+
+impl SchemaTableStates {
+    /// State for a sub-tab (`None` for Definition, which has no rows)
+    pub fn get(&self, sub_tab: SchemaSubTab) -> Option<&TableState> {
+        match sub_tab {
+            SchemaSubTab::Columns => Some(&self.columns),
+            SchemaSubTab::Indexes => Some(&self.indexes),
+            SchemaSubTab::ForeignKeys => Some(&self.foreign_keys),
+            SchemaSubTab::Constraints => Some(&self.constraints),
+            SchemaSubTab::Triggers => Some(&self.triggers),
+            SchemaSubTab::Definition => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, sub_tab: SchemaSubTab) -> Option<&mut TableState> {
+        match sub_tab {
+            SchemaSubTab::Columns => Some(&mut self.columns),
+            SchemaSubTab::Indexes => Some(&mut self.indexes),
+            SchemaSubTab::ForeignKeys => Some(&mut self.foreign_keys),
+            SchemaSubTab::Constraints => Some(&mut self.constraints),
+            SchemaSubTab::Triggers => Some(&mut self.triggers),
+            SchemaSubTab::Definition => None,
+        }
+    }
+
+    /// Selected row of a sub-tab, clamped to `row_count`
+    pub fn selected(&self, sub_tab: SchemaSubTab, row_count: usize) -> Option<usize> {
+        let selected = self.get(sub_tab)?.selected()?;
+        (row_count > 0).then(|| selected.min(row_count - 1))
+    }
+
+    /// Move the selection of a sub-tab by one row, stopping at either end
+    pub fn move_selection(&mut self, sub_tab: SchemaSubTab, row_count: usize, down: bool) {
+        let current = self.selected(sub_tab, row_count);
+        let Some(state) = self.get_mut(sub_tab) else {
+            return;
+        };
+        if row_count == 0 {
+            state.select(None);
+            return;
+        }
+        let next = match (current, down) {
+            (None, _) => 0,
+            (Some(idx), true) => (idx + 1).min(row_count - 1),
+            (Some(idx), false) => idx.saturating_sub(1),
+        };
+        state.select(Some(next));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_selection_stops_at_ends() {
+        let mut states = SchemaTableStates::default();
+        let tab = SchemaSubTab::Indexes;
+
+        states.move_selection(tab, 3, true);
+        assert_eq!(states.selected(tab, 3), Some(0));
+        states.move_selection(tab, 3, false);
+        assert_eq!(states.selected(tab, 3), Some(0));
+
+        for _ in 0..5 {
+            states.move_selection(tab, 3, true);
+        }
+        assert_eq!(states.selected(tab, 3), Some(2));
+    }
+
+    #[test]
+    fn test_selection_is_per_sub_tab() {
+        let mut states = SchemaTableStates::default();
+        states.move_selection(SchemaSubTab::ForeignKeys, 2, true);
+        states.move_selection(SchemaSubTab::ForeignKeys, 2, true);
+
+        assert_eq!(states.selected(SchemaSubTab::ForeignKeys, 2), Some(1));
+        assert_eq!(states.selected(SchemaSubTab::Constraints, 2), None);
+        // A shorter list (another table) clamps the selection
+        assert_eq!(states.selected(SchemaSubTab::ForeignKeys, 1), Some(0));
+    }
+
+    #[test]
+    fn test_definition_has_no_selection() {
+        let mut states = SchemaTableStates::default();
+        states.move_selection(SchemaSubTab::Definition, 3, true);
+        assert_eq!(states.selected(SchemaSubTab::Definition, 3), None);
+    }
+}
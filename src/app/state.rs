@@ -1,29 +1,57 @@
 //! Core App state and update logic
 
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
 use ratatui::widgets::TableState;
 
-use crate::db::DbWorkerHandle;
+use crate::config::{
+    ConfigWatcher, ProjectLoadError, DEFAULT_CLIPBOARD_CONFIRM_ROWS, DEFAULT_REQUEST_TIMEOUT_SECS,
+    DEFAULT_WRAP_ROW_HEIGHT,
+};
+use crate::db::{ConnectRetry, DatabaseType, DbWorkerHandle, ProviderCapabilities};
+use crate::export::{Delimiter, ExportFormat, QuoteStyle};
 use crate::message::Message;
-use crate::model::{Connection, Pagination, Project, QueryHistory, QueryResult, Table};
+use crate::model::{
+    ColumnStats, Connection, IconStyle, Pagination, Project, QueryHistory, QueryOutcome,
+    QueryResult, RecentTables, Table,
+};
 
+use super::browse::BrowseQuery;
+use super::cache::{CacheKey, ResultCache};
+use super::column_search::ColumnSearch;
 use super::enums::{Focus, MainPanelTab, SchemaSubTab, SidebarMode};
-use super::loading::LoadingState;
+use super::loading::{BulkRefresh, LoadingState};
 use super::modal_fields::ConfirmModalField;
 use super::modals::{
-    AddConnectionModal, ColumnVisibilityModal, DeleteProjectModal, HistoryModal, ModalState,
-    ProjectModal, SearchConnectionModal, SearchProjectModal, SearchTableModal, UnifiedSearchModal,
+    AddConnectionModal, ColumnStatsModal, ColumnVisibilityModal, ConfirmQueryModal,
+    ConfirmQuitModal, ConstraintDetailModal, DeleteProjectModal, GlobalSearchModal, HelpModal,
+    HistoryModal, LoadErrorsModal, ModalState, ProjectModal, SearchConnectionModal,
+    SearchProjectModal, SearchTableModal, UnifiedSearchModal,
 };
+use super::preview::{PreviewKey, TablePreview, TablePreviews};
+use super::query_tabs::QueryTab;
+use super::schema_state::SchemaTableStates;
+use super::text_cursor::TextCursor;
 use super::visibility::ColumnVisibilitySettings;
 
 /// Main application state
 pub struct App {
     pub projects: Vec<Project>,
+    /// Project files listed in `config.yaml` that could not be loaded
+    pub(crate) project_load_errors: Vec<ProjectLoadError>,
     pub sidebar_mode: SidebarMode,
     pub selected_project_idx: usize,
     pub selected_connection_idx: usize,
     pub selected_table_idx: Option<usize>,
     pub query: String,
     pub result: Option<QueryResult>,
+    /// Error of the last query, shown in the status bar while there is no result
+    pub query_error: Option<String>,
+    /// Query editor tabs; the active one's slot is empty while its state
+    /// lives in the fields above
+    pub query_tabs: Vec<QueryTab>,
+    pub active_query_tab: usize,
     pub pagination: Pagination,
     pub focus: Focus,
     pub panel_tab: MainPanelTab,
@@ -34,18 +62,134 @@ pub struct App {
     pub query_history: QueryHistory,
     /// Flag indicating that history has been modified and should be saved
     pub history_dirty: bool,
+    /// Minimum time between two history saves (zero saves every change)
+    pub(crate) history_save_interval: Duration,
+    /// When history was last written, to space out saves
+    last_history_save: Option<Instant>,
+    /// Recently opened tables (most recent first)
+    pub recent_tables: RecentTables,
+    /// Flag indicating that recent tables have been modified and should be saved
+    pub recent_tables_dirty: bool,
+    /// Whether `config.yaml` and the project files should be read again from disk
+    pub pending_config_reload: bool,
+    /// Watcher of the config files (`watch_config` setting)
+    pub(crate) config_watcher: Option<ConfigWatcher>,
+    /// Whether the config files changed on disk since they were last loaded
+    pub config_changed_on_disk: bool,
+    /// Index of a newly created project that should be saved to a new project file
+    pub pending_project_save: Option<usize>,
+    /// Text to copy to the clipboard after this update
+    pub pending_clipboard: Option<String>,
+    /// Whether the terminal should be cleared and redrawn from scratch after this update
+    pub pending_redraw: bool,
     /// Data table scroll state for navigation
     pub data_table_state: TableState,
     /// Column visibility settings for schema sub-tabs
     pub column_visibility: ColumnVisibilitySettings,
+    /// Name filter in the Columns sub-tab
+    pub column_search: ColumnSearch,
+    /// Selected row in each schema sub-tab
+    pub schema_states: SchemaTableStates,
+    /// Whether table lists include system schemas
+    pub show_system_objects: bool,
+    /// Whether the Data grid shows each column's type under its name
+    pub show_column_types: bool,
+    /// Whether long Data grid values wrap onto more lines of their row
+    pub wrap_cells: bool,
+    /// Most lines a wrapped Data grid row takes
+    pub(crate) wrap_row_height: u16,
+    /// Result column shown at each Data grid position, when columns were moved
+    pub(crate) column_order: Vec<usize>,
+    /// Result columns hidden from the Data grid
+    pub(crate) hidden_columns: HashSet<usize>,
+    /// SQL that produced the current result, as sent to the database
+    pub executed_query: Option<String>,
+    /// Whether the Data tab shows `executed_query` above the grid
+    pub show_executed_query: bool,
+    /// Whether the main panel fills the window, hiding the sidebar and editor
+    pub fullscreen_data: bool,
+    /// Table browsed in the Data tab, with its filter and order
+    pub(crate) browse: Option<BrowseQuery>,
+    /// First key of a two-key sequence such as `zM`, waiting for the second key
+    pub(crate) pending_key: Option<char>,
+    /// Table to select once the tables of the given connection have loaded
+    pub(crate) restore_table: Option<((usize, usize), String)>,
+    /// Action replayed once the columns of the given table have loaded
+    pub(crate) pending_table_action: Option<((usize, usize, usize), Message)>,
+    /// Retrying of refused or timed-out connections, passed to the DB worker
+    pub(crate) connect_retry: ConnectRetry,
+    /// Whether table lists count rows exactly instead of using estimates
+    pub(crate) exact_table_row_counts: bool,
+    /// Row limit appended to the query generated when opening a table
+    pub(crate) browse_limit: Option<usize>,
+    /// Limit applied to the current result by [`Self::browse_limit`]
+    pub result_limit: Option<usize>,
+    /// Glyphs used for table type icons
+    pub table_icons: IconStyle,
+    /// Draw only ASCII characters
+    pub ascii_mode: bool,
+    /// Whether paging past either end of a result wraps around
+    pub(crate) pagination_wrap: bool,
+    /// Whether the history modal shows times relative to now
+    pub(crate) relative_history_times: bool,
+    /// CSV export field delimiter
+    pub(crate) csv_delimiter: Delimiter,
+    /// CSV export quoting
+    pub(crate) csv_quote_style: QuoteStyle,
+    /// Whether UTF-8 CSV exports start with a BOM
+    pub(crate) csv_with_bom: bool,
     /// Handle to the background DB worker thread
     pub(crate) db_worker: Option<DbWorkerHandle>,
     /// Current loading state for async operations
     pub loading: LoadingState,
     /// Counter for generating unique request IDs
     pub(crate) next_request_id: u64,
+    /// Table list and table details requests still waiting for a response
+    pub(crate) metadata_requests: Vec<u64>,
+    /// Query or cursor request still waiting for a response
+    pub(crate) query_request: Option<u64>,
+    /// Reconnect request still waiting for a response
+    pub(crate) reconnect_request: Option<u64>,
+    /// Requests cancelled with Esc or timed out; their responses are dropped
+    pub(crate) cancelled_requests: HashSet<u64>,
+    /// Table list requests sent by "refresh all", with their progress
+    pub(crate) bulk_refresh: Option<BulkRefresh>,
+    /// Results with more rows than this ask before being copied as JSON
+    pub(crate) clipboard_confirm_rows: usize,
+    /// How long to wait for a DB response before giving up (`None` waits forever)
+    pub(crate) request_timeout: Option<Duration>,
+    /// Server-side statement timeout for connections without their own (0 for none)
+    pub(crate) statement_timeout_secs: u64,
     /// Pending query info for history (conn_name, database, query)
     pub(crate) pending_query_info: Option<(String, String, String)>,
+    /// Whether the browsing cursor behind the current result may hold more rows
+    pub(crate) cursor_has_more: bool,
+    /// Whether the DB worker holds the browsing cursor of the current result
+    pub(crate) cursor_open: bool,
+    /// Row of the whole result held first in `result`; earlier rows were
+    /// dropped from the browsing window
+    pub(crate) result_offset: usize,
+    /// Row the cursor batch being fetched starts at
+    pub(crate) pending_fetch_start: Option<usize>,
+    /// The result was only partly fetched when its query tab was put away,
+    /// and its cursor is closed (refresh to load it again)
+    pub(crate) result_truncated: bool,
+    /// Pending database stats request info (conn_name, database)
+    pub(crate) pending_stats_info: Option<(String, String)>,
+    /// Pending session list request info (conn_name, database)
+    pub(crate) pending_sessions_info: Option<(String, String)>,
+    /// Connection name of the pending role list request
+    pub(crate) pending_roles_info: Option<String>,
+    /// Connection name of the pending server settings request
+    pub(crate) pending_settings_info: Option<String>,
+    /// Recently fetched results, keyed by connection and query text
+    pub(crate) result_cache: ResultCache,
+    /// Cache key of the query request in flight
+    pub(crate) pending_cache_key: Option<CacheKey>,
+    /// Cache key of the browse result currently shown
+    pub(crate) current_cache_key: Option<CacheKey>,
+    /// First rows of tables selected in the sidebar
+    pub(crate) table_previews: TablePreviews,
 }
 
 impl App {
@@ -54,12 +198,16 @@ impl App {
     pub fn new(projects: Vec<Project>) -> Self {
         App {
             projects,
+            project_load_errors: Vec::new(),
             sidebar_mode: SidebarMode::Projects,
             selected_project_idx: 0,
             selected_connection_idx: 0,
             selected_table_idx: None,
             query: String::new(),
             result: None,
+            query_error: None,
+            query_tabs: vec![QueryTab::default()],
+            active_query_tab: 0,
             pagination: Pagination::default(),
             focus: Focus::Sidebar,
             panel_tab: MainPanelTab::Schema,
@@ -68,12 +216,69 @@ impl App {
             modal_state: ModalState::None,
             query_history: QueryHistory::new(),
             history_dirty: false,
+            history_save_interval: Duration::ZERO,
+            last_history_save: None,
+            recent_tables: RecentTables::new(),
+            recent_tables_dirty: false,
+            pending_config_reload: false,
+            config_watcher: None,
+            config_changed_on_disk: false,
+            pending_project_save: None,
+            pending_clipboard: None,
+            pending_redraw: false,
             data_table_state: TableState::default(),
             column_visibility: ColumnVisibilitySettings::default(),
+            column_search: ColumnSearch::default(),
+            schema_states: SchemaTableStates::default(),
+            show_system_objects: false,
+            show_column_types: false,
+            wrap_cells: false,
+            wrap_row_height: DEFAULT_WRAP_ROW_HEIGHT,
+            column_order: Vec::new(),
+            hidden_columns: HashSet::new(),
+            executed_query: None,
+            show_executed_query: false,
+            fullscreen_data: false,
+            pending_table_action: None,
+            browse: None,
+            connect_retry: ConnectRetry::default(),
+            exact_table_row_counts: false,
+            browse_limit: Some(1000),
+            result_limit: None,
+            table_icons: IconStyle::NerdFont,
+            ascii_mode: false,
+            pagination_wrap: false,
+            relative_history_times: false,
+            csv_delimiter: Delimiter::default(),
+            csv_quote_style: QuoteStyle::default(),
+            csv_with_bom: false,
             db_worker: None,
             loading: LoadingState::default(),
             next_request_id: 0,
+            metadata_requests: Vec::new(),
+            bulk_refresh: None,
+            restore_table: None,
+            pending_key: None,
+            query_request: None,
+            reconnect_request: None,
+            cancelled_requests: HashSet::new(),
+            clipboard_confirm_rows: DEFAULT_CLIPBOARD_CONFIRM_ROWS,
+            request_timeout: Some(Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS)),
+            statement_timeout_secs: 0,
             pending_query_info: None,
+            cursor_has_more: false,
+            cursor_open: false,
+            result_offset: 0,
+            pending_fetch_start: None,
+            result_truncated: false,
+            pending_stats_info: None,
+            pending_sessions_info: None,
+            pending_roles_info: None,
+            pending_settings_info: None,
+            result_cache: ResultCache::default(),
+            pending_cache_key: None,
+            current_cache_key: None,
+            table_previews: TablePreviews::default(),
         }
     }
 
@@ -81,12 +286,16 @@ impl App {
     pub fn with_history(projects: Vec<Project>, history: QueryHistory) -> Self {
         App {
             projects,
+            project_load_errors: Vec::new(),
             sidebar_mode: SidebarMode::Projects,
             selected_project_idx: 0,
             selected_connection_idx: 0,
             selected_table_idx: None,
             query: String::new(),
             result: None,
+            query_error: None,
+            query_tabs: vec![QueryTab::default()],
+            active_query_tab: 0,
             pagination: Pagination::default(),
             focus: Focus::Sidebar,
             panel_tab: MainPanelTab::Schema,
@@ -95,12 +304,69 @@ impl App {
             modal_state: ModalState::None,
             query_history: history,
             history_dirty: false,
+            history_save_interval: Duration::ZERO,
+            last_history_save: None,
+            recent_tables: RecentTables::new(),
+            recent_tables_dirty: false,
+            pending_config_reload: false,
+            config_watcher: None,
+            config_changed_on_disk: false,
+            pending_project_save: None,
+            pending_clipboard: None,
+            pending_redraw: false,
             data_table_state: TableState::default(),
             column_visibility: ColumnVisibilitySettings::default(),
+            column_search: ColumnSearch::default(),
+            schema_states: SchemaTableStates::default(),
+            show_system_objects: false,
+            show_column_types: false,
+            wrap_cells: false,
+            wrap_row_height: DEFAULT_WRAP_ROW_HEIGHT,
+            column_order: Vec::new(),
+            hidden_columns: HashSet::new(),
+            executed_query: None,
+            show_executed_query: false,
+            fullscreen_data: false,
+            pending_table_action: None,
+            browse: None,
+            connect_retry: ConnectRetry::default(),
+            exact_table_row_counts: false,
+            browse_limit: Some(1000),
+            result_limit: None,
+            table_icons: IconStyle::NerdFont,
+            ascii_mode: false,
+            pagination_wrap: false,
+            relative_history_times: false,
+            csv_delimiter: Delimiter::default(),
+            csv_quote_style: QuoteStyle::default(),
+            csv_with_bom: false,
             db_worker: None,
             loading: LoadingState::default(),
             next_request_id: 0,
+            metadata_requests: Vec::new(),
+            bulk_refresh: None,
+            restore_table: None,
+            pending_key: None,
+            query_request: None,
+            reconnect_request: None,
+            cancelled_requests: HashSet::new(),
+            clipboard_confirm_rows: DEFAULT_CLIPBOARD_CONFIRM_ROWS,
+            request_timeout: Some(Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS)),
+            statement_timeout_secs: 0,
             pending_query_info: None,
+            cursor_has_more: false,
+            cursor_open: false,
+            result_offset: 0,
+            pending_fetch_start: None,
+            result_truncated: false,
+            pending_stats_info: None,
+            pending_sessions_info: None,
+            pending_roles_info: None,
+            pending_settings_info: None,
+            result_cache: ResultCache::default(),
+            pending_cache_key: None,
+            current_cache_key: None,
+            table_previews: TablePreviews::default(),
         }
     }
 
@@ -133,6 +399,29 @@ impl App {
         }
     }
 
+    /// Preview cache key of the table selected in the sidebar
+    pub(crate) fn selected_preview_key(&self) -> Option<PreviewKey> {
+        let SidebarMode::Connections(proj_idx) = self.sidebar_mode else {
+            return None;
+        };
+        let table = self.selected_table_info()?;
+        Some((proj_idx, self.selected_connection_idx, table.full_name()))
+    }
+
+    /// Row preview of the table selected in the sidebar, once requested
+    pub fn selected_table_preview(&self) -> Option<&TablePreview> {
+        self.table_previews.get(&self.selected_preview_key()?)
+    }
+
+    /// How the last query ended: its result, or the error it failed with
+    pub fn query_outcome(&self) -> Option<QueryOutcome> {
+        match (&self.result, &self.query_error) {
+            (Some(result), _) => Some(QueryOutcome::from_result(result)),
+            (None, Some(e)) => Some(QueryOutcome::Failed(e.clone())),
+            (None, None) => None,
+        }
+    }
+
     /// Get currently selected connection (if any)
     pub fn selected_connection_info(&self) -> Option<&Connection> {
         if let SidebarMode::Connections(proj_idx) = self.sidebar_mode {
@@ -151,6 +440,145 @@ impl App {
         }
     }
 
+    /// Capabilities of the selected connection's database, used to hide
+    /// tabs and actions it does not support
+    pub fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities::for_database_type(&self.database_type())
+    }
+
+    /// Database type of the selected connection, deciding how generated
+    /// queries quote identifiers
+    pub fn database_type(&self) -> DatabaseType {
+        // Every connection is PostgreSQL for now
+        DatabaseType::PostgreSQL
+    }
+
+    /// Rows shown in a schema sub-tab for the selected table
+    pub fn schema_row_count(&self, sub_tab: SchemaSubTab) -> usize {
+        let Some(table) = self.selected_table_info() else {
+            return 0;
+        };
+        match sub_tab {
+            SchemaSubTab::Columns => self.column_search.filtered_indices(&table.columns).len(),
+            SchemaSubTab::Indexes => table.indexes.len(),
+            SchemaSubTab::ForeignKeys => table.foreign_keys.len(),
+            SchemaSubTab::Constraints => table.constraints.len(),
+            SchemaSubTab::Triggers => table.triggers.len(),
+            SchemaSubTab::Definition => 0,
+        }
+    }
+
+    /// Copy the selected column or index definition to the clipboard
+    fn copy_schema_definition(&mut self) {
+        let sub_tab = self.schema_sub_tab;
+        let Some(table) = self.selected_table_info() else {
+            return;
+        };
+        let Some(selected) = self
+            .schema_states
+            .selected(sub_tab, self.schema_row_count(sub_tab))
+        else {
+            self.status_message = "Select a row to copy (j/k)".to_string();
+            return;
+        };
+
+        let copied = match sub_tab {
+            SchemaSubTab::Columns => self
+                .column_search
+                .filtered_indices(&table.columns)
+                .get(selected)
+                .map(|&idx| &table.columns[idx])
+                .map(|col| (format!("column {}", col.name), col.definition())),
+            SchemaSubTab::Indexes => table.indexes.get(selected).and_then(|index| {
+                index
+                    .definition
+                    .clone()
+                    .map(|def| (format!("index {}", index.name), def))
+            }),
+            _ => {
+                self.status_message =
+                    "Copy is available in the Columns and Indexes tabs".to_string();
+                return;
+            }
+        };
+
+        match copied {
+            Some((what, text)) => {
+                self.pending_clipboard = Some(text);
+                self.status_message = format!("Copied {} definition", what);
+            }
+            None => self.status_message = "No definition available".to_string(),
+        }
+    }
+
+    /// Open a popup with the full definition of the selected constraint
+    fn show_constraint_detail(&mut self) {
+        if self.schema_sub_tab != SchemaSubTab::Constraints {
+            self.status_message = "Details are available in the Constraints tab".to_string();
+            return;
+        }
+        let Some(table) = self.selected_table_info() else {
+            return;
+        };
+        let selected = self
+            .schema_states
+            .selected(SchemaSubTab::Constraints, table.constraints.len());
+        match selected.and_then(|idx| table.constraints.get(idx)) {
+            Some(constraint) => {
+                self.modal_state =
+                    ModalState::ConstraintDetail(ConstraintDetailModal::new(constraint));
+            }
+            None => self.status_message = "Select a constraint (j/k)".to_string(),
+        }
+    }
+
+    /// Remember the project files that failed to load and, if there are
+    /// any, list them in a modal with the count in the status bar
+    pub fn report_project_load_errors(&mut self, errors: Vec<ProjectLoadError>) {
+        self.project_load_errors = errors;
+        if self.project_load_errors.is_empty() {
+            return;
+        }
+        self.status_message = match self.project_load_errors.len() {
+            1 => "1 project file failed to load (Shift+w to view)".to_string(),
+            n => format!("{} project files failed to load (Shift+w to view)", n),
+        };
+        self.show_load_errors();
+    }
+
+    /// Open a popup listing the project files that failed to load
+    fn show_load_errors(&mut self) {
+        if self.project_load_errors.is_empty() {
+            self.status_message = "All project files loaded".to_string();
+            return;
+        }
+        self.modal_state = ModalState::LoadErrors(LoadErrorsModal::new(&self.project_load_errors));
+    }
+
+    /// Open a modal with statistics of the selected result column
+    fn show_column_stats(&mut self) {
+        let Some(result) = &self.result else {
+            return;
+        };
+        let column = self.selected_data_column().unwrap_or(0);
+        match ColumnStats::compute(result, column) {
+            Some(stats) => {
+                self.modal_state = ModalState::ColumnStats(ColumnStatsModal {
+                    stats,
+                    partial: self.result_is_partial(),
+                });
+            }
+            None => self.status_message = "No column to summarize".to_string(),
+        }
+    }
+
+    /// Move the selected row in the active schema sub-tab
+    fn move_schema_selection(&mut self, down: bool) {
+        let sub_tab = self.schema_sub_tab;
+        let count = self.schema_row_count(sub_tab);
+        self.schema_states.move_selection(sub_tab, count, down);
+    }
+
     /// Get all tables in current connection (for ER diagram)
     pub fn current_connection_tables(&self) -> Option<&[Table]> {
         if let SidebarMode::Connections(proj_idx) = self.sidebar_mode {
@@ -162,24 +590,85 @@ impl App {
         }
     }
 
+    /// Work that would be lost by quitting now, described for the user
+    pub(crate) fn unfinished_work(&self) -> Vec<String> {
+        let mut reasons = Vec::new();
+        if self.loading.executing_query {
+            reasons.push("A query is still running".to_string());
+        }
+        if self.loading.exporting {
+            reasons.push("An export is in progress".to_string());
+        }
+        if self.loading.fetching_tables.is_some() || self.loading.fetching_details.is_some() {
+            reasons.push("Schema is still loading".to_string());
+        }
+        reasons
+    }
+
+    /// Whether modified history should be written now: at most once per
+    /// `history_save_interval`, the first change right away
+    pub fn history_save_due(&self, now: Instant) -> bool {
+        self.history_dirty
+            && self.last_history_save.is_none_or(|last| {
+                now.saturating_duration_since(last) >= self.history_save_interval
+            })
+    }
+
+    /// Record that history was written at `now`
+    pub fn mark_history_saved(&mut self, now: Instant) {
+        self.history_dirty = false;
+        self.last_history_save = Some(now);
+    }
+
     /// Update app state based on message. Returns true if app should quit.
     pub fn update(&mut self, message: Message) -> bool {
         match message {
-            Message::Quit => return true,
+            Message::Quit => {
+                let reasons = self.unfinished_work();
+                if reasons.is_empty() {
+                    return true;
+                }
+                self.modal_state = ModalState::ConfirmQuit(ConfirmQuitModal::new(reasons));
+            }
+            Message::ForceQuit => return true,
+            Message::ClearStatus => {
+                self.status_message = "Ready".to_string();
+                self.query_error = None;
+                self.pending_key = None;
+                self.column_search.clear();
+                self.pending_redraw = true;
+            }
+            Message::OpenHelp => {
+                self.modal_state = ModalState::Help(HelpModal::default());
+            }
+            Message::ReloadConfig => {
+                self.request_config_reload();
+            }
 
             // Navigation messages (handled by handlers/navigation.rs)
-            Message::NavigateUp => {
-                if self.focus == Focus::Sidebar {
-                    self.navigate_up();
+            Message::NavigateUp => match self.focus {
+                Focus::Sidebar => self.navigate_up(),
+                Focus::MainPanel if self.panel_tab == MainPanelTab::Schema => {
+                    self.move_schema_selection(false);
                 }
-            }
-            Message::NavigateDown => {
-                if self.focus == Focus::Sidebar {
-                    self.navigate_down();
+                _ => {}
+            },
+            Message::NavigateDown => match self.focus {
+                Focus::Sidebar => self.navigate_down(),
+                Focus::MainPanel if self.panel_tab == MainPanelTab::Schema => {
+                    self.move_schema_selection(true);
                 }
-            }
+                _ => {}
+            },
 
-            // Focus messages
+            // Focus messages; the panes hidden by the maximized panel cannot take focus
+            Message::NextFocus
+            | Message::PrevFocus
+            | Message::FocusLeft
+            | Message::FocusRight
+            | Message::FocusUp
+            | Message::FocusDown
+                if self.fullscreen_data => {}
             Message::NextFocus => {
                 self.focus = match self.focus {
                     Focus::Sidebar => Focus::QueryEditor,
@@ -221,6 +710,35 @@ impl App {
                     self.activate();
                 }
             }
+            Message::RefreshResult => {
+                self.refresh_result();
+            }
+            Message::ExecuteQuery => {
+                self.execute_editor_query(false);
+            }
+            Message::ValidateQuery => {
+                self.validate_editor_query();
+            }
+            Message::NewQueryTab => {
+                self.new_query_tab();
+            }
+            Message::NextQueryTab => {
+                self.next_query_tab();
+            }
+            Message::CloseQueryTab => {
+                self.close_query_tab();
+            }
+            Message::ExecuteQueryConfirmed => {
+                let modal = std::mem::replace(&mut self.modal_state, ModalState::None);
+                match modal {
+                    ModalState::ConfirmQuery(ConfirmQueryModal {
+                        target: Some(target),
+                        query,
+                        ..
+                    }) => self.execute_query_on(target, query, true),
+                    _ => self.execute_editor_query(true),
+                }
+            }
             Message::GoBack => {
                 if self.focus == Focus::Sidebar {
                     self.go_back();
@@ -254,8 +772,10 @@ impl App {
                 self.schema_sub_tab = SchemaSubTab::Constraints;
             }
             Message::SwitchToTriggers => {
-                self.panel_tab = MainPanelTab::Schema;
-                self.schema_sub_tab = SchemaSubTab::Triggers;
+                if self.capabilities().supports_triggers {
+                    self.panel_tab = MainPanelTab::Schema;
+                    self.schema_sub_tab = SchemaSubTab::Triggers;
+                }
             }
             Message::SwitchToDefinition => {
                 if let Some(table) = self.selected_table_info() {
@@ -270,6 +790,12 @@ impl App {
             Message::OpenAddConnectionModal => {
                 self.modal_state = ModalState::AddConnection(AddConnectionModal::default());
             }
+            Message::CloneConnection => {
+                self.open_clone_connection_modal();
+            }
+            Message::ImportConnections => {
+                self.open_import_connections_modal();
+            }
             Message::OpenAddProjectModal => {
                 self.modal_state = ModalState::AddProject(ProjectModal::default());
             }
@@ -283,6 +809,18 @@ impl App {
                     }
                 }
             }
+            Message::CloneProject => {
+                self.clone_selected_project();
+            }
+            Message::CreateSampleProject => {
+                self.create_sample_project();
+            }
+            Message::ExportProject => {
+                self.open_export_project_modal();
+            }
+            Message::ImportProject => {
+                self.open_import_project_modal();
+            }
             Message::DeleteProject => {
                 if let SidebarMode::Projects = self.sidebar_mode {
                     if let Some(project) = self.projects.get(self.selected_project_idx) {
@@ -347,6 +885,9 @@ impl App {
                     }
                 }
             }
+            Message::OpenGlobalSearchModal => {
+                self.modal_state = ModalState::GlobalSearch(GlobalSearchModal::new(&self.projects));
+            }
             Message::OpenColumnVisibilityModal => {
                 if self.panel_tab == MainPanelTab::Schema {
                     self.modal_state = ModalState::ColumnVisibility(ColumnVisibilityModal::new(
@@ -354,9 +895,138 @@ impl App {
                     ));
                 }
             }
+            Message::DatabaseTypeNext => {
+                self.cycle_connection_database_type(true);
+            }
+            Message::DatabaseTypePrev => {
+                self.cycle_connection_database_type(false);
+            }
+            Message::ToggleProductionFlag => {
+                self.toggle_connection_production();
+            }
+            Message::TogglePasswordReveal => {
+                self.toggle_password_reveal();
+            }
+            Message::OpenFilterModal => {
+                self.open_filter_modal();
+            }
+            Message::OpenOrderModal => {
+                self.open_order_modal();
+            }
+            Message::ModalOptionNext => {
+                self.cycle_modal_option(true);
+            }
+            Message::ModalOptionPrev => {
+                self.cycle_modal_option(false);
+            }
+            Message::StartColumnSearch => {
+                self.schema_sub_tab = SchemaSubTab::Columns;
+                self.column_search.start();
+            }
+            Message::ColumnSearchInput(c) => {
+                self.column_search.query.push(c);
+                // Jump to the first match as the filter changes
+                self.schema_states.columns.select(Some(0));
+            }
+            Message::ColumnSearchBackspace => {
+                self.column_search.query.pop();
+                self.schema_states.columns.select(Some(0));
+            }
+            Message::ColumnSearchConfirm => {
+                self.column_search.editing = false;
+            }
+            Message::ColumnSearchCancel => {
+                self.column_search.clear();
+            }
+            Message::CancelMetadataFetch => {
+                self.cancel_metadata_fetch();
+            }
+            Message::CopyDefinition => {
+                self.copy_schema_definition();
+            }
+            Message::GenerateSelect => {
+                self.generate_select();
+            }
+            Message::CopyTableName => {
+                self.copy_table_name();
+            }
+            Message::ShowConstraintDetail => {
+                self.show_constraint_detail();
+            }
+            Message::ShowLoadErrors => {
+                self.show_load_errors();
+            }
+            Message::ToggleSystemObjects => {
+                self.toggle_system_objects();
+            }
+            Message::RefreshAll => {
+                self.refresh_all_tables();
+            }
+            Message::StartKeySequence(c) => {
+                self.pending_key = Some(c);
+                self.status_message = format!("{}-", c);
+            }
+            Message::CancelKeySequence => {
+                self.pending_key = None;
+                self.status_message.clear();
+            }
+            Message::CollapseAll => {
+                self.pending_key = None;
+                self.collapse_all_connections();
+            }
+            Message::ExpandAll => {
+                self.pending_key = None;
+                self.expand_all_connections();
+            }
+            Message::Reconnect => {
+                self.reconnect_selected_connection();
+            }
+            Message::ShowDatabaseStats => {
+                if let Some(conn) = self.selected_connection_info().cloned() {
+                    self.send_fetch_database_stats(&conn);
+                }
+            }
+            Message::ShowActiveSessions | Message::RefreshActiveSessions => {
+                if let Some(conn) = self.selected_connection_info().cloned() {
+                    self.send_fetch_active_sessions(&conn);
+                }
+            }
+            Message::ShowRoles => {
+                if let Some(conn) = self.selected_connection_info().cloned() {
+                    self.send_fetch_roles(&conn);
+                } else {
+                    self.status_message = "Select a connection to list its roles".to_string();
+                }
+            }
+            Message::ShowServerSettings => {
+                if let Some(conn) = self.selected_connection_info().cloned() {
+                    self.send_fetch_settings(&conn);
+                } else {
+                    self.status_message = "Select a connection to show its settings".to_string();
+                }
+            }
+            Message::SetSearchPath => {
+                self.open_search_path_modal();
+            }
+            Message::OpenConnectionSnippets => {
+                self.open_snippets_modal();
+            }
+            Message::TerminateSession => {
+                if let ModalState::ActiveSessions(modal) = &mut self.modal_state {
+                    modal.confirm_pid = modal.selected_session().map(|s| s.pid);
+                }
+            }
+            Message::CancelTerminateSession => {
+                if let ModalState::ActiveSessions(modal) = &mut self.modal_state {
+                    modal.confirm_pid = None;
+                }
+            }
             Message::OpenHistoryModal => {
                 if !self.query_history.is_empty() {
-                    self.modal_state = ModalState::History(HistoryModal::default());
+                    self.modal_state = ModalState::History(HistoryModal {
+                        relative_times: self.relative_history_times,
+                        ..HistoryModal::default()
+                    });
                 } else {
                     self.status_message = "No query history".to_string();
                 }
@@ -375,6 +1045,9 @@ impl App {
             Message::UnifiedSearchConfirm => {
                 self.handle_unified_search_confirm();
             }
+            Message::GlobalSearchConfirm => {
+                self.handle_global_search_confirm();
+            }
             Message::UnifiedSearchSwitchSection => {
                 if let ModalState::UnifiedSearch(modal) = &mut self.modal_state {
                     modal.switch_section();
@@ -388,6 +1061,11 @@ impl App {
 
             // Modal control
             Message::CloseModal => {
+                if let ModalState::ImportPassword(modal) = &self.modal_state {
+                    // Skipping the remaining passwords still keeps the imported project
+                    let proj_idx = modal.project_idx;
+                    self.finish_project_import(proj_idx);
+                }
                 self.modal_state = ModalState::None;
             }
             Message::ModalConfirm => {
@@ -396,6 +1074,30 @@ impl App {
             Message::ModalInputChar(c) => {
                 self.handle_modal_input_char(c);
             }
+            Message::ModalCursorLeft => {
+                self.edit_modal_text(|cursor, text| cursor.left(text));
+            }
+            Message::ModalCursorRight => {
+                self.edit_modal_text(|cursor, text| cursor.right(text));
+            }
+            Message::ModalCursorHome => {
+                self.edit_modal_text(|cursor, _| cursor.home());
+            }
+            Message::ModalCursorEnd => {
+                self.edit_modal_text(|cursor, _| cursor.end());
+            }
+            Message::ModalDeleteWord => {
+                self.edit_modal_text(TextCursor::delete_word);
+            }
+            Message::Paste(text) => {
+                if self.is_modal_open() {
+                    self.handle_modal_paste(&text);
+                } else if self.focus == Focus::QueryEditor {
+                    // Keep line breaks: queries often span several lines
+                    self.query
+                        .push_str(&text.replace("\r\n", "\n").replace('\r', "\n"));
+                }
+            }
             Message::ModalInputBackspace => {
                 self.handle_modal_backspace();
             }
@@ -416,16 +1118,47 @@ impl App {
             Message::HistorySelectEntry => {
                 self.handle_history_select_entry();
             }
+            Message::HistoryShowDetail => {
+                self.handle_history_show_detail();
+            }
+            Message::HistoryToggleFilter => {
+                self.handle_history_toggle_filter();
+            }
+            Message::HistoryRerun => {
+                self.handle_history_rerun();
+            }
             Message::ClearHistory => {
                 self.handle_clear_history();
             }
+            Message::OpenRecentTables => {
+                self.open_recent_tables_modal();
+            }
+            Message::RecentTablesNavigateUp => {
+                self.handle_recent_tables_navigate_up();
+            }
+            Message::RecentTablesNavigateDown => {
+                self.handle_recent_tables_navigate_down();
+            }
+            Message::RecentTablesSelect => {
+                self.handle_recent_tables_select();
+            }
 
             // Pagination messages
             Message::PageNext => {
-                self.pagination.next_page();
+                if !self.pagination.has_next() && self.cursor_has_more {
+                    // Pull the next batch from the server; the page advances once it arrives
+                    self.fetch_rows_after_window();
+                } else {
+                    self.pagination.next_page();
+                }
             }
             Message::PagePrev => {
-                self.pagination.prev_page();
+                if !self.pagination.has_prev() && self.result_offset > 0 && self.cursor_open {
+                    // Rows before the window were dropped; fetch them again
+                    self.fetch_rows_before_window();
+                } else {
+                    self.pagination.prev_page();
+                }
             }
             Message::PageFirst => {
                 self.pagination.first_page();
@@ -435,6 +1168,15 @@ impl App {
             }
             Message::PageSizeCycle => {
                 self.pagination.cycle_page_size();
+                // Keep the selected row if it is still on the page, otherwise
+                // select the first row of the new page
+                let start = self.pagination.start_index();
+                let end = self.pagination.end_index();
+                if let Some(selected) = self.data_table_state.selected() {
+                    if !(start..end).contains(&selected) {
+                        self.data_table_state.select(Some(start));
+                    }
+                }
             }
 
             // Data table navigation (handled by handlers/navigation.rs)
@@ -462,6 +1204,91 @@ impl App {
                     }
                 }
             }
+            Message::DataTableColumnLeft => {
+                self.navigate_data_table_column(-1);
+            }
+            Message::DataTableColumnRight => {
+                self.navigate_data_table_column(1);
+            }
+            Message::OpenGoToColumn => {
+                self.open_go_to_column();
+            }
+            Message::GoToColumnConfirm => {
+                self.handle_go_to_column_confirm();
+            }
+            Message::ColumnStats => {
+                self.show_column_stats();
+            }
+            Message::MoveColumnLeft => {
+                self.move_data_column(-1);
+            }
+            Message::MoveColumnRight => {
+                self.move_data_column(1);
+            }
+            Message::HideColumn => {
+                self.hide_data_column();
+            }
+            Message::ShowAllColumns => {
+                self.show_all_data_columns();
+            }
+            Message::ToggleColumnTypes => {
+                self.show_column_types = !self.show_column_types;
+                self.status_message = if self.show_column_types {
+                    "Column types shown".to_string()
+                } else {
+                    "Column types hidden".to_string()
+                };
+            }
+            Message::ToggleCellWrap => {
+                self.wrap_cells = !self.wrap_cells;
+                self.status_message = if self.wrap_cells {
+                    format!(
+                        "Wrapping long values (up to {} lines)",
+                        self.wrap_row_height
+                    )
+                } else {
+                    "Long values truncated".to_string()
+                };
+            }
+            Message::ToggleExecutedQuery => {
+                self.show_executed_query = !self.show_executed_query;
+                self.status_message = if self.show_executed_query {
+                    "Executed SQL shown".to_string()
+                } else {
+                    "Executed SQL hidden".to_string()
+                };
+            }
+            Message::ToggleFullscreenData => {
+                self.fullscreen_data = !self.fullscreen_data;
+                self.status_message = if self.fullscreen_data {
+                    self.focus = Focus::MainPanel;
+                    "Data panel maximized (Shift+f to restore)".to_string()
+                } else {
+                    "Data panel restored".to_string()
+                };
+            }
+
+            // Export messages (handled by handlers/export.rs)
+            Message::ExportCsv => {
+                self.export_result(ExportFormat::Csv);
+            }
+            Message::ExportJson => {
+                self.export_result(ExportFormat::Json);
+            }
+            Message::CopyResultCsv => {
+                self.copy_result(ExportFormat::Csv, false);
+            }
+            Message::CopyResultCsvConfirmed => {
+                self.modal_state = ModalState::None;
+                self.copy_result(ExportFormat::Csv, true);
+            }
+            Message::CopyResultJson => {
+                self.copy_result_json(false);
+            }
+            Message::CopyResultJsonConfirmed => {
+                self.modal_state = ModalState::None;
+                self.copy_result_json(true);
+            }
         }
 
         false
@@ -472,17 +1299,21 @@ impl App {
 mod tests {
     use super::*;
     use crate::app::modals::UnifiedSearchSection;
+    use crate::config::SavedQuery;
     use crate::model::schema::TableType;
+    use crate::model::HistoryEntry;
 
     fn create_test_app_with_result(row_count: usize) -> App {
         let mut app = App::new(vec![]);
         if row_count > 0 {
             app.result = Some(QueryResult {
                 columns: vec!["id".to_string(), "name".to_string()],
+                column_types: Vec::new(),
                 rows: (0..row_count)
                     .map(|i| vec![i.to_string(), format!("row_{}", i)])
                     .collect(),
                 total_rows: row_count,
+                rows_affected: None,
                 execution_time_ms: 0,
             });
             app.pagination = Pagination::new(row_count);
@@ -502,6 +1333,7 @@ mod tests {
                 constraints: vec![],
                 triggers: vec![],
                 row_count: 0,
+                row_count_exact: false,
                 size_bytes: 0,
                 comment: None,
                 details_loaded: false,
@@ -517,6 +1349,7 @@ mod tests {
                 constraints: vec![],
                 triggers: vec![],
                 row_count: 0,
+                row_count_exact: false,
                 size_bytes: 0,
                 comment: None,
                 details_loaded: false,
@@ -532,6 +1365,7 @@ mod tests {
                 constraints: vec![],
                 triggers: vec![],
                 row_count: 0,
+                row_count_exact: false,
                 size_bytes: 0,
                 comment: None,
                 details_loaded: false,
@@ -547,6 +1381,7 @@ mod tests {
                 constraints: vec![],
                 triggers: vec![],
                 row_count: 0,
+                row_count_exact: false,
                 size_bytes: 0,
                 comment: None,
                 details_loaded: false,
@@ -603,6 +1438,125 @@ mod tests {
         assert_eq!(app.data_table_state.selected(), Some(9));
     }
 
+    #[test]
+    fn test_navigate_data_table_column() {
+        let mut app = create_test_app_with_result(3);
+        assert_eq!(app.selected_data_column(), None);
+
+        // The first move selects the first column
+        app.update(Message::DataTableColumnRight);
+        assert_eq!(app.selected_data_column(), Some(0));
+        app.update(Message::DataTableColumnRight);
+        app.update(Message::DataTableColumnRight);
+        assert_eq!(app.selected_data_column(), Some(1));
+        app.update(Message::DataTableColumnLeft);
+        app.update(Message::DataTableColumnLeft);
+        assert_eq!(app.selected_data_column(), Some(0));
+    }
+
+    #[test]
+    fn test_move_data_column() {
+        let mut app = create_test_app_with_result(3);
+        app.update(Message::MoveColumnRight);
+        assert_eq!(app.status_message, "Select a column with h / l to move it");
+
+        app.update(Message::DataTableColumnRight);
+        app.update(Message::MoveColumnRight);
+        assert_eq!(app.data_column_order(), vec![1, 0]);
+        assert_eq!(app.selected_data_position(), Some(1));
+        assert_eq!(app.selected_data_column(), Some(0));
+        assert_eq!(app.status_message, "Moved column id");
+
+        // The edge stops the move; the result keeps the query's order
+        app.update(Message::MoveColumnRight);
+        assert_eq!(app.data_column_order(), vec![1, 0]);
+        assert_eq!(app.result.as_ref().unwrap().columns, vec!["id", "name"]);
+
+        // Jumping to a column selects where it is shown
+        app.update(Message::OpenGoToColumn);
+        for c in "name".chars() {
+            app.update(Message::ModalInputChar(c));
+        }
+        app.update(Message::GoToColumnConfirm);
+        assert_eq!(app.selected_data_position(), Some(0));
+        assert_eq!(app.selected_data_column(), Some(1));
+    }
+
+    #[test]
+    fn test_hide_data_column() {
+        let mut app = create_test_app_with_result(3);
+        app.update(Message::HideColumn);
+        assert_eq!(app.status_message, "Select a column with h / l to hide it");
+
+        app.update(Message::DataTableColumnRight);
+        app.update(Message::HideColumn);
+        assert_eq!(app.visible_data_columns(), vec![1]);
+        assert_eq!(app.selected_data_column(), Some(1));
+        assert_eq!(app.status_message, "Hid column id (+ to show all)");
+
+        // One column always stays
+        app.update(Message::HideColumn);
+        assert_eq!(app.status_message, "Cannot hide the last shown column");
+
+        app.update(Message::ShowAllColumns);
+        assert_eq!(app.visible_data_columns(), vec![0, 1]);
+        assert_eq!(app.status_message, "Showing 1 hidden column again");
+
+        // Jumping to a hidden column shows it
+        app.update(Message::HideColumn);
+        app.update(Message::OpenGoToColumn);
+        for c in "id".chars() {
+            app.update(Message::ModalInputChar(c));
+        }
+        app.update(Message::GoToColumnConfirm);
+        assert_eq!(app.visible_data_columns(), vec![0, 1]);
+        assert_eq!(app.selected_data_column(), Some(0));
+    }
+
+    #[test]
+    fn test_go_to_column_selects_matching_column() {
+        let mut app = create_test_app_with_result(3);
+        app.update(Message::OpenGoToColumn);
+        for c in "nme".chars() {
+            app.update(Message::ModalInputChar(c));
+        }
+        let ModalState::GoToColumn(modal) = &app.modal_state else {
+            panic!("expected go-to-column modal");
+        };
+        assert_eq!(modal.filtered_indices, vec![1]);
+
+        app.update(Message::GoToColumnConfirm);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(app.selected_data_column(), Some(1));
+    }
+
+    #[test]
+    fn test_go_to_column_without_result() {
+        let mut app = App::new(vec![]);
+        app.update(Message::OpenGoToColumn);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(app.status_message, "No result columns to jump to");
+    }
+
+    #[test]
+    fn test_column_stats_modal_uses_selected_column() {
+        let mut app = create_test_app_with_result(4);
+        app.update(Message::DataTableColumnRight);
+        app.update(Message::DataTableColumnRight);
+        app.cursor_has_more = true;
+
+        app.update(Message::ColumnStats);
+
+        let ModalState::ColumnStats(modal) = &app.modal_state else {
+            panic!("expected column stats modal");
+        };
+        assert_eq!(modal.stats.column, "name");
+        assert_eq!(modal.stats.count, 4);
+        assert_eq!(modal.stats.distinct, 4);
+        assert!(modal.stats.numeric.is_none());
+        assert!(modal.partial);
+    }
+
     #[test]
     fn test_navigate_data_table_empty_result() {
         let mut app = create_test_app_with_result(0);
@@ -803,9 +1757,16 @@ mod tests {
                 port: 5432,
                 username: "user".to_string(),
                 password: "".to_string(),
+                password_env: None,
                 database: "db".to_string(),
                 tables: vec![],
                 expanded: false,
+                default_schema: None,
+                color: None,
+                is_production: false,
+                query_timeout_secs: None,
+                snippets: Vec::new(),
+                search_path: None,
             },
             Connection {
                 name: "postgres_prod".to_string(),
@@ -813,9 +1774,16 @@ mod tests {
                 port: 5432,
                 username: "user".to_string(),
                 password: "".to_string(),
+                password_env: None,
                 database: "db".to_string(),
                 tables: vec![],
                 expanded: false,
+                default_schema: None,
+                color: None,
+                is_production: false,
+                query_timeout_secs: None,
+                snippets: Vec::new(),
+                search_path: None,
             },
             Connection {
                 name: "mysql_dev".to_string(),
@@ -823,13 +1791,438 @@ mod tests {
                 port: 3306,
                 username: "user".to_string(),
                 password: "".to_string(),
+                password_env: None,
                 database: "db".to_string(),
                 tables: vec![],
                 expanded: false,
+                default_schema: None,
+                color: None,
+                is_production: false,
+                query_timeout_secs: None,
+                snippets: Vec::new(),
+                search_path: None,
             },
         ]
     }
 
+    #[test]
+    fn test_connection_snippet_is_inserted_into_editor() {
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        project.connections[1].snippets = vec![
+            SavedQuery {
+                name: "top queries".to_string(),
+                query: "SELECT * FROM pg_stat_statements".to_string(),
+            },
+            SavedQuery {
+                name: "locks".to_string(),
+                query: "SELECT * FROM pg_locks".to_string(),
+            },
+        ];
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+
+        app.update(Message::OpenConnectionSnippets);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(
+            app.status_message,
+            "No snippets configured for postgres_local"
+        );
+
+        app.selected_connection_idx = 1;
+        app.update(Message::OpenConnectionSnippets);
+        assert!(matches!(app.modal_state, ModalState::Snippets(_)));
+
+        app.update(Message::ModalNextField);
+        app.update(Message::ModalConfirm);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(app.query, "SELECT * FROM pg_locks");
+        assert_eq!(
+            app.status_message,
+            "Loaded snippet \"locks\" (postgres_prod)"
+        );
+    }
+
+    #[test]
+    fn test_project_load_errors_are_listed() {
+        use crate::config::ProjectLoadError;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut app = App::new(vec![]);
+        app.report_project_load_errors(vec![]);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(app.status_message, "Ready");
+
+        app.report_project_load_errors(vec![
+            ProjectLoadError {
+                path: "projects/a.yaml".to_string(),
+                message: "did not find expected ',' or ']' at line 2 column 9".to_string(),
+            },
+            ProjectLoadError {
+                path: "projects/b.yaml".to_string(),
+                message: "No such file or directory (os error 2)".to_string(),
+            },
+        ]);
+        assert_eq!(
+            app.status_message,
+            "2 project files failed to load (Shift+w to view)"
+        );
+        let ModalState::LoadErrors(modal) = &app.modal_state else {
+            panic!("expected the load errors modal");
+        };
+        assert_eq!(modal.entries[0].0, "projects/a.yaml");
+        assert_eq!(modal.line_count(), 5);
+
+        // Closed, the list can be opened again from the Projects view
+        app.update(Message::CloseModal);
+        let key = crate::event::key_to_message(&app, KeyCode::Char('W'), KeyModifiers::SHIFT);
+        assert_eq!(key, Some(Message::ShowLoadErrors));
+        app.update(Message::ShowLoadErrors);
+        assert!(matches!(app.modal_state, ModalState::LoadErrors(_)));
+    }
+
+    #[test]
+    fn test_create_sample_project_on_first_run() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut app = App::new(vec![]);
+        let key = crate::event::key_to_message(&app, KeyCode::Char('S'), KeyModifiers::SHIFT);
+        assert_eq!(key, Some(Message::CreateSampleProject));
+
+        app.update(Message::CreateSampleProject);
+        assert_eq!(app.projects.len(), 1);
+        assert_eq!(app.projects[0].name, "sample");
+        let conn = &app.projects[0].connections[0];
+        assert_eq!((conn.host.as_str(), conn.port), ("localhost", 5432));
+        assert_eq!(conn.password_env.as_deref(), Some("PGPASSWORD"));
+        assert_eq!(app.pending_project_save, Some(0));
+
+        app.update(Message::CreateSampleProject);
+        assert_eq!(app.projects[1].name, "sample (2)");
+        assert_eq!(app.selected_project_idx, 1);
+    }
+
+    #[test]
+    fn test_history_rerun_runs_on_original_connection() {
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        project.connections[1].is_production = true;
+        let mut history = QueryHistory::new();
+        history.add(HistoryEntry::success(
+            "DELETE FROM users",
+            "postgres_prod",
+            "db",
+            1,
+            0,
+        ));
+        history.add(HistoryEntry::success("SELECT 1", "gone", "db", 1, 1));
+        let mut app = App::with_history(vec![project], history);
+        app.query = "SELECT * FROM orders".to_string();
+
+        // The newest entry ran on a connection that was removed since
+        app.update(Message::OpenHistoryModal);
+        app.update(Message::HistoryRerun);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(app.status_message, "Connection 'gone' no longer exists");
+
+        // Writes on production still ask first, and run on that connection
+        app.update(Message::OpenHistoryModal);
+        app.update(Message::HistoryNavigateDown);
+        app.update(Message::HistoryRerun);
+        let ModalState::ConfirmQuery(modal) = &app.modal_state else {
+            panic!("expected the production confirmation modal");
+        };
+        assert_eq!(modal.connection, "postgres_prod");
+        assert_eq!(modal.target, Some((0, 1)));
+
+        app.update(Message::ExecuteQueryConfirmed);
+        assert_eq!(app.status_message, "DB worker not initialized");
+        assert_eq!(app.query, "SELECT * FROM orders");
+    }
+
+    #[test]
+    fn test_writes_on_production_connection_ask_first() {
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        project.connections[1].is_production = true;
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_connection_idx = 1;
+
+        // Reads run straight away
+        app.query = "SELECT * FROM users".to_string();
+        app.update(Message::ExecuteQuery);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(app.status_message, "DB worker not initialized");
+
+        app.status_message.clear();
+        app.query = "DELETE FROM users".to_string();
+        app.update(Message::ExecuteQuery);
+        let ModalState::ConfirmQuery(modal) = &app.modal_state else {
+            panic!("expected the production confirmation modal");
+        };
+        assert_eq!(modal.connection, "postgres_prod");
+        assert_eq!(modal.query, "DELETE FROM users");
+        assert!(app.status_message.is_empty());
+
+        app.update(Message::ExecuteQueryConfirmed);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(app.status_message, "DB worker not initialized");
+
+        // Other connections are not asked about
+        app.status_message.clear();
+        app.selected_connection_idx = 0;
+        app.update(Message::ExecuteQuery);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(app.status_message, "DB worker not initialized");
+    }
+
+    #[test]
+    fn test_connection_modal_toggles_production_flag() {
+        let mut app = App::new(vec![]);
+        app.modal_state = ModalState::AddConnection(AddConnectionModal {
+            name: "prod".to_string(),
+            user: "app".to_string(),
+            database: "app".to_string(),
+            focused_field: crate::app::ConnectionModalField::Production,
+            ..AddConnectionModal::default()
+        });
+        app.update(Message::ToggleProductionFlag);
+        let ModalState::AddConnection(modal) = &app.modal_state else {
+            panic!("expected the connection modal");
+        };
+        assert!(modal.is_production);
+
+        let conn = app.create_connection_from_modal(modal).unwrap();
+        assert!(conn.is_production);
+    }
+
+    #[test]
+    fn test_invalid_connection_modal_highlights_fields() {
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.modal_state = ModalState::AddConnection(AddConnectionModal {
+            name: "new".to_string(),
+            port: "0".to_string(),
+            ..AddConnectionModal::default()
+        });
+
+        app.update(Message::ModalConfirm);
+        let ModalState::AddConnection(modal) = &app.modal_state else {
+            panic!("expected the connection modal to stay open");
+        };
+        assert!(modal.show_errors);
+        assert_eq!(modal.focused_field, crate::app::ConnectionModalField::Port);
+        assert_eq!(
+            app.status_message,
+            "Invalid connection: fix 3 highlighted field(s)"
+        );
+        assert_eq!(app.projects[0].connections.len(), 3);
+    }
+
+    #[test]
+    fn test_connection_modal_rejects_out_of_range_port_keys() {
+        let mut app = App::new(vec![]);
+        app.modal_state = ModalState::AddConnection(AddConnectionModal {
+            port: String::new(),
+            focused_field: crate::app::ConnectionModalField::Port,
+            ..AddConnectionModal::default()
+        });
+
+        for c in "065536".chars() {
+            app.update(Message::ModalInputChar(c));
+        }
+        let ModalState::AddConnection(modal) = &app.modal_state else {
+            panic!("expected the connection modal");
+        };
+        assert_eq!(modal.port, "6553");
+        assert_eq!(app.status_message, "Port must be 1-65535");
+    }
+
+    #[test]
+    fn test_ctrl_r_reveals_password_until_modal_closes() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut app = App::new(vec![Project::new("main")]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.update(Message::OpenAddConnectionModal);
+        let ctrl_r = |app: &App| {
+            crate::event::key_to_message(app, KeyCode::Char('r'), KeyModifiers::CONTROL)
+        };
+
+        // Only on the password field
+        assert_eq!(ctrl_r(&app), Some(Message::ModalInputChar('r')));
+        if let ModalState::AddConnection(modal) = &mut app.modal_state {
+            modal.focused_field = crate::app::ConnectionModalField::Password;
+        }
+        assert_eq!(ctrl_r(&app), Some(Message::TogglePasswordReveal));
+
+        app.update(Message::TogglePasswordReveal);
+        let ModalState::AddConnection(modal) = &app.modal_state else {
+            panic!("expected add connection modal");
+        };
+        assert!(modal.reveal_password);
+
+        app.update(Message::CloseModal);
+        app.update(Message::OpenAddConnectionModal);
+        let ModalState::AddConnection(modal) = &app.modal_state else {
+            panic!("expected add connection modal");
+        };
+        assert!(!modal.reveal_password);
+    }
+
+    #[test]
+    fn test_paste_into_modal_field_keeps_field_limits() {
+        let mut app = App::new(vec![Project::new("main")]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.update(Message::OpenAddConnectionModal);
+        if let ModalState::AddConnection(modal) = &mut app.modal_state {
+            modal.focused_field = crate::app::ConnectionModalField::Password;
+        }
+        let paste = crate::event::paste_to_message(&app, "s3cret pass\n".to_string());
+        app.update(paste.unwrap());
+
+        let ModalState::AddConnection(modal) = &mut app.modal_state else {
+            panic!("expected add connection modal");
+        };
+        assert_eq!(modal.password, "s3cret pass");
+
+        modal.port.clear();
+        modal.focused_field = crate::app::ConnectionModalField::Port;
+        app.update(Message::Paste(" 54a32".to_string()));
+        let ModalState::AddConnection(modal) = &app.modal_state else {
+            panic!("expected add connection modal");
+        };
+        assert_eq!(modal.port, "5432");
+    }
+
+    #[test]
+    fn test_paste_into_query_editor_keeps_lines() {
+        let mut app = App::new(vec![]);
+        assert_eq!(crate::event::paste_to_message(&app, "x".to_string()), None);
+
+        app.focus = Focus::QueryEditor;
+        app.query = "SELECT 1".to_string();
+        let paste = crate::event::paste_to_message(&app, ";\r\nSELECT 2".to_string());
+        app.update(paste.unwrap());
+        assert_eq!(app.query, "SELECT 1;\nSELECT 2");
+    }
+
+    #[test]
+    fn test_connection_modal_edits_at_cursor() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut app = App::new(vec![Project::new("main")]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.update(Message::OpenAddConnectionModal);
+        if let ModalState::AddConnection(modal) = &mut app.modal_state {
+            modal.focused_field = crate::app::ConnectionModalField::Host;
+            modal.host = "db.exmple.com".to_string();
+        }
+        let key = |app: &App, code| crate::event::key_to_message(app, code, KeyModifiers::NONE);
+
+        assert_eq!(key(&app, KeyCode::Home), Some(Message::ModalCursorHome));
+        app.update(Message::ModalCursorHome);
+        for _ in 0..5 {
+            app.update(key(&app, KeyCode::Right).unwrap());
+        }
+        app.update(Message::ModalInputChar('a'));
+        let ModalState::AddConnection(modal) = &app.modal_state else {
+            panic!("expected add connection modal");
+        };
+        assert_eq!(modal.host, "db.example.com");
+
+        let ctrl_w = crate::event::key_to_message(&app, KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(ctrl_w, Some(Message::ModalDeleteWord));
+        app.update(Message::ModalDeleteWord);
+        app.update(Message::ModalCursorEnd);
+        app.update(Message::ModalInputBackspace);
+        let ModalState::AddConnection(modal) = &app.modal_state else {
+            panic!("expected add connection modal");
+        };
+        assert_eq!(modal.host, "mple.co");
+
+        // Moving to another field edits at its end again
+        app.update(Message::ModalCursorHome);
+        app.update(Message::ModalNextField);
+        app.update(Message::ModalInputChar('1'));
+        let ModalState::AddConnection(modal) = &app.modal_state else {
+            panic!("expected add connection modal");
+        };
+        assert_eq!(modal.port, "54321");
+    }
+
+    #[test]
+    fn test_set_session_search_path() {
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_connection_idx = 1;
+
+        app.update(Message::SetSearchPath);
+        for c in "billing; drop".chars() {
+            app.update(Message::ModalInputChar(c));
+        }
+        app.update(Message::ModalConfirm);
+        assert!(matches!(app.modal_state, ModalState::SearchPath(_)));
+        assert!(app.status_message.starts_with("Invalid schema name"));
+
+        app.modal_state = ModalState::None;
+        app.update(Message::SetSearchPath);
+        for c in "billing,public".chars() {
+            app.update(Message::ModalInputChar(c));
+        }
+        app.update(Message::ModalConfirm);
+        assert!(matches!(app.modal_state, ModalState::None));
+        let conn = &app.projects[0].connections[1];
+        assert_eq!(conn.search_path.as_deref(), Some("billing, public"));
+        assert_eq!(conn.schema(), "billing");
+        assert_eq!(
+            app.status_message,
+            "search_path set to billing, public for postgres_prod"
+        );
+
+        // Reopening shows the current value; clearing it resets the path
+        app.update(Message::SetSearchPath);
+        let ModalState::SearchPath(modal) = &app.modal_state else {
+            panic!("expected the search_path modal");
+        };
+        assert_eq!(modal.value, "billing, public");
+        if let ModalState::SearchPath(modal) = &mut app.modal_state {
+            modal.value.clear();
+        }
+        app.update(Message::ModalConfirm);
+        assert!(app.projects[0].connections[1].search_path.is_none());
+    }
+
+    #[test]
+    fn test_fold_all_connections() {
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        project.connections[1].tables = create_test_tables();
+        project.connections[1].expanded = true;
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_connection_idx = 1;
+        app.selected_table_idx = Some(2);
+
+        app.update(Message::StartKeySequence('z'));
+        assert_eq!(app.pending_key, Some('z'));
+        app.update(Message::CollapseAll);
+        assert_eq!(app.pending_key, None);
+        assert!(app.projects[0].connections.iter().all(|c| !c.expanded));
+        assert_eq!(app.selected_connection_idx, 1);
+        assert_eq!(app.selected_table_idx, None);
+
+        app.update(Message::ExpandAll);
+        assert!(app.projects[0].connections.iter().all(|c| c.expanded));
+        // Loaded tables are kept; the others are fetched through the worker
+        assert_eq!(app.projects[0].connections[1].tables.len(), 4);
+    }
+
     #[test]
     fn test_unified_search_modal_new_with_tables_priority() {
         let modal = UnifiedSearchModal::new(3, 4, true);
@@ -860,7 +2253,8 @@ mod tests {
         modal.query = "postgres".to_string();
         modal.update_filter(&connections, &tables);
 
-        assert_eq!(modal.filtered_connection_indices, vec![0, 1]);
+        // Equal fuzzy scores: the shorter name ranks first
+        assert_eq!(modal.filtered_connection_indices, vec![1, 0]);
     }
 
     #[test]
@@ -941,7 +2335,7 @@ mod tests {
         modal.active_section = UnifiedSearchSection::Connections;
         modal.selected_connection_idx = 1;
 
-        assert_eq!(modal.selected_connection(), Some(1));
+        assert_eq!(modal.selected_connection(), Some(0));
     }
 
     #[test]
@@ -984,6 +2378,24 @@ mod tests {
         assert_eq!(modal.filtered_table_indices.len(), 4);
     }
 
+    #[test]
+    fn test_page_size_cycle_keeps_selection_on_page() {
+        let mut app = create_test_app_with_result(1000);
+        app.pagination = Pagination::new(1000);
+        app.pagination.next_page(); // rows 50-99
+        app.data_table_state.select(Some(60));
+
+        app.update(Message::PageSizeCycle); // rows 0-99
+        assert_eq!(app.pagination.current_page, 0);
+        assert_eq!(app.data_table_state.selected(), Some(60));
+
+        app.pagination.current_page = 3; // rows 300-399
+        app.data_table_state.select(Some(399));
+        app.update(Message::PageSizeCycle); // rows 0-499
+        app.update(Message::PageSizeCycle); // rows 0-49
+        assert_eq!(app.data_table_state.selected(), Some(0));
+    }
+
     #[test]
     fn test_navigate_data_table_respects_page_boundary_down() {
         let mut app = create_test_app_with_result(100);
@@ -1037,4 +2449,795 @@ mod tests {
         app.navigate_data_table(1);
         assert_eq!(app.data_table_state.selected(), Some(74));
     }
+
+    #[test]
+    fn test_clear_status_resets_message_and_column_filter() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut app = App::new(vec![]);
+        app.status_message = "Query failed on db".to_string();
+        app.query_error = Some("syntax error at or near \"SELEC\"".to_string());
+        app.column_search.start();
+        app.column_search.query = "id".to_string();
+
+        let key = crate::event::key_to_message(&app, KeyCode::Char('l'), KeyModifiers::CONTROL);
+        assert_eq!(key, Some(Message::ClearStatus));
+        app.update(Message::ClearStatus);
+
+        assert_eq!(app.status_message, "Ready");
+        assert!(app.query_outcome().is_none());
+        assert!(!app.column_search.is_active());
+        assert!(app.pending_redraw);
+    }
+
+    #[test]
+    fn test_page_next_on_last_loaded_page_requests_more_rows() {
+        let mut app = create_test_app_with_result(50);
+        app.cursor_has_more = true;
+
+        app.update(Message::PageNext);
+
+        // No worker in tests: the fetch is attempted instead of paging locally
+        assert_eq!(app.pagination.current_page, 0);
+        assert_eq!(app.status_message, "DB worker not initialized");
+    }
+
+    #[test]
+    fn test_page_next_without_cursor_stays_on_last_page() {
+        let mut app = create_test_app_with_result(50);
+
+        app.update(Message::PageNext);
+
+        assert_eq!(app.pagination.current_page, 0);
+        assert_eq!(app.status_message, "Ready");
+    }
+
+    #[test]
+    fn test_open_cursor_serves_cached_result() {
+        let mut app = App::new(vec![]);
+        let conn = create_test_connections().remove(0);
+        let query = "SELECT * FROM \"users\"";
+        let key = CacheKey::new(&crate::db::ConnectionParams::from_connection(&conn), query);
+        app.result_cache
+            .insert(key.clone(), create_test_app_with_result(3).result.unwrap());
+        // Columns moved and hidden in the previous result
+        app.column_order = vec![1, 0];
+        app.hidden_columns.insert(0);
+
+        app.send_open_cursor(&conn, query);
+
+        // Served without a worker round-trip
+        assert_eq!(app.result.as_ref().map(|r| r.rows.len()), Some(3));
+        assert_eq!(app.current_cache_key, Some(key));
+        assert!(app.status_message.contains("(cached)"));
+        assert!(app.column_order.is_empty());
+        assert!(app.hidden_columns.is_empty());
+    }
+
+    #[test]
+    fn test_cached_result_is_not_served_for_another_search_path() {
+        let mut app = App::new(vec![]);
+        let mut conn = create_test_connections().remove(0);
+        let query = "SELECT * FROM \"users\"";
+        let key = CacheKey::new(&crate::db::ConnectionParams::from_connection(&conn), query);
+        app.result_cache
+            .insert(key, create_test_app_with_result(3).result.unwrap());
+
+        // The same unqualified name may now refer to another table
+        conn.search_path = Some("billing".to_string());
+        app.send_open_cursor(&conn, query);
+
+        assert!(app.result.is_none());
+        assert_eq!(app.status_message, "DB worker not initialized");
+    }
+
+    #[test]
+    fn test_clone_connection_persists_only_on_confirm() {
+        let mut project = Project::new("proj");
+        project.connections = create_test_connections();
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_connection_idx = 1;
+
+        app.update(Message::CloneConnection);
+        match &app.modal_state {
+            ModalState::CloneConnection(modal) => {
+                assert_eq!(modal.name, "postgres_prod (copy)");
+                assert_eq!(modal.host, "prod.example.com");
+            }
+            other => panic!("unexpected modal: {:?}", other),
+        }
+        assert_eq!(app.projects[0].connections.len(), 3);
+
+        app.update(Message::ModalConfirm);
+
+        assert!(!app.is_modal_open());
+        let clone = app.projects[0].connections.last().unwrap();
+        assert_eq!(clone.name, "postgres_prod (copy)");
+        assert_eq!(clone.host, "prod.example.com");
+        assert_eq!(app.status_message, "Connection cloned");
+    }
+
+    #[test]
+    fn test_clone_project_copies_connections_under_new_name() {
+        let mut project = Project::new("proj");
+        project.connections = create_test_connections();
+        let mut app = App::new(vec![project]);
+
+        app.update(Message::CloneProject);
+
+        assert_eq!(app.projects.len(), 2);
+        assert_eq!(app.projects[1].name, "proj (copy)");
+        assert_eq!(app.projects[1].connections.len(), 3);
+        assert_eq!(app.selected_project_idx, 1);
+        assert_eq!(app.pending_project_save, Some(1));
+
+        // Cloning the source again picks a name that doesn't collide
+        app.selected_project_idx = 0;
+        app.update(Message::CloneProject);
+        assert_eq!(app.projects[2].name, "proj (copy 2)");
+    }
+
+    #[test]
+    fn test_import_connections_confirm_adds_to_current_project() {
+        let mut app = App::new(vec![Project::new("proj")]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.modal_state =
+            ModalState::ImportConnections(crate::app::modals::ImportConnectionsModal {
+                connections: create_test_connections(),
+                sources: vec!["~/.pgpass".to_string()],
+            });
+
+        app.update(Message::ModalConfirm);
+
+        assert!(!app.is_modal_open());
+        assert_eq!(app.projects[0].connections.len(), 3);
+        assert_eq!(app.status_message, "Imported 3 connections");
+    }
+
+    #[test]
+    fn test_export_then_import_project_prompts_for_passwords() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("proj.yaml");
+        let path = path.to_str().unwrap();
+
+        let mut project = Project::new("proj");
+        project.connections = create_test_connections();
+        project.connections[0].password = "secret".to_string();
+        let mut app = App::new(vec![project]);
+
+        app.export_project(0, path);
+        assert!(app.status_message.starts_with("Exported project"));
+
+        app.import_project(path);
+
+        // Name collision with the source project is resolved
+        assert_eq!(app.projects[1].name, "proj (2)");
+        let ModalState::ImportPassword(modal) = &app.modal_state else {
+            panic!("expected password prompt");
+        };
+        assert_eq!(modal.pending, vec![0, 1, 2]);
+
+        for c in "secret".chars() {
+            app.update(Message::ModalInputChar(c));
+        }
+        app.update(Message::ModalConfirm);
+        assert_eq!(app.projects[1].connections[0].password, "secret");
+        assert_eq!(app.pending_project_save, None);
+
+        // Skipping the rest still keeps the project
+        app.update(Message::CloseModal);
+        assert!(!app.is_modal_open());
+        assert_eq!(app.pending_project_save, Some(1));
+    }
+
+    #[test]
+    fn test_global_search_indexes_all_projects() {
+        let mut other = Project::new("other");
+        other.connections = create_test_connections();
+        other.connections[1].tables = create_test_tables();
+        let app = App::new(vec![Project::new("empty"), other]);
+
+        let mut modal = GlobalSearchModal::new(&app.projects);
+        assert_eq!(
+            modal.entries.len(),
+            3 + create_test_tables().len(),
+            "every connection plus loaded tables"
+        );
+
+        modal.query = "users".to_string();
+        modal.update_filter();
+        let entry = modal.selected_entry().unwrap();
+        assert_eq!(entry.path, "other / postgres_prod");
+        assert_eq!(entry.target.project_idx, 1);
+        assert_eq!(entry.target.table_idx, Some(0));
+    }
+
+    #[test]
+    fn test_global_search_confirm_navigates_sidebar() {
+        let mut other = Project::new("other");
+        other.connections = create_test_connections();
+        other.connections[1].tables = create_test_tables();
+        let mut app = App::new(vec![Project::new("empty"), other]);
+
+        app.update(Message::OpenGlobalSearchModal);
+        for c in "users".chars() {
+            app.update(Message::ModalInputChar(c));
+        }
+        app.update(Message::GlobalSearchConfirm);
+
+        assert!(!app.is_modal_open());
+        assert_eq!(app.sidebar_mode, SidebarMode::Connections(1));
+        assert_eq!(app.selected_project_idx, 1);
+        assert_eq!(app.selected_connection_idx, 1);
+        assert_eq!(app.selected_table_idx, Some(0));
+        assert!(app.projects[1].connections[1].expanded);
+    }
+
+    #[test]
+    fn test_activate_table_records_recent_table() {
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        project.connections[1].tables = create_test_tables();
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_connection_idx = 1;
+        app.selected_table_idx = Some(0);
+
+        app.update(Message::Activate);
+
+        assert!(app.recent_tables_dirty);
+        let entry = app.recent_tables.get(0).unwrap();
+        assert_eq!(entry.project, "main");
+        assert_eq!(entry.connection, "postgres_prod");
+        assert_eq!(entry.table, "users");
+    }
+
+    #[test]
+    fn test_recent_tables_select_navigates_sidebar() {
+        let mut other = Project::new("other");
+        other.connections = create_test_connections();
+        other.connections[1].tables = create_test_tables();
+        let mut app = App::new(vec![Project::new("empty"), other]);
+        app.recent_tables.touch(crate::model::RecentTable::new(
+            "other",
+            "postgres_prod",
+            "users",
+        ));
+
+        app.update(Message::OpenRecentTables);
+        app.update(Message::RecentTablesSelect);
+
+        assert!(!app.is_modal_open());
+        assert_eq!(app.sidebar_mode, SidebarMode::Connections(1));
+        assert_eq!(app.selected_connection_idx, 1);
+        assert_eq!(app.selected_table_idx, Some(0));
+        assert_eq!(app.query, "SELECT * FROM \"users\" LIMIT 1000;");
+        assert_eq!(app.result_limit, Some(1000));
+    }
+
+    #[test]
+    fn test_recent_tables_select_missing_connection() {
+        let mut app = App::new(vec![Project::new("main")]);
+        app.recent_tables
+            .touch(crate::model::RecentTable::new("main", "gone", "users"));
+
+        app.update(Message::OpenRecentTables);
+        app.update(Message::RecentTablesSelect);
+
+        assert!(!app.is_modal_open());
+        assert_eq!(app.sidebar_mode, SidebarMode::Projects);
+        assert!(app.status_message.contains("no longer exists"));
+    }
+
+    #[test]
+    fn test_open_recent_tables_when_empty() {
+        let mut app = App::new(vec![]);
+        app.update(Message::OpenRecentTables);
+        assert!(!app.is_modal_open());
+    }
+
+    #[test]
+    fn test_quit_without_pending_work() {
+        let mut app = App::new(vec![]);
+        assert!(app.update(Message::Quit));
+    }
+
+    #[test]
+    fn test_quit_while_query_running_asks_for_confirmation() {
+        let mut app = App::new(vec![]);
+        app.loading.start_executing_query();
+
+        assert!(!app.update(Message::Quit));
+        let ModalState::ConfirmQuit(modal) = &app.modal_state else {
+            panic!("expected quit confirmation");
+        };
+        assert_eq!(modal.reasons, vec!["A query is still running".to_string()]);
+
+        // Cancelling keeps the app running; forcing quits
+        assert!(!app.update(Message::CloseModal));
+        assert!(!app.is_modal_open());
+        assert!(app.update(Message::ForceQuit));
+    }
+
+    #[test]
+    fn test_history_save_spaced_by_interval() {
+        let mut app = App::new(vec![]);
+        app.history_save_interval = Duration::from_secs(30);
+        let start = Instant::now();
+        assert!(!app.history_save_due(start));
+
+        // The first change is written right away
+        app.history_dirty = true;
+        assert!(app.history_save_due(start));
+        app.mark_history_saved(start);
+        assert!(!app.history_dirty);
+
+        // Later ones wait for the interval
+        app.history_dirty = true;
+        assert!(!app.history_save_due(start + Duration::from_secs(29)));
+        assert!(app.history_save_due(start + Duration::from_secs(30)));
+
+        // Unsaved history does not hold up quitting; it is saved on the way out
+        assert!(app.update(Message::Quit));
+    }
+
+    #[test]
+    fn test_zero_history_interval_saves_every_change() {
+        let mut app = App::new(vec![]);
+        let now = Instant::now();
+        app.history_dirty = true;
+        app.mark_history_saved(now);
+        app.history_dirty = true;
+        assert!(app.history_save_due(now));
+    }
+
+    #[test]
+    fn test_ctrl_c_twice_forces_quit() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut app = App::new(vec![]);
+        app.loading.executing_query = true;
+
+        let first = crate::event::key_to_message(&app, KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(first, Some(Message::Quit));
+        assert!(!app.update(Message::Quit));
+
+        let second = crate::event::key_to_message(&app, KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(second, Some(Message::ForceQuit));
+    }
+
+    #[test]
+    fn test_reconnect_clears_loaded_tables() {
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        project.connections[1].tables = create_test_tables();
+        project.connections[1].expanded = true;
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_connection_idx = 1;
+        app.selected_table_idx = Some(0);
+
+        app.update(Message::Reconnect);
+
+        let conn = &app.projects[0].connections[1];
+        assert!(conn.tables.is_empty());
+        assert!(!conn.expanded);
+        assert_eq!(app.selected_table_idx, None);
+        // No worker in tests, so the reconnect itself is not sent
+        assert_eq!(app.status_message, "DB worker not initialized");
+    }
+
+    #[test]
+    fn test_toggle_system_objects_reloads_tables() {
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        project.connections[0].tables = create_test_tables();
+        project.connections[1].tables = create_test_tables();
+        project.connections[1].expanded = true;
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_connection_idx = 1;
+        app.selected_table_idx = Some(0);
+        assert!(!app.show_system_objects);
+
+        app.update(Message::ToggleSystemObjects);
+
+        assert!(app.show_system_objects);
+        assert!(app.projects[0]
+            .connections
+            .iter()
+            .all(|c| c.tables.is_empty()));
+        assert_eq!(app.selected_table_idx, None);
+        // No worker in tests, so the expanded connection cannot refetch
+        assert_eq!(app.status_message, "DB worker not initialized");
+
+        app.update(Message::ToggleSystemObjects);
+        assert!(!app.show_system_objects);
+    }
+
+    #[test]
+    fn test_terminate_session_requires_confirmation() {
+        use crate::app::ActiveSessionsModal;
+        use crate::model::ActiveSession;
+
+        let session = |pid| ActiveSession {
+            pid,
+            user: Some("app".to_string()),
+            state: Some("active".to_string()),
+            wait_event: None,
+            duration_ms: Some(10),
+            query: "SELECT pg_sleep(60)".to_string(),
+        };
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.modal_state = ModalState::ActiveSessions(ActiveSessionsModal::new(
+            "local".to_string(),
+            "app".to_string(),
+            vec![session(100), session(200)],
+        ));
+
+        app.update(Message::ModalNextField);
+        app.update(Message::TerminateSession);
+        let ModalState::ActiveSessions(modal) = &app.modal_state else {
+            panic!("expected sessions modal");
+        };
+        assert_eq!(modal.confirm_pid, Some(200));
+
+        app.update(Message::CancelTerminateSession);
+        let ModalState::ActiveSessions(modal) = &app.modal_state else {
+            panic!("expected sessions modal");
+        };
+        assert_eq!(modal.confirm_pid, None);
+
+        app.update(Message::TerminateSession);
+        app.update(Message::ModalConfirm);
+        let ModalState::ActiveSessions(modal) = &app.modal_state else {
+            panic!("expected sessions modal");
+        };
+        assert_eq!(modal.confirm_pid, None);
+        // No worker in tests, so the termination is not sent
+        assert_eq!(app.status_message, "DB worker not initialized");
+    }
+
+    #[test]
+    fn test_add_connection_rejects_unsupported_database_type() {
+        let mut app = App::new(vec![Project::new("main")]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.update(Message::OpenAddConnectionModal);
+        app.update(Message::DatabaseTypeNext);
+
+        let ModalState::AddConnection(modal) = &mut app.modal_state else {
+            panic!("expected add connection modal");
+        };
+        assert_eq!(modal.port, "3306");
+        modal.name = "shop".to_string();
+        modal.user = "app".to_string();
+        modal.database = "shop".to_string();
+
+        app.update(Message::ModalConfirm);
+
+        assert!(app.is_modal_open());
+        assert_eq!(
+            app.status_message,
+            "MySQL connections are not supported yet"
+        );
+        assert!(app.projects[0].connections.is_empty());
+    }
+
+    #[test]
+    fn test_column_filter_captures_typing_until_confirmed() {
+        use crate::event::key_to_message;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut app = App::new(vec![]);
+        app.focus = Focus::MainPanel;
+        app.panel_tab = MainPanelTab::Schema;
+
+        let press = |app: &mut App, code: KeyCode| {
+            if let Some(msg) = key_to_message(app, code, KeyModifiers::NONE) {
+                app.update(msg);
+            }
+        };
+        press(&mut app, KeyCode::Char('/'));
+        // 'q' and 'd' are filter text, not quit / switch tab
+        press(&mut app, KeyCode::Char('q'));
+        press(&mut app, KeyCode::Char('d'));
+        assert_eq!(app.column_search.query, "qd");
+        assert_eq!(app.panel_tab, MainPanelTab::Schema);
+
+        press(&mut app, KeyCode::Enter);
+        assert!(!app.column_search.editing);
+        assert_eq!(app.column_search.query, "qd");
+
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.column_search.is_active());
+    }
+
+    #[test]
+    fn test_navigation_moves_schema_sub_tab_selection() {
+        use crate::model::schema::Index;
+        use crate::model::IndexType;
+
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        project.connections[0].tables = create_test_tables();
+        project.connections[0].tables[0].indexes = vec![
+            Index::new("users_pkey", IndexType::Primary),
+            Index::new("users_email_idx", IndexType::Index),
+        ];
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_table_idx = Some(0);
+        app.focus = Focus::MainPanel;
+        app.panel_tab = MainPanelTab::Schema;
+        app.schema_sub_tab = SchemaSubTab::Indexes;
+
+        app.update(Message::NavigateDown);
+        app.update(Message::NavigateDown);
+        app.update(Message::NavigateDown);
+        assert_eq!(
+            app.schema_states.selected(SchemaSubTab::Indexes, 2),
+            Some(1)
+        );
+        // The sidebar selection is untouched while the main panel is focused
+        assert_eq!(app.selected_table_idx, Some(0));
+
+        app.update(Message::NavigateUp);
+        assert_eq!(
+            app.schema_states.selected(SchemaSubTab::Indexes, 2),
+            Some(0)
+        );
+        assert_eq!(app.schema_states.selected(SchemaSubTab::Columns, 0), None);
+    }
+
+    #[test]
+    fn test_copy_definition_of_selected_index_and_column() {
+        use crate::model::schema::{Column, Index};
+        use crate::model::IndexType;
+
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        let mut tables = create_test_tables();
+        tables[0].columns = vec![
+            Column::new("id", "integer").primary_key(),
+            Column::new("email", "text").not_null(),
+        ];
+        tables[0].indexes = vec![Index::new("users_pkey", IndexType::Primary)
+            .with_definition("CREATE UNIQUE INDEX users_pkey ON public.users USING btree (id)")];
+        project.connections[0].tables = tables;
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_table_idx = Some(0);
+        app.focus = Focus::MainPanel;
+        app.panel_tab = MainPanelTab::Schema;
+
+        // Nothing selected yet
+        app.update(Message::CopyDefinition);
+        assert!(app.pending_clipboard.is_none());
+
+        app.schema_sub_tab = SchemaSubTab::Indexes;
+        app.update(Message::NavigateDown);
+        app.update(Message::CopyDefinition);
+        assert_eq!(
+            app.pending_clipboard.take().as_deref(),
+            Some("CREATE UNIQUE INDEX users_pkey ON public.users USING btree (id)")
+        );
+
+        // The column selection follows the filter
+        app.update(Message::StartColumnSearch);
+        app.update(Message::ColumnSearchInput('m'));
+        app.update(Message::ColumnSearchConfirm);
+        app.update(Message::CopyDefinition);
+        assert_eq!(
+            app.pending_clipboard.take().as_deref(),
+            Some("email text NOT NULL")
+        );
+        assert_eq!(app.status_message, "Copied column email definition");
+    }
+
+    #[test]
+    fn test_show_constraint_detail_for_selected_row() {
+        use crate::model::schema::{Constraint, ConstraintType};
+
+        let mut project = Project::new("main");
+        project.connections = create_test_connections();
+        let mut tables = create_test_tables();
+        let mut check = Constraint::new("price_check", ConstraintType::Check);
+        check.definition = Some("CHECK ((price > 0) AND (price < 1000))".to_string());
+        tables[0].constraints = vec![check];
+        project.connections[0].tables = tables;
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+        app.selected_table_idx = Some(0);
+        app.focus = Focus::MainPanel;
+        app.panel_tab = MainPanelTab::Schema;
+
+        // Only the Constraints tab has details
+        app.update(Message::ShowConstraintDetail);
+        assert!(matches!(app.modal_state, ModalState::None));
+
+        app.schema_sub_tab = SchemaSubTab::Constraints;
+        app.update(Message::ShowConstraintDetail);
+        assert!(matches!(app.modal_state, ModalState::None));
+
+        app.update(Message::NavigateDown);
+        app.update(Message::ShowConstraintDetail);
+        let ModalState::ConstraintDetail(modal) = &app.modal_state else {
+            panic!("expected the constraint detail modal");
+        };
+        assert_eq!(modal.name, "price_check");
+        assert_eq!(modal.lines, vec!["CHECK ((price > 0) AND (price < 1000))"]);
+    }
+
+    #[test]
+    fn test_copy_result_json_asks_above_threshold() {
+        let mut app = App::new(vec![]);
+        app.update(Message::CopyResultJson);
+        assert_eq!(app.status_message, "Nothing to copy");
+
+        app.clipboard_confirm_rows = 1;
+        app.result = Some(QueryResult {
+            columns: vec!["id".to_string(), "name".to_string()],
+            column_types: Vec::new(),
+            rows: vec![
+                vec!["1".to_string(), "alice".to_string()],
+                vec!["2".to_string(), "bob".to_string()],
+            ],
+            execution_time_ms: 1,
+            total_rows: 2,
+            rows_affected: None,
+        });
+
+        app.update(Message::CopyResultJson);
+        assert!(app.pending_clipboard.is_none());
+        let ModalState::ConfirmCopy(modal) = &app.modal_state else {
+            panic!("expected the copy confirmation modal");
+        };
+        assert_eq!(modal.rows, 2);
+
+        app.update(Message::CopyResultJsonConfirmed);
+        assert!(matches!(app.modal_state, ModalState::None));
+        let text = app.pending_clipboard.take().expect("clipboard text");
+        let value: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value[1]["name"], "bob");
+        assert_eq!(app.status_message, "Copied 2 rows as JSON");
+
+        // Under the threshold the copy happens immediately
+        app.clipboard_confirm_rows = 2;
+        app.update(Message::CopyResultJson);
+        assert!(app.pending_clipboard.is_some());
+    }
+
+    #[test]
+    fn test_copy_result_csv_asks_above_threshold() {
+        use crate::app::modals::CopyKind;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut app = create_test_app_with_result(3);
+        app.executed_query = Some("SELECT * FROM users".to_string());
+        app.clipboard_confirm_rows = 2;
+
+        app.update(Message::CopyResultCsv);
+        let ModalState::ConfirmCopy(modal) = &app.modal_state else {
+            panic!("expected the copy confirmation modal");
+        };
+        assert_eq!(modal.kind, CopyKind::Csv);
+        assert_eq!(modal.rows, 3);
+        let key = crate::event::key_to_message(&app, KeyCode::Char('y'), KeyModifiers::NONE);
+        assert_eq!(key, Some(Message::CopyResultCsvConfirmed));
+
+        // Confirming re-runs the query (no worker in tests)
+        app.update(Message::CopyResultCsvConfirmed);
+        assert!(matches!(app.modal_state, ModalState::None));
+        assert_eq!(app.status_message, "Select a connection to export from");
+
+        // A result still being fetched may be far larger than it looks
+        app.clipboard_confirm_rows = 10;
+        app.cursor_has_more = true;
+        app.update(Message::CopyResultCsv);
+        let ModalState::ConfirmCopy(modal) = &app.modal_state else {
+            panic!("expected the copy confirmation modal");
+        };
+        assert!(modal.more_rows);
+    }
+
+    #[test]
+    fn test_query_tabs_keep_their_query_and_result() {
+        let mut app = App::new(vec![]);
+        app.query = "SELECT 1".to_string();
+        app.result = Some(QueryResult {
+            columns: vec!["?column?".to_string()],
+            column_types: Vec::new(),
+            rows: vec![vec!["1".to_string()]],
+            execution_time_ms: 1,
+            total_rows: 1,
+            rows_affected: None,
+        });
+
+        // A new tab starts from a copy of the query, without a result
+        app.update(Message::NewQueryTab);
+        assert_eq!(app.query_tabs.len(), 2);
+        assert_eq!(app.active_query_tab, 1);
+        assert_eq!(app.query, "SELECT 1");
+        assert!(app.result.is_none());
+
+        app.query = "SELECT 2".to_string();
+        assert_eq!(app.query_tab_titles(), vec!["1 SELECT 1", "2 SELECT 2"]);
+
+        app.update(Message::NextQueryTab);
+        assert_eq!(app.active_query_tab, 0);
+        assert_eq!(app.query, "SELECT 1");
+        assert_eq!(app.result.as_ref().map(|r| r.rows.len()), Some(1));
+
+        // Closing the first tab activates the remaining one
+        app.update(Message::CloseQueryTab);
+        assert_eq!(app.query_tabs.len(), 1);
+        assert_eq!(app.active_query_tab, 0);
+        assert_eq!(app.query, "SELECT 2");
+        assert!(app.result.is_none());
+
+        app.update(Message::CloseQueryTab);
+        assert_eq!(app.query_tabs.len(), 1);
+        assert_eq!(app.status_message, "Cannot close the last query tab");
+    }
+
+    #[test]
+    fn test_query_tab_marks_partly_fetched_result() {
+        let mut app = create_test_app_with_result(50);
+        app.executed_query = Some("SELECT * FROM \"users\"".to_string());
+        app.cursor_has_more = true;
+
+        // Switching away closes the cursor of the first tab's result
+        app.update(Message::NewQueryTab);
+        assert!(!app.cursor_has_more);
+        assert!(!app.result_truncated);
+
+        app.update(Message::NextQueryTab);
+        assert_eq!(app.result.as_ref().map(|r| r.rows.len()), Some(50));
+        assert!(app.result_truncated);
+        assert!(app.result_is_partial());
+    }
+
+    #[test]
+    fn test_query_tabs_stay_while_query_runs() {
+        let mut app = App::new(vec![]);
+        app.loading.start_executing_query();
+
+        app.update(Message::NewQueryTab);
+
+        assert_eq!(app.query_tabs.len(), 1);
+        assert_eq!(
+            app.status_message,
+            "Wait for the running query before switching tabs"
+        );
+    }
+
+    #[test]
+    fn test_fullscreen_data_keeps_focus_on_the_panel() {
+        let mut app = create_test_app_with_result(5);
+        app.focus = Focus::MainPanel;
+        app.panel_tab = MainPanelTab::Data;
+
+        app.update(Message::ToggleFullscreenData);
+        assert!(app.fullscreen_data);
+        assert_eq!(
+            app.status_message,
+            "Data panel maximized (Shift+f to restore)"
+        );
+
+        // The hidden panes cannot take focus; the grid still navigates
+        app.update(Message::NextFocus);
+        app.update(Message::FocusUp);
+        assert_eq!(app.focus, Focus::MainPanel);
+        app.update(Message::DataTableDown);
+        assert_eq!(app.data_table_state.selected(), Some(1));
+
+        app.update(Message::ToggleFullscreenData);
+        assert!(!app.fullscreen_data);
+        app.update(Message::NextFocus);
+        assert_eq!(app.focus, Focus::Sidebar);
+    }
 }
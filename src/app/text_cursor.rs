@@ -0,0 +1,173 @@
+//! Cursor editing for single-line modal text fields
+
+/// Cursor within a single-line text field, counted in chars.
+///
+/// The text itself stays a plain `String` on the modal; `None` means the end
+/// of the text, so a freshly focused field (or one whose text was replaced,
+/// e.g. from a DSN) always starts editing at its end.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TextCursor {
+    pos: Option<usize>,
+}
+
+impl TextCursor {
+    /// Char index of the cursor in `text`
+    pub fn position(&self, text: &str) -> usize {
+        let len = text.chars().count();
+        self.pos.map_or(len, |pos| pos.min(len))
+    }
+
+    /// Insert `c` before the cursor
+    pub fn insert(&mut self, text: &mut String, c: char) {
+        let pos = self.position(text);
+        text.insert(byte_index(text, pos), c);
+        if self.pos.is_some() {
+            self.pos = Some(pos + 1);
+        }
+    }
+
+    /// Delete the char before the cursor
+    pub fn backspace(&mut self, text: &mut String) {
+        let pos = self.position(text);
+        if pos == 0 {
+            return;
+        }
+        text.remove(byte_index(text, pos - 1));
+        if self.pos.is_some() {
+            self.pos = Some(pos - 1);
+        }
+    }
+
+    /// Delete the word before the cursor (and the spaces after it), like
+    /// Ctrl+w in a shell
+    pub fn delete_word(&mut self, text: &mut String) {
+        let pos = self.position(text);
+        let before: Vec<char> = text.chars().take(pos).collect();
+        let mut start = pos;
+        while start > 0 && before[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        while start > 0 && !before[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        text.replace_range(byte_index(text, start)..byte_index(text, pos), "");
+        if self.pos.is_some() {
+            self.pos = Some(start);
+        }
+    }
+
+    pub fn left(&mut self, text: &str) {
+        self.pos = Some(self.position(text).saturating_sub(1));
+    }
+
+    pub fn right(&mut self, text: &str) {
+        let pos = self.position(text) + 1;
+        self.pos = (pos < text.chars().count()).then_some(pos);
+    }
+
+    pub fn home(&mut self) {
+        self.pos = Some(0);
+    }
+
+    pub fn end(&mut self) {
+        self.pos = None;
+    }
+}
+
+/// Byte offset of the char at `char_idx` (the text length past the end)
+fn byte_index(text: &str, char_idx: usize) -> usize {
+    text.char_indices()
+        .nth(char_idx)
+        .map_or(text.len(), |(idx, _)| idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_backspace_at_cursor() {
+        let mut text = "lcalhost".to_string();
+        let mut cursor = TextCursor::default();
+        assert_eq!(cursor.position(&text), 8);
+
+        cursor.home();
+        cursor.right(&text);
+        cursor.insert(&mut text, 'o');
+        assert_eq!(text, "localhost");
+        assert_eq!(cursor.position(&text), 2);
+
+        cursor.backspace(&mut text);
+        cursor.backspace(&mut text);
+        cursor.backspace(&mut text);
+        assert_eq!(text, "calhost");
+        assert_eq!(cursor.position(&text), 0);
+    }
+
+    #[test]
+    fn test_cursor_stays_within_text() {
+        let text = "ab".to_string();
+        let mut cursor = TextCursor::default();
+        cursor.right(&text);
+        assert_eq!(cursor, TextCursor::default());
+
+        cursor.home();
+        cursor.left(&text);
+        assert_eq!(cursor.position(&text), 0);
+
+        cursor.right(&text);
+        cursor.right(&text);
+        assert_eq!(cursor.position(&text), 2);
+
+        // A position past shortened text is read as its end
+        cursor.home();
+        cursor.right(&text);
+        assert_eq!(cursor.position("a"), 1);
+    }
+
+    #[test]
+    fn test_end_follows_appended_text() {
+        let mut text = String::new();
+        let mut cursor = TextCursor::default();
+        for c in "db".chars() {
+            cursor.insert(&mut text, c);
+        }
+        cursor.end();
+        cursor.insert(&mut text, '1');
+        assert_eq!(text, "db1");
+        assert_eq!(cursor.position(&text), 3);
+    }
+
+    #[test]
+    fn test_delete_word() {
+        let mut text = "my  prod db".to_string();
+        let mut cursor = TextCursor::default();
+        cursor.delete_word(&mut text);
+        assert_eq!(text, "my  prod ");
+
+        cursor.delete_word(&mut text);
+        assert_eq!(text, "my  ");
+
+        // Only the word before the cursor goes
+        let mut text = "héllo wörld".to_string();
+        let mut cursor = TextCursor::default();
+        for _ in 0..5 {
+            cursor.left(&text);
+        }
+        cursor.delete_word(&mut text);
+        assert_eq!(text, "wörld");
+        assert_eq!(cursor.position(&text), 0);
+    }
+
+    #[test]
+    fn test_multibyte_chars() {
+        let mut text = "données".to_string();
+        let mut cursor = TextCursor::default();
+        cursor.left(&text);
+        cursor.left(&text);
+        cursor.backspace(&mut text);
+        assert_eq!(text, "donnes");
+        cursor.insert(&mut text, 'é');
+        assert_eq!(text, "données");
+    }
+}
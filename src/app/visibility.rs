@@ -9,6 +9,10 @@ pub struct ColumnsVisibility {
     pub show_nullable: bool,
     pub show_default: bool,
     pub show_key: bool,
+    /// Planner statistics from `pg_stats` (hidden by default)
+    pub show_distinct: bool,
+    pub show_null_frac: bool,
+    pub show_avg_width: bool,
 }
 
 impl Default for ColumnsVisibility {
@@ -20,13 +24,18 @@ impl Default for ColumnsVisibility {
             show_nullable: true,
             show_default: true,
             show_key: true,
+            show_distinct: false,
+            show_null_frac: false,
+            show_avg_width: false,
         }
     }
 }
 
 impl ColumnsVisibility {
     pub fn all_columns() -> &'static [&'static str] {
-        &["Icon", "Name", "Type", "Null", "Default", "Key"]
+        &[
+            "Icon", "Name", "Type", "Null", "Default", "Key", "Distinct", "Null %", "Width",
+        ]
     }
 
     pub fn is_visible(&self, index: usize) -> bool {
@@ -37,6 +46,9 @@ impl ColumnsVisibility {
             3 => self.show_nullable,
             4 => self.show_default,
             5 => self.show_key,
+            6 => self.show_distinct,
+            7 => self.show_null_frac,
+            8 => self.show_avg_width,
             _ => false,
         }
     }
@@ -49,6 +61,9 @@ impl ColumnsVisibility {
             3 => self.show_nullable = !self.show_nullable,
             4 => self.show_default = !self.show_default,
             5 => self.show_key = !self.show_key,
+            6 => self.show_distinct = !self.show_distinct,
+            7 => self.show_null_frac = !self.show_null_frac,
+            8 => self.show_avg_width = !self.show_avg_width,
             _ => {}
         }
     }
@@ -61,6 +76,10 @@ pub struct IndexesVisibility {
     pub show_type: bool,
     pub show_method: bool,
     pub show_columns: bool,
+    /// Usage statistics from `pg_stat_user_indexes` (hidden by default)
+    pub show_scans: bool,
+    pub show_tuples_read: bool,
+    pub show_tuples_fetched: bool,
 }
 
 impl Default for IndexesVisibility {
@@ -70,13 +89,24 @@ impl Default for IndexesVisibility {
             show_type: true,
             show_method: true,
             show_columns: true,
+            show_scans: false,
+            show_tuples_read: false,
+            show_tuples_fetched: false,
         }
     }
 }
 
 impl IndexesVisibility {
     pub fn all_columns() -> &'static [&'static str] {
-        &["Name", "Type", "Method", "Columns"]
+        &[
+            "Name",
+            "Type",
+            "Method",
+            "Columns",
+            "Scans",
+            "Tup Read",
+            "Tup Fetch",
+        ]
     }
 
     pub fn is_visible(&self, index: usize) -> bool {
@@ -85,6 +115,9 @@ impl IndexesVisibility {
             1 => self.show_type,
             2 => self.show_method,
             3 => self.show_columns,
+            4 => self.show_scans,
+            5 => self.show_tuples_read,
+            6 => self.show_tuples_fetched,
             _ => false,
         }
     }
@@ -95,6 +128,9 @@ impl IndexesVisibility {
             1 => self.show_type = !self.show_type,
             2 => self.show_method = !self.show_method,
             3 => self.show_columns = !self.show_columns,
+            4 => self.show_scans = !self.show_scans,
+            5 => self.show_tuples_read = !self.show_tuples_read,
+            6 => self.show_tuples_fetched = !self.show_tuples_fetched,
             _ => {}
         }
     }
@@ -0,0 +1,38 @@
+//! Copy text to the system clipboard through the terminal
+//!
+//! Uses the OSC 52 escape sequence, which most modern terminal emulators (and
+//! tmux with `set-clipboard on`) forward to the system clipboard. It needs no
+//! display server and also works over SSH.
+
+use std::io::{self, Write};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+/// OSC 52 sequence that sets the clipboard to `text`
+pub fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+/// Write `text` to the clipboard via the terminal behind `out`
+pub fn copy_to_clipboard(out: &mut impl Write, text: &str) -> io::Result<()> {
+    out.write_all(osc52_sequence(text).as_bytes())?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_sequence_encodes_text() {
+        assert_eq!(osc52_sequence("hello"), "\x1b]52;c;aGVsbG8=\x07");
+    }
+
+    #[test]
+    fn test_copy_writes_sequence() {
+        let mut out = Vec::new();
+        copy_to_clipboard(&mut out, "CREATE INDEX").unwrap();
+        assert_eq!(out, osc52_sequence("CREATE INDEX").into_bytes());
+    }
+}
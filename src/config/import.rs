@@ -0,0 +1,271 @@
+//! Import connections from libpq configuration files
+//!
+//! Supports:
+//! - `~/.pgpass` (`hostname:port:database:username:password` lines)
+//! - `~/.pg_service.conf` (INI-style service definitions)
+//!
+//! The parsers are pure; [`load_default_sources`] reads the files from their
+//! usual locations (honoring `PGPASSFILE` / `PGSERVICEFILE`).
+
+use std::fs;
+use std::path::PathBuf;
+
+use super::models::ConnectionConfig;
+
+pub(super) const DEFAULT_HOST: &str = "localhost";
+pub(super) const DEFAULT_PORT: u16 = 5432;
+pub(super) const DEFAULT_DATABASE: &str = "postgres";
+
+/// A single `.pgpass` entry. `None` fields are `*` wildcards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgPassEntry {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub database: Option<String>,
+    pub username: Option<String>,
+    pub password: String,
+}
+
+impl PgPassEntry {
+    /// Connection config for this entry, filling wildcards with libpq defaults
+    pub fn to_connection_config(&self) -> ConnectionConfig {
+        let host = self
+            .host
+            .clone()
+            .unwrap_or_else(|| DEFAULT_HOST.to_string());
+        let database = self
+            .database
+            .clone()
+            .unwrap_or_else(|| DEFAULT_DATABASE.to_string());
+        let name = match &self.username {
+            Some(user) => format!("{}@{}/{}", user, host, database),
+            None => format!("{}/{}", host, database),
+        };
+        ConnectionConfig {
+            name,
+            host,
+            port: self.port.unwrap_or(DEFAULT_PORT),
+            database,
+            username: self.username.clone(),
+            password: Some(self.password.clone()).filter(|p| !p.is_empty()),
+            password_env: None,
+            default_schema: None,
+            color: None,
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
+        }
+    }
+}
+
+/// Parse the contents of a `.pgpass` file.
+///
+/// Blank lines, `#` comments and lines without exactly five fields are skipped.
+/// `\:` and `\\` are unescaped; a field consisting of `*` is a wildcard.
+pub fn parse_pgpass(content: &str) -> Vec<PgPassEntry> {
+    content
+        .lines()
+        .filter(|line| {
+            let trimmed = line.trim();
+            !trimmed.is_empty() && !trimmed.starts_with('#')
+        })
+        .filter_map(|line| {
+            let fields = split_pgpass_line(line.trim_end_matches('\r'));
+            let [host, port, database, username, password]: [String; 5] = fields.try_into().ok()?;
+            let wildcard = |s: String| if s == "*" { None } else { Some(s) };
+            let port = match wildcard(port) {
+                Some(p) => Some(p.parse().ok()?),
+                None => None,
+            };
+            Some(PgPassEntry {
+                host: wildcard(host),
+                port,
+                database: wildcard(database),
+                username: wildcard(username),
+                password,
+            })
+        })
+        .collect()
+}
+
+/// Split a `.pgpass` line on unescaped colons, unescaping `\:` and `\\`
+fn split_pgpass_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            ':' => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
+/// Parse the contents of a `pg_service.conf` file into connection configs.
+///
+/// Each `[service]` section becomes a connection named after the service.
+/// Unknown keys are ignored; missing values fall back to libpq defaults.
+pub fn parse_pg_service(content: &str) -> Vec<ConnectionConfig> {
+    let mut connections = Vec::new();
+    let mut current: Option<ConnectionConfig> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            connections.extend(current.take());
+            current = Some(ConnectionConfig {
+                name: name.trim().to_string(),
+                host: DEFAULT_HOST.to_string(),
+                port: DEFAULT_PORT,
+                database: DEFAULT_DATABASE.to_string(),
+                username: None,
+                password: None,
+                password_env: None,
+                default_schema: None,
+                color: None,
+                is_production: false,
+                query_timeout_secs: None,
+                snippets: Vec::new(),
+            });
+            continue;
+        }
+
+        let (Some(conn), Some((key, value))) = (current.as_mut(), line.split_once('=')) else {
+            continue;
+        };
+        let value = value.trim().to_string();
+        match key.trim() {
+            "host" | "hostaddr" => conn.host = value,
+            "port" => {
+                if let Ok(port) = value.parse() {
+                    conn.port = port;
+                }
+            }
+            "dbname" => conn.database = value,
+            "user" => conn.username = Some(value),
+            "password" => conn.password = Some(value),
+            _ => {}
+        }
+    }
+    connections.extend(current);
+    connections
+}
+
+/// Read `.pgpass` and `pg_service.conf` from their default locations.
+///
+/// Missing or unreadable files are skipped. Returns the connections found and
+/// the files they were read from.
+pub fn load_default_sources() -> (Vec<ConnectionConfig>, Vec<PathBuf>) {
+    let mut connections = Vec::new();
+    let mut sources = Vec::new();
+
+    if let Some(path) = source_path("PGSERVICEFILE", ".pg_service.conf") {
+        if let Ok(content) = fs::read_to_string(&path) {
+            connections.extend(parse_pg_service(&content));
+            sources.push(path);
+        }
+    }
+    if let Some(path) = source_path("PGPASSFILE", ".pgpass") {
+        if let Ok(content) = fs::read_to_string(&path) {
+            connections.extend(
+                parse_pgpass(&content)
+                    .iter()
+                    .map(PgPassEntry::to_connection_config),
+            );
+            sources.push(path);
+        }
+    }
+
+    (connections, sources)
+}
+
+/// Path from `env_var` if set, otherwise `file_name` in the home directory
+fn source_path(env_var: &str, file_name: &str) -> Option<PathBuf> {
+    match std::env::var_os(env_var) {
+        Some(path) => Some(PathBuf::from(path)),
+        None => dirs::home_dir().map(|home| home.join(file_name)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pgpass_basic_entry() {
+        let entries = parse_pgpass("db.example.com:5433:app:alice:s3cret\n");
+        assert_eq!(
+            entries,
+            vec![PgPassEntry {
+                host: Some("db.example.com".to_string()),
+                port: Some(5433),
+                database: Some("app".to_string()),
+                username: Some("alice".to_string()),
+                password: "s3cret".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_pgpass_skips_comments_and_malformed_lines() {
+        let content = "# comment\n\n  # indented comment\nonly:three:fields\nhost:notaport:db:user:pw\nlocalhost:5432:db:user:pw\n";
+        let entries = parse_pgpass(content);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].host.as_deref(), Some("localhost"));
+    }
+
+    #[test]
+    fn test_parse_pgpass_wildcards() {
+        let entries = parse_pgpass("*:*:*:bob:pw");
+        let entry = &entries[0];
+        assert_eq!(entry.host, None);
+        assert_eq!(entry.port, None);
+        assert_eq!(entry.database, None);
+
+        let config = entry.to_connection_config();
+        assert_eq!(config.host, "localhost");
+        assert_eq!(config.port, 5432);
+        assert_eq!(config.database, "postgres");
+        assert_eq!(config.name, "bob@localhost/postgres");
+    }
+
+    #[test]
+    fn test_parse_pgpass_escaped_colons() {
+        let entries = parse_pgpass(r"localhost:5432:db:user:pa\:ss\\word");
+        assert_eq!(entries[0].password, r"pa:ss\word");
+    }
+
+    #[test]
+    fn test_parse_pg_service() {
+        let content = "\
+# services
+[reporting]
+host=reports.example.com
+port=6432
+dbname=analytics
+user=reader
+
+[local]
+dbname=dev
+";
+        let connections = parse_pg_service(content);
+        assert_eq!(connections.len(), 2);
+        assert_eq!(connections[0].name, "reporting");
+        assert_eq!(connections[0].host, "reports.example.com");
+        assert_eq!(connections[0].port, 6432);
+        assert_eq!(connections[0].username.as_deref(), Some("reader"));
+        assert_eq!(connections[1].host, "localhost");
+        assert_eq!(connections[1].database, "dev");
+    }
+}
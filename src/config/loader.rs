@@ -1,10 +1,21 @@
 use std::fs;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 
+use super::migrate::{parse_versioned, CONFIG_VERSION};
 use super::models::{Config, ConnectionConfig, ProjectConfig, ProjectFile, Settings};
-use crate::model::QueryHistory;
+use crate::model::{QueryHistory, RecentTables, SessionSnapshot};
+
+/// 読み込めなかったプロジェクトファイル
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectLoadError {
+    /// `config.yaml` に書かれたファイルのパス
+    pub path: String,
+    /// 根本の原因（YAML の構文エラーならその位置を含む）
+    pub message: String,
+}
 
 /// 設定ファイルの読み込みを担当
 pub struct ConfigLoader {
@@ -61,21 +72,31 @@ impl ConfigLoader {
     }
 
     /// メイン設定ファイルを読み込む
-    pub fn load_config(&self) -> Result<Config> {
+    ///
+    /// 古い形式のファイルは現在の形式に変換して書き戻し、変換内容を警告として返す
+    pub fn load_config(&self) -> Result<(Config, Vec<String>)> {
         let config_path = self.config_file_path();
 
         if !config_path.exists() {
             // 設定ファイルが存在しない場合はデフォルト値を返す
-            return Ok(Config::default());
+            return Ok((Config::default(), Vec::new()));
         }
 
         let content = fs::read_to_string(&config_path)
             .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
 
-        let config: Config = serde_norway::from_str(&content)
+        let (config, upgrade) = parse_versioned::<Config>(&content)
             .with_context(|| format!("Failed to parse config file: {}", config_path.display()))?;
 
-        Ok(config)
+        let mut warnings = Vec::new();
+        if let Some(upgrade) = upgrade {
+            warnings.push(format!("Upgraded config file ({})", upgrade));
+            if let Err(e) = self.save_config(&config) {
+                warnings.push(format!("Failed to rewrite upgraded config file: {}", e));
+            }
+        }
+
+        Ok((config, warnings))
     }
 
     /// メイン設定ファイルを保存
@@ -93,7 +114,7 @@ impl ConfigLoader {
         let config_path = self.config_file_path();
         let content = serde_norway::to_string(config).context("Failed to serialize config")?;
 
-        fs::write(&config_path, content)
+        write_atomic(&config_path, &content)
             .with_context(|| format!("Failed to write config file: {}", config_path.display()))?;
 
         Ok(())
@@ -116,17 +137,16 @@ impl ConfigLoader {
     }
 
     /// プロジェクトファイルを読み込む
-    pub fn load_project_file(&self, path: &str) -> Result<ProjectFile> {
+    ///
+    /// 古い形式のファイルであれば変換内容も返す（ファイルは書き換えない）
+    pub fn load_project_file(&self, path: &str) -> Result<(ProjectFile, Option<String>)> {
         let resolved_path = self.resolve_project_path(path)?;
 
         let content = fs::read_to_string(&resolved_path)
             .with_context(|| format!("Failed to read project file: {}", resolved_path.display()))?;
 
-        let project_file: ProjectFile = serde_norway::from_str(&content).with_context(|| {
-            format!("Failed to parse project file: {}", resolved_path.display())
-        })?;
-
-        Ok(project_file)
+        parse_versioned::<ProjectFile>(&content)
+            .with_context(|| format!("Failed to parse project file: {}", resolved_path.display()))
     }
 
     /// プロジェクトファイルを保存
@@ -144,32 +164,71 @@ impl ConfigLoader {
         let content =
             serde_norway::to_string(project_file).context("Failed to serialize project file")?;
 
-        fs::write(&resolved_path, content).with_context(|| {
+        write_atomic(&resolved_path, &content).with_context(|| {
             format!("Failed to write project file: {}", resolved_path.display())
         })?;
 
         Ok(())
     }
 
+    /// 新しいプロジェクトファイルを作成し、メイン設定ファイルに登録する
+    ///
+    /// ファイル名はプロジェクト名から生成し、既存のファイルや登録済みのパスと
+    /// 衝突する場合は `-2`, `-3` ... を付与する。登録したパスを返す
+    pub fn add_project_file(
+        &self,
+        config: &mut Config,
+        project_file: &ProjectFile,
+    ) -> Result<String> {
+        let slug = project_file_slug(&project_file.project.name);
+        let mut path = format!("projects/{}.yaml", slug);
+        let mut n = 2;
+        while config.projects.contains(&path) || self.resolve_project_path(&path)?.exists() {
+            path = format!("projects/{}-{}.yaml", slug, n);
+            n += 1;
+        }
+
+        self.save_project_file(&path, project_file)?;
+        config.projects.push(path.clone());
+        self.save_config(config)?;
+
+        Ok(path)
+    }
+
     /// 全てのプロジェクトファイルを読み込む
     ///
-    /// 読み込みに失敗したファイルは警告を返し、スキップする
-    pub fn load_all_projects(&self, config: &Config) -> (Vec<ProjectFile>, Vec<String>) {
+    /// 読み込みに失敗したファイルはスキップし、エラーとして返す。
+    /// 古い形式のファイルは現在の形式で書き戻し、その旨を警告として返す
+    pub fn load_all_projects(
+        &self,
+        config: &Config,
+    ) -> (Vec<ProjectFile>, Vec<String>, Vec<ProjectLoadError>) {
         let mut projects = Vec::new();
         let mut warnings = Vec::new();
+        let mut errors = Vec::new();
 
         for path in &config.projects {
             match self.load_project_file(path) {
-                Ok(project_file) => {
+                Ok((project_file, upgrade)) => {
+                    if let Some(upgrade) = upgrade {
+                        warnings.push(format!("Upgraded project '{}' ({})", path, upgrade));
+                        if let Err(e) = self.save_project_file(path, &project_file) {
+                            warnings.push(format!(
+                                "Failed to rewrite upgraded project '{}': {}",
+                                path, e
+                            ));
+                        }
+                    }
                     projects.push(project_file);
                 }
-                Err(e) => {
-                    warnings.push(format!("Failed to load project '{}': {}", path, e));
-                }
+                Err(e) => errors.push(ProjectLoadError {
+                    path: path.clone(),
+                    message: e.root_cause().to_string(),
+                }),
             }
         }
 
-        (projects, warnings)
+        (projects, warnings, errors)
     }
 
     /// 設定ディレクトリと初期設定ファイルを作成
@@ -211,6 +270,7 @@ impl ConfigLoader {
     fn create_sample_data(&self) -> Result<()> {
         // サンプルプロジェクトファイルを作成
         let sample_project = ProjectFile {
+            version: CONFIG_VERSION,
             project: ProjectConfig {
                 name: "Sample Project".to_string(),
                 description: Some("This is a sample project to get you started.".to_string()),
@@ -225,6 +285,11 @@ impl ConfigLoader {
                     username: Some("postgres".to_string()),
                     password: None,
                     password_env: Some("POSTGRES_PASSWORD".to_string()),
+                    default_schema: None,
+                    color: None,
+                    is_production: false,
+                    query_timeout_secs: None,
+                    snippets: Vec::new(),
                 },
                 ConnectionConfig {
                     name: "Example MySQL".to_string(),
@@ -234,6 +299,11 @@ impl ConfigLoader {
                     username: Some("root".to_string()),
                     password: None,
                     password_env: Some("MYSQL_PASSWORD".to_string()),
+                    default_schema: None,
+                    color: None,
+                    is_production: false,
+                    query_timeout_secs: None,
+                    snippets: Vec::new(),
                 },
             ],
         };
@@ -247,8 +317,10 @@ impl ConfigLoader {
                 default_project: Some("Sample Project".to_string()),
                 theme: "dark".to_string(),
                 show_row_count: true,
+                ..Settings::default()
             },
             projects: vec![sample_project_path.to_string()],
+            ..Config::default()
         };
         self.save_config(&config)?;
 
@@ -293,11 +365,130 @@ impl ConfigLoader {
         let history_path = self.history_file_path();
         let content = serde_norway::to_string(history).context("Failed to serialize history")?;
 
-        fs::write(&history_path, content)
+        write_atomic(&history_path, &content)
             .with_context(|| format!("Failed to write history file: {}", history_path.display()))?;
 
         Ok(())
     }
+
+    /// 最近開いたテーブル一覧ファイルのパスを返す
+    pub fn recent_tables_file_path(&self) -> PathBuf {
+        self.config_dir.join("recent_tables.yaml")
+    }
+
+    /// 最近開いたテーブル一覧を読み込む
+    pub fn load_recent_tables(&self) -> Result<RecentTables> {
+        let path = self.recent_tables_file_path();
+
+        if !path.exists() {
+            return Ok(RecentTables::new());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read recent tables file: {}", path.display()))?;
+
+        let recent: RecentTables = serde_norway::from_str(&content)
+            .with_context(|| format!("Failed to parse recent tables file: {}", path.display()))?;
+
+        Ok(recent)
+    }
+
+    /// 最近開いたテーブル一覧を保存
+    pub fn save_recent_tables(&self, recent: &RecentTables) -> Result<()> {
+        if !self.config_dir.exists() {
+            fs::create_dir_all(&self.config_dir).with_context(|| {
+                format!(
+                    "Failed to create config directory: {}",
+                    self.config_dir.display()
+                )
+            })?;
+        }
+
+        let path = self.recent_tables_file_path();
+        let content =
+            serde_norway::to_string(recent).context("Failed to serialize recent tables")?;
+
+        write_atomic(&path, &content)
+            .with_context(|| format!("Failed to write recent tables file: {}", path.display()))?;
+
+        Ok(())
+    }
+
+    /// 前回終了時のセッションファイルのパスを返す
+    pub fn session_file_path(&self) -> PathBuf {
+        self.config_dir.join("session.yaml")
+    }
+
+    /// 前回終了時のセッションを読み込む（ファイルがなければ空のセッション）
+    pub fn load_session(&self) -> Result<SessionSnapshot> {
+        let path = self.session_file_path();
+
+        if !path.exists() {
+            return Ok(SessionSnapshot::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read session file: {}", path.display()))?;
+
+        let session: SessionSnapshot = serde_norway::from_str(&content)
+            .with_context(|| format!("Failed to parse session file: {}", path.display()))?;
+
+        Ok(session)
+    }
+
+    /// 終了時のセッションを保存
+    pub fn save_session(&self, session: &SessionSnapshot) -> Result<()> {
+        if !self.config_dir.exists() {
+            fs::create_dir_all(&self.config_dir).with_context(|| {
+                format!(
+                    "Failed to create config directory: {}",
+                    self.config_dir.display()
+                )
+            })?;
+        }
+
+        let path = self.session_file_path();
+        let content = serde_norway::to_string(session).context("Failed to serialize session")?;
+
+        write_atomic(&path, &content)
+            .with_context(|| format!("Failed to write session file: {}", path.display()))?;
+
+        Ok(())
+    }
+}
+
+/// ファイルを原子的に書き換える
+///
+/// 同じディレクトリの一時ファイルに書き込んでから置き換えるため、書き込み中に
+/// 終了しても元のファイルが壊れることはない
+pub(super) fn write_atomic(path: &Path, content: &str) -> io::Result<()> {
+    let temp_path = write_temp_file(path, content)?;
+    fs::rename(&temp_path, path)
+}
+
+/// `path` の隣の一時ファイル（`.<ファイル名>.tmp`）に書き込み、ディスクに同期する
+fn write_temp_file(path: &Path, content: &str) -> io::Result<PathBuf> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let temp_path = path.with_file_name(format!(".{}.tmp", file_name));
+    let mut file = fs::File::create(&temp_path)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()?;
+    Ok(temp_path)
+}
+
+/// プロジェクト名からファイル名に使える文字列を生成する
+pub fn project_file_slug(name: &str) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        "project".to_string()
+    } else {
+        slug
+    }
 }
 
 #[cfg(test)]
@@ -338,7 +529,7 @@ mod tests {
     #[test]
     fn test_load_config_default() {
         let (loader, _temp_dir) = create_test_loader();
-        let config = loader.load_config().unwrap();
+        let (config, _) = loader.load_config().unwrap();
         assert!(config.projects.is_empty());
     }
 
@@ -349,10 +540,12 @@ mod tests {
         let config = Config {
             settings: Default::default(),
             projects: vec!["projects/test.yaml".to_string()],
+            ..Config::default()
         };
 
         loader.save_config(&config).unwrap();
-        let loaded = loader.load_config().unwrap();
+        let (loaded, upgrades) = loader.load_config().unwrap();
+        assert!(upgrades.is_empty());
 
         assert_eq!(loaded.projects.len(), 1);
         assert_eq!(loaded.projects[0], "projects/test.yaml");
@@ -377,7 +570,7 @@ mod tests {
             .exists());
 
         // 設定ファイルにサンプルプロジェクトが登録されている
-        let config = loader.load_config().unwrap();
+        let (config, _) = loader.load_config().unwrap();
         assert_eq!(config.projects.len(), 1);
         assert_eq!(config.projects[0], "projects/sample-project.yaml");
         assert_eq!(
@@ -399,7 +592,7 @@ mod tests {
         assert!(!is_first_run);
 
         // ファイルは上書きされていない（サンプルプロジェクトはそのまま）
-        let config = loader.load_config().unwrap();
+        let (config, _) = loader.load_config().unwrap();
         assert_eq!(config.projects.len(), 1);
     }
 
@@ -409,9 +602,10 @@ mod tests {
         loader.init_config_dir().unwrap();
 
         // サンプルプロジェクトの内容を確認
-        let project = loader
+        let (project, upgrade) = loader
             .load_project_file("projects/sample-project.yaml")
             .unwrap();
+        assert_eq!(upgrade, None);
 
         assert_eq!(project.project.name, "Sample Project");
         assert!(project.project.description.is_some());
@@ -429,4 +623,194 @@ mod tests {
         assert_eq!(mysql_conn.host, "localhost");
         assert_eq!(mysql_conn.port, 3306);
     }
+
+    #[test]
+    fn test_project_file_slug() {
+        assert_eq!(
+            project_file_slug("Sample Project (copy)"),
+            "sample-project-copy"
+        );
+        assert_eq!(project_file_slug("  "), "project");
+    }
+
+    #[test]
+    fn test_add_project_file_avoids_collisions() {
+        let (loader, temp_dir) = create_test_loader();
+        loader.init_config_dir().unwrap();
+        let (mut config, _) = loader.load_config().unwrap();
+        let (mut project_file, _) = loader
+            .load_project_file("projects/sample-project.yaml")
+            .unwrap();
+
+        // 元のプロジェクトと同名でも既存ファイルを上書きしない
+        let path = loader.add_project_file(&mut config, &project_file).unwrap();
+        assert_eq!(path, "projects/sample-project-2.yaml");
+
+        project_file.project.name = "Sample Project (copy)".to_string();
+        let path = loader.add_project_file(&mut config, &project_file).unwrap();
+        assert_eq!(path, "projects/sample-project-copy.yaml");
+        assert!(temp_dir.path().join(&path).exists());
+
+        // メイン設定ファイルに登録されている
+        let (config, _) = loader.load_config().unwrap();
+        assert_eq!(config.projects.len(), 3);
+        assert_eq!(config.projects[2], "projects/sample-project-copy.yaml");
+    }
+
+    #[test]
+    fn test_save_and_load_recent_tables() {
+        let (loader, _temp_dir) = create_test_loader();
+
+        // ファイルがない場合は空の一覧
+        assert!(loader.load_recent_tables().unwrap().is_empty());
+
+        let mut recent = RecentTables::new();
+        recent.touch(crate::model::RecentTable::new("Project", "local", "users"));
+        loader.save_recent_tables(&recent).unwrap();
+
+        let loaded = loader.load_recent_tables().unwrap();
+        assert_eq!(loaded.entries, recent.entries);
+    }
+
+    #[test]
+    fn test_save_and_load_session() {
+        let (loader, _temp_dir) = create_test_loader();
+
+        // ファイルがない場合は空のセッション
+        assert_eq!(loader.load_session().unwrap(), SessionSnapshot::default());
+
+        let session = SessionSnapshot {
+            project: Some("Project".to_string()),
+            connection: Some("local".to_string()),
+            expanded: vec!["local".to_string()],
+            table: Some("users".to_string()),
+            query: "SELECT 1".to_string(),
+            tab: "data".to_string(),
+        };
+        loader.save_session(&session).unwrap();
+
+        assert_eq!(loader.load_session().unwrap(), session);
+    }
+
+    #[test]
+    fn test_interrupted_history_save_keeps_old_file() {
+        let (loader, _temp_dir) = create_test_loader();
+
+        let mut history = QueryHistory::new();
+        history.add(crate::model::HistoryEntry::success(
+            "SELECT 1", "local", "db", 1, 1,
+        ));
+        loader.save_history(&history).unwrap();
+
+        // 一時ファイルへの書き込み後、置き換える前に終了した場合
+        history.add(crate::model::HistoryEntry::success(
+            "SELECT 2", "local", "db", 1, 1,
+        ));
+        let content = serde_norway::to_string(&history).unwrap();
+        let temp_path = write_temp_file(&loader.history_file_path(), &content[..10]).unwrap();
+        assert!(temp_path.exists());
+
+        let loaded = loader.load_history().unwrap();
+        assert_eq!(loaded.len(), 1);
+
+        // 次の保存で一時ファイルは置き換えに使われ、残らない
+        loader.save_history(&history).unwrap();
+        assert_eq!(loader.load_history().unwrap().len(), 2);
+        assert!(!temp_path.exists());
+    }
+
+    #[test]
+    fn test_save_project_file_replaces_existing() {
+        let (loader, temp_dir) = create_test_loader();
+        loader.init_config_dir().unwrap();
+        let path = "projects/sample-project.yaml";
+
+        let (mut project, _) = loader.load_project_file(path).unwrap();
+        project.project.name = "Renamed".to_string();
+        loader.save_project_file(path, &project).unwrap();
+
+        assert_eq!(
+            loader.load_project_file(path).unwrap().0.project.name,
+            "Renamed"
+        );
+        let leftovers: Vec<_> = fs::read_dir(temp_dir.path().join("projects"))
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(leftovers, vec!["sample-project.yaml"]);
+    }
+
+    #[test]
+    fn test_v0_files_are_upgraded_and_rewritten() {
+        let (loader, temp_dir) = create_test_loader();
+        fs::create_dir_all(temp_dir.path().join("projects")).unwrap();
+        fs::write(
+            loader.config_file_path(),
+            "settings:\n  theme: light\nprojects:\n  - projects/app.yaml\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("projects/app.yaml"),
+            "project:\n  name: App\nconnections: []\n",
+        )
+        .unwrap();
+
+        let (config, warnings) = loader.load_config().unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Upgraded config file (version 0 to 1, added defaults"));
+
+        let (projects, warnings, errors) = loader.load_all_projects(&config);
+        assert!(errors.is_empty());
+        assert_eq!(projects[0].project.name, "App");
+        assert_eq!(
+            warnings,
+            vec!["Upgraded project 'projects/app.yaml' (version 0 to 1)"]
+        );
+
+        // The rewritten files load without further upgrades
+        let (config, warnings) = loader.load_config().unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(config.settings.theme, "light");
+        let (_, warnings, _) = loader.load_all_projects(&config);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_broken_project_files_are_reported() {
+        let (loader, temp_dir) = create_test_loader();
+        fs::create_dir_all(temp_dir.path().join("projects")).unwrap();
+        fs::write(
+            temp_dir.path().join("projects/ok.yaml"),
+            "project:\n  name: Ok\nconnections: []\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("projects/broken.yaml"),
+            "project:\n  name: [unclosed\n",
+        )
+        .unwrap();
+        let config = Config {
+            projects: vec![
+                "projects/ok.yaml".to_string(),
+                "projects/broken.yaml".to_string(),
+                "projects/missing.yaml".to_string(),
+            ],
+            ..Config::default()
+        };
+
+        let (projects, _, errors) = loader.load_all_projects(&config);
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].project.name, "Ok");
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].path, "projects/broken.yaml");
+        // The parse error itself, with its location
+        assert!(
+            errors[0].message.contains("line 2"),
+            "{}",
+            errors[0].message
+        );
+        assert_eq!(errors[1].path, "projects/missing.yaml");
+    }
 }
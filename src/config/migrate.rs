@@ -0,0 +1,144 @@
+//! Upgrading config and project files written by older versions of lazydb
+//!
+//! Both `config.yaml` and project files carry a top-level `version`. Files
+//! without one are version 0. Loading an older file applies the upgrade
+//! steps for each version in turn, after which the loader rewrites it.
+
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_norway::Value;
+
+/// Format version of `config.yaml` and project files written by this build
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Parse a config or project file, upgrading it to [`CONFIG_VERSION`].
+///
+/// Returns the parsed file and, if it was upgraded, a description of what
+/// changed. Files from a newer version are read as they are.
+pub fn parse_versioned<T>(content: &str) -> Result<(T, Option<String>)>
+where
+    T: Serialize + DeserializeOwned,
+{
+    // Parse the file as written first, so syntax errors keep their location
+    let parsed: T = serde_norway::from_str(content)?;
+    let raw: Value = serde_norway::from_str(content)?;
+    let Value::Mapping(mapping) = &raw else {
+        return Ok((parsed, None));
+    };
+    let version = mapping.get("version").and_then(Value::as_u64).unwrap_or(0);
+    if version >= u64::from(CONFIG_VERSION) {
+        return Ok((parsed, None));
+    }
+
+    let mut upgraded = mapping.clone();
+    // Version 0 -> 1: files gain a version; missing fields get their defaults
+    upgraded.insert("version".into(), CONFIG_VERSION.into());
+
+    let migrated: T = serde_norway::from_value(Value::Mapping(upgraded))?;
+    let mut added = Vec::new();
+    added_keys(&raw, &serde_norway::to_value(&migrated)?, "", &mut added);
+    added.retain(|key| key != "version");
+
+    let mut note = format!("version {} to {}", version, CONFIG_VERSION);
+    if !added.is_empty() {
+        note.push_str(&format!(", added defaults for {}", added.join(", ")));
+    }
+    Ok((migrated, Some(note)))
+}
+
+/// Collect the paths (`settings.theme`, `connections[0].port`) of keys in
+/// `new` that are missing from `old`. Keys that are null are left out.
+fn added_keys(old: &Value, new: &Value, path: &str, out: &mut Vec<String>) {
+    match (old, new) {
+        (Value::Mapping(old), Value::Mapping(new)) => {
+            for (key, value) in new {
+                let Some(name) = key.as_str() else {
+                    continue;
+                };
+                let key_path = if path.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{}.{}", path, name)
+                };
+                match old.get(key) {
+                    Some(old_value) => added_keys(old_value, value, &key_path, out),
+                    None if !value.is_null() => out.push(key_path),
+                    None => {}
+                }
+            }
+        }
+        (Value::Sequence(old), Value::Sequence(new)) => {
+            for (idx, (old_value, value)) in old.iter().zip(new).enumerate() {
+                added_keys(old_value, value, &format!("{}[{}]", path, idx), out);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, ProjectFile};
+
+    #[test]
+    fn test_v0_config_upgrades_with_defaults() {
+        let yaml = r#"
+settings:
+  theme: light
+projects:
+  - projects/app.yaml
+"#;
+        let (config, note) = parse_versioned::<Config>(yaml).unwrap();
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.settings.theme, "light");
+        assert_eq!(config.settings.result_cache_size, 20);
+        assert_eq!(config.projects, vec!["projects/app.yaml"]);
+
+        let note = note.unwrap();
+        assert!(note.starts_with("version 0 to 1, added defaults for settings."));
+        assert!(note.contains("settings.result_cache_size"));
+        assert!(!note.contains("settings.theme"));
+        // Unset optional settings are not worth reporting
+        assert!(!note.contains("default_project"));
+    }
+
+    #[test]
+    fn test_v0_project_file_upgrades() {
+        let yaml = r#"
+project:
+  name: App
+connections:
+  - name: local
+    host: localhost
+    database: app
+"#;
+        let (project, note) = parse_versioned::<ProjectFile>(yaml).unwrap();
+
+        assert_eq!(project.version, CONFIG_VERSION);
+        assert_eq!(project.connections[0].port, 5432);
+        let note = note.unwrap();
+        assert!(note.contains("connections[0].port"));
+        assert!(note.contains("connections[0].is_production"));
+    }
+
+    #[test]
+    fn test_current_version_is_left_alone() {
+        let yaml = format!("version: {}\nprojects: []\n", CONFIG_VERSION);
+        let (config, note) = parse_versioned::<Config>(&yaml).unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(note, None);
+
+        // A newer file is read without being rewritten
+        let (config, note) = parse_versioned::<Config>("version: 99\n").unwrap();
+        assert_eq!(config.version, 99);
+        assert_eq!(note, None);
+    }
+
+    #[test]
+    fn test_parse_errors_are_reported() {
+        assert!(parse_versioned::<Config>("projects: [unclosed").is_err());
+    }
+}
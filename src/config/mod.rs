@@ -4,6 +4,9 @@
 //! - Main configuration file (`config.yaml`)
 //! - Project configuration files (`projects/*.yaml`)
 //! - Query history persistence
+//! - Importing connections from `.pgpass` / `pg_service.conf`
+//! - Parsing `postgresql://` connection URLs
+//! - Exporting/importing shareable project files
 //!
 //! # Configuration Directory
 //!
@@ -11,10 +14,20 @@
 //! - Linux/macOS: `~/.config/lazydb/`
 //! - Windows: `%APPDATA%\lazydb\`
 
+pub mod dsn;
+pub mod import;
 mod loader;
+mod migrate;
 mod models;
+pub mod share;
+mod watch;
 
-pub use loader::ConfigLoader;
+pub use loader::{project_file_slug, ConfigLoader, ProjectLoadError};
+pub use migrate::CONFIG_VERSION;
+pub use watch::ConfigWatcher;
 // These types are part of the public API and may be used by external consumers
 #[allow(unused_imports)]
-pub use models::{Config, ConnectionConfig, ProjectConfig, ProjectFile, Settings};
+pub use models::{
+    Config, ConnectionConfig, ProjectConfig, ProjectFile, SavedQuery, Settings,
+    DEFAULT_CLIPBOARD_CONFIRM_ROWS, DEFAULT_REQUEST_TIMEOUT_SECS, DEFAULT_WRAP_ROW_HEIGHT,
+};
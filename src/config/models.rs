@@ -1,9 +1,26 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::migrate::CONFIG_VERSION;
+use crate::export::{Delimiter, QuoteStyle};
+use crate::model::IconStyle;
+
+/// Default for [`Settings::request_timeout_secs`]
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 300;
+
+/// Default for [`Settings::clipboard_confirm_rows`]
+pub const DEFAULT_CLIPBOARD_CONFIRM_ROWS: usize = 1000;
+
+/// Default for [`Settings::wrap_row_height`]
+pub const DEFAULT_WRAP_ROW_HEIGHT: u16 = 3;
+
 /// Main configuration file (~/.config/lazydb/config.yaml)
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// File format version (missing in files older than version 1)
+    #[serde(default)]
+    pub version: u32,
+
     /// Global settings
     #[serde(default)]
     pub settings: Settings,
@@ -13,6 +30,16 @@ pub struct Config {
     pub projects: Vec<String>,
 }
 
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            settings: Settings::default(),
+            projects: Vec::new(),
+        }
+    }
+}
+
 /// Global settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
@@ -27,6 +54,94 @@ pub struct Settings {
     /// Whether to show row count
     #[serde(default = "default_true")]
     pub show_row_count: bool,
+
+    /// Glyphs for table type icons in the sidebar (`nerd-font`, `unicode` or `ascii`)
+    #[serde(default)]
+    pub table_icons: IconStyle,
+
+    /// Draw only ASCII characters (for terminals that garble emoji and arrows)
+    #[serde(default)]
+    pub ascii_mode: bool,
+
+    /// Number of query results kept in the result cache (0 disables caching)
+    #[serde(default = "default_result_cache_size")]
+    pub result_cache_size: usize,
+
+    /// Seconds a cached query result stays valid
+    #[serde(default = "default_result_cache_ttl_secs")]
+    pub result_cache_ttl_secs: u64,
+
+    /// Row limit appended to the query generated when opening a table (`null` fetches all rows)
+    #[serde(default = "default_browse_limit")]
+    pub default_browse_limit: Option<usize>,
+
+    /// Ask before copying a result with more rows than this to the clipboard
+    #[serde(default = "default_clipboard_confirm_rows")]
+    pub clipboard_confirm_rows: usize,
+
+    /// Seconds to wait for a database response before giving up (0 waits forever)
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+
+    /// Server-side `statement_timeout` of every session in seconds (0 leaves
+    /// the server's setting). A connection's `query_timeout_secs` overrides it.
+    #[serde(default)]
+    pub statement_timeout_secs: u64,
+
+    /// List catalog tables from system schemas (`pg_catalog`, `information_schema`, ...)
+    #[serde(default)]
+    pub show_system_objects: bool,
+
+    /// Count table rows with `COUNT(*)` instead of reading the planner's
+    /// estimate. Exact but scans every table, so only suited to small databases.
+    #[serde(default)]
+    pub exact_table_row_counts: bool,
+
+    /// Times a refused or timed-out connection attempt is retried (0 disables retrying)
+    #[serde(default)]
+    pub connect_retries: u32,
+
+    /// Milliseconds to wait before the first retry; doubled for each further retry
+    #[serde(default = "default_connect_retry_backoff_ms")]
+    pub connect_retry_backoff_ms: u64,
+
+    /// Wrap around when paging past the last or first page of a result
+    #[serde(default)]
+    pub pagination_wrap: bool,
+
+    /// Most lines a Data grid row takes while long values are wrapped (`w`)
+    #[serde(default = "default_wrap_row_height")]
+    pub wrap_row_height: u16,
+
+    /// Field delimiter for CSV export (`comma`, `tab`, `semicolon` or `pipe`)
+    #[serde(default)]
+    pub csv_delimiter: Delimiter,
+
+    /// Quoting for CSV export (`necessary` or `always`)
+    #[serde(default)]
+    pub csv_quote_style: QuoteStyle,
+
+    /// Start UTF-8 CSV exports with a byte order mark (helps Excel detect the encoding)
+    #[serde(default)]
+    pub csv_with_bom: bool,
+
+    /// Reopen the project, expanded connections and query of the previous run
+    #[serde(default)]
+    pub restore_session: bool,
+
+    /// Show query history times relative to now ("2m ago") instead of as dates
+    #[serde(default)]
+    pub relative_history_times: bool,
+
+    /// Write query history at most once per this many seconds (0 saves every
+    /// change). History still waiting to be written is saved on quit.
+    #[serde(default)]
+    pub history_save_interval_secs: u64,
+
+    /// Watch `config.yaml` and the project files, and offer to reload them
+    /// when they change on disk
+    #[serde(default)]
+    pub watch_config: bool,
 }
 
 impl Default for Settings {
@@ -35,6 +150,27 @@ impl Default for Settings {
             default_project: None,
             theme: default_theme(),
             show_row_count: true,
+            table_icons: IconStyle::NerdFont,
+            ascii_mode: false,
+            result_cache_size: default_result_cache_size(),
+            result_cache_ttl_secs: default_result_cache_ttl_secs(),
+            default_browse_limit: default_browse_limit(),
+            request_timeout_secs: default_request_timeout_secs(),
+            statement_timeout_secs: 0,
+            clipboard_confirm_rows: default_clipboard_confirm_rows(),
+            show_system_objects: false,
+            exact_table_row_counts: false,
+            connect_retries: 0,
+            connect_retry_backoff_ms: default_connect_retry_backoff_ms(),
+            pagination_wrap: false,
+            wrap_row_height: DEFAULT_WRAP_ROW_HEIGHT,
+            csv_delimiter: Delimiter::Comma,
+            csv_quote_style: QuoteStyle::Necessary,
+            csv_with_bom: false,
+            restore_session: false,
+            relative_history_times: false,
+            history_save_interval_secs: 0,
+            watch_config: false,
         }
     }
 }
@@ -47,9 +183,41 @@ fn default_true() -> bool {
     true
 }
 
+fn default_result_cache_size() -> usize {
+    20
+}
+
+fn default_result_cache_ttl_secs() -> u64 {
+    300
+}
+
+fn default_clipboard_confirm_rows() -> usize {
+    DEFAULT_CLIPBOARD_CONFIRM_ROWS
+}
+
+fn default_wrap_row_height() -> u16 {
+    DEFAULT_WRAP_ROW_HEIGHT
+}
+
+fn default_request_timeout_secs() -> u64 {
+    DEFAULT_REQUEST_TIMEOUT_SECS
+}
+
+fn default_connect_retry_backoff_ms() -> u64 {
+    500
+}
+
+fn default_browse_limit() -> Option<usize> {
+    Some(1000)
+}
+
 /// Project file (projects/*.yaml)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectFile {
+    /// File format version (missing in files older than version 1)
+    #[serde(default)]
+    pub version: u32,
+
     /// Project metadata
     pub project: ProjectConfig,
 
@@ -100,6 +268,35 @@ pub struct ConnectionConfig {
     /// Password (specified by environment variable name)
     #[serde(default)]
     pub password_env: Option<String>,
+
+    /// Schema to browse by default (falls back to the server's current schema, then `public`)
+    #[serde(default)]
+    pub default_schema: Option<String>,
+
+    /// Color label shown in the sidebar and status bar (`red`, `green`, `#ff8800`, ...)
+    #[serde(default)]
+    pub color: Option<String>,
+
+    /// Production database: flagged in the status bar, and statements that
+    /// modify data ask for confirmation before running
+    #[serde(default)]
+    pub is_production: bool,
+
+    /// `statement_timeout` of this connection's sessions in seconds,
+    /// overriding `settings.statement_timeout_secs` (0 disables the timeout)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_timeout_secs: Option<u64>,
+
+    /// Named queries offered in the snippet menu of this connection
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub snippets: Vec<SavedQuery>,
+}
+
+/// Query saved under a name, such as a runbook step shared with the team
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedQuery {
+    pub name: String,
+    pub query: String,
 }
 
 fn default_port() -> u16 {
@@ -146,6 +343,52 @@ projects:
             Some("my-project".to_string())
         );
         assert_eq!(config.projects.len(), 2);
+        assert_eq!(config.settings.result_cache_size, 20);
+        assert_eq!(config.settings.result_cache_ttl_secs, 300);
+        assert!(!config.settings.show_system_objects);
+        assert!(!config.settings.exact_table_row_counts);
+        assert_eq!(config.settings.connect_retries, 0);
+        assert_eq!(config.settings.connect_retry_backoff_ms, 500);
+        assert!(!config.settings.pagination_wrap);
+        assert!(!config.settings.relative_history_times);
+        assert_eq!(config.settings.history_save_interval_secs, 0);
+        assert!(!config.settings.watch_config);
+        assert_eq!(config.settings.statement_timeout_secs, 0);
+        assert_eq!(config.settings.csv_delimiter, Delimiter::Comma);
+        assert_eq!(config.settings.csv_quote_style, QuoteStyle::Necessary);
+        assert_eq!(config.settings.table_icons, IconStyle::NerdFont);
+        assert!(!config.settings.ascii_mode);
+        assert_eq!(config.settings.default_browse_limit, Some(1000));
+        assert_eq!(config.settings.request_timeout_secs, 300);
+        assert_eq!(config.settings.clipboard_confirm_rows, 1000);
+        assert!(!config.settings.restore_session);
+    }
+
+    #[test]
+    fn test_settings_table_icons() {
+        for (value, expected) in [
+            ("nerd-font", IconStyle::NerdFont),
+            ("unicode", IconStyle::Unicode),
+            ("ascii", IconStyle::Ascii),
+        ] {
+            let yaml = format!("settings:\n  table_icons: {}\n", value);
+            let config: Config = serde_norway::from_str(&yaml).unwrap();
+            assert_eq!(config.settings.table_icons, expected);
+        }
+    }
+
+    #[test]
+    fn test_settings_csv_options() {
+        let yaml = r#"
+settings:
+  csv_delimiter: tab
+  csv_quote_style: always
+  csv_with_bom: true
+"#;
+        let config: Config = serde_norway::from_str(yaml).unwrap();
+        assert_eq!(config.settings.csv_delimiter, Delimiter::Tab);
+        assert_eq!(config.settings.csv_quote_style, QuoteStyle::Always);
+        assert!(config.settings.csv_with_bom);
     }
 
     #[test]
@@ -162,6 +405,7 @@ connections:
     database: mydb
     username: dbuser
     password_env: PROD_PASSWORD
+    color: red
 
   - name: Development
     host: localhost
@@ -174,6 +418,37 @@ connections:
         assert_eq!(project_file.connections.len(), 2);
         assert_eq!(project_file.connections[0].port, 5432);
         assert_eq!(project_file.connections[1].port, 5432); // default
+        assert_eq!(project_file.connections[0].color.as_deref(), Some("red"));
+        assert!(project_file.connections[1].color.is_none());
+        assert!(project_file.connections[1].snippets.is_empty());
+    }
+
+    #[test]
+    fn test_connection_snippets_round_trip() {
+        let yaml = r#"
+name: Production
+host: prod.example.com
+database: mydb
+snippets:
+  - name: top queries
+    query: SELECT * FROM pg_stat_statements
+"#;
+        let conn: ConnectionConfig = serde_norway::from_str(yaml).unwrap();
+        assert_eq!(
+            conn.snippets,
+            vec![SavedQuery {
+                name: "top queries".to_string(),
+                query: "SELECT * FROM pg_stat_statements".to_string(),
+            }]
+        );
+
+        // Connections without snippets do not write an empty list
+        let written = serde_norway::to_string(&ConnectionConfig {
+            snippets: Vec::new(),
+            ..conn
+        })
+        .unwrap();
+        assert!(!written.contains("snippets"));
     }
 
     #[test]
@@ -186,6 +461,11 @@ connections:
             username: Some("user".to_string()),
             password: Some("direct_password".to_string()),
             password_env: None,
+            default_schema: None,
+            color: None,
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
         };
         assert_eq!(conn.get_password(), Some("direct_password".to_string()));
     }
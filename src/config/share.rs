@@ -0,0 +1,135 @@
+//! Shareable project files
+//!
+//! A project can be exported to a single YAML file for teammates and imported
+//! back into the config directory. Direct passwords are never written to
+//! exported files; `password_env` references are kept since they hold no secret.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use super::loader::write_atomic;
+use super::migrate::parse_versioned;
+use super::models::ProjectFile;
+
+/// Copy of `project_file` with direct passwords removed
+pub fn without_passwords(project_file: &ProjectFile) -> ProjectFile {
+    let mut shared = project_file.clone();
+    for conn in &mut shared.connections {
+        conn.password = None;
+    }
+    shared
+}
+
+/// Write a project to `path` for sharing (passwords omitted).
+///
+/// An existing file at `path` is never replaced.
+pub fn export_project_file(path: &Path, project_file: &ProjectFile) -> Result<()> {
+    if path.exists() {
+        bail!("File already exists: {}", path.display());
+    }
+    let content = serde_norway::to_string(&without_passwords(project_file))
+        .context("Failed to serialize project file")?;
+
+    write_atomic(path, &content)
+        .with_context(|| format!("Failed to write project file: {}", path.display()))?;
+
+    Ok(())
+}
+
+/// Read a shared project file from `path`
+pub fn import_project_file(path: &Path) -> Result<ProjectFile> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read project file: {}", path.display()))?;
+
+    // Shared files are not rewritten; they are saved as new project files
+    let (project_file, _) = parse_versioned::<ProjectFile>(&content)
+        .with_context(|| format!("Failed to parse project file: {}", path.display()))?;
+
+    Ok(project_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ConnectionConfig, ProjectConfig, CONFIG_VERSION};
+    use tempfile::TempDir;
+
+    fn create_project_file() -> ProjectFile {
+        ProjectFile {
+            version: CONFIG_VERSION,
+            project: ProjectConfig {
+                name: "Shop".to_string(),
+                description: None,
+                created_at: None,
+            },
+            connections: vec![
+                ConnectionConfig {
+                    name: "direct".to_string(),
+                    host: "localhost".to_string(),
+                    port: 5432,
+                    database: "shop".to_string(),
+                    username: Some("app".to_string()),
+                    password: Some("secret".to_string()),
+                    password_env: None,
+                    default_schema: None,
+                    color: None,
+                    is_production: false,
+                    query_timeout_secs: None,
+                    snippets: Vec::new(),
+                },
+                ConnectionConfig {
+                    name: "env".to_string(),
+                    host: "localhost".to_string(),
+                    port: 5432,
+                    database: "shop".to_string(),
+                    username: Some("app".to_string()),
+                    password: None,
+                    password_env: Some("SHOP_PASSWORD".to_string()),
+                    default_schema: None,
+                    color: None,
+                    is_production: false,
+                    query_timeout_secs: None,
+                    snippets: Vec::new(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_export_and_import_round_trip_omits_passwords() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("shop.yaml");
+
+        export_project_file(&path, &create_project_file()).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("secret"));
+
+        let imported = import_project_file(&path).unwrap();
+        assert_eq!(imported.project.name, "Shop");
+        assert_eq!(imported.connections.len(), 2);
+        assert_eq!(imported.connections[0].password, None);
+        assert_eq!(
+            imported.connections[1].password_env,
+            Some("SHOP_PASSWORD".to_string())
+        );
+    }
+
+    #[test]
+    fn test_export_does_not_replace_existing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("shop.yaml");
+        fs::write(&path, "keep me\n").unwrap();
+
+        let err = export_project_file(&path, &create_project_file()).unwrap_err();
+        assert!(err.to_string().starts_with("File already exists"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "keep me\n");
+    }
+
+    #[test]
+    fn test_import_missing_file_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(import_project_file(&temp_dir.path().join("missing.yaml")).is_err());
+    }
+}
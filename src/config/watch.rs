@@ -0,0 +1,196 @@
+//! 設定ファイルの変更監視（`settings.watch_config`）
+//!
+//! `config.yaml` とプロジェクトファイルの変更をバックグラウンドで監視する。
+//! エディタの保存は 1 回でも複数のイベントになるため、イベントが
+//! [`DEBOUNCE`] の間途切れてから 1 回だけ変更として通知する。
+//!
+//! lazydb 自身が設定ファイルを書き換えた後は監視を開始し直すため、
+//! その書き込みは変更として通知されない。
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use super::loader::ConfigLoader;
+use super::models::Config;
+
+/// 変更とみなすまでにイベントが途切れている必要のある時間
+pub const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// 設定ファイルの変更を監視するハンドル（drop で監視を終了）
+pub struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+    rx: Receiver<notify::Result<Event>>,
+    /// 監視対象のファイル（`config.yaml` と各プロジェクトファイル）
+    files: HashSet<PathBuf>,
+    debounce: Debounce,
+}
+
+impl ConfigWatcher {
+    /// `config.yaml` と `config` に登録されたプロジェクトファイルの監視を開始
+    pub fn start(loader: &ConfigLoader, config: &Config) -> Result<Self> {
+        let mut files = HashSet::new();
+        files.insert(loader.config_file_path());
+        for path in &config.projects {
+            files.insert(loader.resolve_project_path(path)?);
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let mut watcher =
+            notify::recommended_watcher(tx).context("Failed to start the config file watcher")?;
+        // ファイルは一時ファイルからの rename で置き換えられるので、親ディレクトリを監視する
+        let dirs: HashSet<&Path> = files.iter().filter_map(|file| file.parent()).collect();
+        for dir in dirs {
+            if dir.exists() {
+                watcher
+                    .watch(dir, RecursiveMode::NonRecursive)
+                    .with_context(|| format!("Failed to watch {}", dir.display()))?;
+            }
+        }
+
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+            files,
+            debounce: Debounce::default(),
+        })
+    }
+
+    /// 受信済みのイベントを処理し、変更が落ち着いたら `true` を返す
+    pub fn changed(&mut self, now: Instant) -> bool {
+        loop {
+            match self.rx.try_recv() {
+                Ok(Ok(event)) if is_relevant(&self.files, &event) => self.debounce.event(now),
+                Ok(_) => {}
+                Err(TryRecvError::Empty | TryRecvError::Disconnected) => break,
+            }
+        }
+        self.debounce.settled(now)
+    }
+}
+
+/// 監視対象のファイルを変更したイベントか（読み取りのみのイベントは除く）
+fn is_relevant(files: &HashSet<PathBuf>, event: &Event) -> bool {
+    !matches!(event.kind, EventKind::Access(_))
+        && event.paths.iter().any(|path| files.contains(path))
+}
+
+/// 連続するイベントを最後のイベントから [`DEBOUNCE`] 後の 1 回にまとめる
+#[derive(Debug, Default)]
+struct Debounce {
+    /// 最後に受け取ったイベントの時刻（未通知のものがある場合）
+    last_event: Option<Instant>,
+}
+
+impl Debounce {
+    fn event(&mut self, now: Instant) {
+        self.last_event = Some(now);
+    }
+
+    fn settled(&mut self, now: Instant) -> bool {
+        match self.last_event {
+            Some(last) if now.saturating_duration_since(last) >= DEBOUNCE => {
+                self.last_event = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rapid_events_are_reported_once() {
+        let start = Instant::now();
+        let mut debounce = Debounce::default();
+        assert!(!debounce.settled(start));
+
+        // An editor writing the file several times in a row
+        debounce.event(start);
+        debounce.event(start + Duration::from_millis(100));
+        debounce.event(start + Duration::from_millis(200));
+        assert!(!debounce.settled(start + Duration::from_millis(600)));
+
+        assert!(debounce.settled(start + Duration::from_millis(700)));
+        assert!(!debounce.settled(start + Duration::from_millis(800)));
+    }
+
+    #[test]
+    fn test_only_changes_to_watched_files_are_relevant() {
+        use notify::event::{AccessKind, CreateKind, ModifyKind};
+
+        let project = PathBuf::from("/config/projects/app.yaml");
+        let files = HashSet::from([PathBuf::from("/config/config.yaml"), project.clone()]);
+        let event = |kind: EventKind, path: &str| Event::new(kind).add_path(PathBuf::from(path));
+
+        assert!(is_relevant(
+            &files,
+            &event(
+                EventKind::Modify(ModifyKind::Any),
+                "/config/projects/app.yaml"
+            )
+        ));
+        // Saved through a temporary file renamed over the project file
+        let rename = Event::new(EventKind::Modify(ModifyKind::Any))
+            .add_path(PathBuf::from("/config/projects/.app.yaml.tmp"))
+            .add_path(project);
+        assert!(is_relevant(&files, &rename));
+        assert!(is_relevant(
+            &files,
+            &event(EventKind::Create(CreateKind::File), "/config/config.yaml")
+        ));
+
+        // Files lazydb does not load, and reads of the watched files
+        assert!(!is_relevant(
+            &files,
+            &event(EventKind::Modify(ModifyKind::Any), "/config/history.yaml")
+        ));
+        assert!(!is_relevant(
+            &files,
+            &event(EventKind::Access(AccessKind::Any), "/config/config.yaml")
+        ));
+    }
+
+    #[test]
+    #[ignore] // Waits on real file system events: cargo test -- --ignored
+    fn test_edited_project_file_is_reported() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let loader = ConfigLoader::with_config_dir(temp_dir.path().to_path_buf());
+        std::fs::create_dir_all(temp_dir.path().join("projects")).unwrap();
+        let project_path = temp_dir.path().join("projects/app.yaml");
+        std::fs::write(&project_path, "project:\n  name: App\n").unwrap();
+        let config = Config {
+            projects: vec!["projects/app.yaml".to_string()],
+            ..Config::default()
+        };
+        let mut watcher = ConfigWatcher::start(&loader, &config).unwrap();
+
+        // Count the changes reported while polling for `secs`
+        let mut poll = |secs: u64| {
+            let deadline = Instant::now() + Duration::from_secs(secs);
+            let mut changes = 0;
+            while Instant::now() < deadline {
+                if watcher.changed(Instant::now()) {
+                    changes += 1;
+                }
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            changes
+        };
+
+        // Files lazydb does not load are not reported
+        std::fs::write(temp_dir.path().join("history.yaml"), "[]\n").unwrap();
+        assert_eq!(poll(1), 0);
+
+        std::fs::write(&project_path, "project:\n  name: Renamed\n").unwrap();
+        let changes = poll(2);
+        assert_eq!(changes, 1);
+    }
+}
@@ -4,8 +4,13 @@
 //! non-blocking database operations.
 
 use std::fmt;
+use std::path::PathBuf;
 
-use crate::model::{Connection, QueryResult, Table};
+use crate::export::{ExportConfig, ExportOutput, ExportTarget};
+use crate::model::{
+    resolve_statement_timeout, ActiveSession, Connection, DatabaseRole, DatabaseStats, QueryResult,
+    ServerSetting, Table,
+};
 
 /// Parameters needed to establish a database connection.
 /// This is a thread-safe, owned version of connection details.
@@ -16,6 +21,10 @@ pub struct ConnectionParams {
     pub database: String,
     pub username: String,
     pub password: String,
+    /// `search_path` applied to every session opened with these params
+    pub search_path: Option<String>,
+    /// `statement_timeout` in seconds applied to every session opened with these params
+    pub statement_timeout_secs: Option<u64>,
 }
 
 // Custom Debug implementation to redact password for security
@@ -27,12 +36,17 @@ impl fmt::Debug for ConnectionParams {
             .field("database", &self.database)
             .field("username", &self.username)
             .field("password", &"[REDACTED]")
+            .field("search_path", &self.search_path)
+            .field("statement_timeout_secs", &self.statement_timeout_secs)
             .finish()
     }
 }
 
 impl ConnectionParams {
-    /// Create connection params from a Connection reference
+    /// Create connection params from a Connection reference.
+    ///
+    /// Only the connection's own `query_timeout_secs` is applied; see
+    /// `App::connection_params` for the one falling back to the settings.
     pub fn from_connection(conn: &Connection) -> Self {
         Self {
             host: conn.host.clone(),
@@ -40,8 +54,18 @@ impl ConnectionParams {
             database: conn.database.clone(),
             username: conn.username.clone(),
             password: conn.password.clone(),
+            search_path: conn.search_path.clone(),
+            statement_timeout_secs: resolve_statement_timeout(conn.query_timeout_secs, 0),
         }
     }
+
+    /// Whether both point at the same database as the same user
+    pub fn same_target(&self, other: &ConnectionParams) -> bool {
+        self.host == other.host
+            && self.port == other.port
+            && self.database == other.database
+            && self.username == other.username
+    }
 }
 
 /// Commands sent from the UI thread to the DB worker thread
@@ -52,6 +76,10 @@ pub enum DbCommand {
         request_id: u64,
         connection: ConnectionParams,
         schema: Option<String>,
+        /// Also list catalog tables from the database's system schemas
+        include_system: bool,
+        /// Replace estimated row counts with `COUNT(*)` results
+        exact_row_counts: bool,
         /// Project and connection index to update when complete
         target: (usize, usize),
     },
@@ -75,6 +103,100 @@ pub enum DbCommand {
         project_idx: usize,
     },
 
+    /// Check a query on the server without executing it
+    ValidateQuery {
+        request_id: u64,
+        connection: ConnectionParams,
+        query: String,
+    },
+
+    /// Fetch the first rows of a table for the sidebar preview
+    PreviewTable {
+        request_id: u64,
+        connection: ConnectionParams,
+        table_name: String,
+        schema: Option<String>,
+        /// Number of rows to fetch
+        limit: usize,
+    },
+
+    /// Fetch the database size and its largest tables
+    FetchDatabaseStats {
+        request_id: u64,
+        connection: ConnectionParams,
+        /// Number of largest tables to list
+        limit: usize,
+    },
+
+    /// Fetch the client sessions connected to the database
+    FetchActiveSessions {
+        request_id: u64,
+        connection: ConnectionParams,
+    },
+
+    /// Fetch the users and roles defined on the server
+    FetchRoles {
+        request_id: u64,
+        connection: ConnectionParams,
+    },
+
+    /// Fetch the run-time parameters of the session (`SHOW ALL`)
+    FetchSettings {
+        request_id: u64,
+        connection: ConnectionParams,
+    },
+
+    /// Terminate the session served by backend process `pid`
+    TerminateBackend {
+        request_id: u64,
+        connection: ConnectionParams,
+        pid: i32,
+    },
+
+    /// Open a server-side cursor for browsing and fetch the first batch.
+    ///
+    /// Any previously opened cursor is closed first.
+    OpenCursor {
+        request_id: u64,
+        connection: ConnectionParams,
+        query: String,
+        /// Number of rows to fetch per batch
+        fetch_size: usize,
+    },
+
+    /// Fetch a batch of rows from the open cursor
+    FetchCursor {
+        request_id: u64,
+        /// 0-based row the batch starts at
+        start: usize,
+        fetch_size: usize,
+    },
+
+    /// Close the open cursor, if any
+    CloseCursor,
+
+    /// Export the result of a query to a file or the clipboard.
+    ///
+    /// CSV file exports are streamed from the server with `COPY ... TO STDOUT`
+    /// when the provider supports it; otherwise the query is executed and
+    /// exported client-side.
+    ExportQuery {
+        request_id: u64,
+        connection: ConnectionParams,
+        query: String,
+        target: ExportTarget,
+        /// Format and CSV options of the export
+        config: ExportConfig,
+    },
+
+    /// Drop any connection held for `connection` and verify a fresh one
+    Reconnect {
+        request_id: u64,
+        connection: ConnectionParams,
+        /// Project and connection index of the reconnected connection
+        target: (usize, usize),
+    },
+
     /// Shutdown the worker thread
     Shutdown,
 }
@@ -102,9 +224,95 @@ pub enum DbResponse {
     QueryExecuted {
         request_id: u64,
         result: Result<QueryResult, String>,
+        /// NOTICE/WARNING messages the server raised while running the query
+        notices: Vec<String>,
         /// Project index for result storage
         project_idx: usize,
     },
+
+    /// A query was checked without running it
+    QueryValidated {
+        request_id: u64,
+        result: Result<(), String>,
+    },
+
+    /// First rows of a table were fetched for the sidebar preview
+    TablePreviewLoaded {
+        request_id: u64,
+        result: Result<QueryResult, String>,
+    },
+
+    /// Database size summary was loaded (`None` if the database does not support it)
+    DatabaseStatsLoaded {
+        request_id: u64,
+        result: Result<Option<DatabaseStats>, String>,
+    },
+
+    /// Session list was loaded (`None` if the database does not support it)
+    ActiveSessionsLoaded {
+        request_id: u64,
+        result: Result<Option<Vec<ActiveSession>>, String>,
+    },
+
+    /// Role list was loaded (`None` if the database does not support it)
+    RolesLoaded {
+        request_id: u64,
+        result: Result<Option<Vec<DatabaseRole>>, String>,
+    },
+
+    /// Server settings were loaded (`None` if the database does not support it)
+    SettingsLoaded {
+        request_id: u64,
+        result: Result<Option<Vec<ServerSetting>>, String>,
+    },
+
+    /// A session termination was attempted
+    BackendTerminated {
+        request_id: u64,
+        pid: i32,
+        /// Whether the server signalled the backend
+        result: Result<bool, String>,
+    },
+
+    /// A batch of rows was fetched from the browsing cursor
+    RowsFetched {
+        request_id: u64,
+        result: Result<QueryResult, String>,
+        /// Whether this is the first batch of a newly opened cursor
+        first_batch: bool,
+        /// Whether the cursor may hold more rows
+        has_more: bool,
+    },
+
+    /// Progress report for a long-running operation
+    Progress {
+        request_id: u64,
+        /// Bytes written so far
+        bytes: u64,
+    },
+
+    /// Row count progress of a client-side export
+    RowProgress {
+        request_id: u64,
+        /// Rows written so far
+        rows: u64,
+    },
+
+    /// Export finished
+    ExportCompleted {
+        request_id: u64,
+        /// Bytes written to the file, or the text for the clipboard
+        result: Result<ExportOutput, String>,
+        target: ExportTarget,
+    },
+
+    /// A fresh connection was tested after dropping the old ones
+    Reconnected {
+        request_id: u64,
+        result: Result<(), String>,
+        /// Project and connection index of the reconnected connection
+        target: (usize, usize),
+    },
 }
 
 impl DbCommand {
@@ -114,7 +322,18 @@ impl DbCommand {
             DbCommand::FetchTables { request_id, .. } => Some(*request_id),
             DbCommand::FetchTableDetails { request_id, .. } => Some(*request_id),
             DbCommand::ExecuteQuery { request_id, .. } => Some(*request_id),
-            DbCommand::Shutdown => None,
+            DbCommand::ValidateQuery { request_id, .. } => Some(*request_id),
+            DbCommand::PreviewTable { request_id, .. } => Some(*request_id),
+            DbCommand::FetchDatabaseStats { request_id, .. } => Some(*request_id),
+            DbCommand::FetchActiveSessions { request_id, .. } => Some(*request_id),
+            DbCommand::FetchRoles { request_id, .. } => Some(*request_id),
+            DbCommand::FetchSettings { request_id, .. } => Some(*request_id),
+            DbCommand::TerminateBackend { request_id, .. } => Some(*request_id),
+            DbCommand::OpenCursor { request_id, .. } => Some(*request_id),
+            DbCommand::FetchCursor { request_id, .. } => Some(*request_id),
+            DbCommand::ExportQuery { request_id, .. } => Some(*request_id),
+            DbCommand::Reconnect { request_id, .. } => Some(*request_id),
+            DbCommand::CloseCursor | DbCommand::Shutdown => None,
         }
     }
 }
@@ -126,6 +345,18 @@ impl DbResponse {
             DbResponse::TablesLoaded { request_id, .. } => *request_id,
             DbResponse::TableDetailsLoaded { request_id, .. } => *request_id,
             DbResponse::QueryExecuted { request_id, .. } => *request_id,
+            DbResponse::QueryValidated { request_id, .. } => *request_id,
+            DbResponse::TablePreviewLoaded { request_id, .. } => *request_id,
+            DbResponse::DatabaseStatsLoaded { request_id, .. } => *request_id,
+            DbResponse::ActiveSessionsLoaded { request_id, .. } => *request_id,
+            DbResponse::RolesLoaded { request_id, .. } => *request_id,
+            DbResponse::SettingsLoaded { request_id, .. } => *request_id,
+            DbResponse::BackendTerminated { request_id, .. } => *request_id,
+            DbResponse::RowsFetched { request_id, .. } => *request_id,
+            DbResponse::Progress { request_id, .. } => *request_id,
+            DbResponse::RowProgress { request_id, .. } => *request_id,
+            DbResponse::ExportCompleted { request_id, .. } => *request_id,
+            DbResponse::Reconnected { request_id, .. } => *request_id,
         }
     }
 
@@ -135,6 +366,17 @@ impl DbResponse {
             DbResponse::TablesLoaded { result, .. } => result.is_ok(),
             DbResponse::TableDetailsLoaded { result, .. } => result.is_ok(),
             DbResponse::QueryExecuted { result, .. } => result.is_ok(),
+            DbResponse::QueryValidated { result, .. } => result.is_ok(),
+            DbResponse::TablePreviewLoaded { result, .. } => result.is_ok(),
+            DbResponse::DatabaseStatsLoaded { result, .. } => result.is_ok(),
+            DbResponse::ActiveSessionsLoaded { result, .. } => result.is_ok(),
+            DbResponse::RolesLoaded { result, .. } => result.is_ok(),
+            DbResponse::SettingsLoaded { result, .. } => result.is_ok(),
+            DbResponse::BackendTerminated { result, .. } => result.is_ok(),
+            DbResponse::RowsFetched { result, .. } => result.is_ok(),
+            DbResponse::Progress { .. } | DbResponse::RowProgress { .. } => true,
+            DbResponse::ExportCompleted { result, .. } => result.is_ok(),
+            DbResponse::Reconnected { result, .. } => result.is_ok(),
         }
     }
 }
@@ -151,8 +393,15 @@ mod tests {
             database: "testdb".to_string(),
             username: "testuser".to_string(),
             password: "testpass".to_string(),
+            password_env: None,
             expanded: false,
             tables: Vec::new(),
+            default_schema: None,
+            color: None,
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
+            search_path: None,
         }
     }
 
@@ -176,6 +425,8 @@ mod tests {
             request_id: 42,
             connection: params.clone(),
             schema: None,
+            include_system: false,
+            exact_row_counts: false,
             target: (0, 0),
         };
         assert_eq!(cmd.request_id(), Some(42));
@@ -197,6 +448,39 @@ mod tests {
         };
         assert_eq!(cmd.request_id(), Some(999));
 
+        let cmd = DbCommand::ExportQuery {
+            request_id: 7,
+            connection: ConnectionParams::from_connection(&create_test_connection()),
+            query: "SELECT 1".to_string(),
+            target: ExportTarget::File(PathBuf::from("out.csv")),
+            config: ExportConfig::default(),
+        };
+        assert_eq!(cmd.request_id(), Some(7));
+
+        let cmd = DbCommand::FetchDatabaseStats {
+            request_id: 9,
+            connection: ConnectionParams::from_connection(&create_test_connection()),
+            limit: 10,
+        };
+        assert_eq!(cmd.request_id(), Some(9));
+
+        let cmd = DbCommand::TerminateBackend {
+            request_id: 11,
+            connection: ConnectionParams::from_connection(&create_test_connection()),
+            pid: 1234,
+        };
+        assert_eq!(cmd.request_id(), Some(11));
+
+        let cmd = DbCommand::FetchCursor {
+            request_id: 8,
+            start: 500,
+            fetch_size: 500,
+        };
+        assert_eq!(cmd.request_id(), Some(8));
+
+        let cmd = DbCommand::CloseCursor;
+        assert_eq!(cmd.request_id(), None);
+
         let cmd = DbCommand::Shutdown;
         assert_eq!(cmd.request_id(), None);
     }
@@ -220,6 +504,7 @@ mod tests {
         let resp = DbResponse::QueryExecuted {
             request_id: 999,
             result: Err("error".to_string()),
+            notices: vec![],
             project_idx: 0,
         };
         assert_eq!(resp.request_id(), 999);
@@ -259,10 +544,13 @@ mod tests {
             request_id: 1,
             result: Ok(QueryResult {
                 columns: vec![],
+                column_types: Vec::new(),
                 rows: vec![],
                 execution_time_ms: 0,
                 total_rows: 0,
+                rows_affected: None,
             }),
+            notices: vec![],
             project_idx: 0,
         };
         assert!(resp.is_success());
@@ -270,9 +558,31 @@ mod tests {
         let resp = DbResponse::QueryExecuted {
             request_id: 1,
             result: Err("error".to_string()),
+            notices: vec![],
             project_idx: 0,
         };
         assert!(!resp.is_success());
+
+        let resp = DbResponse::Progress {
+            request_id: 1,
+            bytes: 1024,
+        };
+        assert!(resp.is_success());
+        assert_eq!(resp.request_id(), 1);
+
+        let resp = DbResponse::RowProgress {
+            request_id: 2,
+            rows: 10_000,
+        };
+        assert!(resp.is_success());
+        assert_eq!(resp.request_id(), 2);
+
+        let resp = DbResponse::ExportCompleted {
+            request_id: 1,
+            result: Err("error".to_string()),
+            target: ExportTarget::Clipboard,
+        };
+        assert!(!resp.is_success());
     }
 
     #[test]
@@ -282,6 +592,8 @@ mod tests {
             request_id: 1,
             connection: params,
             schema: Some("public".to_string()),
+            include_system: false,
+            exact_row_counts: false,
             target: (0, 1),
         };
 
@@ -35,6 +35,6 @@ mod provider;
 mod worker;
 
 pub use async_bridge::{ConnectionParams, DbCommand, DbResponse};
-pub use postgres::PostgresProvider;
-pub use provider::{DatabaseProvider, DatabaseType, ProviderError};
-pub use worker::{spawn_db_worker, DbWorkerHandle};
+pub use postgres::{trim_statement, PostgresProvider};
+pub use provider::{DatabaseProvider, DatabaseType, ProviderCapabilities, ProviderError};
+pub use worker::{spawn_db_worker, ConnectRetry, DbWorkerHandle};
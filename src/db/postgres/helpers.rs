@@ -1,5 +1,7 @@
 //! Utility functions for PostgreSQL operations
 
+use crate::db::DatabaseType;
+use crate::export::{Delimiter, QuoteStyle};
 use crate::model::schema::{ForeignKeyAction, SortOrder};
 
 /// Parses a foreign key action string into the corresponding enum variant.
@@ -31,7 +33,44 @@ pub fn parse_fk_action(action: &str) -> ForeignKeyAction {
 /// assert_eq!(quote_identifier("table\"name"), "\"table\"\"name\"");
 /// ```
 pub fn quote_identifier(identifier: &str) -> String {
-    format!("\"{}\"", identifier.replace('"', "\"\""))
+    DatabaseType::PostgreSQL.quote_identifier(identifier)
+}
+
+/// Builds the `COPY ... WITH (...)` option list for a CSV export with a header row.
+///
+/// `FORCE_QUOTE *` quotes every non-NULL value; the header row stays unquoted.
+pub fn copy_csv_options(delimiter: Delimiter, quote_style: QuoteStyle) -> String {
+    let delimiter = match delimiter {
+        Delimiter::Tab => "E'\\t'".to_string(),
+        other => format!("'{}'", other.as_char()),
+    };
+    let mut options = format!("FORMAT csv, HEADER true, DELIMITER {}", delimiter);
+    if quote_style == QuoteStyle::Always {
+        options.push_str(", FORCE_QUOTE *");
+    }
+    options
+}
+
+/// Builds the `COPY ... TO STDOUT` statement that exports a query as CSV.
+///
+/// The query is closed on a new line so a trailing `-- comment` does not
+/// swallow the rest of the statement.
+pub fn copy_to_csv_statement(query: &str, delimiter: Delimiter, quote_style: QuoteStyle) -> String {
+    format!(
+        "COPY ({}\n) TO STDOUT WITH ({})",
+        trim_statement(query),
+        copy_csv_options(delimiter, quote_style)
+    )
+}
+
+/// Trims surrounding whitespace and a single trailing `;` from a statement
+/// sent as one query.
+///
+/// Semicolons inside the statement are left alone, so text holding several
+/// statements is still rejected by the server rather than silently cut.
+pub fn trim_statement(query: &str) -> &str {
+    let query = query.trim();
+    query.strip_suffix(';').unwrap_or(query).trim_end()
 }
 
 /// Validates that an identifier only contains safe characters for PostgreSQL identifiers.
@@ -233,6 +272,21 @@ mod tests {
         assert!(!is_valid_identifier("table)"));
     }
 
+    #[test]
+    fn test_trim_statement() {
+        // No semicolon: only whitespace is trimmed
+        assert_eq!(trim_statement("SELECT 1"), "SELECT 1");
+        assert_eq!(trim_statement("  SELECT 1 \n"), "SELECT 1");
+        // One trailing semicolon is stripped with the whitespace around it
+        assert_eq!(trim_statement("SELECT 1;"), "SELECT 1");
+        assert_eq!(trim_statement("SELECT 1 ;\n"), "SELECT 1");
+        assert_eq!(trim_statement("SELECT 1;;"), "SELECT 1;");
+        // Internal semicolons are kept
+        assert_eq!(trim_statement("SELECT ';'"), "SELECT ';'");
+        assert_eq!(trim_statement("SELECT 1; SELECT 2;"), "SELECT 1; SELECT 2");
+        assert_eq!(trim_statement(" ; "), "");
+    }
+
     #[test]
     fn test_quote_identifier() {
         assert_eq!(quote_identifier("users"), "\"users\"");
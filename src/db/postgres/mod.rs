@@ -12,8 +12,12 @@ mod trait_impl;
 mod tests;
 
 // Re-export from parent for internal use
-pub(super) use super::provider::{DatabaseProvider, DatabaseType, ProviderError};
+pub(super) use super::provider::{
+    DatabaseProvider, DatabaseType, ProviderCapabilities, ProviderError,
+};
 
 // Re-export the main types
+pub(crate) use helpers::quote_identifier;
+pub use helpers::trim_statement;
 pub use pool::{ConnectionPool, PoolConfig, PoolState};
 pub use provider::PostgresProvider;
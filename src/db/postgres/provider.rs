@@ -3,11 +3,14 @@
 use postgres::{Client, NoTls};
 use r2d2::PooledConnection;
 use r2d2_postgres::PostgresConnectionManager;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use crate::config::ConnectionConfig;
+use crate::model::schema::IndexUsage;
+use crate::model::DEFAULT_SCHEMA;
 
 use super::pool::{ConnectionPool, PoolState};
+use super::queries::InternalQueries;
 use super::ProviderError;
 
 /// Connection source for PostgresProvider
@@ -18,9 +21,15 @@ enum ConnectionSource {
     Pool(ConnectionPool),
 }
 
+/// NOTICE/WARNING messages received from the server, oldest first
+type NoticeBuffer = Arc<Mutex<Vec<String>>>;
+
 /// PostgreSQL database provider
 pub struct PostgresProvider {
     source: ConnectionSource,
+    /// Notices raised on the single connection since they were last taken.
+    /// Pooled connections do not collect notices.
+    notices: NoticeBuffer,
 }
 
 /// A wrapper that provides a uniform interface for both single and pooled connections
@@ -61,12 +70,10 @@ impl PostgresProvider {
             config.host, config.port, config.database, username, password
         );
 
-        let client = Client::connect(&connection_string, NoTls)
-            .map_err(|e| ProviderError::ConnectionFailed(e.to_string()))?;
-
-        Ok(Self {
-            source: ConnectionSource::Single(Box::new(Mutex::new(client))),
-        })
+        let mut config: postgres::Config = connection_string
+            .parse()
+            .map_err(|e: postgres::Error| ProviderError::ConnectionFailed(e.to_string()))?;
+        Self::connect_single(&mut config)
     }
 
     /// Create a new PostgresProvider from connection parameters.
@@ -88,12 +95,26 @@ impl PostgresProvider {
             .user(username)
             .password(password);
 
+        Self::connect_single(&mut config)
+    }
+
+    /// Open a single connection that collects the notices the server sends
+    fn connect_single(config: &mut postgres::Config) -> Result<Self, ProviderError> {
+        let notices = NoticeBuffer::default();
+        let sink = Arc::clone(&notices);
+        config.notice_callback(move |notice| {
+            if let Ok(mut notices) = sink.lock() {
+                notices.push(format_notice(notice.severity(), notice.message()));
+            }
+        });
+
         let client = config
             .connect(NoTls)
             .map_err(|e| ProviderError::ConnectionFailed(e.to_string()))?;
 
         Ok(Self {
             source: ConnectionSource::Single(Box::new(Mutex::new(client))),
+            notices,
         })
     }
 
@@ -104,6 +125,7 @@ impl PostgresProvider {
     pub fn with_pool(pool: ConnectionPool) -> Self {
         Self {
             source: ConnectionSource::Pool(pool),
+            notices: NoticeBuffer::default(),
         }
     }
 
@@ -142,6 +164,14 @@ impl PostgresProvider {
         }
     }
 
+    /// Remove and return the notices received so far
+    pub(super) fn drain_notices(&self) -> Vec<String> {
+        self.notices
+            .lock()
+            .map(|mut notices| std::mem::take(&mut *notices))
+            .unwrap_or_default()
+    }
+
     /// Get the pool state if using a connection pool.
     ///
     /// Returns `None` if using a single connection.
@@ -156,4 +186,59 @@ impl PostgresProvider {
     pub fn is_pooled(&self) -> bool {
         matches!(&self.source, ConnectionSource::Pool(_))
     }
+
+    /// Get the scan counters of each index on a table, keyed by index name.
+    pub fn get_index_usage(
+        &self,
+        table_name: &str,
+        schema: Option<&str>,
+    ) -> Result<Vec<(String, IndexUsage)>, ProviderError> {
+        let mut client = self.get_connection()?;
+        InternalQueries::get_index_usage(&mut client, table_name, schema.unwrap_or(DEFAULT_SCHEMA))
+    }
+
+    /// Run `SET search_path TO <path>` on the session.
+    ///
+    /// `path` is inserted as is, so it must be validated by the caller
+    /// (see [`crate::model::normalize_search_path`]).
+    pub fn set_search_path(&self, path: &str) -> Result<(), ProviderError> {
+        let mut client = self.get_connection()?;
+        client
+            .batch_execute(&format!("SET search_path TO {}", path))
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))
+    }
+
+    /// Run `SET statement_timeout` on the session, so the server cancels
+    /// statements running longer than `secs` seconds
+    pub fn set_statement_timeout(&self, secs: u64) -> Result<(), ProviderError> {
+        let mut client = self.get_connection()?;
+        client
+            .batch_execute(&format!("SET statement_timeout = '{}s'", secs))
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))
+    }
+
+    /// Get the first existing schema on the session's `search_path`.
+    ///
+    /// Returns `None` if no schema on the search path exists.
+    pub fn current_schema(&self) -> Result<Option<String>, ProviderError> {
+        let mut client = self.get_connection()?;
+
+        let rows = client
+            .query("SELECT current_schema()::text", &[])
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+        Ok(rows.first().and_then(|row| row.get::<_, Option<String>>(0)))
+    }
+}
+
+/// Render a server notice for display.
+///
+/// Plain `NOTICE` (and the quieter `INFO`/`LOG`/`DEBUG`) messages are shown as
+/// is; `WARNING` keeps its severity so it stands out.
+fn format_notice(severity: &str, message: &str) -> String {
+    if severity.eq_ignore_ascii_case("WARNING") {
+        format!("{}: {}", severity, message)
+    } else {
+        message.to_string()
+    }
 }
@@ -0,0 +1,79 @@
+//! Column planner statistics query
+
+use postgres::Client;
+
+use crate::db::postgres::ProviderError;
+use crate::model::schema::{Column, PlannerStats};
+
+/// Read the statistics `ANALYZE` collected for each column of a table from
+/// `pg_stats`, keyed by column name.
+///
+/// Columns of a table that has never been analyzed have no row.
+pub fn get_column_stats(
+    client: &mut Client,
+    table_name: &str,
+    schema: &str,
+) -> Result<Vec<(String, PlannerStats)>, ProviderError> {
+    // Partitioned and inheritance parents only have rows with `inherited = true`;
+    // prefer the table's own statistics when both exist
+    let query = r#"
+        SELECT DISTINCT ON (attname)
+            attname::text as column_name,
+            null_frac,
+            n_distinct,
+            avg_width
+        FROM pg_stats
+        WHERE schemaname = $1
+        AND tablename = $2
+        ORDER BY attname, inherited
+    "#;
+
+    let rows = client
+        .query(query, &[&schema, &table_name])
+        .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+    let stats = rows
+        .iter()
+        .map(|row| {
+            let name: String = row.get(0);
+            (
+                name,
+                PlannerStats {
+                    null_frac: row.get(1),
+                    n_distinct: row.get(2),
+                    avg_width: row.get(3),
+                },
+            )
+        })
+        .collect();
+
+    Ok(stats)
+}
+
+/// Attach planner statistics to the columns they belong to
+pub fn apply_column_stats(columns: &mut [Column], stats: Vec<(String, PlannerStats)>) {
+    for (name, column_stats) in stats {
+        if let Some(column) = columns.iter_mut().find(|column| column.name == name) {
+            column.planner_stats = Some(column_stats);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_column_stats_leaves_unanalyzed_columns_empty() {
+        let mut columns = vec![Column::new("id", "integer"), Column::new("note", "text")];
+        let stats = PlannerStats {
+            null_frac: 0.0,
+            n_distinct: -1.0,
+            avg_width: 4,
+        };
+        apply_column_stats(&mut columns, vec![("id".to_string(), stats)]);
+
+        assert_eq!(columns[0].planner_stats, Some(stats));
+        assert!(columns[1].planner_stats.is_none());
+    }
+}
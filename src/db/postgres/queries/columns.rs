@@ -76,6 +76,7 @@ pub fn get_columns(
                 is_auto_increment,
                 comment,
                 ordinal_position: ordinal_position as usize,
+                planner_stats: None,
             }
         })
         .collect();
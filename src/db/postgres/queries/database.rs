@@ -0,0 +1,56 @@
+//! Database-wide size queries
+
+use postgres::Client;
+
+use crate::db::postgres::ProviderError;
+use crate::model::TableSize;
+
+pub fn get_database_size(client: &mut Client) -> Result<u64, ProviderError> {
+    let rows = client
+        .query("SELECT pg_database_size(current_database())::bigint", &[])
+        .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+    let size: i64 = match rows.first() {
+        Some(row) => row.get(0),
+        None => 0,
+    };
+
+    Ok(size as u64)
+}
+
+pub fn get_largest_tables(
+    client: &mut Client,
+    limit: usize,
+) -> Result<Vec<TableSize>, ProviderError> {
+    // Regular, partitioned and materialized tables outside the system schemas
+    let query = r#"
+        SELECT
+            n.nspname::text,
+            c.relname::text,
+            pg_total_relation_size(c.oid)::bigint AS total_bytes
+        FROM pg_class c
+        JOIN pg_namespace n ON n.oid = c.relnamespace
+        WHERE c.relkind IN ('r', 'p', 'm')
+        AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+        AND n.nspname NOT LIKE 'pg_toast%'
+        ORDER BY total_bytes DESC, n.nspname, c.relname
+        LIMIT $1
+    "#;
+
+    let limit = limit as i64;
+    let rows = client
+        .query(query, &[&limit])
+        .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let total_bytes: i64 = row.get(2);
+            TableSize {
+                schema: row.get(0),
+                name: row.get(1),
+                total_bytes: total_bytes as u64,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,88 @@
+//! Index usage statistics query
+
+use postgres::Client;
+
+use crate::db::postgres::ProviderError;
+use crate::model::schema::{Index, IndexUsage};
+
+/// Read the cumulative scan counters of each index on a table from
+/// `pg_stat_user_indexes`, keyed by index name.
+pub fn get_index_usage(
+    client: &mut Client,
+    table_name: &str,
+    schema: &str,
+) -> Result<Vec<(String, IndexUsage)>, ProviderError> {
+    let query = r#"
+        SELECT
+            indexrelname::text as index_name,
+            idx_scan,
+            idx_tup_read,
+            idx_tup_fetch
+        FROM pg_stat_user_indexes
+        WHERE schemaname = $1
+        AND relname = $2
+        ORDER BY indexrelname
+    "#;
+
+    let rows = client
+        .query(query, &[&schema, &table_name])
+        .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+    let usage = rows
+        .iter()
+        .map(|row| {
+            let name: String = row.get(0);
+            let scans: Option<i64> = row.get(1);
+            let tuples_read: Option<i64> = row.get(2);
+            let tuples_fetched: Option<i64> = row.get(3);
+            (
+                name,
+                IndexUsage {
+                    scans: scans.unwrap_or(0).max(0) as u64,
+                    tuples_read: tuples_read.unwrap_or(0).max(0) as u64,
+                    tuples_fetched: tuples_fetched.unwrap_or(0).max(0) as u64,
+                },
+            )
+        })
+        .collect();
+
+    Ok(usage)
+}
+
+/// Attach usage statistics to the indexes they belong to
+pub fn apply_index_usage(indexes: &mut [Index], usage: Vec<(String, IndexUsage)>) {
+    for (name, stats) in usage {
+        if let Some(index) = indexes.iter_mut().find(|index| index.name == name) {
+            index.usage = Some(stats);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::IndexType;
+
+    #[test]
+    fn test_apply_index_usage_matches_by_name() {
+        let mut indexes = vec![
+            Index::new("users_pkey", IndexType::Primary),
+            Index::new("users_email_idx", IndexType::Index),
+        ];
+        let unused = IndexUsage {
+            scans: 0,
+            tuples_read: 0,
+            tuples_fetched: 0,
+        };
+        apply_index_usage(
+            &mut indexes,
+            vec![
+                ("users_email_idx".to_string(), unused),
+                ("dropped_idx".to_string(), unused),
+            ],
+        );
+
+        assert!(indexes[0].usage.is_none());
+        assert_eq!(indexes[1].usage, Some(unused));
+    }
+}
@@ -82,6 +82,8 @@ pub fn get_indexes(
                 columns,
                 is_unique,
                 comment: None,
+                definition: Some(index_def),
+                usage: None,
             }
         })
         .collect();
@@ -2,16 +2,25 @@
 //!
 //! Each module contains query logic for specific table metadata types.
 
+mod column_stats;
 mod columns;
 mod constraints;
+mod database;
 mod foreign_keys;
+mod index_stats;
 mod indexes;
+mod roles;
+mod sessions;
+mod settings;
 mod stats;
 mod triggers;
 
 use postgres::Client;
 
-use crate::model::schema::{Column, Constraint, ForeignKey, Index, Trigger};
+use crate::model::schema::{
+    Column, Constraint, ForeignKey, Index, IndexUsage, PlannerStats, Trigger,
+};
+use crate::model::{ActiveSession, DatabaseRole, ServerSetting, TableSize};
 
 use super::ProviderError;
 
@@ -27,6 +36,23 @@ impl InternalQueries {
         columns::get_columns(client, table_name, schema)
     }
 
+    /// Retrieves per-column planner statistics (`null_frac`, `n_distinct`,
+    /// `avg_width`) from `pg_stats`.
+    ///
+    /// Tables that were never analyzed return no statistics.
+    pub fn get_column_stats(
+        client: &mut Client,
+        table_name: &str,
+        schema: &str,
+    ) -> Result<Vec<(String, PlannerStats)>, ProviderError> {
+        column_stats::get_column_stats(client, table_name, schema)
+    }
+
+    /// Attaches statistics from `get_column_stats` to the matching columns.
+    pub fn apply_column_stats(columns: &mut [Column], stats: Vec<(String, PlannerStats)>) {
+        column_stats::apply_column_stats(columns, stats)
+    }
+
     pub fn get_indexes(
         client: &mut Client,
         table_name: &str,
@@ -35,6 +61,23 @@ impl InternalQueries {
         indexes::get_indexes(client, table_name, schema)
     }
 
+    /// Retrieves per-index scan counters from `pg_stat_user_indexes`.
+    ///
+    /// Counters accumulate since the statistics were last reset, so an index
+    /// with zero scans over a long period is a candidate for removal.
+    pub fn get_index_usage(
+        client: &mut Client,
+        table_name: &str,
+        schema: &str,
+    ) -> Result<Vec<(String, IndexUsage)>, ProviderError> {
+        index_stats::get_index_usage(client, table_name, schema)
+    }
+
+    /// Attaches usage counters from `get_index_usage` to the matching indexes.
+    pub fn apply_index_usage(indexes: &mut [Index], usage: Vec<(String, IndexUsage)>) {
+        index_stats::apply_index_usage(indexes, usage)
+    }
+
     pub fn get_foreign_keys(
         client: &mut Client,
         table_name: &str,
@@ -74,6 +117,46 @@ impl InternalQueries {
         stats::get_table_stats(client, table_name, schema)
     }
 
+    /// Retrieves the total size of the current database in bytes.
+    pub fn get_database_size(client: &mut Client) -> Result<u64, ProviderError> {
+        database::get_database_size(client)
+    }
+
+    /// Retrieves the `limit` largest tables of the current database, biggest first.
+    ///
+    /// Sizes come from `pg_total_relation_size` and include indexes and TOAST data.
+    pub fn get_largest_tables(
+        client: &mut Client,
+        limit: usize,
+    ) -> Result<Vec<TableSize>, ProviderError> {
+        database::get_largest_tables(client, limit)
+    }
+
+    /// Retrieves the client sessions connected to the current database,
+    /// excluding the session running the query.
+    pub fn get_active_sessions(client: &mut Client) -> Result<Vec<ActiveSession>, ProviderError> {
+        sessions::get_active_sessions(client)
+    }
+
+    /// Terminates a backend with `pg_terminate_backend`.
+    ///
+    /// Returns `false` if the server did not signal the process.
+    pub fn terminate_backend(client: &mut Client, pid: i32) -> Result<bool, ProviderError> {
+        sessions::terminate_backend(client, pid)
+    }
+
+    /// Retrieves the roles defined on the server from `pg_roles`.
+    ///
+    /// Falls back to the connected role alone when the catalog is not readable.
+    pub fn get_roles(client: &mut Client) -> Result<Vec<DatabaseRole>, ProviderError> {
+        roles::get_roles(client)
+    }
+
+    /// Retrieves the run-time parameters of the session with `SHOW ALL`.
+    pub fn get_settings(client: &mut Client) -> Result<Vec<ServerSetting>, ProviderError> {
+        settings::get_settings(client)
+    }
+
     /// Retrieves triggers defined on a table.
     pub fn get_triggers(
         client: &mut Client,
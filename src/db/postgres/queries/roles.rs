@@ -0,0 +1,40 @@
+//! Role (user) queries
+
+use postgres::error::SqlState;
+use postgres::Client;
+
+use crate::db::postgres::ProviderError;
+use crate::model::DatabaseRole;
+
+pub fn get_roles(client: &mut Client) -> Result<Vec<DatabaseRole>, ProviderError> {
+    let query = r#"
+        SELECT
+            rolname::text,
+            rolsuper,
+            rolcanlogin,
+            rolvaliduntil::text
+        FROM pg_roles
+        ORDER BY rolname
+    "#;
+
+    match client.query(query, &[]) {
+        Ok(rows) => Ok(rows
+            .iter()
+            .map(|row| DatabaseRole {
+                name: row.get(0),
+                superuser: row.get(1),
+                can_login: row.get(2),
+                valid_until: row.get(3),
+            })
+            .collect()),
+        // Locked-down servers may revoke access to the catalog; the connected
+        // role is still worth showing
+        Err(e) if e.code() == Some(&SqlState::INSUFFICIENT_PRIVILEGE) => {
+            let row = client
+                .query_one("SELECT current_user::text", &[])
+                .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+            Ok(vec![DatabaseRole::name_only(row.get::<_, String>(0))])
+        }
+        Err(e) => Err(ProviderError::QueryFailed(e.to_string())),
+    }
+}
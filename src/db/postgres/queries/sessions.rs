@@ -0,0 +1,55 @@
+//! Session (backend) queries
+
+use postgres::Client;
+
+use crate::db::postgres::ProviderError;
+use crate::model::ActiveSession;
+
+pub fn get_active_sessions(client: &mut Client) -> Result<Vec<ActiveSession>, ProviderError> {
+    // Client backends of the current database, excluding our own; the duration
+    // is measured from the query start for running queries and from the last
+    // state change otherwise
+    let query = r#"
+        SELECT
+            pid,
+            usename::text,
+            state,
+            CASE WHEN wait_event IS NULL THEN NULL
+                ELSE wait_event_type || ':' || wait_event END,
+            (EXTRACT(EPOCH FROM now() - CASE WHEN state = 'active' THEN query_start
+                ELSE state_change END) * 1000)::bigint AS duration_ms,
+            COALESCE(query, '')
+        FROM pg_stat_activity
+        WHERE datname = current_database()
+        AND pid <> pg_backend_pid()
+        AND backend_type = 'client backend'
+        ORDER BY state = 'active' DESC, duration_ms DESC NULLS LAST, pid
+    "#;
+
+    let rows = client
+        .query(query, &[])
+        .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let duration_ms: Option<i64> = row.get(4);
+            ActiveSession {
+                pid: row.get(0),
+                user: row.get(1),
+                state: row.get(2),
+                wait_event: row.get(3),
+                duration_ms: duration_ms.map(|ms| ms.max(0) as u64),
+                query: row.get(5),
+            }
+        })
+        .collect())
+}
+
+pub fn terminate_backend(client: &mut Client, pid: i32) -> Result<bool, ProviderError> {
+    let rows = client
+        .query("SELECT pg_terminate_backend($1)", &[&pid])
+        .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+    Ok(rows.first().map(|row| row.get(0)).unwrap_or(false))
+}
@@ -0,0 +1,22 @@
+//! Run-time parameter queries
+
+use postgres::Client;
+
+use crate::db::postgres::ProviderError;
+use crate::model::ServerSetting;
+
+pub fn get_settings(client: &mut Client) -> Result<Vec<ServerSetting>, ProviderError> {
+    // SHOW ALL reports the values in effect for this session, sorted by name
+    let rows = client
+        .query("SHOW ALL", &[])
+        .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| ServerSetting {
+            name: row.get(0),
+            setting: row.get(1),
+            description: row.get(2),
+        })
+        .collect())
+}
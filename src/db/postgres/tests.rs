@@ -2,10 +2,14 @@
 
 use std::env;
 
-use super::helpers::{is_valid_identifier, parse_column_sort_order, quote_identifier};
+use super::helpers::{
+    copy_csv_options, copy_to_csv_statement, is_valid_identifier, parse_column_sort_order,
+    quote_identifier,
+};
 use super::pool::{ConnectionPool, PoolConfig};
 use super::{PostgresProvider, ProviderError};
 use crate::db::provider::DatabaseProvider;
+use crate::export::{Delimiter, QuoteStyle};
 use crate::model::schema::SortOrder;
 
 fn create_test_provider() -> PostgresProvider {
@@ -31,11 +35,11 @@ fn test_postgres_connection() {
     let version = provider.get_version().expect("Failed to get version");
     println!("PostgreSQL version: {}", version);
 
-    let schemas = provider.get_schemas().expect("Failed to get schemas");
+    let schemas = provider.get_schemas(false).expect("Failed to get schemas");
     println!("Schemas: {:?}", schemas);
 
     let tables = provider
-        .get_tables(Some("public"))
+        .get_tables(Some("public"), false)
         .expect("Failed to get tables");
     println!(
         "Tables: {:?}",
@@ -43,6 +47,62 @@ fn test_postgres_connection() {
     );
 }
 
+#[test]
+#[ignore]
+fn test_duplicate_computed_columns_are_renamed() {
+    let provider = create_test_provider();
+
+    let result = provider
+        .execute_query("SELECT 1, 2")
+        .expect("Failed to execute query");
+
+    assert_eq!(result.columns, vec!["?column?", "?column?_2"]);
+}
+
+#[test]
+#[ignore]
+fn test_trailing_semicolon_is_trimmed() {
+    let provider = create_test_provider();
+
+    let result = provider
+        .execute_query("SELECT 1 AS one;\n")
+        .expect("Failed to execute query");
+
+    assert_eq!(result.rows, vec![vec!["1".to_string()]]);
+    assert!(provider.validate_query("SELECT 1;").is_ok());
+}
+
+#[test]
+#[ignore]
+fn test_rows_affected_is_reported_for_statements_without_rows() {
+    let provider = create_test_provider();
+
+    // A temporary table goes away with the session, leaving the database as it was
+    let result = provider
+        .execute_query("CREATE TEMP TABLE lazydb_affected AS SELECT generate_series(1, 3) AS n")
+        .expect("Failed to execute query");
+    assert_eq!(result.rows_affected, Some(3));
+    assert!(result.rows.is_empty());
+
+    let result = provider
+        .execute_query("SELECT 1 AS one")
+        .expect("Failed to execute query");
+    assert_eq!(result.rows_affected, None);
+}
+
+#[test]
+#[ignore]
+fn test_raised_notices_are_collected() {
+    let provider = create_test_provider();
+
+    provider
+        .execute_query("DO $$ BEGIN RAISE NOTICE 'hi'; RAISE WARNING 'careful'; END $$")
+        .expect("Failed to execute query");
+
+    assert_eq!(provider.take_notices(), vec!["hi", "WARNING: careful"]);
+    assert!(provider.take_notices().is_empty());
+}
+
 #[test]
 #[ignore]
 fn test_parameterized_query() {
@@ -130,6 +190,102 @@ fn test_get_table_details() {
         .any(|c| c.name == "id" && c.is_primary_key));
 }
 
+#[test]
+#[ignore]
+fn test_get_index_usage() {
+    let provider = create_test_provider();
+
+    let usage = provider
+        .get_index_usage("users", Some("public"))
+        .expect("Failed to get index usage");
+    println!("Index usage: {:?}", usage);
+
+    let table = provider
+        .get_table_details("users", Some("public"))
+        .expect("Failed to get table details");
+    for (name, stats) in usage {
+        let index = table.indexes.iter().find(|i| i.name == name).unwrap();
+        assert!(index.usage.is_some_and(|u| u.scans >= stats.scans));
+    }
+}
+
+#[test]
+#[ignore]
+fn test_active_sessions_and_terminate_backend() {
+    let provider = create_test_provider();
+    let other = create_test_provider();
+
+    // Keep the other session checked out while it is listed and terminated
+    let mut other_client = other.get_connection().unwrap();
+    let pid: i32 = other_client
+        .query_one("SELECT pg_backend_pid()", &[])
+        .expect("Failed to get backend pid")
+        .get(0);
+
+    let sessions = provider
+        .get_active_sessions()
+        .expect("Failed to list sessions");
+    assert!(sessions.iter().any(|s| s.pid == pid));
+
+    assert!(provider
+        .terminate_backend(pid)
+        .expect("Failed to terminate backend"));
+}
+
+#[test]
+#[ignore]
+fn test_get_roles_includes_connected_user() {
+    let provider = create_test_provider();
+    let mut client = provider.get_connection().unwrap();
+    let user: String = client
+        .query_one("SELECT current_user::text", &[])
+        .expect("Failed to get current user")
+        .get(0);
+
+    let roles = provider.get_roles().expect("Failed to list roles");
+    let role = roles
+        .iter()
+        .find(|r| r.name == user)
+        .expect("connected user listed");
+    assert_eq!(role.can_login, Some(true));
+}
+
+#[test]
+#[ignore]
+fn test_get_settings_lists_session_parameters() {
+    let provider = create_test_provider();
+    let settings = provider.get_settings().expect("Failed to list settings");
+    let search_path = settings
+        .iter()
+        .find(|s| s.name == "search_path")
+        .expect("search_path listed");
+    assert!(!search_path.description.is_empty());
+}
+
+#[test]
+#[ignore]
+fn test_validate_query_without_running_it() {
+    let provider = create_test_provider();
+    provider
+        .validate_query("SELECT oid, relname FROM pg_class WHERE relkind = $1")
+        .expect("valid query");
+
+    let err = provider
+        .validate_query("SELECT no_such_column FROM pg_class")
+        .unwrap_err();
+    assert!(err.to_string().contains("no_such_column"));
+    assert!(provider.validate_query("SELEC 1").is_err());
+
+    // Nothing is executed: the table is never created
+    provider
+        .validate_query("CREATE TABLE lazydb_validate_probe (id int)")
+        .expect("valid DDL");
+    let rows = provider
+        .execute_query("SELECT to_regclass('lazydb_validate_probe') IS NULL")
+        .unwrap();
+    assert_eq!(rows.rows[0][0], "t");
+}
+
 #[test]
 #[ignore]
 fn test_get_foreign_keys() {
@@ -225,6 +381,35 @@ fn test_connection_failure_invalid_port() {
     }
 }
 
+#[test]
+fn test_copy_csv_options_unit() {
+    assert_eq!(
+        copy_csv_options(Delimiter::Comma, QuoteStyle::Necessary),
+        "FORMAT csv, HEADER true, DELIMITER ','"
+    );
+    assert_eq!(
+        copy_csv_options(Delimiter::Tab, QuoteStyle::Necessary),
+        "FORMAT csv, HEADER true, DELIMITER E'\\t'"
+    );
+    assert_eq!(
+        copy_csv_options(Delimiter::Pipe, QuoteStyle::Always),
+        "FORMAT csv, HEADER true, DELIMITER '|', FORCE_QUOTE *"
+    );
+}
+
+#[test]
+fn test_copy_to_csv_statement_unit() {
+    assert_eq!(
+        copy_to_csv_statement(
+            "SELECT * FROM users -- active only;",
+            Delimiter::Comma,
+            QuoteStyle::Necessary
+        ),
+        "COPY (SELECT * FROM users -- active only\n) TO STDOUT WITH \
+         (FORMAT csv, HEADER true, DELIMITER ',')"
+    );
+}
+
 #[test]
 fn test_is_valid_identifier_unit() {
     // Valid identifiers
@@ -311,7 +496,7 @@ fn test_nonexistent_table() {
 fn test_nonexistent_schema() {
     let provider = create_test_provider();
 
-    let result = provider.get_tables(Some("nonexistent_schema_12345"));
+    let result = provider.get_tables(Some("nonexistent_schema_12345"), false);
     // This should succeed but return empty
     match result {
         Ok(tables) => {
@@ -649,7 +834,7 @@ fn test_provider_with_pool_multiple_operations() {
 
     // Perform multiple operations to verify connection reuse
     for i in 0..5 {
-        let schemas = provider.get_schemas().expect("Failed to get schemas");
+        let schemas = provider.get_schemas(false).expect("Failed to get schemas");
         assert!(
             !schemas.is_empty(),
             "Iteration {}: schemas should not be empty",
@@ -1,38 +1,124 @@
 //! DatabaseProvider trait implementation for PostgreSQL
 
+use std::io::{Read, Write};
 use std::time::Instant;
 
-use crate::model::schema::{Table, TableType};
-use crate::model::QueryResult;
+use postgres::fallible_iterator::FallibleIterator;
+use postgres::types::ToSql;
 
-use super::helpers::{convert_value_to_string, is_valid_identifier, quote_identifier};
+use crate::export::{Delimiter, QuoteStyle};
+use crate::model::schema::{Table, TableType};
+use crate::model::{
+    unique_column_names, ActiveSession, DatabaseRole, QueryResult, ServerSetting, TableSize,
+    DEFAULT_SCHEMA,
+};
+
+use super::helpers::{
+    convert_value_to_string, copy_to_csv_statement, is_valid_identifier, quote_identifier,
+    trim_statement,
+};
 use super::queries::InternalQueries;
-use super::{DatabaseProvider, DatabaseType, PostgresProvider, ProviderError};
+use super::{
+    DatabaseProvider, DatabaseType, PostgresProvider, ProviderCapabilities, ProviderError,
+};
+
+/// Read buffer size used when streaming `COPY` output
+const COPY_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Name of the server-side cursor used for browsing
+const BROWSE_CURSOR: &str = "lazydb_browse";
+
+/// Schemas holding PostgreSQL's own catalog
+const SYSTEM_SCHEMAS: &[&str] = &["pg_catalog", "information_schema", "pg_toast"];
+
+/// Type names shown under the grid headers (`int4`, `text`, ...)
+fn type_names<'a>(types: impl IntoIterator<Item = &'a postgres::types::Type>) -> Vec<String> {
+    types.into_iter().map(|t| t.name().to_string()).collect()
+}
+
+/// Convert fetched rows into a `QueryResult`
+fn build_query_result(rows: &[postgres::Row], execution_time_ms: u64) -> QueryResult {
+    if rows.is_empty() {
+        return QueryResult {
+            columns: Vec::new(),
+            column_types: Vec::new(),
+            rows: Vec::new(),
+            execution_time_ms,
+            total_rows: 0,
+            rows_affected: None,
+        };
+    }
+
+    // Get column names and types once, not per row
+    let col_info: Vec<(String, &postgres::types::Type)> = rows[0]
+        .columns()
+        .iter()
+        .map(|c| (c.name().to_string(), c.type_()))
+        .collect();
+
+    // Computed columns (`SELECT 1, 1`) share the name `?column?`
+    let columns = unique_column_names(col_info.iter().map(|(name, _)| name.clone()).collect());
+    let column_types = type_names(col_info.iter().map(|(_, col_type)| *col_type));
+
+    // Convert rows to strings using pre-fetched type information
+    let result_rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            col_info
+                .iter()
+                .enumerate()
+                .map(|(i, (_, col_type))| convert_value_to_string(row, i, col_type))
+                .collect()
+        })
+        .collect();
+
+    let total_rows = result_rows.len();
+    QueryResult {
+        columns,
+        column_types,
+        rows: result_rows,
+        execution_time_ms,
+        total_rows,
+        rows_affected: None,
+    }
+}
 
 impl DatabaseProvider for PostgresProvider {
     fn database_type(&self) -> DatabaseType {
         DatabaseType::PostgreSQL
     }
 
-    fn get_schemas(&self) -> Result<Vec<String>, ProviderError> {
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities::all()
+    }
+
+    fn system_schemas(&self) -> &'static [&'static str] {
+        SYSTEM_SCHEMAS
+    }
+
+    fn get_schemas(&self, include_system: bool) -> Result<Vec<String>, ProviderError> {
         let query = r#"
             SELECT schema_name
             FROM information_schema.schemata
-            WHERE schema_name NOT IN ('pg_catalog', 'information_schema', 'pg_toast')
+            WHERE $1 OR NOT (schema_name = ANY($2))
             ORDER BY schema_name
         "#;
 
         let mut client = self.get_connection()?;
 
         let rows = client
-            .query(query, &[])
+            .query(query, &[&include_system, &SYSTEM_SCHEMAS])
             .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
 
         Ok(rows.iter().map(|row| row.get::<_, String>(0)).collect())
     }
 
-    fn get_tables(&self, schema: Option<&str>) -> Result<Vec<Table>, ProviderError> {
-        let schema = schema.unwrap_or("public");
+    fn get_tables(
+        &self,
+        schema: Option<&str>,
+        include_system: bool,
+    ) -> Result<Vec<Table>, ProviderError> {
+        let schema = schema.unwrap_or(DEFAULT_SCHEMA);
 
         // Query to get tables with estimated row count and size
         let query = r#"
@@ -43,17 +129,17 @@ impl DatabaseProvider for PostgresProvider {
                 COALESCE(s.n_live_tup, 0)::bigint as row_count,
                 COALESCE(pg_total_relation_size((quote_ident(t.table_schema) || '.' || quote_ident(t.table_name))::regclass), 0)::bigint as size_bytes
             FROM information_schema.tables t
-            LEFT JOIN pg_stat_user_tables s
+            LEFT JOIN pg_stat_all_tables s
                 ON s.schemaname = t.table_schema AND s.relname = t.table_name
             WHERE t.table_schema = $1
-            AND t.table_type IN ('BASE TABLE', 'VIEW')
+            AND ($2 OR t.table_type IN ('BASE TABLE', 'VIEW'))
             ORDER BY t.table_name
         "#;
 
         let mut client = self.get_connection()?;
 
         let rows = client
-            .query(query, &[&schema])
+            .query(query, &[&schema, &include_system])
             .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
 
         let tables = rows
@@ -67,6 +153,8 @@ impl DatabaseProvider for PostgresProvider {
 
                 let table_type = match table_type_str.as_str() {
                     "VIEW" => TableType::View,
+                    "FOREIGN" => TableType::ForeignTable,
+                    "LOCAL TEMPORARY" => TableType::Temporary,
                     _ => TableType::BaseTable,
                 };
 
@@ -87,7 +175,7 @@ impl DatabaseProvider for PostgresProvider {
         table_name: &str,
         schema: Option<&str>,
     ) -> Result<Table, ProviderError> {
-        let schema_str = schema.unwrap_or("public");
+        let schema_str = schema.unwrap_or(DEFAULT_SCHEMA);
 
         // Get basic table info
         let table_query = r#"
@@ -119,11 +207,17 @@ impl DatabaseProvider for PostgresProvider {
             _ => TableType::BaseTable,
         };
 
-        // Get columns
-        let columns = InternalQueries::get_columns(&mut client, table_name, schema_str)?;
+        // Get columns, with planner statistics once the table has been analyzed
+        let mut columns = InternalQueries::get_columns(&mut client, table_name, schema_str)?;
+        if let Ok(stats) = InternalQueries::get_column_stats(&mut client, table_name, schema_str) {
+            InternalQueries::apply_column_stats(&mut columns, stats);
+        }
 
-        // Get indexes
-        let indexes = InternalQueries::get_indexes(&mut client, table_name, schema_str)?;
+        // Get indexes, with usage statistics where the server collects them
+        let mut indexes = InternalQueries::get_indexes(&mut client, table_name, schema_str)?;
+        if let Ok(usage) = InternalQueries::get_index_usage(&mut client, table_name, schema_str) {
+            InternalQueries::apply_index_usage(&mut indexes, usage);
+        }
 
         // Get foreign keys
         let foreign_keys = InternalQueries::get_foreign_keys(&mut client, table_name, schema_str)?;
@@ -158,49 +252,124 @@ impl DatabaseProvider for PostgresProvider {
 
         let mut client = self.get_connection()?;
 
-        let rows = client
-            .query(query, &[])
+        let statement = client
+            .prepare(trim_statement(query))
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+        let mut iter = client
+            .query_raw(&statement, std::iter::empty::<&dyn ToSql>())
             .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+        let rows: Vec<postgres::Row> = iter
+            .by_ref()
+            .collect()
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+        // The command tag is only known once every row was read; statements
+        // without a result set report the rows they changed there
+        let rows_affected = if statement.columns().is_empty() {
+            iter.rows_affected()
+        } else {
+            None
+        };
 
         let execution_time_ms = start.elapsed().as_millis() as u64;
 
-        if rows.is_empty() {
-            return Ok(QueryResult {
-                columns: Vec::new(),
-                rows: Vec::new(),
-                execution_time_ms,
-                total_rows: 0,
-            });
+        Ok(QueryResult {
+            rows_affected,
+            ..build_query_result(&rows, execution_time_ms)
+        })
+    }
+
+    fn open_cursor(&self, query: &str) -> Result<(), ProviderError> {
+        // The cursor and its transaction belong to one session, which a pool
+        // does not keep between calls
+        if self.is_pooled() {
+            return Err(ProviderError::Unsupported("server-side cursors on a pool"));
         }
+        let query = trim_statement(query);
+        let mut client = self.get_connection()?;
 
-        // Get column names and types once, not per row
-        let col_info: Vec<(String, &postgres::types::Type)> = rows[0]
-            .columns()
-            .iter()
-            .map(|c| (c.name().to_string(), c.type_()))
-            .collect();
+        // Cursors only live inside a transaction; it stays open until close_cursor.
+        // SCROLL lets rows that were dropped from the window be fetched again.
+        client
+            .batch_execute(&format!(
+                "BEGIN READ ONLY; DECLARE {} SCROLL CURSOR FOR {}",
+                BROWSE_CURSOR, query
+            ))
+            .map_err(|e| {
+                let _ = client.batch_execute("ROLLBACK");
+                ProviderError::QueryFailed(e.to_string())
+            })
+    }
 
-        let columns: Vec<String> = col_info.iter().map(|(name, _)| name.clone()).collect();
+    fn fetch_cursor(&self, start: usize, count: usize) -> Result<QueryResult, ProviderError> {
+        if self.is_pooled() {
+            return Err(ProviderError::Unsupported("server-side cursors on a pool"));
+        }
+        let begin = Instant::now();
 
-        // Convert rows to strings using pre-fetched type information
-        let result_rows: Vec<Vec<String>> = rows
-            .iter()
-            .map(|row| {
-                col_info
-                    .iter()
-                    .enumerate()
-                    .map(|(i, (_, col_type))| convert_value_to_string(row, i, col_type))
-                    .collect()
-            })
-            .collect();
+        let mut client = self.get_connection()?;
 
-        let total_rows = result_rows.len();
-        Ok(QueryResult {
-            columns,
-            rows: result_rows,
-            execution_time_ms,
-            total_rows,
-        })
+        // Position on the row before `start` (ABSOLUTE 0 is before the first row)
+        client
+            .batch_execute(&format!("MOVE ABSOLUTE {} IN {}", start, BROWSE_CURSOR))
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+        let rows = client
+            .query(
+                &format!("FETCH FORWARD {} FROM {}", count, BROWSE_CURSOR),
+                &[],
+            )
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+        let execution_time_ms = begin.elapsed().as_millis() as u64;
+
+        Ok(build_query_result(&rows, execution_time_ms))
+    }
+
+    fn close_cursor(&self) -> Result<(), ProviderError> {
+        if self.is_pooled() {
+            return Err(ProviderError::Unsupported("server-side cursors on a pool"));
+        }
+        let mut client = self.get_connection()?;
+
+        client
+            .batch_execute(&format!("CLOSE {}; COMMIT", BROWSE_CURSOR))
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))
+    }
+
+    fn copy_query_to_csv(
+        &self,
+        query: &str,
+        delimiter: Delimiter,
+        quote_style: QuoteStyle,
+        writer: &mut dyn Write,
+        on_progress: &mut dyn FnMut(u64),
+    ) -> Result<u64, ProviderError> {
+        let copy_statement = copy_to_csv_statement(query, delimiter, quote_style);
+
+        let mut client = self.get_connection()?;
+        let mut reader = client
+            .copy_out(copy_statement.as_str())
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+
+        let mut buf = vec![0u8; COPY_CHUNK_SIZE];
+        let mut total = 0u64;
+        loop {
+            let n = reader
+                .read(&mut buf)
+                .map_err(|e| ProviderError::QueryFailed(e.to_string()))?;
+            if n == 0 {
+                break;
+            }
+            writer
+                .write_all(&buf[..n])
+                .map_err(|e| ProviderError::InternalError(e.to_string()))?;
+            total += n as u64;
+            on_progress(total);
+        }
+        writer
+            .flush()
+            .map_err(|e| ProviderError::InternalError(e.to_string()))?;
+
+        Ok(total)
     }
 
     fn get_row_count(
@@ -208,7 +377,7 @@ impl DatabaseProvider for PostgresProvider {
         table_name: &str,
         schema: Option<&str>,
     ) -> Result<usize, ProviderError> {
-        let schema = schema.unwrap_or("public");
+        let schema = schema.unwrap_or(DEFAULT_SCHEMA);
 
         // Validate identifiers to prevent SQL injection
         // Only allow alphanumeric characters, underscores, and dollar signs (PostgreSQL identifier rules)
@@ -235,7 +404,7 @@ impl DatabaseProvider for PostgresProvider {
     }
 
     fn get_table_size(&self, table_name: &str, schema: Option<&str>) -> Result<u64, ProviderError> {
-        let schema = schema.unwrap_or("public");
+        let schema = schema.unwrap_or(DEFAULT_SCHEMA);
 
         // Use quote_ident to safely handle identifiers and prevent SQL injection
         let query = r#"
@@ -260,6 +429,49 @@ impl DatabaseProvider for PostgresProvider {
         Ok(size as u64)
     }
 
+    fn get_database_size(&self) -> Result<u64, ProviderError> {
+        let mut client = self.get_connection()?;
+        InternalQueries::get_database_size(&mut client)
+    }
+
+    fn get_largest_tables(&self, limit: usize) -> Result<Vec<TableSize>, ProviderError> {
+        let mut client = self.get_connection()?;
+        InternalQueries::get_largest_tables(&mut client, limit)
+    }
+
+    fn get_active_sessions(&self) -> Result<Vec<ActiveSession>, ProviderError> {
+        let mut client = self.get_connection()?;
+        InternalQueries::get_active_sessions(&mut client)
+    }
+
+    fn terminate_backend(&self, pid: i32) -> Result<bool, ProviderError> {
+        let mut client = self.get_connection()?;
+        InternalQueries::terminate_backend(&mut client, pid)
+    }
+
+    fn get_roles(&self) -> Result<Vec<DatabaseRole>, ProviderError> {
+        let mut client = self.get_connection()?;
+        InternalQueries::get_roles(&mut client)
+    }
+
+    fn get_settings(&self) -> Result<Vec<ServerSetting>, ProviderError> {
+        let mut client = self.get_connection()?;
+        InternalQueries::get_settings(&mut client)
+    }
+
+    fn validate_query(&self, query: &str) -> Result<(), ProviderError> {
+        let mut client = self.get_connection()?;
+        // Preparing parses and plans the statement on the server without executing it
+        client
+            .prepare(trim_statement(query))
+            .map(|_| ())
+            .map_err(|e| ProviderError::QueryFailed(e.to_string()))
+    }
+
+    fn take_notices(&self) -> Vec<String> {
+        self.drain_notices()
+    }
+
     fn test_connection(&self) -> Result<(), ProviderError> {
         let mut client = self.get_connection()?;
 
@@ -285,3 +497,18 @@ impl DatabaseProvider for PostgresProvider {
         Ok(version)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use postgres::types::Type;
+
+    #[test]
+    fn test_type_names_follow_column_order() {
+        let types = [Type::INT4, Type::TEXT, Type::TIMESTAMPTZ, Type::JSONB];
+        assert_eq!(
+            type_names(types.iter()),
+            vec!["int4", "text", "timestamptz", "jsonb"]
+        );
+    }
+}
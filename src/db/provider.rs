@@ -1,8 +1,11 @@
+use std::io::Write;
+
+use crate::export::{Delimiter, QuoteStyle};
 use crate::model::schema::{Column, Constraint, ForeignKey, Index, Table};
-use crate::model::QueryResult;
+use crate::model::{ActiveSession, DatabaseRole, QueryResult, ServerSetting, TableSize};
 
 /// Supported database types
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DatabaseType {
     PostgreSQL,
     MySQL,
@@ -11,6 +14,48 @@ pub enum DatabaseType {
     // Future: add more database types
 }
 
+impl DatabaseType {
+    /// Selection order in the connection modal
+    pub const ALL: [DatabaseType; 4] = [
+        DatabaseType::PostgreSQL,
+        DatabaseType::MySQL,
+        DatabaseType::MariaDB,
+        DatabaseType::SQLite,
+    ];
+
+    /// Port the server listens on by default (`None` for file-based databases)
+    pub fn default_port(self) -> Option<u16> {
+        match self {
+            DatabaseType::PostgreSQL => Some(5432),
+            DatabaseType::MySQL | DatabaseType::MariaDB => Some(3306),
+            DatabaseType::SQLite => None,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|t| *t == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(self) -> Self {
+        let idx = Self::ALL.iter().position(|t| *t == self).unwrap_or(0);
+        Self::ALL[(idx + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Quote an identifier the way this database expects.
+    ///
+    /// PostgreSQL and SQLite use double quotes, MySQL and MariaDB backticks;
+    /// the quote character inside the identifier is escaped by doubling it.
+    pub fn quote_identifier(self, identifier: &str) -> String {
+        let quote = match self {
+            DatabaseType::PostgreSQL | DatabaseType::SQLite => '"',
+            DatabaseType::MySQL | DatabaseType::MariaDB => '`',
+        };
+        let escaped = identifier.replace(quote, &format!("{quote}{quote}"));
+        format!("{quote}{escaped}{quote}")
+    }
+}
+
 impl std::fmt::Display for DatabaseType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -22,6 +67,50 @@ impl std::fmt::Display for DatabaseType {
     }
 }
 
+/// Optional features a database offers, used to decide which UI affordances to show
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProviderCapabilities {
+    /// Stored procedures and functions
+    pub supports_routines: bool,
+    /// Table triggers
+    pub supports_triggers: bool,
+    /// Materialized views
+    pub supports_materialized_views: bool,
+    /// `EXPLAIN ANALYZE` (execute and report actual timings)
+    pub supports_explain_analyze: bool,
+    /// Foreign key constraints (drives the Relations tab)
+    pub supports_foreign_keys: bool,
+}
+
+impl ProviderCapabilities {
+    /// Every capability enabled
+    pub fn all() -> Self {
+        Self {
+            supports_routines: true,
+            supports_triggers: true,
+            supports_materialized_views: true,
+            supports_explain_analyze: true,
+            supports_foreign_keys: true,
+        }
+    }
+
+    /// Capabilities typically offered by a database type
+    pub fn for_database_type(database_type: &DatabaseType) -> Self {
+        match database_type {
+            DatabaseType::PostgreSQL => Self::all(),
+            DatabaseType::MySQL | DatabaseType::MariaDB => Self {
+                supports_materialized_views: false,
+                ..Self::all()
+            },
+            DatabaseType::SQLite => Self {
+                supports_triggers: true,
+                supports_foreign_keys: true,
+                ..Self::default()
+            },
+        }
+    }
+}
+
 /// Database provider trait - extensible architecture for multiple DB support
 ///
 /// This trait defines the interface for database metadata retrieval.
@@ -39,6 +128,14 @@ impl std::fmt::Display for DatabaseType {
 ///   These delegate to `get_table_details` by default. Override only if you need
 ///   optimized partial queries for specific use cases.
 ///
+/// ## Optional Capabilities (default to `ProviderError::Unsupported`)
+/// - `open_cursor` / `fetch_cursor` / `close_cursor`, `copy_query_to_csv`,
+///   `get_database_size`, `get_largest_tables`, `get_active_sessions`,
+///   `terminate_backend`, `get_roles`, `get_settings`, `validate_query`:
+///   Implement only what the
+///   database offers. Callers treat `Unsupported` as "feature unavailable"
+///   and fall back or hide the feature instead of reporting a failure.
+///
 /// # Example
 /// ```ignore
 /// struct PostgresProvider { /* connection details */ }
@@ -58,11 +155,37 @@ pub trait DatabaseProvider: Send + Sync {
     /// Returns the type of database this provider handles
     fn database_type(&self) -> DatabaseType;
 
-    /// Get list of schemas/databases
-    fn get_schemas(&self) -> Result<Vec<String>, ProviderError>;
+    /// Returns the optional features this database offers.
+    ///
+    /// Default implementation derives them from `database_type`.
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities::for_database_type(&self.database_type())
+    }
+
+    /// Quote an identifier for use in generated SQL.
+    ///
+    /// Default implementation follows `database_type`.
+    fn quote_identifier(&self, identifier: &str) -> String {
+        self.database_type().quote_identifier(identifier)
+    }
+
+    /// Schemas holding the database's own catalog, hidden unless system objects are shown
+    fn system_schemas(&self) -> &'static [&'static str] {
+        &[]
+    }
 
-    /// Get list of tables in a schema
-    fn get_tables(&self, schema: Option<&str>) -> Result<Vec<Table>, ProviderError>;
+    /// Get list of schemas/databases (`include_system` adds [`system_schemas`](Self::system_schemas))
+    fn get_schemas(&self, include_system: bool) -> Result<Vec<String>, ProviderError>;
+
+    /// Get list of tables in a schema.
+    ///
+    /// Only tables and views are listed unless `include_system` is set, which
+    /// lists every relation type the database reports.
+    fn get_tables(
+        &self,
+        schema: Option<&str>,
+        include_system: bool,
+    ) -> Result<Vec<Table>, ProviderError>;
 
     /// Get detailed table information including columns, indexes, foreign keys, and constraints.
     ///
@@ -126,6 +249,56 @@ pub trait DatabaseProvider: Send + Sync {
     /// Execute a query and return results
     fn execute_query(&self, query: &str) -> Result<QueryResult, ProviderError>;
 
+    /// Remove and return the NOTICE/WARNING messages the server sent since
+    /// the last call (e.g. from `RAISE NOTICE`).
+    /// Default implementation returns no notices.
+    fn take_notices(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Open a server-side cursor for `query` so its rows can be fetched in batches.
+    ///
+    /// Only one cursor is open per provider; it stays open until `close_cursor`.
+    /// The cursor lives in one session, so pooled providers do not support it.
+    /// Default implementation returns `Unsupported`.
+    fn open_cursor(&self, _query: &str) -> Result<(), ProviderError> {
+        Err(ProviderError::Unsupported("server-side cursors"))
+    }
+
+    /// Fetch up to `count` rows from the cursor opened by `open_cursor`,
+    /// starting at the 0-based row `start`.
+    ///
+    /// Rows before the last batch can be fetched again. A short batch means
+    /// the result ends within it.
+    fn fetch_cursor(&self, _start: usize, _count: usize) -> Result<QueryResult, ProviderError> {
+        Err(ProviderError::Unsupported("server-side cursors"))
+    }
+
+    /// Close the cursor opened by `open_cursor`.
+    fn close_cursor(&self) -> Result<(), ProviderError> {
+        Err(ProviderError::Unsupported("server-side cursors"))
+    }
+
+    /// Stream the result of a query as CSV (with a header row) into `writer`
+    /// without materializing it in memory, separating fields with `delimiter`
+    /// and quoting values as `quote_style` asks.
+    ///
+    /// `on_progress` is called with the total number of bytes written so far.
+    /// Returns the total number of bytes written.
+    ///
+    /// Default implementation returns `Unsupported`; callers should fall
+    /// back to `execute_query` and a client-side export.
+    fn copy_query_to_csv(
+        &self,
+        _query: &str,
+        _delimiter: Delimiter,
+        _quote_style: QuoteStyle,
+        _writer: &mut dyn Write,
+        _on_progress: &mut dyn FnMut(u64),
+    ) -> Result<u64, ProviderError> {
+        Err(ProviderError::Unsupported("server-side CSV export"))
+    }
+
     /// Get table row count
     fn get_row_count(&self, table_name: &str, schema: Option<&str>)
         -> Result<usize, ProviderError>;
@@ -133,6 +306,57 @@ pub trait DatabaseProvider: Send + Sync {
     /// Get table size in bytes
     fn get_table_size(&self, table_name: &str, schema: Option<&str>) -> Result<u64, ProviderError>;
 
+    /// Get the total size of the connected database in bytes.
+    ///
+    /// Default implementation returns `Unsupported`.
+    fn get_database_size(&self) -> Result<u64, ProviderError> {
+        Err(ProviderError::Unsupported("database size"))
+    }
+
+    /// Get the `limit` largest tables of the connected database, biggest first.
+    ///
+    /// Default implementation returns `Unsupported`.
+    fn get_largest_tables(&self, _limit: usize) -> Result<Vec<TableSize>, ProviderError> {
+        Err(ProviderError::Unsupported("table sizes"))
+    }
+
+    /// Get the client sessions connected to the database, excluding this one.
+    ///
+    /// Default implementation returns `Unsupported`.
+    fn get_active_sessions(&self) -> Result<Vec<ActiveSession>, ProviderError> {
+        Err(ProviderError::Unsupported("session list"))
+    }
+
+    /// Terminate the session served by the backend process `pid`.
+    ///
+    /// Returns `false` if no such session was signalled.
+    /// Default implementation returns `Unsupported`.
+    fn terminate_backend(&self, _pid: i32) -> Result<bool, ProviderError> {
+        Err(ProviderError::Unsupported("terminating sessions"))
+    }
+
+    /// Get the users and roles defined on the server.
+    ///
+    /// Attributes the connected user cannot read are left as `None`.
+    /// Default implementation returns `Unsupported`.
+    fn get_roles(&self) -> Result<Vec<DatabaseRole>, ProviderError> {
+        Err(ProviderError::Unsupported("role list"))
+    }
+
+    /// Get the run-time parameters in effect for the session.
+    ///
+    /// Default implementation returns `Unsupported`.
+    fn get_settings(&self) -> Result<Vec<ServerSetting>, ProviderError> {
+        Err(ProviderError::Unsupported("server settings"))
+    }
+
+    /// Check that `query` parses and refers to existing objects, without running it.
+    ///
+    /// Default implementation returns `Unsupported`.
+    fn validate_query(&self, _query: &str) -> Result<(), ProviderError> {
+        Err(ProviderError::Unsupported("query validation"))
+    }
+
     /// Test the connection
     fn test_connection(&self) -> Result<(), ProviderError>;
 
@@ -150,6 +374,8 @@ pub enum ProviderError {
     Timeout(String),
     InvalidConfiguration(String),
     NotImplemented(String),
+    /// The database does not offer this capability (as opposed to a failure)
+    Unsupported(&'static str),
     /// Internal errors such as mutex poisoning or other synchronization issues
     InternalError(String),
 }
@@ -170,67 +396,138 @@ impl std::fmt::Display for ProviderError {
                 write!(f, "Invalid configuration: {}", msg)
             }
             ProviderError::NotImplemented(msg) => write!(f, "Not implemented: {}", msg),
+            ProviderError::Unsupported(feature) => {
+                write!(f, "Not supported by this database: {}", feature)
+            }
             ProviderError::InternalError(msg) => write!(f, "Internal error: {}", msg),
         }
     }
 }
 
+impl ProviderError {
+    /// Returns true if the error means the feature is unavailable rather than failed
+    pub fn is_unsupported(&self) -> bool {
+        matches!(self, ProviderError::Unsupported(_))
+    }
+}
+
+/// Likely cause of a failed connection attempt, recognized from the driver's error text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionDiagnosis {
+    HostNotFound,
+    Refused,
+    Timeout,
+    SslRequired,
+    HbaRejected,
+    Ssl,
+    AuthFailed,
+    DatabaseMissing,
+    Unknown,
+}
+
+impl ConnectionDiagnosis {
+    /// Classify a connection error message
+    pub fn from_error(msg: &str) -> Self {
+        // Names quoted by the server (user, database, host) must not decide
+        // the diagnosis, e.g. a database called "timeout_logs"
+        let msg = strip_quoted(&msg.to_lowercase());
+        let has = |patterns: &[&str]| patterns.iter().any(|p| msg.contains(p));
+
+        // Specific server messages first, then the generic substrings
+        if has(&["authentication failed", "invalid password"])
+            || (msg.contains("role") && msg.contains("does not exist"))
+        {
+            ConnectionDiagnosis::AuthFailed
+        } else if msg.contains("database") && msg.contains("does not exist") {
+            ConnectionDiagnosis::DatabaseMissing
+        } else if has(&["ssl required", "ssl is required", "requires ssl"]) {
+            ConnectionDiagnosis::SslRequired
+        } else if msg.contains("no pg_hba.conf entry") {
+            // Also names the encryption tried, which does not mean SSL would be accepted
+            ConnectionDiagnosis::HbaRejected
+        } else if has(&[
+            "could not translate host",
+            "failed to lookup address",
+            "name or service not known",
+            "nodename nor servname",
+            "name resolution",
+            "unknown host",
+            "no such host",
+        ]) {
+            ConnectionDiagnosis::HostNotFound
+        } else if has(&["connection refused", "could not connect"]) {
+            ConnectionDiagnosis::Refused
+        } else if has(&["timed out", "timeout"]) {
+            ConnectionDiagnosis::Timeout
+        } else if has(&["ssl", "tls"]) {
+            ConnectionDiagnosis::Ssl
+        } else if has(&["password", "auth"]) && has(&["fail", "denied"]) {
+            ConnectionDiagnosis::AuthFailed
+        } else {
+            ConnectionDiagnosis::Unknown
+        }
+    }
+
+    /// What went wrong and what to check, or `None` when not recognized
+    pub fn explanation(self) -> Option<&'static str> {
+        Some(match self {
+            ConnectionDiagnosis::HostNotFound => {
+                "Host not found: Cannot resolve the hostname. \
+                 Check that the host address is correct."
+            }
+            ConnectionDiagnosis::Refused => {
+                "Connection refused: Cannot connect to the database server. \
+                 Check that the host and port are correct and the server is running."
+            }
+            ConnectionDiagnosis::Timeout => {
+                "Connection timeout: The server did not respond in time. \
+                 Check network connectivity and firewall settings."
+            }
+            ConnectionDiagnosis::SslRequired => {
+                "SSL required: The server rejected the unencrypted connection. \
+                 lazydb connects without SSL, so allow this host in pg_hba.conf \
+                 or connect through an SSH tunnel."
+            }
+            ConnectionDiagnosis::HbaRejected => {
+                "Connection rejected: The server does not allow this host, user and \
+                 database combination (pg_hba.conf). Ask the server administrator \
+                 to add an entry for it."
+            }
+            ConnectionDiagnosis::Ssl => {
+                "SSL/TLS error: Secure connection failed. \
+                 Check SSL configuration and certificates."
+            }
+            ConnectionDiagnosis::AuthFailed => {
+                "Authentication failed: Invalid username or password. \
+                 Check your credentials and try again."
+            }
+            ConnectionDiagnosis::DatabaseMissing => {
+                "Database not found: The specified database does not exist. \
+                 Check the database name and ensure it has been created."
+            }
+            ConnectionDiagnosis::Unknown => return None,
+        })
+    }
+}
+
+/// Remove double-quoted names from an error message
+fn strip_quoted(msg: &str) -> String {
+    let mut quoted = false;
+    msg.chars()
+        .filter(|&c| {
+            if c == '"' {
+                quoted = !quoted;
+            }
+            !quoted && c != '"'
+        })
+        .collect()
+}
+
 /// Format connection error with user-friendly message and hints
 fn format_connection_error(msg: &str) -> String {
-    let msg_lower = msg.to_lowercase();
-
-    if msg_lower.contains("connection refused") || msg_lower.contains("could not connect") {
-        format!(
-            "Connection refused: Cannot connect to the database server. \
-             Check that the host and port are correct and the server is running. \
-             (Details: {})",
-            msg
-        )
-    } else if msg_lower.contains("authentication failed")
-        || msg_lower.contains("invalid password")
-        || ((msg_lower.contains("password") || msg_lower.contains("auth"))
-            && (msg_lower.contains("fail") || msg_lower.contains("denied")))
-    {
-        format!(
-            "Authentication failed: Invalid username or password. \
-             Check your credentials and try again. \
-             (Details: {})",
-            msg
-        )
-    } else if msg_lower.contains("could not translate host")
-        || msg_lower.contains("name resolution")
-        || msg_lower.contains("unknown host")
-        || msg_lower.contains("no such host")
-    {
-        format!(
-            "Host not found: Cannot resolve the hostname. \
-             Check that the host address is correct. \
-             (Details: {})",
-            msg
-        )
-    } else if msg_lower.contains("timed out") || msg_lower.contains("timeout") {
-        format!(
-            "Connection timeout: The server did not respond in time. \
-             Check network connectivity and firewall settings. \
-             (Details: {})",
-            msg
-        )
-    } else if msg_lower.contains("ssl") || msg_lower.contains("tls") {
-        format!(
-            "SSL/TLS error: Secure connection failed. \
-             Check SSL configuration and certificates. \
-             (Details: {})",
-            msg
-        )
-    } else if msg_lower.contains("database") && msg_lower.contains("does not exist") {
-        format!(
-            "Database not found: The specified database does not exist. \
-             Check the database name and ensure it has been created. \
-             (Details: {})",
-            msg
-        )
-    } else {
-        format!("Connection failed: {} (Check connection settings)", msg)
+    match ConnectionDiagnosis::from_error(msg).explanation() {
+        Some(explanation) => format!("{} (Details: {})", explanation, msg),
+        None => format!("Connection failed: {} (Check connection settings)", msg),
     }
 }
 
@@ -377,6 +674,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_diagnose_driver_connection_errors() {
+        // rust-postgres が返す実際のエラーメッセージを分類できるべき
+        let cases = [
+            (
+                "error connecting to server: failed to lookup address information: \
+                 Name or service not known",
+                ConnectionDiagnosis::HostNotFound,
+            ),
+            (
+                "error connecting to server: Connection refused (os error 111)",
+                ConnectionDiagnosis::Refused,
+            ),
+            (
+                "db error: FATAL: no pg_hba.conf entry for host \"10.0.0.5\", user \"app\", \
+                 database \"app\", no encryption",
+                ConnectionDiagnosis::HbaRejected,
+            ),
+            (
+                "db error: FATAL: no pg_hba.conf entry for host \"10.0.0.5\", user \"app\", \
+                 database \"app\", SSL off",
+                ConnectionDiagnosis::HbaRejected,
+            ),
+            (
+                "db error: FATAL: SSL required",
+                ConnectionDiagnosis::SslRequired,
+            ),
+            (
+                "db error: FATAL: password authentication failed for user \"app\"",
+                ConnectionDiagnosis::AuthFailed,
+            ),
+            (
+                "db error: FATAL: role \"nobody\" does not exist",
+                ConnectionDiagnosis::AuthFailed,
+            ),
+            (
+                "db error: FATAL: database \"shop\" does not exist",
+                ConnectionDiagnosis::DatabaseMissing,
+            ),
+            // Quoted names do not decide the diagnosis
+            (
+                "db error: FATAL: password authentication failed for user \"russell\"",
+                ConnectionDiagnosis::AuthFailed,
+            ),
+            (
+                "db error: FATAL: password authentication failed for user \"ssl_admin\"",
+                ConnectionDiagnosis::AuthFailed,
+            ),
+            (
+                "db error: FATAL: database \"timeout_logs\" does not exist",
+                ConnectionDiagnosis::DatabaseMissing,
+            ),
+            (
+                "db error: FATAL: role \"tls_reader\" does not exist",
+                ConnectionDiagnosis::AuthFailed,
+            ),
+            (
+                "db error: FATAL: no pg_hba.conf entry for host \"10.0.0.5\", user \"app\", \
+                 database \"refused_orders\", no encryption",
+                ConnectionDiagnosis::HbaRejected,
+            ),
+            ("unexpected EOF", ConnectionDiagnosis::Unknown),
+        ];
+        for (msg, expected) in cases {
+            assert_eq!(ConnectionDiagnosis::from_error(msg), expected, "{}", msg);
+        }
+    }
+
+    #[test]
+    fn test_connection_failed_keeps_details() {
+        // 分類できない場合も元のメッセージを表示するべき
+        let display = ProviderError::ConnectionFailed("unexpected EOF".to_string()).to_string();
+        assert_eq!(
+            display,
+            "Connection failed: unexpected EOF (Check connection settings)"
+        );
+
+        let display = ProviderError::ConnectionFailed(
+            "db error: FATAL: database \"shop\" does not exist".to_string(),
+        )
+        .to_string();
+        assert!(display.starts_with("Database not found:"), "{}", display);
+        assert!(display.ends_with("(Details: db error: FATAL: database \"shop\" does not exist)"));
+    }
+
     // ===========================================
     // クエリエラーのテスト
     // ===========================================
@@ -521,4 +903,179 @@ mod tests {
             display
         );
     }
+
+    // ===========================================
+    // 未対応機能のテスト
+    // ===========================================
+
+    #[test]
+    fn test_unsupported_displays_feature_name() {
+        let error = ProviderError::Unsupported("server-side cursors");
+        let display = error.to_string();
+
+        // 失敗ではなく未対応であることがわかるべき
+        assert!(
+            display.contains("Not supported") && display.contains("server-side cursors"),
+            "Expected unsupported message, got: {}",
+            display
+        );
+    }
+
+    #[test]
+    fn test_is_unsupported() {
+        assert!(ProviderError::Unsupported("database size").is_unsupported());
+        assert!(!ProviderError::NotImplemented("x".to_string()).is_unsupported());
+        assert!(!ProviderError::QueryFailed("x".to_string()).is_unsupported());
+    }
+
+    // 未対応の機能はデフォルト実装で Unsupported を返すべき
+    struct MinimalProvider;
+
+    impl DatabaseProvider for MinimalProvider {
+        fn database_type(&self) -> DatabaseType {
+            DatabaseType::SQLite
+        }
+        fn get_schemas(&self, _include_system: bool) -> Result<Vec<String>, ProviderError> {
+            Ok(vec![])
+        }
+        fn get_tables(
+            &self,
+            _schema: Option<&str>,
+            _include_system: bool,
+        ) -> Result<Vec<Table>, ProviderError> {
+            Ok(vec![])
+        }
+        fn get_table_details(
+            &self,
+            table_name: &str,
+            _schema: Option<&str>,
+        ) -> Result<Table, ProviderError> {
+            Ok(Table::new(table_name))
+        }
+        fn execute_query(&self, _query: &str) -> Result<QueryResult, ProviderError> {
+            Err(ProviderError::QueryFailed("no".to_string()))
+        }
+        fn get_row_count(
+            &self,
+            _table_name: &str,
+            _schema: Option<&str>,
+        ) -> Result<usize, ProviderError> {
+            Ok(0)
+        }
+        fn get_table_size(
+            &self,
+            _table_name: &str,
+            _schema: Option<&str>,
+        ) -> Result<u64, ProviderError> {
+            Ok(0)
+        }
+        fn test_connection(&self) -> Result<(), ProviderError> {
+            Ok(())
+        }
+        fn get_version(&self) -> Result<String, ProviderError> {
+            Ok(String::new())
+        }
+    }
+
+    #[test]
+    fn test_default_methods_return_unsupported() {
+        let provider = MinimalProvider;
+        assert!(provider.get_database_size().unwrap_err().is_unsupported());
+        assert!(provider.get_largest_tables(5).unwrap_err().is_unsupported());
+        assert!(provider.get_active_sessions().unwrap_err().is_unsupported());
+        assert!(provider.terminate_backend(1).unwrap_err().is_unsupported());
+        assert!(provider.get_roles().unwrap_err().is_unsupported());
+        assert!(provider.get_settings().unwrap_err().is_unsupported());
+        assert!(provider
+            .validate_query("SELECT 1")
+            .unwrap_err()
+            .is_unsupported());
+        assert!(provider
+            .open_cursor("SELECT 1")
+            .unwrap_err()
+            .is_unsupported());
+        assert!(provider.fetch_cursor(0, 10).unwrap_err().is_unsupported());
+        assert!(provider.close_cursor().unwrap_err().is_unsupported());
+
+        let mut out = Vec::new();
+        let result = provider.copy_query_to_csv(
+            "SELECT 1",
+            Delimiter::Comma,
+            QuoteStyle::Necessary,
+            &mut out,
+            &mut |_| {},
+        );
+        assert!(result.unwrap_err().is_unsupported());
+    }
+
+    // ===========================================
+    // 機能フラグのテスト
+    // ===========================================
+
+    #[test]
+    fn test_postgres_capabilities_are_complete() {
+        let caps = ProviderCapabilities::for_database_type(&DatabaseType::PostgreSQL);
+        assert_eq!(caps, ProviderCapabilities::all());
+    }
+
+    #[test]
+    fn test_sqlite_capabilities_are_reduced() {
+        let caps = ProviderCapabilities::for_database_type(&DatabaseType::SQLite);
+        assert!(caps.supports_triggers);
+        assert!(caps.supports_foreign_keys);
+        assert!(!caps.supports_routines);
+        assert!(!caps.supports_materialized_views);
+        assert!(!caps.supports_explain_analyze);
+    }
+
+    #[test]
+    fn test_default_capabilities_follow_database_type() {
+        // デフォルト実装は database_type から導出されるべき
+        let caps = MinimalProvider.capabilities();
+        assert_eq!(
+            caps,
+            ProviderCapabilities::for_database_type(&DatabaseType::SQLite)
+        );
+    }
+
+    #[test]
+    fn test_default_port_per_database_type() {
+        assert_eq!(DatabaseType::PostgreSQL.default_port(), Some(5432));
+        assert_eq!(DatabaseType::MySQL.default_port(), Some(3306));
+        assert_eq!(DatabaseType::SQLite.default_port(), None);
+    }
+
+    // ===========================================
+    // 識別子のクォートのテスト
+    // ===========================================
+
+    #[test]
+    fn test_quote_identifier_per_database_type() {
+        assert_eq!(
+            DatabaseType::PostgreSQL.quote_identifier("Table Name"),
+            "\"Table Name\""
+        );
+        assert_eq!(
+            DatabaseType::PostgreSQL.quote_identifier("a\"b`c"),
+            "\"a\"\"b`c\""
+        );
+        assert_eq!(DatabaseType::MySQL.quote_identifier("order"), "`order`");
+        assert_eq!(
+            DatabaseType::MariaDB.quote_identifier("a`b\"c"),
+            "`a``b\"c`"
+        );
+    }
+
+    #[test]
+    fn test_default_quote_identifier_follows_database_type() {
+        // デフォルト実装は database_type のクォート規則に従うべき
+        assert_eq!(MinimalProvider.quote_identifier("x\"y"), "\"x\"\"y\"");
+    }
+
+    #[test]
+    fn test_database_type_cycle_wraps() {
+        assert_eq!(DatabaseType::PostgreSQL.next(), DatabaseType::MySQL);
+        assert_eq!(DatabaseType::PostgreSQL.prev(), DatabaseType::SQLite);
+        assert_eq!(DatabaseType::SQLite.next(), DatabaseType::PostgreSQL);
+    }
 }
@@ -5,7 +5,7 @@ use std::thread::{self, JoinHandle};
 
 use crate::db::async_bridge::{DbCommand, DbResponse};
 
-use super::DbWorker;
+use super::{ConnectRetry, DbWorker};
 
 /// Handle to the spawned worker thread
 pub struct DbWorkerHandle {
@@ -29,6 +29,15 @@ impl DbWorkerHandle {
         self.response_rx.try_recv()
     }
 
+    /// Whether the worker thread is still running.
+    ///
+    /// The worker stops when it panics or when its command channel closes.
+    pub fn is_alive(&self) -> bool {
+        self.thread_handle
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+    }
+
     /// Shutdown the worker and wait for it to finish
     pub fn shutdown(mut self) {
         let _ = self.command_tx.send(DbCommand::Shutdown);
@@ -51,14 +60,14 @@ impl Drop for DbWorkerHandle {
 /// Spawn a new database worker thread
 ///
 /// Returns a handle that can be used to send commands and receive responses.
-pub fn spawn_db_worker() -> DbWorkerHandle {
+pub fn spawn_db_worker(connect_retry: ConnectRetry) -> DbWorkerHandle {
     let (cmd_tx, cmd_rx) = std::sync::mpsc::channel();
     let (resp_tx, resp_rx) = std::sync::mpsc::channel();
 
     let handle = thread::Builder::new()
         .name("db-worker".to_string())
         .spawn(move || {
-            let worker = DbWorker::new(cmd_rx, resp_tx);
+            let worker = DbWorker::new(cmd_rx, resp_tx).with_connect_retry(connect_retry);
             worker.run();
         })
         .expect("Failed to spawn db-worker thread");
@@ -4,21 +4,41 @@
 //! in the background, preventing UI blocking during network I/O.
 
 mod handle;
+mod retry;
 
 #[cfg(test)]
 mod tests;
 
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
 use std::sync::mpsc::{Receiver, Sender};
 
 use super::async_bridge::{ConnectionParams, DbCommand, DbResponse};
-use super::{DatabaseProvider, PostgresProvider};
+use super::{DatabaseProvider, DatabaseType, PostgresProvider, ProviderError};
+use crate::export::{
+    export_with_progress, prepare_output_path, ExportConfig, ExportError, ExportFormat,
+    ExportOutput, ExportTarget, UTF8_BOM,
+};
+use crate::model::resolve_default_schema;
+use crate::model::schema::TableType;
+
+/// Minimum number of bytes between two progress reports
+const PROGRESS_INTERVAL_BYTES: u64 = 1024 * 1024;
 
 pub use handle::{spawn_db_worker, DbWorkerHandle};
+pub use retry::ConnectRetry;
 
 /// Background worker that processes database commands
 pub struct DbWorker {
     command_rx: Receiver<DbCommand>,
     response_tx: Sender<DbResponse>,
+    /// Connection holding the open browsing cursor, if any
+    cursor: Option<PostgresProvider>,
+    /// Parameters the browsing cursor's connection was opened with
+    cursor_connection: Option<ConnectionParams>,
+    /// Retrying of connection attempts that fail for transient reasons
+    connect_retry: ConnectRetry,
 }
 
 impl DbWorker {
@@ -27,14 +47,23 @@ impl DbWorker {
         Self {
             command_rx,
             response_tx,
+            cursor: None,
+            cursor_connection: None,
+            connect_retry: ConnectRetry::default(),
         }
     }
 
+    /// Retry connection attempts that are refused or time out
+    pub fn with_connect_retry(mut self, connect_retry: ConnectRetry) -> Self {
+        self.connect_retry = connect_retry;
+        self
+    }
+
     /// Run the worker's main loop
     ///
     /// This will block until a Shutdown command is received or the command
     /// channel is closed.
-    pub fn run(self) {
+    pub fn run(mut self) {
         loop {
             match self.command_rx.recv() {
                 Ok(DbCommand::Shutdown) => break,
@@ -45,15 +74,22 @@ impl DbWorker {
     }
 
     /// Handle a single database command
-    fn handle_command(&self, cmd: DbCommand) {
+    fn handle_command(&mut self, cmd: DbCommand) {
         match cmd {
             DbCommand::FetchTables {
                 request_id,
                 connection,
                 schema,
+                include_system,
+                exact_row_counts,
                 target,
             } => {
-                let result = self.fetch_tables(&connection, schema.as_deref());
+                let result = self.fetch_tables(
+                    &connection,
+                    schema.as_deref(),
+                    include_system,
+                    exact_row_counts,
+                );
                 let _ = self.response_tx.send(DbResponse::TablesLoaded {
                     request_id,
                     result,
@@ -82,28 +118,206 @@ impl DbWorker {
                 query,
                 project_idx,
             } => {
-                let result = self.execute_query(&connection, &query);
+                let (result, notices) = self.execute_query(&connection, &query);
                 let _ = self.response_tx.send(DbResponse::QueryExecuted {
                     request_id,
                     result,
+                    notices,
                     project_idx,
                 });
             }
 
+            DbCommand::ValidateQuery {
+                request_id,
+                connection,
+                query,
+            } => {
+                let result = self.validate_query(&connection, &query);
+                let _ = self
+                    .response_tx
+                    .send(DbResponse::QueryValidated { request_id, result });
+            }
+
+            DbCommand::PreviewTable {
+                request_id,
+                connection,
+                table_name,
+                schema,
+                limit,
+            } => {
+                let result = self.preview_table(&connection, &table_name, schema.as_deref(), limit);
+                let _ = self
+                    .response_tx
+                    .send(DbResponse::TablePreviewLoaded { request_id, result });
+            }
+
+            DbCommand::FetchDatabaseStats {
+                request_id,
+                connection,
+                limit,
+            } => {
+                let result = self.fetch_database_stats(&connection, limit);
+                let _ = self
+                    .response_tx
+                    .send(DbResponse::DatabaseStatsLoaded { request_id, result });
+            }
+
+            DbCommand::FetchActiveSessions {
+                request_id,
+                connection,
+            } => {
+                let result = self.fetch_active_sessions(&connection);
+                let _ = self
+                    .response_tx
+                    .send(DbResponse::ActiveSessionsLoaded { request_id, result });
+            }
+
+            DbCommand::FetchRoles {
+                request_id,
+                connection,
+            } => {
+                let result = self.fetch_roles(&connection);
+                let _ = self
+                    .response_tx
+                    .send(DbResponse::RolesLoaded { request_id, result });
+            }
+
+            DbCommand::FetchSettings {
+                request_id,
+                connection,
+            } => {
+                let result = self.fetch_settings(&connection);
+                let _ = self
+                    .response_tx
+                    .send(DbResponse::SettingsLoaded { request_id, result });
+            }
+
+            DbCommand::TerminateBackend {
+                request_id,
+                connection,
+                pid,
+            } => {
+                let result = self.terminate_backend(&connection, pid);
+                let _ = self.response_tx.send(DbResponse::BackendTerminated {
+                    request_id,
+                    pid,
+                    result,
+                });
+            }
+
+            DbCommand::OpenCursor {
+                request_id,
+                connection,
+                query,
+                fetch_size,
+            } => {
+                let (result, has_more) = match self.open_cursor(&connection, &query) {
+                    Ok(None) => {
+                        let (result, has_more) = self.fetch_cursor(0, fetch_size);
+                        // A result that fits in one batch is never fetched again
+                        if !has_more {
+                            self.close_cursor();
+                        }
+                        (result, has_more)
+                    }
+                    Ok(Some(all_rows)) => (Ok(all_rows), false),
+                    Err(e) => (Err(e), false),
+                };
+                let _ = self.response_tx.send(DbResponse::RowsFetched {
+                    request_id,
+                    result,
+                    first_batch: true,
+                    has_more,
+                });
+            }
+
+            DbCommand::FetchCursor {
+                request_id,
+                start,
+                fetch_size,
+            } => {
+                let (result, has_more) = self.fetch_cursor(start, fetch_size);
+                let _ = self.response_tx.send(DbResponse::RowsFetched {
+                    request_id,
+                    result,
+                    first_batch: false,
+                    has_more,
+                });
+            }
+
+            DbCommand::CloseCursor => self.close_cursor(),
+
+            DbCommand::ExportQuery {
+                request_id,
+                connection,
+                query,
+                target,
+                config,
+            } => {
+                let result = self.export_query(request_id, &connection, &query, &target, &config);
+                let _ = self.response_tx.send(DbResponse::ExportCompleted {
+                    request_id,
+                    result,
+                    target,
+                });
+            }
+
+            DbCommand::Reconnect {
+                request_id,
+                connection,
+                target,
+            } => {
+                let result = self.reconnect(&connection);
+                let _ = self.response_tx.send(DbResponse::Reconnected {
+                    request_id,
+                    result,
+                    target,
+                });
+            }
+
             DbCommand::Shutdown => {
                 // Already handled in run()
             }
         }
     }
 
-    /// Create a provider connection and fetch tables
+    /// Create a provider connection and fetch tables.
+    ///
+    /// With `include_system`, tables of the provider's system schemas follow
+    /// the connection's own schema.
     fn fetch_tables(
         &self,
         conn: &ConnectionParams,
         schema: Option<&str>,
+        include_system: bool,
+        exact_row_counts: bool,
     ) -> Result<Vec<crate::model::Table>, String> {
         let provider = self.create_provider(conn)?;
-        provider.get_tables(schema).map_err(|e| e.to_string())
+        // Without a configured schema, ask the server which one the session uses
+        let detected = match schema {
+            Some(_) => None,
+            None => provider.current_schema().ok().flatten(),
+        };
+        let schema = resolve_default_schema(schema, detected.as_deref());
+        let mut tables = provider
+            .get_tables(Some(&schema), include_system)
+            .map_err(|e| e.to_string())?;
+
+        if include_system {
+            for system_schema in provider.system_schemas() {
+                if *system_schema != schema {
+                    tables.extend(
+                        provider
+                            .get_tables(Some(system_schema), true)
+                            .map_err(|e| e.to_string())?,
+                    );
+                }
+            }
+        }
+        if exact_row_counts {
+            count_rows_exactly(&provider, &mut tables);
+        }
+        Ok(tables)
     }
 
     /// Create a provider connection and fetch table details
@@ -119,25 +333,302 @@ impl DbWorker {
             .map_err(|e| e.to_string())
     }
 
-    /// Create a provider connection and execute a query
+    /// Create a provider connection and execute a query.
+    ///
+    /// Also returns the notices the server raised while running it, which
+    /// are kept even when the query fails.
     fn execute_query(
         &self,
         conn: &ConnectionParams,
         query: &str,
+    ) -> (Result<crate::model::QueryResult, String>, Vec<String>) {
+        let provider = match self.create_provider(conn) {
+            Ok(provider) => provider,
+            Err(e) => return (Err(e), Vec::new()),
+        };
+        let result = provider.execute_query(query).map_err(|e| e.to_string());
+        (result, provider.take_notices())
+    }
+
+    /// Create a provider connection and fetch the database size summary.
+    ///
+    /// Returns `Ok(None)` if the provider does not support it.
+    fn fetch_database_stats(
+        &self,
+        conn: &ConnectionParams,
+        limit: usize,
+    ) -> Result<Option<crate::model::DatabaseStats>, String> {
+        let provider = self.create_provider(conn)?;
+        let stats = provider.get_database_size().and_then(|database_size| {
+            provider
+                .get_largest_tables(limit)
+                .map(|largest_tables| crate::model::DatabaseStats {
+                    database_size,
+                    largest_tables,
+                })
+        });
+        match stats {
+            Ok(stats) => Ok(Some(stats)),
+            Err(e) if e.is_unsupported() => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Create a provider connection and list the sessions connected to the database.
+    ///
+    /// Returns `Ok(None)` if the provider does not support it.
+    fn fetch_active_sessions(
+        &self,
+        conn: &ConnectionParams,
+    ) -> Result<Option<Vec<crate::model::ActiveSession>>, String> {
+        let provider = self.create_provider(conn)?;
+        match provider.get_active_sessions() {
+            Ok(sessions) => Ok(Some(sessions)),
+            Err(e) if e.is_unsupported() => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Create a provider connection and list the roles defined on the server.
+    ///
+    /// Returns `Ok(None)` if the provider does not support it.
+    fn fetch_roles(
+        &self,
+        conn: &ConnectionParams,
+    ) -> Result<Option<Vec<crate::model::DatabaseRole>>, String> {
+        let provider = self.create_provider(conn)?;
+        match provider.get_roles() {
+            Ok(roles) => Ok(Some(roles)),
+            Err(e) if e.is_unsupported() => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Create a provider connection and list the session's run-time parameters.
+    ///
+    /// Returns `Ok(None)` if the provider does not support it.
+    fn fetch_settings(
+        &self,
+        conn: &ConnectionParams,
+    ) -> Result<Option<Vec<crate::model::ServerSetting>>, String> {
+        let provider = self.create_provider(conn)?;
+        match provider.get_settings() {
+            Ok(settings) => Ok(Some(settings)),
+            Err(e) if e.is_unsupported() => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Create a provider connection and check `query` without executing it
+    fn validate_query(&self, conn: &ConnectionParams, query: &str) -> Result<(), String> {
+        let provider = self.create_provider(conn)?;
+        provider.validate_query(query).map_err(|e| e.to_string())
+    }
+
+    /// Create a provider connection and fetch the first `limit` rows of a table
+    fn preview_table(
+        &self,
+        conn: &ConnectionParams,
+        table_name: &str,
+        schema: Option<&str>,
+        limit: usize,
     ) -> Result<crate::model::QueryResult, String> {
         let provider = self.create_provider(conn)?;
-        provider.execute_query(query).map_err(|e| e.to_string())
+        let quote = |name: &str| provider.quote_identifier(name);
+        let relation = match schema {
+            Some(schema) => format!("{}.{}", quote(schema), quote(table_name)),
+            None => quote(table_name),
+        };
+        provider
+            .execute_query(&format!("SELECT * FROM {} LIMIT {}", relation, limit))
+            .map_err(|e| e.to_string())
+    }
+
+    /// Create a provider connection and terminate the backend `pid`
+    fn terminate_backend(&self, conn: &ConnectionParams, pid: i32) -> Result<bool, String> {
+        let provider = self.create_provider(conn)?;
+        provider.terminate_backend(pid).map_err(|e| e.to_string())
+    }
+
+    /// Create a dedicated connection and open a browsing cursor on it,
+    /// replacing any cursor that is already open.
+    ///
+    /// Returns the full result instead when the provider has no cursors.
+    fn open_cursor(
+        &mut self,
+        conn: &ConnectionParams,
+        query: &str,
+    ) -> Result<Option<crate::model::QueryResult>, String> {
+        self.close_cursor();
+        let provider = self.create_provider(conn)?;
+        match provider.open_cursor(query) {
+            Ok(()) => {
+                self.cursor = Some(provider);
+                self.cursor_connection = Some(conn.clone());
+                Ok(None)
+            }
+            Err(e) if e.is_unsupported() => provider
+                .execute_query(query)
+                .map(Some)
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Fetch a batch starting at row `start` from the open cursor.
+    ///
+    /// Returns the batch and whether more rows may follow it. The cursor
+    /// stays open so the app can fetch earlier rows again; it is closed if
+    /// fetching fails.
+    fn fetch_cursor(
+        &mut self,
+        start: usize,
+        fetch_size: usize,
+    ) -> (Result<crate::model::QueryResult, String>, bool) {
+        let Some(provider) = self.cursor.as_ref() else {
+            return (Err("No open cursor".to_string()), false);
+        };
+        match provider.fetch_cursor(start, fetch_size) {
+            Ok(result) => {
+                let has_more = result.rows.len() >= fetch_size;
+                (Ok(result), has_more)
+            }
+            Err(e) => {
+                self.close_cursor();
+                (Err(e.to_string()), false)
+            }
+        }
+    }
+
+    /// Close the open cursor and release its connection
+    fn close_cursor(&mut self) {
+        self.cursor_connection = None;
+        if let Some(provider) = self.cursor.take() {
+            let _ = provider.close_cursor();
+        }
+    }
+
+    /// Drop the cursor connection if it belongs to `conn`, then check that a
+    /// fresh connection can be established.
+    ///
+    /// Other operations connect on demand, so the cursor is the only
+    /// connection the worker keeps open.
+    fn reconnect(&mut self, conn: &ConnectionParams) -> Result<(), String> {
+        if self
+            .cursor_connection
+            .as_ref()
+            .is_some_and(|c| c.same_target(conn))
+        {
+            self.close_cursor();
+        }
+        let provider = self.create_provider(conn)?;
+        provider.test_connection().map_err(|e| e.to_string())
+    }
+
+    /// Create a provider connection and export a query's result.
+    ///
+    /// CSV files are streamed from the server when the provider supports it.
+    /// Otherwise the query is executed and exported client-side.
+    fn export_query(
+        &self,
+        request_id: u64,
+        conn: &ConnectionParams,
+        query: &str,
+        target: &ExportTarget,
+        config: &ExportConfig,
+    ) -> Result<ExportOutput, String> {
+        let provider = self.create_provider(conn)?;
+
+        if let (ExportTarget::File(path), ExportFormat::Csv) = (target, config.format) {
+            prepare_output_path(path, config).map_err(|e| e.to_string())?;
+            let file = File::create(path)
+                .map_err(|e| ExportError::from_io_error(e, path.to_path_buf()).to_string())?;
+            let mut writer = BufWriter::new(file);
+            // COPY output is UTF-8, so the BOM goes in front of it as is
+            let bom: &[u8] = if config.writes_bom() { UTF8_BOM } else { &[] };
+            writer
+                .write_all(bom)
+                .map_err(|e| ExportError::from_io_error(e, path.to_path_buf()).to_string())?;
+            let mut last_reported = 0u64;
+            let mut on_progress = |bytes: u64| {
+                if bytes - last_reported >= PROGRESS_INTERVAL_BYTES {
+                    last_reported = bytes;
+                    let _ = self
+                        .response_tx
+                        .send(DbResponse::Progress { request_id, bytes });
+                }
+            };
+
+            match provider.copy_query_to_csv(
+                query,
+                config.delimiter,
+                config.quote_style,
+                &mut writer,
+                &mut on_progress,
+            ) {
+                Err(ProviderError::Unsupported(_)) => {}
+                result => {
+                    return result
+                        .map(|bytes| ExportOutput::File(bytes + bom.len() as u64))
+                        .map_err(|e| e.to_string())
+                }
+            }
+        }
+
+        // Client-side fallback through QueryResult
+        let result = provider.execute_query(query).map_err(|e| e.to_string())?;
+        let mut on_rows = |rows: u64| {
+            let _ = self
+                .response_tx
+                .send(DbResponse::RowProgress { request_id, rows });
+        };
+        export_with_progress(&result, target, config, Some(&mut on_rows)).map_err(|e| e.to_string())
     }
 
     /// Create a new database provider from connection parameters
     fn create_provider(&self, conn: &ConnectionParams) -> Result<PostgresProvider, String> {
-        PostgresProvider::connect(
-            &conn.host,
-            conn.port,
-            &conn.database,
-            &conn.username,
-            &conn.password,
+        // Runs on the worker thread, so sleeping between attempts is fine
+        let provider = retry::connect_with_retry(
+            self.connect_retry,
+            || {
+                PostgresProvider::connect(
+                    &conn.host,
+                    conn.port,
+                    &conn.database,
+                    &conn.username,
+                    &conn.password,
+                )
+            },
+            std::thread::sleep,
         )
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+
+        // Connections are opened per command, so the session's search_path
+        // and statement timeout are re-applied to each of them
+        if let Some(path) = &conn.search_path {
+            provider.set_search_path(path).map_err(|e| e.to_string())?;
+        }
+        if let Some(secs) = conn.statement_timeout_secs {
+            provider
+                .set_statement_timeout(secs)
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(provider)
+    }
+}
+
+/// Replace the estimated row counts of `tables` with `COUNT(*)` results.
+///
+/// Views and foreign tables are skipped since counting them runs their query
+/// or scans the remote server. A table whose count fails keeps its estimate.
+fn count_rows_exactly(provider: &impl DatabaseProvider, tables: &mut [crate::model::Table]) {
+    for table in tables
+        .iter_mut()
+        .filter(|t| !matches!(t.table_type, TableType::View | TableType::ForeignTable))
+    {
+        if let Ok(count) = provider.get_row_count(&table.name, table.schema.as_deref()) {
+            table.row_count = count;
+            table.row_count_exact = true;
+        }
     }
 }
@@ -0,0 +1,133 @@
+//! Retrying connection attempts that failed for transient reasons
+
+use std::time::Duration;
+
+use crate::db::provider::ConnectionDiagnosis;
+use crate::db::ProviderError;
+
+/// How often and how patiently a failed connection attempt is repeated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConnectRetry {
+    /// Attempts made after the first one fails (0 disables retrying)
+    pub retries: u32,
+    /// Wait before the first retry; doubled before each further retry
+    pub backoff: Duration,
+}
+
+impl ConnectRetry {
+    pub fn new(retries: u32, backoff: Duration) -> Self {
+        Self { retries, backoff }
+    }
+
+    /// Wait before retry number `attempt` (starting at 0)
+    fn delay(&self, attempt: u32) -> Duration {
+        self.backoff.saturating_mul(2u32.saturating_pow(attempt))
+    }
+}
+
+/// Whether a connection error may go away by itself.
+///
+/// Only refused and timed-out connections are retried; authentication and
+/// configuration errors fail the same way every time.
+pub fn is_transient(error: &ProviderError) -> bool {
+    match error {
+        ProviderError::Timeout(_) => true,
+        ProviderError::ConnectionFailed(msg) => matches!(
+            ConnectionDiagnosis::from_error(msg),
+            ConnectionDiagnosis::Refused | ConnectionDiagnosis::Timeout
+        ),
+        _ => false,
+    }
+}
+
+/// Call `connect` until it succeeds, fails permanently or runs out of retries,
+/// calling `sleep` with the backoff between attempts
+pub fn connect_with_retry<T>(
+    policy: ConnectRetry,
+    mut connect: impl FnMut() -> Result<T, ProviderError>,
+    mut sleep: impl FnMut(Duration),
+) -> Result<T, ProviderError> {
+    let mut attempt = 0;
+    loop {
+        match connect() {
+            Err(e) if attempt < policy.retries && is_transient(&e) => {
+                sleep(policy.delay(attempt));
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn refused() -> ProviderError {
+        ProviderError::ConnectionFailed(
+            "error connecting to server: Connection refused (os error 111)".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_retries_transient_failures_with_backoff() {
+        let policy = ConnectRetry::new(3, Duration::from_millis(100));
+        let mut failures = 2;
+        let mut waits = Vec::new();
+
+        let result = connect_with_retry(
+            policy,
+            || {
+                if failures > 0 {
+                    failures -= 1;
+                    Err(refused())
+                } else {
+                    Ok("connected")
+                }
+            },
+            |wait| waits.push(wait),
+        );
+
+        assert_eq!(result.unwrap(), "connected");
+        assert_eq!(
+            waits,
+            vec![Duration::from_millis(100), Duration::from_millis(200)]
+        );
+    }
+
+    #[test]
+    fn test_gives_up_after_the_configured_retries() {
+        let policy = ConnectRetry::new(2, Duration::ZERO);
+        let mut attempts = 0;
+        let result: Result<(), _> = connect_with_retry(
+            policy,
+            || {
+                attempts += 1;
+                Err(refused())
+            },
+            |_| {},
+        );
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn test_never_retries_authentication_failures() {
+        let policy = ConnectRetry::new(5, Duration::ZERO);
+        let mut attempts = 0;
+        let result: Result<(), _> = connect_with_retry(
+            policy,
+            || {
+                attempts += 1;
+                Err(ProviderError::ConnectionFailed(
+                    "db error: FATAL: password authentication failed for user \"app\"".to_string(),
+                ))
+            },
+            |_| {},
+        );
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+}
@@ -68,15 +68,34 @@ fn test_worker_channel_close() {
 
 #[test]
 fn test_spawn_db_worker() {
-    let handle = spawn_db_worker();
+    let handle = spawn_db_worker(ConnectRetry::default());
 
     // Shutdown cleanly
     handle.shutdown();
 }
 
+#[test]
+fn test_dropped_command_sender_is_detected() {
+    let mut handle = spawn_db_worker(ConnectRetry::default());
+    assert!(handle.is_alive());
+
+    // Replacing the sender drops the only one, which closes the command channel
+    handle.command_tx = mpsc::channel().0;
+
+    let start = Instant::now();
+    while handle.is_alive() {
+        assert!(start.elapsed() < TEST_TIMEOUT, "worker did not stop");
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(matches!(
+        handle.try_recv(),
+        Err(mpsc::TryRecvError::Disconnected)
+    ));
+}
+
 #[test]
 fn test_worker_handle_drop() {
-    let handle = spawn_db_worker();
+    let handle = spawn_db_worker(ConnectRetry::default());
 
     // Just drop it - should shutdown cleanly via Drop impl
     drop(handle);
@@ -84,7 +103,7 @@ fn test_worker_handle_drop() {
 
 #[test]
 fn test_fetch_tables_connection_error() {
-    let handle = spawn_db_worker();
+    let handle = spawn_db_worker(ConnectRetry::default());
 
     // Send a command with invalid connection params
     let invalid_conn = ConnectionParams {
@@ -93,6 +112,8 @@ fn test_fetch_tables_connection_error() {
         database: "testdb".to_string(),
         username: "testuser".to_string(),
         password: "testpass".to_string(),
+        search_path: None,
+        statement_timeout_secs: None,
     };
 
     handle
@@ -100,6 +121,8 @@ fn test_fetch_tables_connection_error() {
             request_id: 1,
             connection: invalid_conn,
             schema: Some("public".to_string()),
+            include_system: false,
+            exact_row_counts: false,
             target: (0, 0),
         })
         .unwrap();
@@ -123,7 +146,7 @@ fn test_fetch_tables_connection_error() {
 
 #[test]
 fn test_fetch_table_details_connection_error() {
-    let handle = spawn_db_worker();
+    let handle = spawn_db_worker(ConnectRetry::default());
 
     let invalid_conn = ConnectionParams {
         host: "invalid-host-that-does-not-exist.local".to_string(),
@@ -131,6 +154,8 @@ fn test_fetch_table_details_connection_error() {
         database: "testdb".to_string(),
         username: "testuser".to_string(),
         password: "testpass".to_string(),
+        search_path: None,
+        statement_timeout_secs: None,
     };
 
     handle
@@ -161,7 +186,7 @@ fn test_fetch_table_details_connection_error() {
 
 #[test]
 fn test_execute_query_connection_error() {
-    let handle = spawn_db_worker();
+    let handle = spawn_db_worker(ConnectRetry::default());
 
     let invalid_conn = ConnectionParams {
         host: "invalid-host-that-does-not-exist.local".to_string(),
@@ -169,6 +194,8 @@ fn test_execute_query_connection_error() {
         database: "testdb".to_string(),
         username: "testuser".to_string(),
         password: "testpass".to_string(),
+        search_path: None,
+        statement_timeout_secs: None,
     };
 
     handle
@@ -196,9 +223,85 @@ fn test_execute_query_connection_error() {
     handle.shutdown();
 }
 
+#[test]
+fn test_reconnect_connection_error() {
+    let handle = spawn_db_worker(ConnectRetry::default());
+
+    let invalid_conn = ConnectionParams {
+        host: "invalid-host-that-does-not-exist.local".to_string(),
+        port: 5432,
+        database: "testdb".to_string(),
+        username: "testuser".to_string(),
+        password: "testpass".to_string(),
+        search_path: None,
+        statement_timeout_secs: None,
+    };
+
+    handle
+        .send(DbCommand::Reconnect {
+            request_id: 4,
+            connection: invalid_conn,
+            target: (0, 1),
+        })
+        .unwrap();
+
+    match wait_for_response(&handle) {
+        DbResponse::Reconnected {
+            request_id,
+            result,
+            target,
+        } => {
+            assert_eq!(request_id, 4);
+            assert_eq!(target, (0, 1));
+            assert!(result.is_err());
+        }
+        _ => panic!("Expected Reconnected response"),
+    }
+
+    handle.shutdown();
+}
+
+#[test]
+fn test_terminate_backend_connection_error() {
+    let handle = spawn_db_worker(ConnectRetry::default());
+
+    let invalid_conn = ConnectionParams {
+        host: "invalid-host-that-does-not-exist.local".to_string(),
+        port: 5432,
+        database: "testdb".to_string(),
+        username: "testuser".to_string(),
+        password: "testpass".to_string(),
+        search_path: None,
+        statement_timeout_secs: None,
+    };
+
+    handle
+        .send(DbCommand::TerminateBackend {
+            request_id: 5,
+            connection: invalid_conn,
+            pid: 4242,
+        })
+        .unwrap();
+
+    match wait_for_response(&handle) {
+        DbResponse::BackendTerminated {
+            request_id,
+            pid,
+            result,
+        } => {
+            assert_eq!(request_id, 5);
+            assert_eq!(pid, 4242);
+            assert!(result.is_err());
+        }
+        _ => panic!("Expected BackendTerminated response"),
+    }
+
+    handle.shutdown();
+}
+
 #[test]
 fn test_multiple_commands() {
-    let handle = spawn_db_worker();
+    let handle = spawn_db_worker(ConnectRetry::default());
 
     let invalid_conn = ConnectionParams {
         host: "invalid-host".to_string(),
@@ -206,6 +309,8 @@ fn test_multiple_commands() {
         database: "testdb".to_string(),
         username: "testuser".to_string(),
         password: "testpass".to_string(),
+        search_path: None,
+        statement_timeout_secs: None,
     };
 
     // Send multiple commands
@@ -215,6 +320,8 @@ fn test_multiple_commands() {
                 request_id: i,
                 connection: invalid_conn.clone(),
                 schema: None,
+                include_system: false,
+                exact_row_counts: false,
                 target: (0, i as usize),
             })
             .unwrap();
@@ -241,3 +348,75 @@ fn test_multiple_commands() {
 
     handle.shutdown();
 }
+
+/// Provider that answers `COUNT(*)` for every table except `broken`
+struct CountingProvider;
+
+impl DatabaseProvider for CountingProvider {
+    fn database_type(&self) -> crate::db::DatabaseType {
+        crate::db::DatabaseType::PostgreSQL
+    }
+    fn get_schemas(&self, _include_system: bool) -> Result<Vec<String>, ProviderError> {
+        Ok(vec![])
+    }
+    fn get_tables(
+        &self,
+        _schema: Option<&str>,
+        _include_system: bool,
+    ) -> Result<Vec<crate::model::Table>, ProviderError> {
+        Ok(vec![])
+    }
+    fn get_table_details(
+        &self,
+        table_name: &str,
+        _schema: Option<&str>,
+    ) -> Result<crate::model::Table, ProviderError> {
+        Ok(crate::model::Table::new(table_name))
+    }
+    fn execute_query(&self, _query: &str) -> Result<crate::model::QueryResult, ProviderError> {
+        Err(ProviderError::QueryFailed("no".to_string()))
+    }
+    fn get_row_count(
+        &self,
+        table_name: &str,
+        _schema: Option<&str>,
+    ) -> Result<usize, ProviderError> {
+        match table_name {
+            "broken" => Err(ProviderError::QueryFailed("permission denied".to_string())),
+            _ => Ok(42),
+        }
+    }
+    fn get_table_size(
+        &self,
+        _table_name: &str,
+        _schema: Option<&str>,
+    ) -> Result<u64, ProviderError> {
+        Ok(0)
+    }
+    fn test_connection(&self) -> Result<(), ProviderError> {
+        Ok(())
+    }
+    fn get_version(&self) -> Result<String, ProviderError> {
+        Ok(String::new())
+    }
+}
+
+#[test]
+fn test_exact_row_counts_replace_estimates() {
+    use crate::model::Table;
+
+    let mut tables = vec![
+        Table::new("users").with_stats(40, 0),
+        Table::new("active_users")
+            .with_table_type(TableType::View)
+            .with_stats(0, 0),
+        Table::new("broken").with_stats(7, 0),
+    ];
+    count_rows_exactly(&CountingProvider, &mut tables);
+
+    assert_eq!((tables[0].row_count, tables[0].row_count_exact), (42, true));
+    // Views are not counted
+    assert_eq!((tables[1].row_count, tables[1].row_count_exact), (0, false));
+    // A failed count keeps the estimate
+    assert_eq!((tables[2].row_count, tables[2].row_count_exact), (7, false));
+}
@@ -0,0 +1,290 @@
+//! Keybinding reference used by the help overlay
+//!
+//! Every normal-mode entry names the key event and the message it produces;
+//! the tests below replay them through [`handle_normal_input`](super::handle_normal_input)
+//! so the help overlay cannot drift from the actual key handling.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::message::Message;
+
+/// Where a keybinding applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyContext {
+    Global,
+    Projects,
+    Connections,
+    Editor,
+    Schema,
+    Data,
+    Modal,
+}
+
+impl KeyContext {
+    pub const ALL: [KeyContext; 7] = [
+        KeyContext::Global,
+        KeyContext::Projects,
+        KeyContext::Connections,
+        KeyContext::Editor,
+        KeyContext::Schema,
+        KeyContext::Data,
+        KeyContext::Modal,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            KeyContext::Global => "Global",
+            KeyContext::Projects => "Sidebar: Projects",
+            KeyContext::Connections => "Sidebar: Connections",
+            KeyContext::Editor => "Query Editor",
+            KeyContext::Schema => "Schema Tab",
+            KeyContext::Data => "Data Tab",
+            KeyContext::Modal => "Modals",
+        }
+    }
+}
+
+/// A documented keybinding
+#[derive(Debug)]
+pub struct KeyBinding {
+    pub context: KeyContext,
+    /// Keys as shown to the user (may list alternatives)
+    pub keys: &'static str,
+    pub description: &'static str,
+    /// Representative key event and the message it sends
+    #[allow(dead_code)] // Checked against the key handlers in tests
+    pub key: (KeyCode, KeyModifiers),
+    #[allow(dead_code)] // Checked against the key handlers in tests
+    pub message: Message,
+    /// Key typed before `key` for two-key sequences such as `zM`
+    #[allow(dead_code)] // Checked against the key handlers in tests
+    pub prefix: Option<char>,
+}
+
+const fn bind(
+    context: KeyContext,
+    keys: &'static str,
+    description: &'static str,
+    key: (KeyCode, KeyModifiers),
+    message: Message,
+) -> KeyBinding {
+    KeyBinding {
+        context,
+        keys,
+        description,
+        key,
+        message,
+        prefix: None,
+    }
+}
+
+/// A binding pressed after `prefix` (e.g. `z` then `M`)
+const fn bind_seq(
+    context: KeyContext,
+    keys: &'static str,
+    description: &'static str,
+    prefix: char,
+    key: (KeyCode, KeyModifiers),
+    message: Message,
+) -> KeyBinding {
+    KeyBinding {
+        context,
+        keys,
+        description,
+        key,
+        message,
+        prefix: Some(prefix),
+    }
+}
+
+const NONE: KeyModifiers = KeyModifiers::NONE;
+const SHIFT: KeyModifiers = KeyModifiers::SHIFT;
+const CTRL: KeyModifiers = KeyModifiers::CONTROL;
+
+const fn ch(c: char) -> KeyCode {
+    KeyCode::Char(c)
+}
+
+#[rustfmt::skip]
+pub const KEYBINDINGS: &[KeyBinding] = &[
+    // Global
+    bind(KeyContext::Global, "q / Ctrl+c", "Quit", (ch('q'), NONE), Message::Quit),
+    bind(KeyContext::Global, "?", "Show this help", (ch('?'), NONE), Message::OpenHelp),
+    bind(KeyContext::Global, "j / ↓", "Move down", (ch('j'), NONE), Message::NavigateDown),
+    bind(KeyContext::Global, "k / ↑", "Move up", (ch('k'), NONE), Message::NavigateUp),
+    bind(KeyContext::Global, "Tab", "Next pane", (KeyCode::Tab, NONE), Message::NextFocus),
+    bind(KeyContext::Global, "Shift+Tab", "Previous pane", (KeyCode::BackTab, SHIFT), Message::PrevFocus),
+    bind(KeyContext::Global, "Shift+h / Shift+←", "Pane to the left", (ch('H'), SHIFT), Message::FocusLeft),
+    bind(KeyContext::Global, "Shift+l / Shift+→", "Pane to the right", (ch('L'), SHIFT), Message::FocusRight),
+    bind(KeyContext::Global, "Shift+k / Shift+↑", "Pane above", (ch('K'), SHIFT), Message::FocusUp),
+    bind(KeyContext::Global, "Shift+j / Shift+↓", "Pane below", (ch('J'), SHIFT), Message::FocusDown),
+    bind(KeyContext::Global, "s", "Schema tab", (ch('s'), NONE), Message::SwitchToSchema),
+    bind(KeyContext::Global, "r", "Relations tab", (ch('r'), NONE), Message::SwitchToRelations),
+    bind(KeyContext::Global, "Ctrl+p", "Search all projects", (ch('p'), CTRL), Message::OpenGlobalSearchModal),
+    bind(KeyContext::Global, "Ctrl+r", "Query history", (ch('r'), CTRL), Message::OpenHistoryModal),
+    bind(KeyContext::Global, "Ctrl+o", "Recently opened tables", (ch('o'), CTRL), Message::OpenRecentTables),
+    bind(KeyContext::Global, "Ctrl+l", "Clear status and redraw", (ch('l'), CTRL), Message::ClearStatus),
+    bind(KeyContext::Global, "F5", "Reload config and project files from disk", (KeyCode::F(5), NONE), Message::ReloadConfig),
+    bind(KeyContext::Global, "1-6", "Schema sub-tabs", (ch('1'), NONE), Message::SwitchToColumns),
+    // Sidebar: projects
+    bind(KeyContext::Projects, "Enter", "Open project", (KeyCode::Enter, NONE), Message::Activate),
+    bind(KeyContext::Projects, "a", "Add project", (ch('a'), NONE), Message::OpenAddProjectModal),
+    bind(KeyContext::Projects, "e", "Edit project", (ch('e'), NONE), Message::OpenEditProjectModal),
+    bind(KeyContext::Projects, "d", "Delete project", (ch('d'), NONE), Message::DeleteProject),
+    bind(KeyContext::Projects, "Shift+c", "Clone project", (ch('C'), SHIFT), Message::CloneProject),
+    bind(KeyContext::Projects, "Shift+s", "Create sample project", (ch('S'), SHIFT), Message::CreateSampleProject),
+    bind(KeyContext::Projects, "Shift+e", "Export project", (ch('E'), SHIFT), Message::ExportProject),
+    bind(KeyContext::Projects, "Shift+i", "Import project", (ch('I'), SHIFT), Message::ImportProject),
+    bind(KeyContext::Projects, "/", "Search projects", (ch('/'), NONE), Message::OpenSearchProjectModal),
+    bind(KeyContext::Projects, "Shift+w", "Project files that failed to load", (ch('W'), SHIFT), Message::ShowLoadErrors),
+    // Sidebar: connections
+    bind(KeyContext::Connections, "Enter", "Expand connection / open table", (KeyCode::Enter, NONE), Message::Activate),
+    bind(KeyContext::Connections, "Backspace", "Back to projects", (KeyCode::Backspace, NONE), Message::GoBack),
+    bind(KeyContext::Connections, "a", "Add connection", (ch('a'), NONE), Message::OpenAddConnectionModal),
+    bind(KeyContext::Connections, "Shift+c", "Clone connection", (ch('C'), SHIFT), Message::CloneConnection),
+    bind(KeyContext::Connections, "Shift+i", "Import from .pgpass / pg_service.conf", (ch('I'), SHIFT), Message::ImportConnections),
+    bind(KeyContext::Connections, "/", "Search connections and tables", (ch('/'), NONE), Message::OpenUnifiedSearchModal),
+    bind(KeyContext::Connections, "i", "Database size summary", (ch('i'), NONE), Message::ShowDatabaseStats),
+    bind(KeyContext::Connections, "Shift+a", "Active sessions (terminate with x)", (ch('A'), SHIFT), Message::ShowActiveSessions),
+    bind(KeyContext::Connections, "Shift+u", "Users and roles", (ch('U'), SHIFT), Message::ShowRoles),
+    bind(KeyContext::Connections, "Shift+v", "Server settings (SHOW ALL)", (ch('V'), SHIFT), Message::ShowServerSettings),
+    bind(KeyContext::Connections, "Shift+p", "Set the session search_path", (ch('P'), SHIFT), Message::SetSearchPath),
+    bind(KeyContext::Connections, "Shift+n", "Snippets of the connection", (ch('N'), SHIFT), Message::OpenConnectionSnippets),
+    bind(KeyContext::Connections, "y", "Copy the selected table's qualified name", (ch('y'), NONE), Message::CopyTableName),
+    bind(KeyContext::Connections, "c", "Reconnect", (ch('c'), NONE), Message::Reconnect),
+    bind(KeyContext::Connections, "Shift+r", "Refresh the tables of every expanded connection", (ch('R'), SHIFT), Message::RefreshAll),
+    bind_seq(KeyContext::Connections, "z M", "Collapse all connections", 'z', (ch('M'), SHIFT), Message::CollapseAll),
+    bind_seq(KeyContext::Connections, "z R", "Expand all connections", 'z', (ch('R'), SHIFT), Message::ExpandAll),
+    bind(KeyContext::Connections, "Shift+s", "Show / hide system schemas", (ch('S'), SHIFT), Message::ToggleSystemObjects),
+    // Query editor
+    bind(KeyContext::Editor, "Enter", "Run query on the selected connection", (KeyCode::Enter, NONE), Message::ExecuteQuery),
+    bind(KeyContext::Editor, "v", "Validate query without running it", (ch('v'), NONE), Message::ValidateQuery),
+    bind(KeyContext::Editor, "t", "New tab with a copy of the query", (ch('t'), NONE), Message::NewQueryTab),
+    bind(KeyContext::Editor, "]", "Next query tab", (ch(']'), NONE), Message::NextQueryTab),
+    bind(KeyContext::Editor, "w", "Close query tab", (ch('w'), NONE), Message::CloseQueryTab),
+    // Schema tab
+    bind(KeyContext::Schema, "d", "Data tab", (ch('d'), NONE), Message::SwitchToData),
+    bind(KeyContext::Schema, "c", "Choose visible columns", (ch('c'), NONE), Message::OpenColumnVisibilityModal),
+    bind(KeyContext::Schema, "/", "Filter columns by name (Enter keeps, Esc clears)", (ch('/'), NONE), Message::StartColumnSearch),
+    bind(KeyContext::Schema, "y", "Copy column / index definition", (ch('y'), NONE), Message::CopyDefinition),
+    bind(KeyContext::Schema, "g", "SELECT the listed columns in the editor", (ch('g'), NONE), Message::GenerateSelect),
+    bind(KeyContext::Schema, "Enter", "Full constraint definition (Constraints tab)", (KeyCode::Enter, NONE), Message::ShowConstraintDetail),
+    bind(KeyContext::Schema, "j / ↓", "Next row", (ch('j'), NONE), Message::NavigateDown),
+    bind(KeyContext::Schema, "k / ↑", "Previous row", (ch('k'), NONE), Message::NavigateUp),
+    // Data tab
+    bind(KeyContext::Data, "j / ↓", "Next row", (ch('j'), NONE), Message::DataTableDown),
+    bind(KeyContext::Data, "k / ↑", "Previous row", (ch('k'), NONE), Message::DataTableUp),
+    bind(KeyContext::Data, "PageDown", "Scroll down a page", (KeyCode::PageDown, NONE), Message::DataTablePageDown),
+    bind(KeyContext::Data, "PageUp", "Scroll up a page", (KeyCode::PageUp, NONE), Message::DataTablePageUp),
+    bind(KeyContext::Data, "g", "First row", (ch('g'), NONE), Message::DataTableFirst),
+    bind(KeyContext::Data, "Shift+g", "Last row", (ch('G'), SHIFT), Message::DataTableLast),
+    bind(KeyContext::Data, "h / ←", "Previous column", (ch('h'), NONE), Message::DataTableColumnLeft),
+    bind(KeyContext::Data, "l / →", "Next column", (ch('l'), NONE), Message::DataTableColumnRight),
+    bind(KeyContext::Data, "<", "Move the selected column left", (ch('<'), NONE), Message::MoveColumnLeft),
+    bind(KeyContext::Data, ">", "Move the selected column right", (ch('>'), NONE), Message::MoveColumnRight),
+    bind(KeyContext::Data, "-", "Hide the selected column", (ch('-'), NONE), Message::HideColumn),
+    bind(KeyContext::Data, "+", "Show all hidden columns", (ch('+'), NONE), Message::ShowAllColumns),
+    bind(KeyContext::Data, "i", "Statistics of the selected column", (ch('i'), NONE), Message::ColumnStats),
+    bind(KeyContext::Data, "n", "Next page", (ch('n'), NONE), Message::PageNext),
+    bind(KeyContext::Data, "p", "Previous page", (ch('p'), NONE), Message::PagePrev),
+    bind(KeyContext::Data, "z", "Cycle page size", (ch('z'), NONE), Message::PageSizeCycle),
+    bind(KeyContext::Data, "t", "Show / hide column types in the header", (ch('t'), NONE), Message::ToggleColumnTypes),
+    bind(KeyContext::Data, "e", "Show / hide the SQL behind the result", (ch('e'), NONE), Message::ToggleExecutedQuery),
+    bind(KeyContext::Data, "w", "Wrap / truncate long values", (ch('w'), NONE), Message::ToggleCellWrap),
+    bind(KeyContext::Data, "/", "Jump to a result column by name", (ch('/'), NONE), Message::OpenGoToColumn),
+    bind(KeyContext::Data, "f", "Filter rows of the selected table", (ch('f'), NONE), Message::OpenFilterModal),
+    bind(KeyContext::Data, "o", "Order rows of the selected table on the server", (ch('o'), NONE), Message::OpenOrderModal),
+    bind(KeyContext::Data, "Shift+f", "Maximize / restore the Data panel", (ch('F'), SHIFT), Message::ToggleFullscreenData),
+    bind(KeyContext::Data, "Shift+r", "Refresh (bypass cache)", (ch('R'), SHIFT), Message::RefreshResult),
+    bind(KeyContext::Data, "x", "Export CSV", (ch('x'), NONE), Message::ExportCsv),
+    bind(KeyContext::Data, "Shift+x", "Export JSON", (ch('X'), SHIFT), Message::ExportJson),
+    bind(KeyContext::Data, "Shift+y", "Copy result as CSV", (ch('Y'), SHIFT), Message::CopyResultCsv),
+    bind(KeyContext::Data, "Ctrl+y", "Copy fetched rows as JSON", (ch('y'), CTRL), Message::CopyResultJson),
+    // Modals (common keys; each modal shows its own hints)
+    bind(KeyContext::Modal, "Esc", "Close / cancel", (KeyCode::Esc, NONE), Message::CloseModal),
+    bind(KeyContext::Modal, "Tab", "Next field", (KeyCode::Tab, NONE), Message::ModalNextField),
+    bind(KeyContext::Modal, "Shift+Tab", "Previous field", (KeyCode::BackTab, SHIFT), Message::ModalPrevField),
+    bind(KeyContext::Modal, "Enter", "Confirm", (KeyCode::Enter, NONE), Message::ModalConfirm),
+];
+
+/// Bindings for one context, in declaration order
+pub fn bindings_for(context: KeyContext) -> impl Iterator<Item = &'static KeyBinding> {
+    KEYBINDINGS.iter().filter(move |b| b.context == context)
+}
+
+/// Lines in the help overlay: a heading and a blank line per context plus one per binding
+pub fn help_line_count() -> usize {
+    KeyContext::ALL.len() * 2 + KEYBINDINGS.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{App, Focus, MainPanelTab, SidebarMode};
+    use crate::event::handle_normal_input;
+    use crate::model::QueryResult;
+
+    fn app_in(context: KeyContext) -> App {
+        let mut app = App::new(vec![]);
+        match context {
+            KeyContext::Global | KeyContext::Projects | KeyContext::Modal => {}
+            KeyContext::Connections => app.sidebar_mode = SidebarMode::Connections(0),
+            KeyContext::Editor => app.focus = Focus::QueryEditor,
+            KeyContext::Schema => {
+                app.focus = Focus::MainPanel;
+                app.panel_tab = MainPanelTab::Schema;
+            }
+            KeyContext::Data => {
+                app.focus = Focus::MainPanel;
+                app.panel_tab = MainPanelTab::Data;
+                app.result = Some(QueryResult {
+                    columns: vec![],
+                    column_types: Vec::new(),
+                    rows: vec![],
+                    total_rows: 0,
+                    rows_affected: None,
+                    execution_time_ms: 0,
+                });
+            }
+        }
+        app
+    }
+
+    #[test]
+    fn test_keybindings_match_key_handling() {
+        // Modal keys vary per modal and are not checked here
+        for binding in KEYBINDINGS
+            .iter()
+            .filter(|b| b.context != KeyContext::Modal)
+        {
+            let mut app = app_in(binding.context);
+            if let Some(prefix) = binding.prefix {
+                // The first key only starts the sequence
+                assert_eq!(
+                    handle_normal_input(&app, KeyCode::Char(prefix), NONE),
+                    Some(Message::StartKeySequence(prefix)),
+                    "{} in {}",
+                    binding.keys,
+                    binding.context.label()
+                );
+                app.pending_key = Some(prefix);
+            }
+            let (code, modifiers) = binding.key;
+            assert_eq!(
+                handle_normal_input(&app, code, modifiers),
+                Some(binding.message.clone()),
+                "{} ({}) in {}",
+                binding.keys,
+                binding.description,
+                binding.context.label()
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_context_has_bindings() {
+        for context in KeyContext::ALL {
+            assert!(bindings_for(context).next().is_some(), "{:?}", context);
+        }
+    }
+}
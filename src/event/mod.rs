@@ -2,25 +2,50 @@
 //!
 //! This module contains handlers for converting keyboard events into messages.
 
+pub mod keymap;
 mod modal;
 mod normal;
 
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyModifiers};
 
-use crate::app::App;
+use crate::app::{App, Focus, ModalState};
 use crate::message::Message;
 
-pub use modal::handle_modal_input;
-pub use normal::handle_normal_input;
+pub use modal::{handle_modal_ctrl_input, handle_modal_input};
+pub use normal::{handle_column_search_input, handle_normal_input};
+
+/// Route text pasted from the terminal (bracketed paste) to where it can be
+/// typed: the open modal or the query editor
+pub fn paste_to_message(app: &App, text: String) -> Option<Message> {
+    if app.is_modal_open() || app.focus == Focus::QueryEditor {
+        Some(Message::Paste(text))
+    } else {
+        None
+    }
+}
 
 /// Convert a key event into a message based on current app state
-pub fn key_to_message(
-    app: &App,
-    key_code: KeyCode,
-    modifiers: crossterm::event::KeyModifiers,
-) -> Option<Message> {
+pub fn key_to_message(app: &App, key_code: KeyCode, modifiers: KeyModifiers) -> Option<Message> {
+    // Ctrl+c again while asked to confirm quitting forces the quit
+    if key_code == KeyCode::Char('c')
+        && modifiers.contains(KeyModifiers::CONTROL)
+        && matches!(app.modal_state, ModalState::ConfirmQuit(_))
+    {
+        return Some(Message::ForceQuit);
+    }
+
     if app.is_modal_open() {
-        handle_modal_input(app, key_code)
+        let ctrl_message = if modifiers.contains(KeyModifiers::CONTROL) {
+            handle_modal_ctrl_input(app, key_code)
+        } else {
+            None
+        };
+        ctrl_message.or_else(|| handle_modal_input(app, key_code))
+    } else if key_code == KeyCode::Char('l') && modifiers.contains(KeyModifiers::CONTROL) {
+        // Also ends a column filter being typed, which would otherwise take the `l`
+        Some(Message::ClearStatus)
+    } else if app.column_search.editing {
+        handle_column_search_input(key_code)
     } else {
         handle_normal_input(app, key_code, modifiers)
     }
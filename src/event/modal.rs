@@ -3,9 +3,11 @@
 use crossterm::event::KeyCode;
 
 use crate::app::{
-    AddConnectionModal, App, ColumnVisibilityModal, ConfirmModalField, ConnectionModalField,
-    DeleteProjectModal, HistoryModal, ModalState, ProjectModal, ProjectModalField,
-    SearchConnectionModal, SearchProjectModal, SearchTableModal, UnifiedSearchModal,
+    ActiveSessionsModal, AddConnectionModal, App, ColumnVisibilityModal, ConfirmCopyModal,
+    ConfirmModalField, ConfirmQueryModal, ConfirmQuitModal, ConnectionModalField, CopyKind,
+    DeleteProjectModal, FilterModal, FilterModalField, HistoryModal, ModalState, OrderModal,
+    OrderModalField, ProjectModal, ProjectModalField, SearchConnectionModal, SearchProjectModal,
+    SearchTableModal, UnifiedSearchModal,
 };
 use crate::message::Message;
 
@@ -13,17 +15,62 @@ use crate::message::Message;
 pub fn handle_modal_input(app: &App, key_code: KeyCode) -> Option<Message> {
     match &app.modal_state {
         ModalState::None => None,
-        ModalState::AddConnection(modal) => handle_connection_modal(key_code, modal),
+        ModalState::AddConnection(modal) | ModalState::CloneConnection(modal) => {
+            handle_connection_modal(key_code, modal)
+        }
         ModalState::AddProject(modal) | ModalState::EditProject(_, modal) => {
             handle_project_modal(key_code, modal)
         }
         ModalState::DeleteProject(modal) => handle_delete_modal(key_code, modal),
+        ModalState::ConfirmQuit(modal) => handle_confirm_quit_modal(key_code, modal),
+        ModalState::ConfirmCopy(modal) => handle_confirm_copy_modal(key_code, modal),
+        ModalState::ConfirmQuery(modal) => handle_confirm_query_modal(key_code, modal),
+        ModalState::Filter(modal) => handle_filter_modal(key_code, modal),
+        ModalState::Order(modal) => handle_order_modal(key_code, modal),
+        ModalState::Help(_) => handle_help_modal(key_code),
+        ModalState::ConstraintDetail(_) | ModalState::LoadErrors(_) => {
+            handle_detail_modal(key_code)
+        }
         ModalState::SearchProject(modal) => handle_search_project_modal(key_code, modal),
         ModalState::SearchConnection(modal) => handle_search_connection_modal(key_code, modal),
         ModalState::SearchTable(modal) => handle_search_table_modal(key_code, modal),
         ModalState::UnifiedSearch(modal) => handle_unified_search_modal(key_code, modal),
+        ModalState::GlobalSearch(_) => handle_global_search_modal(key_code),
+        ModalState::GoToColumn(_) => handle_go_to_column_modal(key_code),
         ModalState::History(modal) => handle_history_modal(key_code, modal),
+        ModalState::RecentTables(_) => handle_recent_tables_modal(key_code),
         ModalState::ColumnVisibility(modal) => handle_column_visibility_modal(key_code, modal),
+        ModalState::DatabaseStats(_) | ModalState::ColumnStats(_) => handle_info_modal(key_code),
+        ModalState::ActiveSessions(modal) => handle_sessions_modal(key_code, modal),
+        ModalState::Roles(_) => handle_roles_modal(key_code),
+        ModalState::Snippets(_) => handle_snippets_modal(key_code),
+        ModalState::ServerSettings(_) => handle_server_settings_modal(key_code),
+        ModalState::ImportConnections(_) => handle_import_modal(key_code),
+        ModalState::ExportProject(..)
+        | ModalState::ImportProject(_)
+        | ModalState::ImportPassword(_)
+        | ModalState::SearchPath(_) => handle_prompt_modal(key_code),
+    }
+}
+
+/// Handle Ctrl+key shortcuts when a modal is open
+pub fn handle_modal_ctrl_input(app: &App, key_code: KeyCode) -> Option<Message> {
+    match (&app.modal_state, key_code) {
+        // Show or mask the password being typed
+        (
+            ModalState::AddConnection(modal) | ModalState::CloneConnection(modal),
+            KeyCode::Char('r'),
+        ) if modal.focused_field == ConnectionModalField::Password => {
+            Some(Message::TogglePasswordReveal)
+        }
+        (
+            ModalState::AddConnection(_)
+            | ModalState::CloneConnection(_)
+            | ModalState::AddProject(_)
+            | ModalState::EditProject(..),
+            KeyCode::Char('w'),
+        ) => Some(Message::ModalDeleteWord),
+        _ => None,
     }
 }
 
@@ -32,6 +79,25 @@ fn handle_connection_modal(key_code: KeyCode, modal: &AddConnectionModal) -> Opt
         KeyCode::Esc => Some(Message::CloseModal),
         KeyCode::Tab => Some(Message::ModalNextField),
         KeyCode::BackTab => Some(Message::ModalPrevField),
+        KeyCode::Left | KeyCode::Char('h')
+            if modal.focused_field == ConnectionModalField::DatabaseType =>
+        {
+            Some(Message::DatabaseTypePrev)
+        }
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ')
+            if modal.focused_field == ConnectionModalField::DatabaseType =>
+        {
+            Some(Message::DatabaseTypeNext)
+        }
+        KeyCode::Left
+        | KeyCode::Right
+        | KeyCode::Char('h')
+        | KeyCode::Char('l')
+        | KeyCode::Char(' ')
+            if modal.focused_field == ConnectionModalField::Production =>
+        {
+            Some(Message::ToggleProductionFlag)
+        }
         KeyCode::Down | KeyCode::Char('j')
             if matches!(
                 modal.focused_field,
@@ -64,6 +130,10 @@ fn handle_connection_modal(key_code: KeyCode, modal: &AddConnectionModal) -> Opt
         {
             Some(Message::ModalNextField)
         }
+        KeyCode::Left => Some(Message::ModalCursorLeft),
+        KeyCode::Right => Some(Message::ModalCursorRight),
+        KeyCode::Home => Some(Message::ModalCursorHome),
+        KeyCode::End => Some(Message::ModalCursorEnd),
         KeyCode::Enter => match modal.focused_field {
             ConnectionModalField::ButtonOk => Some(Message::ModalConfirm),
             ConnectionModalField::ButtonCancel => Some(Message::CloseModal),
@@ -75,6 +145,52 @@ fn handle_connection_modal(key_code: KeyCode, modal: &AddConnectionModal) -> Opt
     }
 }
 
+fn handle_filter_modal(key_code: KeyCode, modal: &FilterModal) -> Option<Message> {
+    let on_selector = matches!(
+        modal.focused_field,
+        FilterModalField::Column | FilterModalField::Operator
+    );
+    match key_code {
+        KeyCode::Esc => Some(Message::CloseModal),
+        KeyCode::Tab | KeyCode::Down => Some(Message::ModalNextField),
+        KeyCode::BackTab | KeyCode::Up => Some(Message::ModalPrevField),
+        KeyCode::Left | KeyCode::Char('h') if on_selector => Some(Message::ModalOptionPrev),
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') if on_selector => {
+            Some(Message::ModalOptionNext)
+        }
+        KeyCode::Enter => match modal.focused_field {
+            FilterModalField::ButtonCancel => Some(Message::CloseModal),
+            _ => Some(Message::ModalConfirm),
+        },
+        KeyCode::Backspace => Some(Message::ModalInputBackspace),
+        KeyCode::Char(c) if modal.focused_field == FilterModalField::Value => {
+            Some(Message::ModalInputChar(c))
+        }
+        _ => None,
+    }
+}
+
+fn handle_order_modal(key_code: KeyCode, modal: &OrderModal) -> Option<Message> {
+    let on_selector = matches!(
+        modal.focused_field,
+        OrderModalField::Column | OrderModalField::Direction
+    );
+    match key_code {
+        KeyCode::Esc => Some(Message::CloseModal),
+        KeyCode::Tab | KeyCode::Down | KeyCode::Char('j') => Some(Message::ModalNextField),
+        KeyCode::BackTab | KeyCode::Up | KeyCode::Char('k') => Some(Message::ModalPrevField),
+        KeyCode::Left | KeyCode::Char('h') if on_selector => Some(Message::ModalOptionPrev),
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') if on_selector => {
+            Some(Message::ModalOptionNext)
+        }
+        KeyCode::Enter => match modal.focused_field {
+            OrderModalField::ButtonCancel => Some(Message::CloseModal),
+            _ => Some(Message::ModalConfirm),
+        },
+        _ => None,
+    }
+}
+
 fn handle_project_modal(key_code: KeyCode, modal: &ProjectModal) -> Option<Message> {
     match key_code {
         KeyCode::Esc => Some(Message::CloseModal),
@@ -112,6 +228,10 @@ fn handle_project_modal(key_code: KeyCode, modal: &ProjectModal) -> Option<Messa
         {
             Some(Message::ModalNextField)
         }
+        KeyCode::Left => Some(Message::ModalCursorLeft),
+        KeyCode::Right => Some(Message::ModalCursorRight),
+        KeyCode::Home => Some(Message::ModalCursorHome),
+        KeyCode::End => Some(Message::ModalCursorEnd),
         KeyCode::Enter => match modal.focused_field {
             ProjectModalField::ButtonOk => Some(Message::ModalConfirm),
             ProjectModalField::ButtonCancel => Some(Message::CloseModal),
@@ -138,6 +258,76 @@ fn handle_delete_modal(key_code: KeyCode, modal: &DeleteProjectModal) -> Option<
     }
 }
 
+fn handle_confirm_copy_modal(key_code: KeyCode, modal: &ConfirmCopyModal) -> Option<Message> {
+    let confirmed = match modal.kind {
+        CopyKind::Json => Message::CopyResultJsonConfirmed,
+        CopyKind::Csv => Message::CopyResultCsvConfirmed,
+    };
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('n') => Some(Message::CloseModal),
+        KeyCode::Char('y') => Some(confirmed),
+        KeyCode::Tab | KeyCode::Left | KeyCode::Right | KeyCode::Char('h') | KeyCode::Char('l') => {
+            Some(Message::ModalNextField)
+        }
+        KeyCode::BackTab => Some(Message::ModalPrevField),
+        KeyCode::Enter => match modal.focused_field {
+            ConfirmModalField::ButtonOk => Some(confirmed),
+            ConfirmModalField::ButtonCancel => Some(Message::CloseModal),
+        },
+        _ => None,
+    }
+}
+
+fn handle_confirm_query_modal(key_code: KeyCode, modal: &ConfirmQueryModal) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('n') => Some(Message::CloseModal),
+        KeyCode::Char('y') => Some(Message::ExecuteQueryConfirmed),
+        KeyCode::Tab | KeyCode::Left | KeyCode::Right | KeyCode::Char('h') | KeyCode::Char('l') => {
+            Some(Message::ModalNextField)
+        }
+        KeyCode::BackTab => Some(Message::ModalPrevField),
+        KeyCode::Enter => match modal.focused_field {
+            ConfirmModalField::ButtonOk => Some(Message::ExecuteQueryConfirmed),
+            ConfirmModalField::ButtonCancel => Some(Message::CloseModal),
+        },
+        _ => None,
+    }
+}
+
+fn handle_confirm_quit_modal(key_code: KeyCode, modal: &ConfirmQuitModal) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('n') => Some(Message::CloseModal),
+        KeyCode::Char('y') => Some(Message::ForceQuit),
+        KeyCode::Tab | KeyCode::Left | KeyCode::Right | KeyCode::Char('h') | KeyCode::Char('l') => {
+            Some(Message::ModalNextField)
+        }
+        KeyCode::BackTab => Some(Message::ModalPrevField),
+        KeyCode::Enter => match modal.focused_field {
+            ConfirmModalField::ButtonOk => Some(Message::ForceQuit),
+            ConfirmModalField::ButtonCancel => Some(Message::CloseModal),
+        },
+        _ => None,
+    }
+}
+
+fn handle_help_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => Some(Message::CloseModal),
+        KeyCode::Up | KeyCode::Char('k') => Some(Message::ModalPrevField),
+        KeyCode::Down | KeyCode::Char('j') => Some(Message::ModalNextField),
+        _ => None,
+    }
+}
+
+fn handle_detail_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => Some(Message::CloseModal),
+        KeyCode::Up | KeyCode::Char('k') => Some(Message::ModalPrevField),
+        KeyCode::Down | KeyCode::Char('j') => Some(Message::ModalNextField),
+        _ => None,
+    }
+}
+
 fn handle_search_project_modal(key_code: KeyCode, _modal: &SearchProjectModal) -> Option<Message> {
     match key_code {
         KeyCode::Esc => Some(Message::CloseModal),
@@ -183,6 +373,34 @@ fn handle_search_table_modal(key_code: KeyCode, _modal: &SearchTableModal) -> Op
     }
 }
 
+fn handle_global_search_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc => Some(Message::CloseModal),
+        KeyCode::Enter => Some(Message::GlobalSearchConfirm),
+        KeyCode::Up => Some(Message::ModalPrevField),
+        KeyCode::Down => Some(Message::ModalNextField),
+        KeyCode::Tab => Some(Message::ModalNextField),
+        KeyCode::BackTab => Some(Message::ModalPrevField),
+        KeyCode::Backspace => Some(Message::ModalInputBackspace),
+        KeyCode::Char(c) => Some(Message::ModalInputChar(c)),
+        _ => None,
+    }
+}
+
+fn handle_go_to_column_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc => Some(Message::CloseModal),
+        KeyCode::Enter => Some(Message::GoToColumnConfirm),
+        KeyCode::Up => Some(Message::ModalPrevField),
+        KeyCode::Down => Some(Message::ModalNextField),
+        KeyCode::Tab => Some(Message::ModalNextField),
+        KeyCode::BackTab => Some(Message::ModalPrevField),
+        KeyCode::Backspace => Some(Message::ModalInputBackspace),
+        KeyCode::Char(c) => Some(Message::ModalInputChar(c)),
+        _ => None,
+    }
+}
+
 fn handle_unified_search_modal(key_code: KeyCode, _modal: &UnifiedSearchModal) -> Option<Message> {
     match key_code {
         KeyCode::Esc => Some(Message::CloseModal),
@@ -203,12 +421,25 @@ fn handle_history_modal(key_code: KeyCode, _modal: &HistoryModal) -> Option<Mess
         KeyCode::Up | KeyCode::Char('k') => Some(Message::HistoryNavigateUp),
         KeyCode::Down | KeyCode::Char('j') => Some(Message::HistoryNavigateDown),
         KeyCode::Enter => Some(Message::HistorySelectEntry),
+        KeyCode::Char('d') => Some(Message::HistoryShowDetail),
+        KeyCode::Char('f') => Some(Message::HistoryToggleFilter),
+        KeyCode::Char('x') => Some(Message::HistoryRerun),
         // 'c' to clear history
         KeyCode::Char('c') => Some(Message::ClearHistory),
         _ => None,
     }
 }
 
+fn handle_recent_tables_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('q') => Some(Message::CloseModal),
+        KeyCode::Up | KeyCode::Char('k') => Some(Message::RecentTablesNavigateUp),
+        KeyCode::Down | KeyCode::Char('j') => Some(Message::RecentTablesNavigateDown),
+        KeyCode::Enter => Some(Message::RecentTablesSelect),
+        _ => None,
+    }
+}
+
 fn handle_column_visibility_modal(
     key_code: KeyCode,
     _modal: &ColumnVisibilityModal,
@@ -221,3 +452,78 @@ fn handle_column_visibility_modal(
         _ => None,
     }
 }
+
+/// Read-only modals only need to be closed
+fn handle_prompt_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc => Some(Message::CloseModal),
+        KeyCode::Enter => Some(Message::ModalConfirm),
+        KeyCode::Backspace => Some(Message::ModalInputBackspace),
+        KeyCode::Char(c) => Some(Message::ModalInputChar(c)),
+        _ => None,
+    }
+}
+
+fn handle_import_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('q') => Some(Message::CloseModal),
+        KeyCode::Enter => Some(Message::ModalConfirm),
+        _ => None,
+    }
+}
+
+fn handle_sessions_modal(key_code: KeyCode, modal: &ActiveSessionsModal) -> Option<Message> {
+    // Waiting for the user to confirm a termination
+    if modal.confirm_pid.is_some() {
+        return match key_code {
+            KeyCode::Char('y') | KeyCode::Enter => Some(Message::ModalConfirm),
+            KeyCode::Char('n') | KeyCode::Esc => Some(Message::CancelTerminateSession),
+            _ => None,
+        };
+    }
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('q') => Some(Message::CloseModal),
+        KeyCode::Up | KeyCode::Char('k') => Some(Message::ModalPrevField),
+        KeyCode::Down | KeyCode::Char('j') => Some(Message::ModalNextField),
+        KeyCode::Char('x') => Some(Message::TerminateSession),
+        KeyCode::Char('r') => Some(Message::RefreshActiveSessions),
+        _ => None,
+    }
+}
+
+fn handle_roles_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('q') => Some(Message::CloseModal),
+        KeyCode::Up | KeyCode::Char('k') => Some(Message::ModalPrevField),
+        KeyCode::Down | KeyCode::Char('j') => Some(Message::ModalNextField),
+        _ => None,
+    }
+}
+
+fn handle_snippets_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('q') => Some(Message::CloseModal),
+        KeyCode::Up | KeyCode::Char('k') => Some(Message::ModalPrevField),
+        KeyCode::Down | KeyCode::Char('j') => Some(Message::ModalNextField),
+        KeyCode::Enter => Some(Message::ModalConfirm),
+        _ => None,
+    }
+}
+
+fn handle_server_settings_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Enter => Some(Message::CloseModal),
+        KeyCode::Up | KeyCode::BackTab => Some(Message::ModalPrevField),
+        KeyCode::Down | KeyCode::Tab => Some(Message::ModalNextField),
+        KeyCode::Backspace => Some(Message::ModalInputBackspace),
+        KeyCode::Char(c) => Some(Message::ModalInputChar(c)),
+        _ => None,
+    }
+}
+
+fn handle_info_modal(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => Some(Message::CloseModal),
+        _ => None,
+    }
+}
@@ -2,7 +2,7 @@
 
 use crossterm::event::{KeyCode, KeyModifiers};
 
-use crate::app::{App, Focus, MainPanelTab, SidebarMode};
+use crate::app::{App, Focus, MainPanelTab, SchemaSubTab, SidebarMode};
 use crate::message::Message;
 
 /// Handle keyboard input in normal mode (no modal open)
@@ -11,10 +11,18 @@ pub fn handle_normal_input(
     key_code: KeyCode,
     modifiers: KeyModifiers,
 ) -> Option<Message> {
+    // Second key of a sequence such as `zM`
+    if let Some(prefix) = app.pending_key {
+        return Some(handle_key_sequence(prefix, key_code));
+    }
+
     // Check if we're in data table navigation mode
     let in_data_table = app.focus == Focus::MainPanel
         && app.panel_tab == MainPanelTab::Data
         && app.result.is_some();
+    let in_columns_tab = app.focus == Focus::MainPanel
+        && app.panel_tab == MainPanelTab::Schema
+        && app.schema_sub_tab == SchemaSubTab::Columns;
 
     match (key_code, modifiers) {
         // Quit
@@ -22,6 +30,13 @@ pub fn handle_normal_input(
             Some(Message::Quit)
         }
 
+        // Keybinding help overlay
+        (KeyCode::Char('?'), _) => Some(Message::OpenHelp),
+        // Clear the status bar and redraw (like Ctrl+l in a shell)
+        (KeyCode::Char('l'), KeyModifiers::CONTROL) => Some(Message::ClearStatus),
+        // Pick up project files edited outside lazydb
+        (KeyCode::F(5), _) => Some(Message::ReloadConfig),
+
         // Shift + movement keys: directional pane navigation
         (KeyCode::Left, KeyModifiers::SHIFT) | (KeyCode::Char('H'), KeyModifiers::SHIFT) => {
             Some(Message::FocusLeft)
@@ -43,12 +58,62 @@ pub fn handle_normal_input(
         (KeyCode::PageDown, _) if in_data_table => Some(Message::DataTablePageDown),
         (KeyCode::Char('g'), _) if in_data_table => Some(Message::DataTableFirst),
         (KeyCode::Char('G'), KeyModifiers::SHIFT) if in_data_table => Some(Message::DataTableLast),
+        (KeyCode::Left | KeyCode::Char('h'), KeyModifiers::NONE) if in_data_table => {
+            Some(Message::DataTableColumnLeft)
+        }
+        (KeyCode::Right | KeyCode::Char('l'), KeyModifiers::NONE) if in_data_table => {
+            Some(Message::DataTableColumnRight)
+        }
+        (KeyCode::Char('<'), _) if in_data_table => Some(Message::MoveColumnLeft),
+        (KeyCode::Char('>'), _) if in_data_table => Some(Message::MoveColumnRight),
+        (KeyCode::Char('-'), _) if in_data_table => Some(Message::HideColumn),
+        (KeyCode::Char('+'), _) if in_data_table => Some(Message::ShowAllColumns),
+        (KeyCode::Char('i'), _) if in_data_table => Some(Message::ColumnStats),
+        (KeyCode::Char('t'), _) if in_data_table => Some(Message::ToggleColumnTypes),
+        (KeyCode::Char('e'), _) if in_data_table => Some(Message::ToggleExecutedQuery),
+        (KeyCode::Char('w'), _) if in_data_table => Some(Message::ToggleCellWrap),
+
+        // Column filter and copying definitions (Schema tab)
+        (KeyCode::Char('/'), _)
+            if app.focus == Focus::MainPanel && app.panel_tab == MainPanelTab::Schema =>
+        {
+            Some(Message::StartColumnSearch)
+        }
+        (KeyCode::Char('y'), _)
+            if app.focus == Focus::MainPanel && app.panel_tab == MainPanelTab::Schema =>
+        {
+            Some(Message::CopyDefinition)
+        }
+        (KeyCode::Enter, _)
+            if app.focus == Focus::MainPanel && app.panel_tab == MainPanelTab::Schema =>
+        {
+            Some(Message::ShowConstraintDetail)
+        }
+        (KeyCode::Char('g'), _)
+            if app.focus == Focus::MainPanel && app.panel_tab == MainPanelTab::Schema =>
+        {
+            Some(Message::GenerateSelect)
+        }
+        (KeyCode::Esc, _) if in_columns_tab && app.column_search.is_active() => {
+            Some(Message::ColumnSearchCancel)
+        }
+        // Stop waiting for table lists / details that are still loading
+        (KeyCode::Esc, _)
+            if app.loading.fetching_tables.is_some() || app.loading.is_fetching_details() =>
+        {
+            Some(Message::CancelMetadataFetch)
+        }
 
-        // Regular navigation within current pane (Sidebar)
+        // Regular navigation within current pane (Sidebar, schema sub-tab rows)
         (KeyCode::Up | KeyCode::Char('k'), _) => Some(Message::NavigateUp),
         (KeyCode::Down | KeyCode::Char('j'), _) => Some(Message::NavigateDown),
         (KeyCode::Tab, _) => Some(Message::NextFocus),
         (KeyCode::BackTab, _) => Some(Message::PrevFocus),
+        (KeyCode::Enter, _) if app.focus == Focus::QueryEditor => Some(Message::ExecuteQuery),
+        (KeyCode::Char('v'), _) if app.focus == Focus::QueryEditor => Some(Message::ValidateQuery),
+        (KeyCode::Char('t'), _) if app.focus == Focus::QueryEditor => Some(Message::NewQueryTab),
+        (KeyCode::Char(']'), _) if app.focus == Focus::QueryEditor => Some(Message::NextQueryTab),
+        (KeyCode::Char('w'), _) if app.focus == Focus::QueryEditor => Some(Message::CloseQueryTab),
         (KeyCode::Enter, _) => Some(Message::Activate),
         (KeyCode::Backspace, _) if app.focus == Focus::Sidebar => Some(Message::GoBack),
 
@@ -56,10 +121,16 @@ pub fn handle_normal_input(
         (KeyCode::Char('s'), _) => Some(Message::SwitchToSchema),
         (KeyCode::Char('d'), _) if app.focus != Focus::Sidebar => Some(Message::SwitchToData),
 
+        // Global search across all projects: Ctrl+p (like "quick open" in editors)
+        (KeyCode::Char('p'), KeyModifiers::CONTROL) => Some(Message::OpenGlobalSearchModal),
+
         // Query history: Ctrl+r to open history modal (like shell reverse-search)
         (KeyCode::Char('r'), KeyModifiers::CONTROL) => Some(Message::OpenHistoryModal),
         (KeyCode::Char('r'), _) => Some(Message::SwitchToRelations),
 
+        // Recently opened tables: Ctrl+o (like jumping back in vim)
+        (KeyCode::Char('o'), KeyModifiers::CONTROL) => Some(Message::OpenRecentTables),
+
         // Schema sub-tab shortcuts (1-6)
         (KeyCode::Char('1'), _) => Some(Message::SwitchToColumns),
         (KeyCode::Char('2'), _) => Some(Message::SwitchToIndexes),
@@ -75,9 +146,53 @@ pub fn handle_normal_input(
         (KeyCode::Char('G'), KeyModifiers::SHIFT) if app.panel_tab == MainPanelTab::Data => {
             Some(Message::PageLast)
         }
+        // Vim-style folds of every connection: zM collapses, zR expands
+        (KeyCode::Char('z'), KeyModifiers::NONE)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::StartKeySequence('z'))
+        }
         (KeyCode::Char('z'), _) if app.panel_tab == MainPanelTab::Data => {
             Some(Message::PageSizeCycle)
         }
+        (KeyCode::Char('R'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::RefreshAll)
+        }
+        (KeyCode::Char('R'), KeyModifiers::SHIFT) if app.panel_tab == MainPanelTab::Data => {
+            Some(Message::RefreshResult)
+        }
+        (KeyCode::Char('/'), _)
+            if app.focus == Focus::MainPanel && app.panel_tab == MainPanelTab::Data =>
+        {
+            Some(Message::OpenGoToColumn)
+        }
+        (KeyCode::Char('f'), _)
+            if app.focus == Focus::MainPanel && app.panel_tab == MainPanelTab::Data =>
+        {
+            Some(Message::OpenFilterModal)
+        }
+        (KeyCode::Char('o'), KeyModifiers::NONE)
+            if app.focus == Focus::MainPanel && app.panel_tab == MainPanelTab::Data =>
+        {
+            Some(Message::OpenOrderModal)
+        }
+        (KeyCode::Char('F'), KeyModifiers::SHIFT)
+            if app.focus == Focus::MainPanel && app.panel_tab == MainPanelTab::Data =>
+        {
+            Some(Message::ToggleFullscreenData)
+        }
+
+        // Export current result (Data tab)
+        (KeyCode::Char('x'), _) if in_data_table => Some(Message::ExportCsv),
+        (KeyCode::Char('X'), KeyModifiers::SHIFT) if in_data_table => Some(Message::ExportJson),
+        (KeyCode::Char('Y'), KeyModifiers::SHIFT) if in_data_table => Some(Message::CopyResultCsv),
+        (KeyCode::Char('y'), KeyModifiers::CONTROL) if in_data_table => {
+            Some(Message::CopyResultJson)
+        }
 
         // Add operation: 'a' key in sidebar (Project or Connection depending on mode)
         (KeyCode::Char('a'), _) if app.focus == Focus::Sidebar => match app.sidebar_mode {
@@ -85,6 +200,30 @@ pub fn handle_normal_input(
             SidebarMode::Connections(_) => Some(Message::OpenAddConnectionModal),
         },
 
+        // Connection snippets: 'N' key in Connections view
+        (KeyCode::Char('N'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::OpenConnectionSnippets)
+        }
+
+        // Clone connection: 'C' key in Connections view
+        (KeyCode::Char('C'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::CloneConnection)
+        }
+
+        // Import connections from .pgpass / pg_service.conf: 'I' key in Connections view
+        (KeyCode::Char('I'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::ImportConnections)
+        }
+
         // Project edit: 'e' key in Projects view
         (KeyCode::Char('e'), _)
             if app.focus == Focus::Sidebar && matches!(app.sidebar_mode, SidebarMode::Projects) =>
@@ -92,6 +231,39 @@ pub fn handle_normal_input(
             Some(Message::OpenEditProjectModal)
         }
 
+        // Project clone: 'C' key in Projects view
+        (KeyCode::Char('C'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar && matches!(app.sidebar_mode, SidebarMode::Projects) =>
+        {
+            Some(Message::CloneProject)
+        }
+
+        // Sample project for a first run: 'S' key in Projects view
+        (KeyCode::Char('S'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar && matches!(app.sidebar_mode, SidebarMode::Projects) =>
+        {
+            Some(Message::CreateSampleProject)
+        }
+
+        // Project files that failed to load: 'W' key in Projects view
+        (KeyCode::Char('W'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar && matches!(app.sidebar_mode, SidebarMode::Projects) =>
+        {
+            Some(Message::ShowLoadErrors)
+        }
+
+        // Project export/import: 'E' / 'I' keys in Projects view
+        (KeyCode::Char('E'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar && matches!(app.sidebar_mode, SidebarMode::Projects) =>
+        {
+            Some(Message::ExportProject)
+        }
+        (KeyCode::Char('I'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar && matches!(app.sidebar_mode, SidebarMode::Projects) =>
+        {
+            Some(Message::ImportProject)
+        }
+
         // Project delete: 'd' key in Projects view
         (KeyCode::Char('d'), _)
             if app.focus == Focus::Sidebar && matches!(app.sidebar_mode, SidebarMode::Projects) =>
@@ -114,6 +286,70 @@ pub fn handle_normal_input(
             Some(Message::OpenUnifiedSearchModal)
         }
 
+        // Database size summary: 'i' key in Connections view
+        (KeyCode::Char('i'), _)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::ShowDatabaseStats)
+        }
+
+        // Active sessions: 'A' key in Connections view
+        (KeyCode::Char('A'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::ShowActiveSessions)
+        }
+
+        // Users and roles: 'U' key in Connections view
+        (KeyCode::Char('U'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::ShowRoles)
+        }
+
+        // Server settings: 'V' key in Connections view
+        (KeyCode::Char('V'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::ShowServerSettings)
+        }
+
+        // Session search_path: 'P' key in Connections view
+        (KeyCode::Char('P'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::SetSearchPath)
+        }
+
+        // Show/hide system schemas: 'S' key in Connections view
+        (KeyCode::Char('S'), KeyModifiers::SHIFT)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::ToggleSystemObjects)
+        }
+
+        // Copy the selected table's qualified name: 'y' key in Connections view
+        (KeyCode::Char('y'), KeyModifiers::NONE)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::CopyTableName)
+        }
+
+        // Reconnect: 'c' key in Connections view
+        (KeyCode::Char('c'), _)
+            if app.focus == Focus::Sidebar
+                && matches!(app.sidebar_mode, SidebarMode::Connections(_)) =>
+        {
+            Some(Message::Reconnect)
+        }
+
         // Column visibility: 'c' key in Schema tab when main panel is focused
         (KeyCode::Char('c'), _)
             if app.focus == Focus::MainPanel && app.panel_tab == MainPanelTab::Schema =>
@@ -124,3 +360,25 @@ pub fn handle_normal_input(
         _ => None,
     }
 }
+
+/// Second key of a sequence started with `prefix`; unbound keys cancel the sequence
+fn handle_key_sequence(prefix: char, key_code: KeyCode) -> Message {
+    match (prefix, key_code) {
+        ('z', KeyCode::Char('M')) => Message::CollapseAll,
+        ('z', KeyCode::Char('R')) => Message::ExpandAll,
+        _ => Message::CancelKeySequence,
+    }
+}
+
+/// Handle keyboard input while typing a column filter
+pub fn handle_column_search_input(key_code: KeyCode) -> Option<Message> {
+    match key_code {
+        KeyCode::Esc => Some(Message::ColumnSearchCancel),
+        KeyCode::Enter => Some(Message::ColumnSearchConfirm),
+        KeyCode::Up => Some(Message::NavigateUp),
+        KeyCode::Down => Some(Message::NavigateDown),
+        KeyCode::Backspace => Some(Message::ColumnSearchBackspace),
+        KeyCode::Char(c) => Some(Message::ColumnSearchInput(c)),
+        _ => None,
+    }
+}
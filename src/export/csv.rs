@@ -2,13 +2,18 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
-use crate::export::Encoding;
+use crate::export::{report_rows, Encoding, ExportConfig, QuoteStyle, UTF8_BOM};
 use crate::model::QueryResult;
 
 /// CSVの値をエスケープする
-/// カンマ、ダブルクォート、改行を含む場合はダブルクォートで囲む
-fn escape_csv_value(value: &str) -> String {
-    if value.contains(',') || value.contains('"') || value.contains('\n') || value.contains('\r') {
+/// 区切り文字、ダブルクォート、改行を含む場合（`QuoteStyle::Always` では常に）ダブルクォートで囲む
+fn escape_csv_value(value: &str, delimiter: char, quote_style: QuoteStyle) -> String {
+    let needs_quotes = quote_style == QuoteStyle::Always
+        || value.contains(delimiter)
+        || value.contains('"')
+        || value.contains('\n')
+        || value.contains('\r');
+    if needs_quotes {
         // ダブルクォートを二重にしてエスケープ
         let escaped = value.replace('"', "\"\"");
         format!("\"{}\"", escaped)
@@ -32,24 +37,58 @@ fn encode_string(content: &str, encoding: Encoding) -> Vec<u8> {
     }
 }
 
-pub fn export(result: &QueryResult, path: &Path, encoding: Encoding) -> anyhow::Result<()> {
+/// CSVのテキストを組み立てる（エンコード・BOMの付与は行わない）
+pub fn format(result: &QueryResult, config: &ExportConfig) -> String {
+    format_with_progress(result, config, &mut |_| {})
+}
+
+/// CSVのテキストを組み立て、書き込んだ行数を `on_rows` に通知する
+fn format_with_progress(
+    result: &QueryResult,
+    config: &ExportConfig,
+    on_rows: &mut dyn FnMut(u64),
+) -> String {
+    let delimiter = config.delimiter.as_char();
+    let separator = delimiter.to_string();
+    let escape_line = |values: &[String], quote_style: QuoteStyle| -> String {
+        values
+            .iter()
+            .map(|v| escape_csv_value(v, delimiter, quote_style))
+            .collect::<Vec<_>>()
+            .join(&separator)
+    };
     let mut output = String::new();
 
-    // ヘッダー行
-    let header: Vec<String> = result.columns.iter().map(|c| escape_csv_value(c)).collect();
-    output.push_str(&header.join(","));
+    // ヘッダー行（COPY の FORCE_QUOTE と同じく、カラム名は必要な場合のみ囲む）
+    output.push_str(&escape_line(&result.columns, QuoteStyle::Necessary));
     output.push('\n');
 
     // データ行
-    for row in &result.rows {
-        let escaped_row: Vec<String> = row.iter().map(|v| escape_csv_value(v)).collect();
-        output.push_str(&escaped_row.join(","));
+    for (i, row) in result.rows.iter().enumerate() {
+        output.push_str(&escape_line(row, config.quote_style));
         output.push('\n');
+        report_rows(on_rows, i + 1, result.rows.len());
     }
 
+    output
+}
+
+pub fn export(
+    result: &QueryResult,
+    path: &Path,
+    config: &ExportConfig,
+    on_rows: &mut dyn FnMut(u64),
+) -> anyhow::Result<()> {
     // エンコードしてファイルに書き込み
-    let bytes = encode_string(&output, encoding);
+    let bytes = encode_string(
+        &format_with_progress(result, config, on_rows),
+        config.encoding,
+    );
     let mut file = File::create(path)?;
+    // BOMはUTF-8の場合のみ（Shift_JIS / EUC-JPにはBOMがない）
+    if config.writes_bom() {
+        file.write_all(UTF8_BOM)?;
+    }
     file.write_all(&bytes)?;
 
     Ok(())
@@ -59,23 +98,37 @@ pub fn export(result: &QueryResult, path: &Path, encoding: Encoding) -> anyhow::
 mod tests {
     use super::*;
 
+    fn escape(value: &str) -> String {
+        escape_csv_value(value, ',', QuoteStyle::Necessary)
+    }
+
     #[test]
     fn test_escape_csv_value_simple() {
-        assert_eq!(escape_csv_value("hello"), "hello");
+        assert_eq!(escape("hello"), "hello");
     }
 
     #[test]
     fn test_escape_csv_value_with_comma() {
-        assert_eq!(escape_csv_value("hello, world"), "\"hello, world\"");
+        assert_eq!(escape("hello, world"), "\"hello, world\"");
     }
 
     #[test]
     fn test_escape_csv_value_with_quotes() {
-        assert_eq!(escape_csv_value("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape("say \"hi\""), "\"say \"\"hi\"\"\"");
     }
 
     #[test]
     fn test_escape_csv_value_with_newline() {
-        assert_eq!(escape_csv_value("line1\nline2"), "\"line1\nline2\"");
+        assert_eq!(escape("line1\nline2"), "\"line1\nline2\"");
+    }
+
+    #[test]
+    fn test_escape_csv_value_with_other_delimiter() {
+        assert_eq!(
+            escape_csv_value("a\tb", '\t', QuoteStyle::Necessary),
+            "\"a\tb\""
+        );
+        assert_eq!(escape_csv_value("a,b", '\t', QuoteStyle::Necessary), "a,b");
+        assert_eq!(escape_csv_value("ab", ';', QuoteStyle::Always), "\"ab\"");
     }
 }
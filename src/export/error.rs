@@ -12,6 +12,8 @@ pub enum ExportError {
     },
     /// Path not found (typically means parent directory doesn't exist when creating a file)
     PathNotFound { path: PathBuf },
+    /// The directory the file would be written into does not exist
+    ParentDirNotFound { dir: PathBuf },
     /// Permission denied when writing file
     PermissionDenied { path: PathBuf },
     /// Disk full or quota exceeded
@@ -38,6 +40,13 @@ impl std::fmt::Display for ExportError {
                     path.display()
                 )
             }
+            ExportError::ParentDirNotFound { dir } => {
+                write!(
+                    f,
+                    "Parent directory does not exist: '{}' (Create it first or enable creating missing directories)",
+                    dir.display()
+                )
+            }
             ExportError::PermissionDenied { path } => {
                 write!(
                     f,
@@ -82,6 +91,14 @@ impl ExportError {
         }
     }
 
+    /// Convert an error returned by an exporter, categorizing I/O failures
+    pub fn from_anyhow(err: anyhow::Error, path: PathBuf) -> anyhow::Error {
+        match err.downcast::<std::io::Error>() {
+            Ok(io_err) => Self::from_io_error(io_err, path).into(),
+            Err(err) => err,
+        }
+    }
+
     /// Check if the error is a disk full error
     fn is_disk_full_error(err: &std::io::Error) -> bool {
         // ENOSPC on Unix, ERROR_DISK_FULL on Windows
@@ -150,6 +167,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parent_dir_not_found_displays_directory() {
+        let err = ExportError::ParentDirNotFound {
+            dir: PathBuf::from("/nonexistent/dir"),
+        };
+        let display = err.to_string();
+
+        assert!(
+            display.contains("Parent directory does not exist: '/nonexistent/dir'"),
+            "Expected parent directory message, got: {}",
+            display
+        );
+    }
+
     #[test]
     fn test_permission_denied_displays_user_friendly_message() {
         let err = ExportError::PermissionDenied {
@@ -2,7 +2,7 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
-use crate::export::Encoding;
+use crate::export::{report_rows, Encoding};
 use crate::model::QueryResult;
 
 /// 文字列をエンコードしてバイト列を返す
@@ -20,26 +20,70 @@ fn encode_string(content: &str, encoding: Encoding) -> Vec<u8> {
     }
 }
 
-pub fn export(result: &QueryResult, path: &Path, encoding: Encoding) -> anyhow::Result<()> {
+/// 1行をカラム名をキーとするオブジェクトに変換する
+fn row_to_object(columns: &[String], row: &[String]) -> serde_json::Map<String, serde_json::Value> {
+    let mut obj = serde_json::Map::new();
+    for (i, col) in columns.iter().enumerate() {
+        let value = row.get(i).cloned().unwrap_or_default();
+        obj.insert(col.clone(), serde_json::Value::String(value));
+    }
+    obj
+}
+
+/// JSON配列のテキストを組み立てる
+pub fn format(result: &QueryResult) -> anyhow::Result<String> {
     // 各行をオブジェクトに変換
     let rows: Vec<serde_json::Map<String, serde_json::Value>> = result
         .rows
         .iter()
-        .map(|row| {
-            let mut obj = serde_json::Map::new();
-            for (i, col) in result.columns.iter().enumerate() {
-                let value = row.get(i).cloned().unwrap_or_default();
-                obj.insert(col.clone(), serde_json::Value::String(value));
-            }
-            obj
-        })
+        .map(|row| row_to_object(&result.columns, row))
         .collect();
 
     // JSONにシリアライズ（整形出力）
-    let json = serde_json::to_string_pretty(&rows)?;
+    Ok(serde_json::to_string_pretty(&rows)?)
+}
+
+pub fn export(result: &QueryResult, path: &Path, encoding: Encoding) -> anyhow::Result<()> {
+    // エンコードしてファイルに書き込み
+    let bytes = encode_string(&format(result)?, encoding);
+    let mut file = File::create(path)?;
+    file.write_all(&bytes)?;
+
+    Ok(())
+}
+
+/// JSON Lines形式（1行に1オブジェクト、配列で囲まない）のテキストを組み立てる
+/// 結果が空の場合は空文字列になる
+pub fn format_lines(result: &QueryResult) -> anyhow::Result<String> {
+    format_lines_with_progress(result, &mut |_| {})
+}
+
+/// JSON Lines形式のテキストを組み立て、書き込んだ行数を `on_rows` に通知する
+fn format_lines_with_progress(
+    result: &QueryResult,
+    on_rows: &mut dyn FnMut(u64),
+) -> anyhow::Result<String> {
+    let mut output = String::new();
+    for (i, row) in result.rows.iter().enumerate() {
+        output.push_str(&serde_json::to_string(&row_to_object(
+            &result.columns,
+            row,
+        ))?);
+        output.push('\n');
+        report_rows(on_rows, i + 1, result.rows.len());
+    }
+    Ok(output)
+}
 
+/// JSON Lines形式で書き出す
+pub fn export_lines(
+    result: &QueryResult,
+    path: &Path,
+    encoding: Encoding,
+    on_rows: &mut dyn FnMut(u64),
+) -> anyhow::Result<()> {
     // エンコードしてファイルに書き込み
-    let bytes = encode_string(&json, encoding);
+    let bytes = encode_string(&format_lines_with_progress(result, on_rows)?, encoding);
     let mut file = File::create(path)?;
     file.write_all(&bytes)?;
 
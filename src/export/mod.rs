@@ -5,8 +5,10 @@
 //!
 //! # Supported Formats
 //!
-//! - CSV (Comma-Separated Values)
+//! - CSV (Comma-Separated Values; tab, semicolon and pipe delimiters are also available)
 //! - JSON (JavaScript Object Notation)
+//! - JSON Lines (one JSON object per line, for `jq` and bulk loaders)
+//! - Excel (`.xlsx`, a single worksheet with a bold, filterable header row)
 //!
 //! # Supported Encodings
 //!
@@ -14,6 +16,13 @@
 //! - Shift_JIS (for Japanese Windows compatibility)
 //! - EUC-JP (for Japanese Unix compatibility)
 //!
+//! Results can also be copied to the clipboard ([`ExportTarget::Clipboard`]);
+//! the clipboard always receives UTF-8 text without a BOM.
+//!
+//! UTF-8 CSV files can start with a byte order mark so that Excel detects
+//! the encoding. Shift_JIS and EUC-JP have no BOM, and JSON is always
+//! written without one.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -22,6 +31,7 @@
 //! let config = ExportConfig {
 //!     format: ExportFormat::Csv,
 //!     encoding: Encoding::Utf8,
+//!     ..ExportConfig::default()
 //! };
 //! export_to_file(&query_result, Path::new("output.csv"), &config)?;
 //! ```
@@ -32,12 +42,15 @@
 mod csv;
 mod error;
 mod json;
+mod xlsx;
 
 // Re-export for future use when export is integrated with UI
 #[allow(unused_imports)]
 pub use error::ExportError;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
 
 use crate::model::QueryResult;
 
@@ -46,6 +59,10 @@ use crate::model::QueryResult;
 pub enum ExportFormat {
     Csv,
     Json,
+    /// One JSON object per line, without an enclosing array
+    JsonLines,
+    /// Excel workbook; numeric values are written as number cells
+    Xlsx,
 }
 
 /// Character encoding for export files
@@ -57,11 +74,59 @@ pub enum Encoding {
     EucJp,
 }
 
+/// Field delimiter for CSV export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Delimiter {
+    #[default]
+    Comma,
+    Tab,
+    Semicolon,
+    Pipe,
+}
+
+impl Delimiter {
+    pub const ALL: [Delimiter; 4] = [
+        Delimiter::Comma,
+        Delimiter::Tab,
+        Delimiter::Semicolon,
+        Delimiter::Pipe,
+    ];
+
+    pub fn as_char(self) -> char {
+        match self {
+            Delimiter::Comma => ',',
+            Delimiter::Tab => '\t',
+            Delimiter::Semicolon => ';',
+            Delimiter::Pipe => '|',
+        }
+    }
+}
+
+/// Which CSV values are wrapped in double quotes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuoteStyle {
+    /// Only values containing the delimiter, a double quote or a line break
+    #[default]
+    Necessary,
+    /// Every value
+    Always,
+}
+
 /// Export configuration
 #[derive(Debug, Clone)]
 pub struct ExportConfig {
     pub format: ExportFormat,
     pub encoding: Encoding,
+    /// Field delimiter (CSV only)
+    pub delimiter: Delimiter,
+    /// Quoting of values (CSV only)
+    pub quote_style: QuoteStyle,
+    /// Start UTF-8 CSV files with a byte order mark (ignored for other encodings and JSON)
+    pub with_bom: bool,
+    /// Create missing parent directories instead of failing
+    pub create_dirs: bool,
 }
 
 impl Default for ExportConfig {
@@ -69,20 +134,148 @@ impl Default for ExportConfig {
         Self {
             format: ExportFormat::Csv,
             encoding: Encoding::Utf8,
+            delimiter: Delimiter::Comma,
+            quote_style: QuoteStyle::Necessary,
+            with_bom: false,
+            create_dirs: false,
         }
     }
 }
 
+/// Where an export is written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportTarget {
+    File(PathBuf),
+    Clipboard,
+}
+
+/// What [`export`] produced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportOutput {
+    /// Number of bytes written to the file
+    File(u64),
+    /// Formatted text to hand to the clipboard
+    Clipboard(String),
+}
+
+/// UTF-8 byte order mark
+pub const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Rows written between two progress reports of a row-oriented export
+pub const PROGRESS_INTERVAL_ROWS: usize = 10_000;
+
+/// Report `written` rows every [`PROGRESS_INTERVAL_ROWS`] rows and once
+/// the last of `total` rows is written
+fn report_rows(on_rows: &mut dyn FnMut(u64), written: usize, total: usize) {
+    if written.is_multiple_of(PROGRESS_INTERVAL_ROWS) || written == total {
+        on_rows(written as u64);
+    }
+}
+
+impl ExportConfig {
+    /// Whether a CSV file written with this config starts with [`UTF8_BOM`]
+    pub fn writes_bom(&self) -> bool {
+        self.with_bom && self.format == ExportFormat::Csv && self.encoding == Encoding::Utf8
+    }
+
+    /// File extension for the exported file (`tsv` for tab-delimited CSV)
+    pub fn extension(&self) -> &'static str {
+        match (self.format, self.delimiter) {
+            (ExportFormat::Csv, Delimiter::Tab) => "tsv",
+            (ExportFormat::Csv, _) => "csv",
+            (ExportFormat::Json, _) => "json",
+            (ExportFormat::JsonLines, _) => "jsonl",
+            (ExportFormat::Xlsx, _) => "xlsx",
+        }
+    }
+}
+
+/// Make sure the directory `path` will be written into exists.
+///
+/// Missing directories are created when `config.create_dirs` is set;
+/// otherwise [`ExportError::ParentDirNotFound`] is returned.
+pub fn prepare_output_path(path: &Path, config: &ExportConfig) -> Result<(), ExportError> {
+    // A bare file name has an empty parent, i.e. the working directory
+    let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) else {
+        return Ok(());
+    };
+    if dir.is_dir() {
+        return Ok(());
+    }
+    if config.create_dirs {
+        std::fs::create_dir_all(dir).map_err(|e| ExportError::from_io_error(e, dir.to_path_buf()))
+    } else {
+        Err(ExportError::ParentDirNotFound {
+            dir: dir.to_path_buf(),
+        })
+    }
+}
+
+/// Format query results as text in the configured format.
+///
+/// The encoding and BOM settings only apply to files and are ignored here.
+/// Excel is a binary format and cannot be formatted as text.
+pub fn format_text(result: &QueryResult, config: &ExportConfig) -> anyhow::Result<String> {
+    match config.format {
+        ExportFormat::Csv => Ok(csv::format(result, config)),
+        ExportFormat::Json => json::format(result),
+        ExportFormat::JsonLines => json::format_lines(result),
+        ExportFormat::Xlsx => anyhow::bail!("Excel files cannot be copied as text"),
+    }
+}
+
+/// Export query results to `target`.
+///
+/// Nothing touches the clipboard here: the formatted text is returned in
+/// [`ExportOutput::Clipboard`] for the caller to copy.
+pub fn export(
+    result: &QueryResult,
+    target: &ExportTarget,
+    config: &ExportConfig,
+) -> anyhow::Result<ExportOutput> {
+    export_with_progress(result, target, config, None)
+}
+
+/// Export query results to `target`, reporting progress to `on_rows`.
+///
+/// CSV, JSON Lines and Excel files call `on_rows` with the number of rows
+/// written so far every [`PROGRESS_INTERVAL_ROWS`] rows and after the last
+/// row. JSON arrays are serialized in one go and clipboard text is not
+/// reported.
+pub fn export_with_progress(
+    result: &QueryResult,
+    target: &ExportTarget,
+    config: &ExportConfig,
+    on_rows: Option<&mut dyn FnMut(u64)>,
+) -> anyhow::Result<ExportOutput> {
+    let mut no_progress = |_| {};
+    let on_rows = on_rows.unwrap_or(&mut no_progress);
+    let path = match target {
+        ExportTarget::File(path) => path,
+        ExportTarget::Clipboard => return format_text(result, config).map(ExportOutput::Clipboard),
+    };
+
+    prepare_output_path(path, config)?;
+    match config.format {
+        ExportFormat::Csv => csv::export(result, path, config, on_rows),
+        ExportFormat::Json => json::export(result, path, config.encoding),
+        ExportFormat::JsonLines => json::export_lines(result, path, config.encoding, on_rows),
+        ExportFormat::Xlsx => xlsx::export(result, path, on_rows),
+    }
+    .map_err(|e| ExportError::from_anyhow(e, path.clone()))?;
+    let bytes = std::fs::metadata(path)
+        .map_err(|e| ExportError::from_io_error(e, path.clone()))?
+        .len();
+    Ok(ExportOutput::File(bytes))
+}
+
 /// Export query results to a file
 pub fn export_to_file(
     result: &QueryResult,
     path: &Path,
     config: &ExportConfig,
 ) -> anyhow::Result<()> {
-    match config.format {
-        ExportFormat::Csv => csv::export(result, path, config.encoding),
-        ExportFormat::Json => json::export(result, path, config.encoding),
-    }
+    export(result, &ExportTarget::File(path.to_path_buf()), config).map(|_| ())
 }
 
 #[cfg(test)]
@@ -94,6 +287,7 @@ mod tests {
     fn create_test_query_result() -> QueryResult {
         QueryResult {
             columns: vec!["id".to_string(), "name".to_string(), "email".to_string()],
+            column_types: Vec::new(),
             rows: vec![
                 vec![
                     "1".to_string(),
@@ -113,6 +307,7 @@ mod tests {
             ],
             execution_time_ms: 100,
             total_rows: 3,
+            rows_affected: None,
         }
     }
 
@@ -124,6 +319,7 @@ mod tests {
         let config = ExportConfig {
             format: ExportFormat::Csv,
             encoding: Encoding::Utf8,
+            ..ExportConfig::default()
         };
 
         export_to_file(&result, &path, &config).unwrap();
@@ -135,30 +331,103 @@ mod tests {
 
     #[test]
     fn test_export_csv_with_special_characters() {
+        for delimiter in Delimiter::ALL {
+            let sep = delimiter.as_char();
+            let temp_dir = TempDir::new().unwrap();
+            let path = temp_dir.path().join("output.csv");
+            let with_delimiter = format!("Hello{} World", sep);
+            let result = QueryResult {
+                columns: vec!["id".to_string(), "description".to_string()],
+                column_types: Vec::new(),
+                rows: vec![
+                    vec!["1".to_string(), with_delimiter.clone()],
+                    vec!["2".to_string(), "Line with \"quotes\"".to_string()],
+                    vec!["3".to_string(), "Line\nwith\nnewlines".to_string()],
+                ],
+                execution_time_ms: 50,
+                total_rows: 3,
+                rows_affected: None,
+            };
+            let config = ExportConfig {
+                delimiter,
+                ..ExportConfig::default()
+            };
+
+            export_to_file(&result, &path, &config).unwrap();
+
+            let content = fs::read_to_string(&path).unwrap();
+            // 区切り文字・引用符・改行を含む値はダブルクォートで囲む
+            assert!(
+                content.starts_with(&format!("id{}description\n", sep)),
+                "{:?}",
+                delimiter
+            );
+            assert!(
+                content.contains(&format!("1{}\"{}\"", sep, with_delimiter)),
+                "{:?}",
+                delimiter
+            );
+            assert!(content.contains("\"Line with \"\"quotes\"\"\""));
+            assert!(content.contains("\"Line\nwith\nnewlines\""));
+        }
+    }
+
+    #[test]
+    fn test_export_csv_other_delimiters_do_not_trigger_quoting() {
+        for delimiter in [Delimiter::Tab, Delimiter::Semicolon, Delimiter::Pipe] {
+            let temp_dir = TempDir::new().unwrap();
+            let path = temp_dir.path().join("output.csv");
+            let result = QueryResult {
+                columns: vec!["id".to_string(), "amount".to_string()],
+                column_types: Vec::new(),
+                rows: vec![vec!["1".to_string(), "1,5".to_string()]],
+                execution_time_ms: 10,
+                total_rows: 1,
+                rows_affected: None,
+            };
+            let config = ExportConfig {
+                delimiter,
+                ..ExportConfig::default()
+            };
+
+            export_to_file(&result, &path, &config).unwrap();
+
+            let sep = delimiter.as_char();
+            let content = fs::read_to_string(&path).unwrap();
+            assert_eq!(content, format!("id{0}amount\n1{0}1,5\n", sep));
+        }
+    }
+
+    #[test]
+    fn test_export_csv_quote_always() {
         let temp_dir = TempDir::new().unwrap();
         let path = temp_dir.path().join("output.csv");
-        let result = QueryResult {
-            columns: vec!["id".to_string(), "description".to_string()],
-            rows: vec![
-                vec!["1".to_string(), "Hello, World".to_string()],
-                vec!["2".to_string(), "Line with \"quotes\"".to_string()],
-                vec!["3".to_string(), "Line\nwith\nnewlines".to_string()],
-            ],
-            execution_time_ms: 50,
-            total_rows: 3,
-        };
+        let result = create_test_query_result();
         let config = ExportConfig {
-            format: ExportFormat::Csv,
-            encoding: Encoding::Utf8,
+            delimiter: Delimiter::Semicolon,
+            quote_style: QuoteStyle::Always,
+            ..ExportConfig::default()
         };
 
         export_to_file(&result, &path, &config).unwrap();
 
         let content = fs::read_to_string(&path).unwrap();
-        // CSVでは、カンマ・引用符・改行を含む値はダブルクォートで囲む
-        assert!(content.contains("\"Hello, World\""));
-        assert!(content.contains("\"Line with \"\"quotes\"\"\""));
-        assert!(content.contains("\"Line\nwith\nnewlines\""));
+        // Column names are quoted only when needed, as with COPY's FORCE_QUOTE
+        assert!(content.starts_with("id;name;email\n\"1\";\"Alice\";"));
+    }
+
+    #[test]
+    fn test_export_config_extension() {
+        let mut config = ExportConfig::default();
+        assert_eq!(config.extension(), "csv");
+        config.delimiter = Delimiter::Tab;
+        assert_eq!(config.extension(), "tsv");
+        config.format = ExportFormat::Json;
+        assert_eq!(config.extension(), "json");
+        config.format = ExportFormat::JsonLines;
+        assert_eq!(config.extension(), "jsonl");
+        config.format = ExportFormat::Xlsx;
+        assert_eq!(config.extension(), "xlsx");
     }
 
     #[test]
@@ -169,6 +438,7 @@ mod tests {
         let config = ExportConfig {
             format: ExportFormat::Json,
             encoding: Encoding::Utf8,
+            ..ExportConfig::default()
         };
 
         export_to_file(&result, &path, &config).unwrap();
@@ -192,16 +462,19 @@ mod tests {
         let path = temp_dir.path().join("output.json");
         let result = QueryResult {
             columns: vec!["id".to_string(), "名前".to_string()],
+            column_types: Vec::new(),
             rows: vec![
                 vec!["1".to_string(), "田中太郎".to_string()],
                 vec!["2".to_string(), "鈴木花子".to_string()],
             ],
             execution_time_ms: 50,
             total_rows: 2,
+            rows_affected: None,
         };
         let config = ExportConfig {
             format: ExportFormat::Json,
             encoding: Encoding::Utf8,
+            ..ExportConfig::default()
         };
 
         export_to_file(&result, &path, &config).unwrap();
@@ -220,13 +493,16 @@ mod tests {
         let path = temp_dir.path().join("output_sjis.csv");
         let result = QueryResult {
             columns: vec!["id".to_string(), "名前".to_string()],
+            column_types: Vec::new(),
             rows: vec![vec!["1".to_string(), "田中太郎".to_string()]],
             execution_time_ms: 50,
             total_rows: 1,
+            rows_affected: None,
         };
         let config = ExportConfig {
             format: ExportFormat::Csv,
             encoding: Encoding::ShiftJis,
+            ..ExportConfig::default()
         };
 
         export_to_file(&result, &path, &config).unwrap();
@@ -239,19 +515,93 @@ mod tests {
         assert!(decoded.contains("田中太郎"));
     }
 
+    #[test]
+    fn test_export_csv_utf8_with_bom() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("output_bom.csv");
+        let result = create_test_query_result();
+        let config = ExportConfig {
+            with_bom: true,
+            ..ExportConfig::default()
+        };
+
+        export_to_file(&result, &path, &config).unwrap();
+
+        let bytes = fs::read(&path).unwrap();
+        assert!(bytes.starts_with(UTF8_BOM));
+        assert!(bytes[UTF8_BOM.len()..].starts_with(b"id,name,email\n"));
+    }
+
+    #[test]
+    fn test_export_csv_utf8_without_bom() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("output.csv");
+        let result = create_test_query_result();
+
+        export_to_file(&result, &path, &ExportConfig::default()).unwrap();
+
+        let bytes = fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"id,"));
+    }
+
+    #[test]
+    fn test_export_csv_shift_jis_ignores_bom() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("output_sjis.csv");
+        let result = QueryResult {
+            columns: vec!["id".to_string(), "名前".to_string()],
+            column_types: Vec::new(),
+            rows: vec![vec!["1".to_string(), "田中太郎".to_string()]],
+            execution_time_ms: 50,
+            total_rows: 1,
+            rows_affected: None,
+        };
+        let config = ExportConfig {
+            encoding: Encoding::ShiftJis,
+            with_bom: true,
+            ..ExportConfig::default()
+        };
+
+        export_to_file(&result, &path, &config).unwrap();
+
+        // Shift_JISにはBOMがないため、先頭はヘッダーのまま
+        let bytes = fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"id,"));
+    }
+
+    #[test]
+    fn test_export_json_ignores_bom() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("output.json");
+        let result = create_test_query_result();
+        let config = ExportConfig {
+            format: ExportFormat::Json,
+            with_bom: true,
+            ..ExportConfig::default()
+        };
+
+        export_to_file(&result, &path, &config).unwrap();
+
+        let bytes = fs::read(&path).unwrap();
+        assert!(!bytes.starts_with(UTF8_BOM));
+    }
+
     #[test]
     fn test_export_csv_euc_jp() {
         let temp_dir = TempDir::new().unwrap();
         let path = temp_dir.path().join("output_eucjp.csv");
         let result = QueryResult {
             columns: vec!["id".to_string(), "名前".to_string()],
+            column_types: Vec::new(),
             rows: vec![vec!["1".to_string(), "田中太郎".to_string()]],
             execution_time_ms: 50,
             total_rows: 1,
+            rows_affected: None,
         };
         let config = ExportConfig {
             format: ExportFormat::Csv,
             encoding: Encoding::EucJp,
+            ..ExportConfig::default()
         };
 
         export_to_file(&result, &path, &config).unwrap();
@@ -268,13 +618,16 @@ mod tests {
         let path = temp_dir.path().join("empty.csv");
         let result = QueryResult {
             columns: vec!["id".to_string(), "name".to_string()],
+            column_types: Vec::new(),
             rows: vec![],
             execution_time_ms: 10,
             total_rows: 0,
+            rows_affected: None,
         };
         let config = ExportConfig {
             format: ExportFormat::Csv,
             encoding: Encoding::Utf8,
+            ..ExportConfig::default()
         };
 
         export_to_file(&result, &path, &config).unwrap();
@@ -290,13 +643,16 @@ mod tests {
         let path = temp_dir.path().join("empty.json");
         let result = QueryResult {
             columns: vec!["id".to_string(), "name".to_string()],
+            column_types: Vec::new(),
             rows: vec![],
             execution_time_ms: 10,
             total_rows: 0,
+            rows_affected: None,
         };
         let config = ExportConfig {
             format: ExportFormat::Json,
             encoding: Encoding::Utf8,
+            ..ExportConfig::default()
         };
 
         export_to_file(&result, &path, &config).unwrap();
@@ -307,11 +663,88 @@ mod tests {
         assert_eq!(parsed.as_array().unwrap().len(), 0);
     }
 
+    #[test]
+    fn test_export_json_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("output.jsonl");
+        let result = create_test_query_result();
+        let config = ExportConfig {
+            format: ExportFormat::JsonLines,
+            ..ExportConfig::default()
+        };
+
+        export_to_file(&result, &path, &config).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(content.ends_with('\n'));
+
+        // 各行が単独でJSONとしてパースできる
+        for (line, name) in lines.iter().zip(["Alice", "Bob", "Charlie"]) {
+            let parsed: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(parsed.is_object());
+            assert_eq!(parsed["name"], name);
+        }
+    }
+
+    #[test]
+    fn test_export_json_lines_with_newline_in_value() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("output.jsonl");
+        let result = QueryResult {
+            columns: vec!["id".to_string(), "note".to_string()],
+            column_types: Vec::new(),
+            rows: vec![vec!["1".to_string(), "line1\nline2".to_string()]],
+            execution_time_ms: 10,
+            total_rows: 1,
+            rows_affected: None,
+        };
+        let config = ExportConfig {
+            format: ExportFormat::JsonLines,
+            ..ExportConfig::default()
+        };
+
+        export_to_file(&result, &path, &config).unwrap();
+
+        // 値の中の改行はエスケープされ、1レコード1行のまま
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content, "{\"id\":\"1\",\"note\":\"line1\\nline2\"}\n");
+    }
+
+    #[test]
+    fn test_export_json_lines_empty_result() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("empty.jsonl");
+        let result = QueryResult {
+            columns: vec!["id".to_string(), "name".to_string()],
+            column_types: Vec::new(),
+            rows: vec![],
+            execution_time_ms: 10,
+            total_rows: 0,
+            rows_affected: None,
+        };
+        let config = ExportConfig {
+            format: ExportFormat::JsonLines,
+            ..ExportConfig::default()
+        };
+
+        export_to_file(&result, &path, &config).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 0);
+        assert!(content.is_empty());
+    }
+
     #[test]
     fn test_export_config_default() {
         let config = ExportConfig::default();
         assert_eq!(config.format, ExportFormat::Csv);
         assert_eq!(config.encoding, Encoding::Utf8);
+        assert_eq!(config.delimiter, Delimiter::Comma);
+        assert_eq!(config.quote_style, QuoteStyle::Necessary);
+        assert!(!config.with_bom);
+        assert!(!config.create_dirs);
     }
 
     #[test]
@@ -333,4 +766,249 @@ mod tests {
 
         assert!(nested_path.exists());
     }
+
+    #[test]
+    fn test_export_missing_parent_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing_dir = temp_dir.path().join("missing");
+        let path = missing_dir.join("output.csv");
+        let result = create_test_query_result();
+
+        let err = export_to_file(&result, &path, &ExportConfig::default()).unwrap_err();
+
+        match err.downcast_ref::<ExportError>() {
+            Some(ExportError::ParentDirNotFound { dir }) => assert_eq!(dir, &missing_dir),
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(err.to_string().contains("Parent directory does not exist"));
+        assert!(!missing_dir.exists());
+    }
+
+    #[test]
+    fn test_export_creates_missing_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("a").join("b").join("output.json");
+        let result = create_test_query_result();
+        let config = ExportConfig {
+            format: ExportFormat::Json,
+            create_dirs: true,
+            ..ExportConfig::default()
+        };
+
+        export_to_file(&result, &path, &config).unwrap();
+
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn test_prepare_output_path_bare_file_name() {
+        assert!(prepare_output_path(Path::new("output.csv"), &ExportConfig::default()).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_export_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let read_only = temp_dir.path().join("read_only");
+        fs::create_dir(&read_only).unwrap();
+        fs::set_permissions(&read_only, fs::Permissions::from_mode(0o555)).unwrap();
+
+        // root ignores directory permissions, so there is nothing to test
+        if fs::File::create(read_only.join("probe")).is_ok() {
+            return;
+        }
+
+        let path = read_only.join("output.csv");
+        let result = create_test_query_result();
+        let err = export_to_file(&result, &path, &ExportConfig::default()).unwrap_err();
+
+        fs::set_permissions(&read_only, fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(matches!(
+            err.downcast_ref::<ExportError>(),
+            Some(ExportError::PermissionDenied { .. })
+        ));
+        assert!(err.to_string().contains("Permission denied"));
+    }
+
+    #[test]
+    fn test_export_to_clipboard_returns_text() {
+        let result = create_test_query_result();
+        let config = ExportConfig {
+            delimiter: Delimiter::Tab,
+            // ファイル向けの設定はクリップボードには影響しない
+            encoding: Encoding::ShiftJis,
+            with_bom: true,
+            ..ExportConfig::default()
+        };
+
+        let output = export(&result, &ExportTarget::Clipboard, &config).unwrap();
+
+        assert_eq!(
+            output,
+            ExportOutput::Clipboard(
+                "id\tname\temail\n1\tAlice\talice@example.com\n2\tBob\tbob@example.com\n3\tCharlie\tcharlie@example.com\n"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_text_matches_file_contents() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = create_test_query_result();
+        for format in [
+            ExportFormat::Csv,
+            ExportFormat::Json,
+            ExportFormat::JsonLines,
+        ] {
+            let path = temp_dir.path().join("output");
+            let config = ExportConfig {
+                format,
+                ..ExportConfig::default()
+            };
+
+            let output = export(&result, &ExportTarget::File(path.clone()), &config).unwrap();
+
+            let content = fs::read_to_string(&path).unwrap();
+            assert_eq!(output, ExportOutput::File(content.len() as u64));
+            assert_eq!(
+                format_text(&result, &config).unwrap(),
+                content,
+                "{:?}",
+                format
+            );
+        }
+    }
+
+    /// xlsx（zip）内のファイルを文字列として読み出す
+    fn read_xlsx_part(path: &Path, name: &str) -> String {
+        use std::io::Read;
+
+        let mut archive = zip::ZipArchive::new(fs::File::open(path).unwrap()).unwrap();
+        let mut content = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
+
+    #[test]
+    fn test_export_xlsx() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("output.xlsx");
+        let result = QueryResult {
+            columns: vec!["id".to_string(), "name".to_string(), "zip".to_string()],
+            column_types: Vec::new(),
+            rows: vec![
+                vec!["1".to_string(), "Alice".to_string(), "012".to_string()],
+                vec!["2".to_string(), "Bob".to_string(), "345".to_string()],
+            ],
+            execution_time_ms: 10,
+            total_rows: 2,
+            rows_affected: None,
+        };
+        let config = ExportConfig {
+            format: ExportFormat::Xlsx,
+            ..ExportConfig::default()
+        };
+
+        export_to_file(&result, &path, &config).unwrap();
+
+        let strings = read_xlsx_part(&path, "xl/sharedStrings.xml");
+        for text in ["id", "name", "zip", "Alice", "Bob", "012"] {
+            assert!(strings.contains(&format!("<t>{}</t>", text)), "{}", text);
+        }
+        let sheet = read_xlsx_part(&path, "xl/worksheets/sheet1.xml");
+        assert!(sheet.contains("<autoFilter ref=\"A1:C3\"/>"), "{}", sheet);
+        // 数値はそのまま数値セルに、先頭ゼロ付きの値は文字列セルになる
+        assert!(sheet.contains("<c r=\"A2\"><v>1</v></c>"), "{}", sheet);
+        assert!(sheet.contains("<c r=\"C3\"><v>345</v></c>"), "{}", sheet);
+        assert!(sheet.contains("<c r=\"C2\" t=\"s\">"), "{}", sheet);
+        // ヘッダー行は太字
+        assert!(read_xlsx_part(&path, "xl/styles.xml").contains("<b/>"));
+    }
+
+    #[test]
+    fn test_export_xlsx_to_clipboard_fails() {
+        let config = ExportConfig {
+            format: ExportFormat::Xlsx,
+            ..ExportConfig::default()
+        };
+
+        assert!(export(
+            &create_test_query_result(),
+            &ExportTarget::Clipboard,
+            &config
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_export_with_progress_reports_rows() {
+        let temp_dir = TempDir::new().unwrap();
+        let total = PROGRESS_INTERVAL_ROWS + 5;
+        let result = QueryResult {
+            columns: vec!["id".to_string()],
+            column_types: Vec::new(),
+            rows: (0..total).map(|i| vec![i.to_string()]).collect(),
+            execution_time_ms: 0,
+            total_rows: total,
+            rows_affected: None,
+        };
+        // 行単位で書き出す形式は一定行数ごとと最後の行で通知する
+        for format in [
+            ExportFormat::Csv,
+            ExportFormat::JsonLines,
+            ExportFormat::Xlsx,
+        ] {
+            let config = ExportConfig {
+                format,
+                ..ExportConfig::default()
+            };
+            let target = ExportTarget::File(temp_dir.path().join("output"));
+            let mut reported = Vec::new();
+
+            export_with_progress(
+                &result,
+                &target,
+                &config,
+                Some(&mut |rows| reported.push(rows)),
+            )
+            .unwrap();
+
+            assert_eq!(
+                reported,
+                vec![PROGRESS_INTERVAL_ROWS as u64, total as u64],
+                "{:?}",
+                format
+            );
+        }
+
+        // JSON配列とクリップボードは通知しない
+        let mut reported = Vec::new();
+        let config = ExportConfig {
+            format: ExportFormat::Json,
+            ..ExportConfig::default()
+        };
+        let target = ExportTarget::File(temp_dir.path().join("output.json"));
+        export_with_progress(
+            &result,
+            &target,
+            &config,
+            Some(&mut |rows| reported.push(rows)),
+        )
+        .unwrap();
+        export_with_progress(
+            &result,
+            &ExportTarget::Clipboard,
+            &ExportConfig::default(),
+            Some(&mut |rows| reported.push(rows)),
+        )
+        .unwrap();
+        assert!(reported.is_empty());
+    }
 }
@@ -0,0 +1,107 @@
+use std::path::Path;
+
+use rust_xlsxwriter::{ColNum, Format, RowNum, Workbook};
+
+use crate::export::report_rows;
+use crate::model::QueryResult;
+
+/// Excelで精度を失わずに扱える有効桁数
+const MAX_NUMERIC_DIGITS: usize = 15;
+
+/// 数値として書き込める値であれば数値を返す
+///
+/// `007` のような先頭ゼロ付きの値、指数表記、15桁を超える値（IDなど）は
+/// 表示が変わってしまうため文字列のまま扱う
+fn numeric_value(value: &str) -> Option<f64> {
+    let unsigned = value.strip_prefix('-').unwrap_or(value);
+    let (int_part, frac_part) = match unsigned.split_once('.') {
+        Some((int_part, frac_part)) if !frac_part.is_empty() => (int_part, frac_part),
+        Some(_) => return None,
+        None => (unsigned, ""),
+    };
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if int_part.is_empty()
+        || !all_digits(int_part)
+        || !all_digits(frac_part)
+        || (int_part.len() > 1 && int_part.starts_with('0'))
+        || int_part.len() + frac_part.len() > MAX_NUMERIC_DIGITS
+    {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// 1シートのExcelファイルとして書き出す
+/// ヘッダー行は太字にしてオートフィルターを設定する
+/// 書き込んだ行数は `on_rows` に通知する
+pub fn export(
+    result: &QueryResult,
+    path: &Path,
+    on_rows: &mut dyn FnMut(u64),
+) -> anyhow::Result<()> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    let bold = Format::new().set_bold();
+
+    // ヘッダー行
+    for (col, name) in result.columns.iter().enumerate() {
+        worksheet.write_string_with_format(0, ColNum::try_from(col)?, name, &bold)?;
+    }
+
+    // データ行
+    for (i, row) in result.rows.iter().enumerate() {
+        let row_num = RowNum::try_from(i + 1)?;
+        for (col, value) in row.iter().enumerate() {
+            let col = ColNum::try_from(col)?;
+            match numeric_value(value) {
+                Some(number) => worksheet.write_number(row_num, col, number)?,
+                None => worksheet.write_string(row_num, col, value)?,
+            };
+        }
+        report_rows(on_rows, i + 1, result.rows.len());
+    }
+
+    if !result.columns.is_empty() {
+        let last_row = RowNum::try_from(result.rows.len())?;
+        let last_col = ColNum::try_from(result.columns.len() - 1)?;
+        worksheet.autofilter(0, 0, last_row, last_col)?;
+    }
+
+    workbook.save(path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_value() {
+        assert_eq!(numeric_value("42"), Some(42.0));
+        assert_eq!(numeric_value("-3.5"), Some(-3.5));
+        assert_eq!(numeric_value("0"), Some(0.0));
+        assert_eq!(numeric_value("0.25"), Some(0.25));
+    }
+
+    #[test]
+    fn test_numeric_value_keeps_text() {
+        for value in [
+            "",
+            "-",
+            "abc",
+            "007",
+            "1e5",
+            "1.",
+            ".5",
+            "+1",
+            " 1",
+            "NaN",
+            "inf",
+            "1,000",
+            "1234567890123456",
+        ] {
+            assert_eq!(numeric_value(value), None, "{:?}", value);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Fuzzy (subsequence) matching for search modals
+//!
+//! A needle matches a haystack when all of its characters appear in order,
+//! ignoring case (so `usr` matches `user_sessions`). Matches are scored so
+//! that consecutive runs and matches at word starts rank higher, similar to fzf.
+
+/// Score for every matched character
+const SCORE_MATCH: i64 = 16;
+/// Bonus when a match directly follows the previous one
+const BONUS_CONSECUTIVE: i64 = 24;
+/// Bonus when a match starts a word (start of text, after `_`/`-`/`.`/space, or camelCase hump)
+const BONUS_WORD_START: i64 = 20;
+/// Penalty per skipped character between matches
+const PENALTY_GAP: i64 = 2;
+/// Penalty per character before the first match
+const PENALTY_LEADING: i64 = 1;
+
+/// A successful fuzzy match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    /// Char indices of the matched characters in the haystack
+    pub positions: Vec<usize>,
+}
+
+/// Score `needle` against `haystack`, or `None` if it doesn't match.
+///
+/// An empty needle matches everything with a score of 0.
+pub fn fuzzy_match(needle: &str, haystack: &str) -> Option<i64> {
+    fuzzy_match_positions(needle, haystack).map(|m| m.score)
+}
+
+/// Like [`fuzzy_match`], also returning which characters matched
+pub fn fuzzy_match_positions(needle: &str, haystack: &str) -> Option<FuzzyMatch> {
+    let needle: Vec<char> = needle.chars().collect();
+    let haystack: Vec<char> = haystack.chars().collect();
+
+    if needle.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    // Try every occurrence of the first needle char as a starting point and keep the best
+    (0..haystack.len())
+        .filter(|&start| chars_eq(haystack[start], needle[0]))
+        .filter_map(|start| match_from(&needle, &haystack, start))
+        .max_by_key(|m| (m.score, std::cmp::Reverse(m.positions[0])))
+}
+
+/// Greedily match `needle` against `haystack` starting at `start`
+fn match_from(needle: &[char], haystack: &[char], start: usize) -> Option<FuzzyMatch> {
+    let mut positions = Vec::with_capacity(needle.len());
+    let mut hay_idx = start;
+    for &n in needle {
+        while hay_idx < haystack.len() && !chars_eq(haystack[hay_idx], n) {
+            hay_idx += 1;
+        }
+        if hay_idx == haystack.len() {
+            return None;
+        }
+        positions.push(hay_idx);
+        hay_idx += 1;
+    }
+
+    let mut score = -(start as i64) * PENALTY_LEADING;
+    for (i, &pos) in positions.iter().enumerate() {
+        score += SCORE_MATCH;
+        if is_word_start(haystack, pos) {
+            score += BONUS_WORD_START;
+        }
+        if i > 0 {
+            let gap = pos - positions[i - 1] - 1;
+            if gap == 0 {
+                score += BONUS_CONSECUTIVE;
+            } else {
+                score -= gap as i64 * PENALTY_GAP;
+            }
+        }
+    }
+
+    Some(FuzzyMatch { score, positions })
+}
+
+fn chars_eq(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn is_word_start(haystack: &[char], pos: usize) -> bool {
+    if pos == 0 {
+        return true;
+    }
+    let prev = haystack[pos - 1];
+    let current = haystack[pos];
+    matches!(prev, '_' | '-' | '.' | ' ' | '/') || (prev.is_lowercase() && current.is_uppercase())
+}
+
+/// Indices of `candidates` matching `needle`, best match first.
+///
+/// With an empty needle every candidate is returned in its original order.
+/// Ties are broken by shorter candidate first, then original order.
+pub fn rank<'a>(needle: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<usize> {
+    if needle.is_empty() {
+        return candidates
+            .into_iter()
+            .enumerate()
+            .map(|(idx, _)| idx)
+            .collect();
+    }
+    let mut scored: Vec<(usize, i64, usize)> = candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, candidate)| {
+            fuzzy_match(needle, candidate).map(|score| (idx, score, candidate.chars().count()))
+        })
+        .collect();
+    scored.sort_by_key(|&(_, score, len)| (std::cmp::Reverse(score), len));
+    scored.into_iter().map(|(idx, _, _)| idx).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsequence_matches() {
+        assert!(fuzzy_match("usr", "user_sessions").is_some());
+        assert!(fuzzy_match("USR", "user_sessions").is_some());
+        assert!(fuzzy_match("ses", "user_sessions").is_some());
+    }
+
+    #[test]
+    fn test_out_of_order_does_not_match() {
+        assert_eq!(fuzzy_match("rsu", "user"), None);
+        assert_eq!(fuzzy_match("userx", "user"), None);
+    }
+
+    #[test]
+    fn test_empty_needle_matches_everything() {
+        assert_eq!(fuzzy_match("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_positions() {
+        let m = fuzzy_match_positions("us", "user_sessions").unwrap();
+        assert_eq!(m.positions, vec![0, 1]);
+
+        let m = fuzzy_match_positions("ss", "user_sessions").unwrap();
+        // The consecutive "ss" in "sessions" beats the scattered "s...s"
+        assert_eq!(m.positions, vec![7, 8]);
+
+        let m = fuzzy_match_positions("se", "user_sessions").unwrap();
+        // Word start "s" of "sessions" is preferred over the "s" in "user"
+        assert_eq!(m.positions, vec![5, 6]);
+    }
+
+    #[test]
+    fn test_consecutive_beats_scattered() {
+        let consecutive = fuzzy_match("user", "users").unwrap();
+        let scattered = fuzzy_match("user", "u_s_e_r").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn test_word_start_beats_middle() {
+        let word_start = fuzzy_match("s", "user_sessions").unwrap();
+        let middle = fuzzy_match("s", "bus").unwrap();
+        assert!(word_start > middle);
+    }
+
+    #[test]
+    fn test_camel_case_hump_is_word_start() {
+        let hump = fuzzy_match("ui", "userId").unwrap();
+        let plain = fuzzy_match("ui", "useridx").unwrap();
+        assert!(hump > plain);
+    }
+
+    #[test]
+    fn test_rank_sorts_by_score() {
+        let names = ["user_sessions", "orders", "users", "audit_user_log"];
+        let ranked = rank("user", names);
+        assert_eq!(ranked[0], 2); // "users": prefix, shortest
+        assert!(!ranked.contains(&1)); // "orders" doesn't contain u-s-e-r in order
+        assert_eq!(ranked.len(), 3);
+    }
+
+    #[test]
+    fn test_rank_empty_needle_keeps_order() {
+        assert_eq!(rank("", ["b", "a", "c"]), vec![0, 1, 2]);
+    }
+}
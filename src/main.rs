@@ -15,32 +15,39 @@
 //! # Modules
 //!
 //! - [`app`] - Application state and update logic
+//! - [`clipboard`] - Clipboard access through the terminal (OSC 52)
 //! - [`config`] - Configuration file management
 //! - [`db`] - Database provider abstraction layer
 //! - [`event`] - Keyboard event handling
 //! - [`export`] - Data export (CSV, JSON)
+//! - [`fuzzy`] - Fuzzy matching for search modals
 //! - [`message`] - Message types for TEA pattern
 //! - [`model`] - Data models
 //! - [`ui`] - User interface components
 
 mod app;
+mod clipboard;
 mod config;
 mod db;
 mod event;
 mod export;
+mod fuzzy;
 mod message;
 mod model;
 mod ui;
 
 use std::io;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use app::App;
 use clap::Parser;
-use config::ConfigLoader;
+use config::{Config, ConfigLoader, ConfigWatcher, ProjectFile};
 use crossterm::{
-    event::{poll, read, DisableMouseCapture, EnableMouseCapture, Event},
+    event::{
+        poll, read, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste,
+        EnableMouseCapture, Event,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -64,42 +71,91 @@ fn main() -> Result<()> {
     // Initialize config directory and load projects
     let config_loader = ConfigLoader::new()?;
     config_loader.init_config_dir()?;
-    let config = config_loader.load_config()?;
-    let (project_files, _warnings) = config_loader.load_all_projects(&config);
+    let (mut config, mut warnings) = config_loader.load_config()?;
+    let (project_files, project_warnings, load_errors) = config_loader.load_all_projects(&config);
+    warnings.extend(project_warnings);
     let projects: Vec<Project> = project_files.into_iter().map(Project::from).collect();
 
     // Load query history (ignore errors - start with empty history if load fails)
     let history = config_loader.load_history().unwrap_or_default();
+    let recent_tables = config_loader.load_recent_tables().unwrap_or_default();
 
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app with loaded projects and history
     let mut app = App::with_history(projects, history);
+    app.recent_tables = recent_tables;
+    app.apply_settings(&config.settings);
+
+    // Report files that were upgraded from an older format
+    if let Some(first) = warnings.first() {
+        app.status_message = match warnings.len() {
+            1 => first.clone(),
+            n => format!("{} (and {} more warnings)", first, n - 1),
+        };
+    }
+    // Broken project files take precedence: they are listed in a modal
+    app.report_project_load_errors(load_errors);
+    restart_config_watcher(&mut app, &config_loader, &config);
 
     // Spawn background DB worker thread
-    let db_worker = spawn_db_worker();
+    let db_worker = spawn_db_worker(app.connect_retry);
     app.set_db_worker(db_worker);
 
+    // Reopen where the previous run left off (needs the worker to list tables)
+    if config.settings.restore_session {
+        match config_loader.load_session() {
+            Ok(session) => app.restore_session(&session),
+            Err(e) => app.status_message = format!("Failed to restore session: {}", e),
+        }
+    }
+
     // Main loop
-    let res = run_app(&mut terminal, &mut app, &config_loader);
+    let res = run_app(&mut terminal, &mut app, &config_loader, &mut config);
+
+    // Write history still waiting for its save interval
+    let history_res = if app.history_dirty {
+        config_loader.save_history(&app.query_history)
+    } else {
+        Ok(())
+    };
+
+    // Remember where we left off for the next launch
+    let session_res = if config.settings.restore_session {
+        config_loader.save_session(&app.session_snapshot())
+    } else {
+        Ok(())
+    };
 
     // Restore terminal
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
     if let Err(err) = res {
         eprintln!("Error: {err:?}");
     }
+    if let Err(err) = history_res {
+        eprintln!("Failed to save history: {err:?}");
+    }
+    if let Err(err) = session_res {
+        eprintln!("Failed to save session: {err:?}");
+    }
 
     Ok(())
 }
@@ -108,13 +164,29 @@ fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
     config_loader: &ConfigLoader,
+    config: &mut Config,
 ) -> Result<()> {
     loop {
+        // Process any pending DB responses (non-blocking)
+        app.process_db_responses();
+
+        // Notice config files edited outside lazydb (`watch_config`)
+        app.process_config_changes(Instant::now());
+
+        // Terminal requests from key handling or DB responses (e.g. a finished
+        // clipboard export), applied before the next input event arrives
+        apply_terminal_requests(terminal, app)?;
+
         // View: render UI
         terminal.draw(|frame| ui::draw(frame, app))?;
 
-        // Process any pending DB responses (non-blocking)
-        app.process_db_responses();
+        // Save history if dirty, spaced out by `history_save_interval_secs`
+        if app.history_save_due(Instant::now()) {
+            if let Err(e) = config_loader.save_history(&app.query_history) {
+                app.status_message = format!("Failed to save history: {}", e);
+            }
+            app.mark_history_saved(Instant::now());
+        }
 
         // Poll for input events with timeout (allows checking DB responses regularly)
         if !poll(POLL_TIMEOUT)? {
@@ -123,27 +195,93 @@ fn run_app(
         }
 
         // Handle input events
-        if let Event::Key(key) = read()? {
-            let message = event::key_to_message(app, key.code, key.modifiers);
+        let message = match read()? {
+            Event::Key(key) => event::key_to_message(app, key.code, key.modifiers),
+            Event::Paste(text) => event::paste_to_message(app, text),
+            _ => None,
+        };
 
-            if let Some(msg) = message {
-                // Update: process message
-                let should_quit = app.update(msg);
+        if let Some(msg) = message {
+            // Update: process message
+            let should_quit = app.update(msg);
 
-                // Save history if dirty
-                if app.history_dirty {
-                    if let Err(e) = config_loader.save_history(&app.query_history) {
-                        app.status_message = format!("Failed to save history: {}", e);
+            // Save recently opened tables if dirty
+            if app.recent_tables_dirty {
+                if let Err(e) = config_loader.save_recent_tables(&app.recent_tables) {
+                    app.status_message = format!("Failed to save recent tables: {}", e);
+                }
+                app.recent_tables_dirty = false;
+            }
+
+            // Read the config and project files again after they were edited elsewhere
+            if std::mem::take(&mut app.pending_config_reload) {
+                match config_loader.load_config() {
+                    Ok((reloaded, mut warnings)) => {
+                        let (project_files, project_warnings, load_errors) =
+                            config_loader.load_all_projects(&reloaded);
+                        warnings.extend(project_warnings);
+                        *config = reloaded;
+                        let projects = project_files.into_iter().map(Project::from).collect();
+                        app.reload_projects(projects, warnings, load_errors);
+                        restart_config_watcher(app, config_loader, config);
                     }
-                    app.history_dirty = false;
+                    Err(e) => app.status_message = format!("Failed to reload config: {:#}", e),
                 }
+            }
 
-                if should_quit {
-                    break;
+            // Save newly created project to its own file
+            if let Some(proj_idx) = app.pending_project_save.take() {
+                if let Some(project) = app.projects.get(proj_idx) {
+                    let project_file = ProjectFile::from(project);
+                    match config_loader.add_project_file(config, &project_file) {
+                        Ok(path) => {
+                            app.status_message = format!("Project saved to {}", path);
+                            // Watch the new file; our own writes are not reported
+                            restart_config_watcher(app, config_loader, config);
+                        }
+                        Err(e) => app.status_message = format!("Failed to save project: {}", e),
+                    }
                 }
             }
+
+            if should_quit {
+                break;
+            }
         }
     }
 
     Ok(())
 }
+
+/// Repaint the screen and copy text to the clipboard when the app asked to
+fn apply_terminal_requests(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+) -> Result<()> {
+    // Repaint everything, e.g. after other output garbled the screen
+    if std::mem::take(&mut app.pending_redraw) {
+        terminal.clear()?;
+    }
+
+    // Copy requested text through the terminal
+    if let Some(text) = app.pending_clipboard.take() {
+        if let Err(e) = clipboard::copy_to_clipboard(terminal.backend_mut(), &text) {
+            app.status_message = format!("Failed to copy to clipboard: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Start watching the config files afresh when `watch_config` is on.
+///
+/// Also called after lazydb writes them, so its own writes are not reported.
+fn restart_config_watcher(app: &mut App, config_loader: &ConfigLoader, config: &Config) {
+    app.config_watcher = None;
+    if !config.settings.watch_config {
+        return;
+    }
+    match ConfigWatcher::start(config_loader, config) {
+        Ok(watcher) => app.config_watcher = Some(watcher),
+        Err(e) => app.status_message = format!("Failed to watch config files: {:#}", e),
+    }
+}
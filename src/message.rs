@@ -18,6 +18,11 @@
 #[derive(Debug, Clone, PartialEq)]
 pub enum Message {
     Quit,
+    ForceQuit,
+    // Keybinding help overlay
+    OpenHelp,
+    // Reset the status message and redraw the whole screen (Ctrl+l)
+    ClearStatus,
     NavigateUp,
     NavigateDown,
     NextFocus,
@@ -28,6 +33,18 @@ pub enum Message {
     FocusUp,
     FocusDown,
     Activate,
+    // Run the query in the editor against the selected connection
+    ExecuteQuery,
+    /// Check the editor query on the server without running it
+    ValidateQuery,
+    /// Open a query editor tab with a copy of the current query
+    NewQueryTab,
+    /// Activate the next query editor tab
+    NextQueryTab,
+    /// Close the active query editor tab
+    CloseQueryTab,
+    /// Run the statement held by the production write confirmation
+    ExecuteQueryConfirmed,
     GoBack,
     // Main panel tabs
     SwitchToSchema,
@@ -42,9 +59,19 @@ pub enum Message {
     SwitchToDefinition,
     // Connection modal messages
     OpenAddConnectionModal,
+    CloneConnection,
+    ImportConnections,
     // Project modal messages
     OpenAddProjectModal,
     OpenEditProjectModal,
+    CloneProject,
+    CreateSampleProject,
+    /// List the project files that failed to load at startup
+    ShowLoadErrors,
+    /// Read `config.yaml` and the project files again from disk
+    ReloadConfig,
+    ExportProject,
+    ImportProject,
     DeleteProject,
     // Search modal messages
     OpenSearchProjectModal,
@@ -53,27 +80,101 @@ pub enum Message {
     #[allow(dead_code)] // Legacy: kept for potential future use
     OpenSearchTableModal,
     OpenUnifiedSearchModal,
+    OpenGlobalSearchModal,
     SearchConfirm,
     SearchConnectionConfirm,
     TableSearchConfirm,
     UnifiedSearchConfirm,
+    GlobalSearchConfirm,
     UnifiedSearchSwitchSection,
+    // Column filter in the Columns sub-tab
+    StartColumnSearch,
+    ColumnSearchInput(char),
+    ColumnSearchBackspace,
+    ColumnSearchConfirm,
+    ColumnSearchCancel,
+    // Copy the selected column / index definition (Schema tab)
+    CopyDefinition,
+    /// Show the full definition of the selected constraint (Schema tab)
+    ShowConstraintDetail,
     // Column visibility modal messages
     OpenColumnVisibilityModal,
     ToggleColumnVisibility,
+    // Connection modal: cycle the database type
+    DatabaseTypeNext,
+    DatabaseTypePrev,
+    /// Connection modal: flip the production flag
+    ToggleProductionFlag,
+    /// Connection modal: show or mask the typed password
+    TogglePasswordReveal,
+    /// Open the WHERE clause builder for the selected table (Data tab)
+    OpenFilterModal,
+    /// Open the server-side ORDER BY picker for the selected table (Data tab)
+    OpenOrderModal,
+    // Filter and order modals: cycle the focused selector
+    ModalOptionNext,
+    ModalOptionPrev,
     // Common modal messages
     CloseModal,
     ModalConfirm,
     ModalInputChar(char),
     ModalInputBackspace,
+    // Connection and project modals: edit within the focused text field
+    ModalCursorLeft,
+    ModalCursorRight,
+    ModalCursorHome,
+    ModalCursorEnd,
+    /// Delete the word before the cursor (Ctrl+w)
+    ModalDeleteWord,
+    /// Text pasted from the terminal, for the open modal or the query editor
+    Paste(String),
     ModalNextField,
     ModalPrevField,
+    // Database stats modal
+    ShowDatabaseStats,
+    // Active sessions modal
+    ShowActiveSessions,
+    RefreshActiveSessions,
+    /// List the users and roles of the server (DBA view)
+    ShowRoles,
+    /// Pick one of the selected connection's snippets into the editor
+    OpenConnectionSnippets,
+    /// List the session's run-time parameters (`SHOW ALL`)
+    ShowServerSettings,
+    /// Set the `search_path` of the selected connection's sessions
+    SetSearchPath,
+    // Ask to confirm terminating the selected session
+    TerminateSession,
+    CancelTerminateSession,
+    // Drop loaded state for the selected connection and connect again
+    Reconnect,
+    /// Stop waiting for the table list / table details being loaded
+    CancelMetadataFetch,
+    // Show or hide system schemas in table lists
+    ToggleSystemObjects,
+    /// Re-fetch the tables of every expanded connection in all projects
+    RefreshAll,
+    /// Wait for the second key of a sequence starting with this key (`z` of `zM`)
+    StartKeySequence(char),
+    /// Drop a started key sequence whose second key is not bound
+    CancelKeySequence,
+    /// Collapse / expand every connection of the current project
+    CollapseAll,
+    ExpandAll,
     // Query history messages
     OpenHistoryModal,
     HistoryNavigateUp,
     HistoryNavigateDown,
     HistorySelectEntry,
+    HistoryShowDetail,
+    HistoryToggleFilter,
+    HistoryRerun,
     ClearHistory,
+    // Recent tables messages
+    OpenRecentTables,
+    RecentTablesNavigateUp,
+    RecentTablesNavigateDown,
+    RecentTablesSelect,
     // Pagination messages
     PageNext,
     PagePrev,
@@ -87,4 +188,44 @@ pub enum Message {
     DataTablePageDown,
     DataTableFirst,
     DataTableLast,
+    DataTableColumnLeft,
+    DataTableColumnRight,
+    /// Open a searchable list of the result's columns to jump to one
+    OpenGoToColumn,
+    GoToColumnConfirm,
+    /// Show statistics of the selected result column
+    ColumnStats,
+    /// Move the selected result column one place left in the Data grid
+    MoveColumnLeft,
+    /// Move the selected result column one place right in the Data grid
+    MoveColumnRight,
+    /// Hide the selected result column from the Data grid
+    HideColumn,
+    /// Show the result columns hidden from the Data grid again
+    ShowAllColumns,
+    /// Show or hide column types under the Data grid headers
+    ToggleColumnTypes,
+    /// Wrap long Data grid values onto more lines, or cut them at the column width
+    ToggleCellWrap,
+    /// Show or hide the SQL that produced the current result
+    ToggleExecutedQuery,
+    /// Maximize the main panel over the sidebar and query editor, or restore it
+    ToggleFullscreenData,
+    /// Re-fetch the current result, bypassing the result cache
+    RefreshResult,
+    // Export messages
+    ExportCsv,
+    ExportJson,
+    /// Put a SELECT listing the selected table's columns into the query editor
+    GenerateSelect,
+    /// Copy the selected table's schema-qualified, quoted name to the clipboard
+    CopyTableName,
+    /// Copy the current result to the clipboard as CSV
+    CopyResultCsv,
+    /// Copy as CSV after confirming a result above the row threshold
+    CopyResultCsvConfirmed,
+    /// Copy the fetched rows to the clipboard as a JSON array
+    CopyResultJson,
+    /// Copy as JSON after confirming a result above the row threshold
+    CopyResultJsonConfirmed,
 }
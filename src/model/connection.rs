@@ -1,5 +1,8 @@
 use super::schema::Table;
-use crate::config::ConnectionConfig;
+use crate::config::{ConnectionConfig, SavedQuery};
+
+/// Schema used when neither the config nor the server provides one
+pub const DEFAULT_SCHEMA: &str = "public";
 
 #[derive(Debug, Clone)]
 pub struct Connection {
@@ -9,8 +12,92 @@ pub struct Connection {
     pub database: String,
     pub username: String,
     pub password: String,
+    /// Environment variable the password was read from, if any
+    pub password_env: Option<String>,
     pub expanded: bool,
     pub tables: Vec<Table>,
+    /// Schema configured for this connection, if any
+    pub default_schema: Option<String>,
+    /// Color label configured for this connection, if any
+    pub color: Option<String>,
+    /// Production database (flagged in the status bar, writes ask first)
+    pub is_production: bool,
+    /// `statement_timeout` override in seconds (0 disables the timeout)
+    pub query_timeout_secs: Option<u64>,
+    /// Named queries offered in the snippet menu
+    pub snippets: Vec<SavedQuery>,
+    /// `search_path` set for the session from the UI (not saved)
+    pub search_path: Option<String>,
+}
+
+impl Connection {
+    /// Schema to browse for this connection, without asking the server.
+    ///
+    /// A session `search_path` takes precedence over the configured schema.
+    pub fn schema(&self) -> String {
+        let from_path = self
+            .search_path
+            .as_deref()
+            .and_then(first_search_path_schema);
+        resolve_default_schema(from_path.or(self.default_schema.as_deref()), None)
+    }
+}
+
+/// First schema named on a normalized `search_path`, skipping `"$user"`
+fn first_search_path_schema(path: &str) -> Option<&str> {
+    path.split(',')
+        .map(|schema| schema.trim().trim_matches('"'))
+        .find(|schema| *schema != "$user")
+}
+
+/// Check a `search_path` typed by the user and normalize its spacing.
+///
+/// Each schema must be a plain identifier or a double-quoted name, so the
+/// value can be placed in `SET search_path TO ...` without escaping.
+pub fn normalize_search_path(input: &str) -> Result<String, String> {
+    let schemas: Vec<&str> = input.split(',').map(str::trim).collect();
+    if schemas.iter().all(|s| s.is_empty()) {
+        return Err("search_path is empty".to_string());
+    }
+    for schema in &schemas {
+        let quoted = schema.len() >= 2
+            && schema.starts_with('"')
+            && schema.ends_with('"')
+            && !schema[1..schema.len() - 1].contains('"');
+        let mut chars = schema.chars();
+        let plain = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+            && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+        if !quoted && !plain {
+            return Err(format!("Invalid schema name: {:?}", schema));
+        }
+    }
+    Ok(schemas.join(", "))
+}
+
+impl From<&Connection> for ConnectionConfig {
+    /// Passwords read from an environment variable keep referring to it
+    /// rather than being written out in plain text.
+    fn from(conn: &Connection) -> Self {
+        let password = if conn.password_env.is_some() || conn.password.is_empty() {
+            None
+        } else {
+            Some(conn.password.clone())
+        };
+        Self {
+            name: conn.name.clone(),
+            host: conn.host.clone(),
+            port: conn.port,
+            database: conn.database.clone(),
+            username: Some(conn.username.clone()).filter(|u| !u.is_empty()),
+            password,
+            password_env: conn.password_env.clone(),
+            default_schema: conn.default_schema.clone(),
+            color: conn.color.clone(),
+            is_production: conn.is_production,
+            query_timeout_secs: conn.query_timeout_secs,
+            snippets: conn.snippets.clone(),
+        }
+    }
 }
 
 impl From<ConnectionConfig> for Connection {
@@ -23,8 +110,148 @@ impl From<ConnectionConfig> for Connection {
             database: config.database,
             username: config.username.unwrap_or_default(),
             password,
+            password_env: config.password_env,
             expanded: false,
             tables: Vec::new(),
+            default_schema: config.default_schema,
+            color: config.color,
+            is_production: config.is_production,
+            query_timeout_secs: config.query_timeout_secs,
+            snippets: config.snippets,
+            search_path: None,
+        }
+    }
+}
+
+/// Resolve the schema to browse.
+///
+/// Priority:
+/// 1. The schema configured on the connection
+/// 2. The schema reported by the server (`current_schema()`)
+/// 3. [`DEFAULT_SCHEMA`]
+///
+/// Blank values are ignored.
+pub fn resolve_default_schema(configured: Option<&str>, detected: Option<&str>) -> String {
+    [configured, detected]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .find(|s| !s.is_empty())
+        .unwrap_or(DEFAULT_SCHEMA)
+        .to_string()
+}
+
+/// Resolve the `statement_timeout` (in seconds) of a connection's sessions.
+///
+/// Priority:
+/// 1. The connection's `query_timeout_secs`
+/// 2. The `statement_timeout_secs` setting
+///
+/// A value of 0 means no timeout, so a connection can also opt out of the
+/// global one. Returns `None` when no timeout applies.
+pub fn resolve_statement_timeout(connection: Option<u64>, setting: u64) -> Option<u64> {
+    Some(connection.unwrap_or(setting)).filter(|&secs| secs > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_connection(password_env: Option<&str>) -> Connection {
+        Connection {
+            name: "test".to_string(),
+            host: "localhost".to_string(),
+            port: 5432,
+            database: "testdb".to_string(),
+            username: "user".to_string(),
+            password: "secret".to_string(),
+            password_env: password_env.map(str::to_string),
+            expanded: true,
+            tables: Vec::new(),
+            default_schema: Some("app".to_string()),
+            color: Some("red".to_string()),
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
+            search_path: None,
         }
     }
+
+    #[test]
+    fn test_connection_config_from_connection_keeps_direct_password() {
+        let config = ConnectionConfig::from(&create_connection(None));
+        assert_eq!(config.password, Some("secret".to_string()));
+        assert_eq!(config.password_env, None);
+        assert_eq!(config.default_schema, Some("app".to_string()));
+        assert_eq!(config.color, Some("red".to_string()));
+        assert_eq!(Connection::from(config).color, Some("red".to_string()));
+    }
+
+    #[test]
+    fn test_connection_config_from_connection_keeps_password_env() {
+        let config = ConnectionConfig::from(&create_connection(Some("DB_PASSWORD")));
+        assert_eq!(config.password, None);
+        assert_eq!(config.password_env, Some("DB_PASSWORD".to_string()));
+    }
+
+    #[test]
+    fn test_normalize_search_path() {
+        assert_eq!(
+            normalize_search_path(" billing ,\"$user\",public").unwrap(),
+            "billing, \"$user\", public"
+        );
+        assert_eq!(
+            normalize_search_path("\"My Schema\"").unwrap(),
+            "\"My Schema\""
+        );
+        assert!(normalize_search_path("  ").is_err());
+        assert!(normalize_search_path("public,").is_err());
+        assert!(normalize_search_path("public; DROP TABLE users").is_err());
+        assert!(normalize_search_path("\"a\"b\"").is_err());
+    }
+
+    #[test]
+    fn test_schema_follows_session_search_path() {
+        let mut conn = create_connection(None);
+        assert_eq!(conn.schema(), "app");
+
+        conn.search_path = Some("\"$user\", billing".to_string());
+        assert_eq!(conn.schema(), "billing");
+    }
+
+    #[test]
+    fn test_resolve_default_schema_prefers_configured() {
+        assert_eq!(
+            resolve_default_schema(Some("app"), Some("reporting")),
+            "app"
+        );
+    }
+
+    #[test]
+    fn test_resolve_default_schema_uses_detected() {
+        assert_eq!(resolve_default_schema(None, Some("reporting")), "reporting");
+    }
+
+    #[test]
+    fn test_resolve_default_schema_falls_back_to_public() {
+        assert_eq!(resolve_default_schema(None, None), DEFAULT_SCHEMA);
+    }
+
+    #[test]
+    fn test_resolve_default_schema_ignores_blank_values() {
+        assert_eq!(resolve_default_schema(Some("  "), Some("")), DEFAULT_SCHEMA);
+        assert_eq!(resolve_default_schema(Some(""), Some("sales")), "sales");
+    }
+
+    #[test]
+    fn test_resolve_statement_timeout() {
+        // The connection's own timeout wins
+        assert_eq!(resolve_statement_timeout(Some(5), 60), Some(5));
+        assert_eq!(resolve_statement_timeout(Some(120), 0), Some(120));
+        // Then the global setting
+        assert_eq!(resolve_statement_timeout(None, 60), Some(60));
+        // 0 on the connection turns the global timeout off
+        assert_eq!(resolve_statement_timeout(Some(0), 60), None);
+        assert_eq!(resolve_statement_timeout(None, 0), None);
+    }
 }
@@ -5,16 +5,37 @@
 //! - [`Project`] - Project containing multiple connections
 //! - [`QueryResult`] - Results from SQL query execution
 //! - [`QueryHistory`] - Persisted query history
+//! - [`RecentTables`] - Recently opened tables
+//! - [`SessionSnapshot`] - Open project, connections and query restored on launch
+//! - [`DatabaseStats`] - Database size summary
+//! - [`ActiveSession`] - Sessions connected to a database
+//! - [`DatabaseRole`] - Users and roles defined on the server
+//! - [`ServerSetting`] - Run-time parameters of the session
 //! - [`schema`] - Database schema models (tables, columns, indexes, etc.)
 
 mod connection;
 pub mod history;
 mod project;
 mod query;
+mod recent;
+mod role;
 pub mod schema;
+mod session;
+mod setting;
+mod snapshot;
+mod stats;
 
-pub use connection::Connection;
+pub use connection::{
+    normalize_search_path, resolve_default_schema, resolve_statement_timeout, Connection,
+    DEFAULT_SCHEMA,
+};
 pub use history::{HistoryEntry, QueryHistory};
-pub use project::Project;
-pub use query::{Pagination, QueryResult};
-pub use schema::{ConstraintType, ForeignKey, IndexType, SortOrder, Table};
+pub use project::{copy_name, unique_name, Project};
+pub use query::{unique_column_names, Pagination, QueryOutcome, QueryResult, PAGE_SIZES};
+pub use recent::{RecentTable, RecentTables};
+pub use role::DatabaseRole;
+pub use schema::{ConstraintType, ForeignKey, IconStyle, IndexType, SortOrder, Table};
+pub use session::ActiveSession;
+pub use setting::ServerSetting;
+pub use snapshot::SessionSnapshot;
+pub use stats::{ColumnStats, DatabaseStats, TableSize};
@@ -1,5 +1,7 @@
+use chrono::Utc;
+
 use super::connection::Connection;
-use crate::config::ProjectFile;
+use crate::config::{ConnectionConfig, ProjectConfig, ProjectFile, CONFIG_VERSION};
 
 #[derive(Debug, Clone)]
 pub struct Project {
@@ -14,6 +16,51 @@ impl Project {
             connections: Vec::new(),
         }
     }
+
+    /// Deep copy of this project under a new name.
+    ///
+    /// Connections (including passwords) are copied; loaded tables and
+    /// expansion state are not.
+    pub fn duplicate(&self, name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            connections: self
+                .connections
+                .iter()
+                .map(|conn| Connection {
+                    expanded: false,
+                    tables: Vec::new(),
+                    ..conn.clone()
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Name for a copy of `name` that doesn't collide with `existing` names
+///
+/// Tries `"<name> (copy)"`, then `"<name> (copy 2)"`, `"<name> (copy 3)"`, ...
+pub fn copy_name<'a>(name: &str, existing: impl IntoIterator<Item = &'a str> + Clone) -> String {
+    let is_taken = |candidate: &str| existing.clone().into_iter().any(|n| n == candidate);
+    let mut candidate = format!("{} (copy)", name);
+    let mut n = 2;
+    while is_taken(&candidate) {
+        candidate = format!("{} (copy {})", name, n);
+        n += 1;
+    }
+    candidate
+}
+
+/// `name` if no existing name matches, otherwise `"<name> (2)"`, `"<name> (3)"`, ...
+pub fn unique_name<'a>(name: &str, existing: impl IntoIterator<Item = &'a str> + Clone) -> String {
+    let is_taken = |candidate: &str| existing.clone().into_iter().any(|n| n == candidate);
+    let mut candidate = name.to_string();
+    let mut n = 2;
+    while is_taken(&candidate) {
+        candidate = format!("{} ({})", name, n);
+        n += 1;
+    }
+    candidate
 }
 
 impl From<ProjectFile> for Project {
@@ -24,3 +71,79 @@ impl From<ProjectFile> for Project {
         }
     }
 }
+
+impl From<&Project> for ProjectFile {
+    fn from(project: &Project) -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            project: ProjectConfig {
+                name: project.name.clone(),
+                description: None,
+                created_at: Some(Utc::now()),
+            },
+            connections: project
+                .connections
+                .iter()
+                .map(ConnectionConfig::from)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_project() -> Project {
+        let mut project = Project::new("Shop");
+        project.connections.push(Connection {
+            name: "primary".to_string(),
+            host: "db1".to_string(),
+            port: 5432,
+            database: "shop".to_string(),
+            username: "app".to_string(),
+            password: "secret".to_string(),
+            password_env: None,
+            expanded: true,
+            tables: Vec::new(),
+            default_schema: None,
+            color: None,
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
+            search_path: None,
+        });
+        project
+    }
+
+    #[test]
+    fn test_duplicate_copies_connections() {
+        let copy = create_project().duplicate("Shop (copy)");
+        assert_eq!(copy.name, "Shop (copy)");
+        assert_eq!(copy.connections.len(), 1);
+        assert_eq!(copy.connections[0].password, "secret");
+        assert!(!copy.connections[0].expanded);
+    }
+
+    #[test]
+    fn test_copy_name_avoids_collisions() {
+        assert_eq!(copy_name("Shop", ["Shop"]), "Shop (copy)");
+        assert_eq!(
+            copy_name("Shop", ["Shop", "Shop (copy)", "Shop (copy 2)"]),
+            "Shop (copy 3)"
+        );
+    }
+
+    #[test]
+    fn test_unique_name() {
+        assert_eq!(unique_name("Shop", ["Other"]), "Shop");
+        assert_eq!(unique_name("Shop", ["Shop", "Shop (2)"]), "Shop (3)");
+    }
+
+    #[test]
+    fn test_project_file_from_project() {
+        let file = ProjectFile::from(&create_project());
+        assert_eq!(file.project.name, "Shop");
+        assert_eq!(file.connections[0].password, Some("secret".to_string()));
+    }
+}
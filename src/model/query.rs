@@ -1,10 +1,100 @@
+use std::collections::HashSet;
+
 #[derive(Debug, Clone)]
 pub struct QueryResult {
     pub columns: Vec<String>,
+    /// Database type name of each column (empty when the provider does not report them)
+    pub column_types: Vec<String>,
     pub rows: Vec<Vec<String>>,
     pub execution_time_ms: u64,
     #[allow(dead_code)]
     pub total_rows: usize,
+    /// Rows changed by a statement that returns no rows (`INSERT`, `UPDATE`, ...)
+    pub rows_affected: Option<u64>,
+}
+
+/// How the last query ended, as summarized in the status bar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryOutcome {
+    /// The query returned a result set (possibly empty)
+    Returned { rows: usize, execution_time_ms: u64 },
+    /// A statement without a result set changed this many rows
+    Affected { rows: u64, execution_time_ms: u64 },
+    /// The query failed with this error
+    Failed(String),
+}
+
+impl QueryOutcome {
+    pub fn from_result(result: &QueryResult) -> Self {
+        match result.rows_affected {
+            Some(rows) => Self::Affected {
+                rows,
+                execution_time_ms: result.execution_time_ms,
+            },
+            None => Self::Returned {
+                rows: result.rows.len(),
+                execution_time_ms: result.execution_time_ms,
+            },
+        }
+    }
+
+    /// Status bar text, e.g. `3 rows returned`, `1 row affected` or `Error: ...`
+    pub fn summary(&self) -> String {
+        let rows = |n: u64| {
+            if n == 1 {
+                "1 row".to_string()
+            } else {
+                format!("{} rows", n)
+            }
+        };
+        match self {
+            Self::Returned { rows: n, .. } => format!("{} returned", rows(*n as u64)),
+            Self::Affected { rows: n, .. } => format!("{} affected", rows(*n)),
+            Self::Failed(e) => format!("Error: {}", e),
+        }
+    }
+
+    /// Time the query took; failed queries report none
+    pub fn execution_time_ms(&self) -> Option<u64> {
+        match self {
+            Self::Returned {
+                execution_time_ms, ..
+            }
+            | Self::Affected {
+                execution_time_ms, ..
+            } => Some(*execution_time_ms),
+            Self::Failed(_) => None,
+        }
+    }
+}
+
+/// Placeholder PostgreSQL uses for unnamed result columns
+const UNNAMED_COLUMN: &str = "?column?";
+
+/// Make result column names unique so grid headers and JSON keys stay unambiguous.
+///
+/// Blank names become `?column?`; repeated names get `_2`, `_3`, ... appended
+/// (skipping suffixes that are already taken by another column).
+pub fn unique_column_names(names: Vec<String>) -> Vec<String> {
+    let mut seen: HashSet<String> = HashSet::with_capacity(names.len());
+    names
+        .into_iter()
+        .map(|name| {
+            let name = if name.trim().is_empty() {
+                UNNAMED_COLUMN.to_string()
+            } else {
+                name
+            };
+            let mut unique = name.clone();
+            let mut n = 2;
+            while seen.contains(&unique) {
+                unique = format!("{}_{}", name, n);
+                n += 1;
+            }
+            seen.insert(unique.clone());
+            unique
+        })
+        .collect()
 }
 
 /// Available page sizes for pagination
@@ -16,6 +106,8 @@ pub struct Pagination {
     pub current_page: usize,
     pub page_size: usize,
     pub total_rows: usize,
+    /// Whether moving past the last (or first) page wraps around instead of stopping
+    pub wrap: bool,
 }
 
 impl Default for Pagination {
@@ -24,6 +116,7 @@ impl Default for Pagination {
             current_page: 0,
             page_size: PAGE_SIZES[0],
             total_rows: 0,
+            wrap: false,
         }
     }
 }
@@ -34,9 +127,15 @@ impl Pagination {
             current_page: 0,
             page_size: PAGE_SIZES[0],
             total_rows,
+            wrap: false,
         }
     }
 
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
     /// Total number of pages
     pub fn total_pages(&self) -> usize {
         if self.total_rows == 0 {
@@ -46,17 +145,21 @@ impl Pagination {
         }
     }
 
-    /// Go to next page if possible
+    /// Go to next page; on the last page, wrap to the first one or stay
     pub fn next_page(&mut self) {
         if self.current_page + 1 < self.total_pages() {
             self.current_page += 1;
+        } else if self.wrap {
+            self.current_page = 0;
         }
     }
 
-    /// Go to previous page if possible
+    /// Go to previous page; on the first page, wrap to the last one or stay
     pub fn prev_page(&mut self) {
         if self.current_page > 0 {
             self.current_page -= 1;
+        } else if self.wrap {
+            self.last_page();
         }
     }
 
@@ -70,6 +173,12 @@ impl Pagination {
         self.current_page = self.total_pages().saturating_sub(1);
     }
 
+    /// Update the total row count while keeping the current page
+    pub fn set_total_rows(&mut self, total_rows: usize) {
+        self.total_rows = total_rows;
+        self.current_page = self.current_page.min(self.total_pages() - 1);
+    }
+
     /// Cycle to next page size
     pub fn cycle_page_size(&mut self) {
         let current_idx = PAGE_SIZES
@@ -77,9 +186,15 @@ impl Pagination {
             .position(|&s| s == self.page_size)
             .unwrap_or(0);
         let next_idx = (current_idx + 1) % PAGE_SIZES.len();
-        self.page_size = PAGE_SIZES[next_idx];
-        // Reset to first page when changing page size
-        self.current_page = 0;
+        self.set_page_size(PAGE_SIZES[next_idx]);
+    }
+
+    /// Change the page size, moving to the page that holds the row
+    /// previously shown at the top
+    pub fn set_page_size(&mut self, page_size: usize) {
+        let first_row = self.start_index();
+        self.page_size = page_size.max(1);
+        self.current_page = (first_row / self.page_size).min(self.total_pages() - 1);
     }
 
     /// Get start index for current page
@@ -101,12 +216,82 @@ impl Pagination {
     pub fn has_prev(&self) -> bool {
         self.current_page > 0
     }
+
+    /// Whether `next_page` would change the page (including wrapping)
+    pub fn can_next(&self) -> bool {
+        self.has_next() || (self.wrap && self.total_pages() > 1)
+    }
+
+    /// Whether `prev_page` would change the page (including wrapping)
+    pub fn can_prev(&self) -> bool {
+        self.has_prev() || (self.wrap && self.total_pages() > 1)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn result(rows: usize, rows_affected: Option<u64>) -> QueryResult {
+        QueryResult {
+            columns: Vec::new(),
+            column_types: Vec::new(),
+            rows: vec![Vec::new(); rows],
+            execution_time_ms: 5,
+            total_rows: rows,
+            rows_affected,
+        }
+    }
+
+    #[test]
+    fn test_query_outcome_summary() {
+        assert_eq!(
+            QueryOutcome::from_result(&result(3, None)).summary(),
+            "3 rows returned"
+        );
+        assert_eq!(
+            QueryOutcome::from_result(&result(0, None)).summary(),
+            "0 rows returned"
+        );
+        let affected = QueryOutcome::from_result(&result(0, Some(1)));
+        assert_eq!(affected.summary(), "1 row affected");
+        assert_eq!(affected.execution_time_ms(), Some(5));
+
+        let failed = QueryOutcome::Failed("syntax error".to_string());
+        assert_eq!(failed.summary(), "Error: syntax error");
+        assert_eq!(failed.execution_time_ms(), None);
+    }
+
+    #[test]
+    fn test_unique_column_names_suffixes_duplicates() {
+        assert_eq!(
+            unique_column_names(names(&["?column?", "?column?", "id", "?column?"])),
+            names(&["?column?", "?column?_2", "id", "?column?_3"])
+        );
+        // Unique names are untouched
+        assert_eq!(unique_column_names(names(&["a", "b"])), names(&["a", "b"]));
+    }
+
+    #[test]
+    fn test_unique_column_names_skips_taken_suffixes() {
+        assert_eq!(
+            unique_column_names(names(&["a", "a_2", "a"])),
+            names(&["a", "a_2", "a_3"])
+        );
+    }
+
+    #[test]
+    fn test_unique_column_names_fills_blank_names() {
+        assert_eq!(
+            unique_column_names(names(&["", " "])),
+            names(&["?column?", "?column?_2"])
+        );
+    }
+
     #[test]
     fn test_pagination_default() {
         let p = Pagination::default();
@@ -196,6 +381,19 @@ mod tests {
         assert_eq!(p.end_index(), 125);
     }
 
+    #[test]
+    fn test_pagination_set_total_rows_keeps_page() {
+        let mut p = Pagination::new(100);
+        p.next_page();
+        p.set_total_rows(600);
+        assert_eq!(p.current_page, 1);
+        assert_eq!(p.total_pages(), 12);
+
+        // Clamp when the current page no longer exists
+        p.set_total_rows(10);
+        assert_eq!(p.current_page, 0);
+    }
+
     #[test]
     fn test_pagination_cycle_page_size() {
         let mut p = Pagination::new(500);
@@ -203,7 +401,7 @@ mod tests {
 
         p.cycle_page_size();
         assert_eq!(p.page_size, 100);
-        assert_eq!(p.current_page, 0); // Reset to first page
+        assert_eq!(p.current_page, 0);
 
         p.cycle_page_size();
         assert_eq!(p.page_size, 500);
@@ -211,4 +409,84 @@ mod tests {
         p.cycle_page_size();
         assert_eq!(p.page_size, 50); // Cycles back
     }
+
+    #[test]
+    fn test_pagination_clamps_without_wrap() {
+        let mut p = Pagination::new(120); // 3 pages
+        assert!(!p.can_prev());
+        p.prev_page();
+        assert_eq!(p.current_page, 0);
+
+        p.last_page();
+        assert!(!p.can_next());
+        p.next_page();
+        assert_eq!(p.current_page, 2);
+    }
+
+    #[test]
+    fn test_pagination_wraps_when_enabled() {
+        let mut p = Pagination::new(120).with_wrap(true);
+        assert!(p.can_prev());
+        p.prev_page();
+        assert_eq!(p.current_page, 2);
+
+        assert!(p.can_next());
+        p.next_page();
+        assert_eq!(p.current_page, 0);
+    }
+
+    #[test]
+    fn test_pagination_wrap_single_page_stays() {
+        let mut p = Pagination::new(10).with_wrap(true);
+        assert!(!p.can_next());
+        assert!(!p.can_prev());
+        p.next_page();
+        p.prev_page();
+        assert_eq!(p.current_page, 0);
+    }
+
+    #[test]
+    fn test_page_size_change_keeps_first_visible_row() {
+        let mut p = Pagination::new(1000);
+        // Page 4 of size 50 starts at row 200
+        p.current_page = 4;
+
+        p.cycle_page_size();
+        assert_eq!(p.page_size, 100);
+        assert_eq!(p.current_page, 2);
+        assert!((p.start_index()..p.end_index()).contains(&200));
+
+        p.cycle_page_size();
+        assert_eq!(p.page_size, 500);
+        assert_eq!(p.current_page, 0);
+        assert!((p.start_index()..p.end_index()).contains(&200));
+
+        // Shrinking back lands on the page that starts with the old top row
+        p.current_page = 1;
+        p.cycle_page_size();
+        assert_eq!(p.page_size, 50);
+        assert_eq!(p.start_index(), 500);
+    }
+
+    #[test]
+    fn test_page_size_change_rounds_down_to_containing_page() {
+        let mut p = Pagination::new(1000);
+        p.page_size = 100;
+        p.current_page = 3; // rows 300-399
+
+        p.set_page_size(500);
+        assert_eq!(p.current_page, 0);
+        assert!((p.start_index()..p.end_index()).contains(&300));
+    }
+
+    #[test]
+    fn test_page_size_change_clamps_to_last_page() {
+        let mut p = Pagination::new(120);
+        p.page_size = 100;
+        p.current_page = 1; // rows 100-119
+
+        p.set_page_size(500);
+        assert_eq!(p.current_page, 0);
+        assert_eq!(p.end_index(), 120);
+    }
 }
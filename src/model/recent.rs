@@ -0,0 +1,130 @@
+//! Recently opened tables (most recently used first)
+
+use serde::{Deserialize, Serialize};
+
+/// A table that was opened, identified by names so it survives restarts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentTable {
+    /// Project name
+    pub project: String,
+
+    /// Connection name
+    pub connection: String,
+
+    /// Table name
+    pub table: String,
+}
+
+impl RecentTable {
+    pub fn new(
+        project: impl Into<String>,
+        connection: impl Into<String>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            project: project.into(),
+            connection: connection.into(),
+            table: table.into(),
+        }
+    }
+}
+
+/// Bounded MRU list of opened tables
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentTables {
+    /// List of recently opened tables (newest first)
+    #[serde(default)]
+    pub entries: Vec<RecentTable>,
+
+    /// Maximum number of entries
+    #[serde(default = "default_max_entries")]
+    pub max_entries: usize,
+}
+
+fn default_max_entries() -> usize {
+    20
+}
+
+impl Default for RecentTables {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecentTables {
+    /// Create a new empty list
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            max_entries: default_max_entries(),
+        }
+    }
+
+    /// Record that a table was opened, moving it to the front
+    pub fn touch(&mut self, entry: RecentTable) {
+        self.entries.retain(|e| e != &entry);
+        self.entries.insert(0, entry);
+        self.entries.truncate(self.max_entries);
+    }
+
+    /// Check if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Get entry at the specified index
+    pub fn get(&self, index: usize) -> Option<&RecentTable> {
+        self.entries.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_touch_moves_entry_to_front() {
+        let mut recent = RecentTables::new();
+        recent.touch(RecentTable::new("p", "db", "users"));
+        recent.touch(RecentTable::new("p", "db", "orders"));
+        recent.touch(RecentTable::new("p", "db", "users"));
+
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent.get(0).unwrap().table, "users");
+        assert_eq!(recent.get(1).unwrap().table, "orders");
+    }
+
+    #[test]
+    fn test_same_table_on_other_connection_is_separate() {
+        let mut recent = RecentTables::new();
+        recent.touch(RecentTable::new("p", "staging", "users"));
+        recent.touch(RecentTable::new("p", "prod", "users"));
+        assert_eq!(recent.len(), 2);
+    }
+
+    #[test]
+    fn test_touch_is_bounded() {
+        let mut recent = RecentTables {
+            entries: Vec::new(),
+            max_entries: 2,
+        };
+        recent.touch(RecentTable::new("p", "db", "a"));
+        recent.touch(RecentTable::new("p", "db", "b"));
+        recent.touch(RecentTable::new("p", "db", "c"));
+
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent.get(0).unwrap().table, "c");
+        assert_eq!(recent.get(1).unwrap().table, "b");
+    }
+
+    #[test]
+    fn test_deserialize_without_max_entries_uses_default() {
+        let recent: RecentTables = serde_norway::from_str("entries: []").unwrap();
+        assert_eq!(recent.max_entries, 20);
+    }
+}
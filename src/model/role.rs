@@ -0,0 +1,49 @@
+//! Database users and roles
+
+/// A role (user or group) defined on the server.
+///
+/// Attributes the connected user is not allowed to read are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseRole {
+    pub name: String,
+    /// Superuser status
+    pub superuser: Option<bool>,
+    /// Whether the role can log in (i.e. is a user rather than a group)
+    pub can_login: Option<bool>,
+    /// Password expiry, as reported by the server
+    pub valid_until: Option<String>,
+}
+
+impl DatabaseRole {
+    /// A role whose attributes could not be read
+    pub fn name_only(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            superuser: None,
+            can_login: None,
+            valid_until: None,
+        }
+    }
+
+    /// Whether the attributes of this role were hidden from the connected user
+    pub fn is_limited(&self) -> bool {
+        self.superuser.is_none() && self.can_login.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_only_role_is_limited() {
+        assert!(DatabaseRole::name_only("app").is_limited());
+
+        let role = DatabaseRole {
+            superuser: Some(false),
+            can_login: Some(true),
+            ..DatabaseRole::name_only("app")
+        };
+        assert!(!role.is_limited());
+    }
+}
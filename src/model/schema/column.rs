@@ -12,6 +12,20 @@ pub struct Column {
     pub is_auto_increment: bool,
     pub comment: Option<String>,
     pub ordinal_position: usize,
+    /// Planner statistics from `pg_stats`; `None` until the table is analyzed
+    pub planner_stats: Option<PlannerStats>,
+}
+
+/// Data distribution the query planner collected for a column with `ANALYZE`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlannerStats {
+    /// Fraction of rows that are NULL
+    pub null_frac: f32,
+    /// Distinct values: a count when positive, or minus the fraction of rows
+    /// when negative (`-1` means every row is distinct)
+    pub n_distinct: f32,
+    /// Average width of the stored values in bytes
+    pub avg_width: i32,
 }
 
 impl Column {
@@ -26,6 +40,7 @@ impl Column {
             is_auto_increment: false,
             comment: None,
             ordinal_position: 0,
+            planner_stats: None,
         }
     }
 
@@ -64,4 +79,62 @@ impl Column {
         self.ordinal_position = pos;
         self
     }
+
+    /// Column definition as written in `CREATE TABLE`, e.g. `email varchar(255) NOT NULL`
+    pub fn definition(&self) -> String {
+        let mut definition = format!("{} {}", quote_identifier(&self.name), self.data_type);
+        if !self.is_nullable {
+            definition.push_str(" NOT NULL");
+        }
+        if let Some(default) = &self.default_value {
+            definition.push_str(" DEFAULT ");
+            definition.push_str(default);
+        }
+        definition
+    }
+}
+
+/// Quote an identifier unless it is a plain lowercase name
+fn quote_identifier(name: &str) -> String {
+    let plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '$');
+    if plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_definition_includes_constraints() {
+        let col = Column::new("email", "varchar(255)")
+            .not_null()
+            .default("''::character varying");
+        assert_eq!(
+            col.definition(),
+            "email varchar(255) NOT NULL DEFAULT ''::character varying"
+        );
+        assert_eq!(Column::new("note", "text").definition(), "note text");
+    }
+
+    #[test]
+    fn test_definition_quotes_mixed_case_names() {
+        assert_eq!(
+            Column::new("UserId", "integer").definition(),
+            "\"UserId\" integer"
+        );
+        assert_eq!(
+            Column::new("a\"b", "integer").definition(),
+            "\"a\"\"b\" integer"
+        );
+    }
 }
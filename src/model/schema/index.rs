@@ -97,6 +97,17 @@ impl IndexColumn {
     }
 }
 
+/// Cumulative usage counters of an index since statistics were last reset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexUsage {
+    /// Number of index scans started on the index
+    pub scans: u64,
+    /// Number of index entries returned by scans
+    pub tuples_read: u64,
+    /// Number of live table rows fetched by simple index scans
+    pub tuples_fetched: u64,
+}
+
 /// Database index
 #[derive(Debug, Clone)]
 pub struct Index {
@@ -106,6 +117,10 @@ pub struct Index {
     pub columns: Vec<IndexColumn>,
     pub is_unique: bool,
     pub comment: Option<String>,
+    /// Full `CREATE INDEX` statement as reported by the database
+    pub definition: Option<String>,
+    /// Usage statistics, when the database collects them
+    pub usage: Option<IndexUsage>,
 }
 
 impl Index {
@@ -117,6 +132,8 @@ impl Index {
             columns: Vec::new(),
             is_unique: matches!(index_type, IndexType::Primary | IndexType::Unique),
             comment: None,
+            definition: None,
+            usage: None,
         }
     }
 
@@ -129,4 +146,9 @@ impl Index {
         self.method = method;
         self
     }
+
+    pub fn with_definition(mut self, definition: impl Into<String>) -> Self {
+        self.definition = Some(definition.into());
+        self
+    }
 }
@@ -9,9 +9,9 @@ mod index;
 mod table;
 mod trigger;
 
-pub use column::Column;
+pub use column::{Column, PlannerStats};
 pub use constraint::{Constraint, ConstraintType};
 pub use foreign_key::{ForeignKey, ForeignKeyAction};
-pub use index::{Index, IndexColumn, IndexMethod, IndexType, SortOrder};
-pub use table::{Table, TableType};
+pub use index::{Index, IndexColumn, IndexMethod, IndexType, IndexUsage, SortOrder};
+pub use table::{IconStyle, Table, TableType};
 pub use trigger::{Trigger, TriggerEvent, TriggerOrientation, TriggerTiming};
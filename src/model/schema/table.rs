@@ -1,5 +1,7 @@
 //! Table types and structures
 
+use serde::{Deserialize, Serialize};
+
 #[cfg(test)]
 use super::ForeignKeyAction;
 use super::{Column, Constraint, ForeignKey, Index, Trigger};
@@ -26,15 +28,43 @@ impl std::fmt::Display for TableType {
     }
 }
 
+/// Glyph set used for table type icons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IconStyle {
+    /// Nerd Font icons (needs a patched font)
+    #[default]
+    NerdFont,
+    /// Plain Unicode symbols available in most fonts
+    Unicode,
+    /// ASCII letters for terminals without either
+    Ascii,
+}
+
 impl TableType {
     /// Returns the icon for this table type (Nerd Font icons)
     pub fn icon(&self) -> &'static str {
-        match self {
-            TableType::BaseTable => "󰓫",        // table icon
-            TableType::View => "󰈈",             // eye icon (view)
-            TableType::MaterializedView => "󱁉", // cached/materialized icon
-            TableType::ForeignTable => "󰌷",     // link/external icon
-            TableType::Temporary => "󰔛",        // clock/temporary icon
+        self.icon_with(IconStyle::NerdFont)
+    }
+
+    /// Returns the icon for this table type in the given glyph set
+    pub fn icon_with(&self, style: IconStyle) -> &'static str {
+        match (style, self) {
+            (IconStyle::NerdFont, TableType::BaseTable) => "󰓫", // table icon
+            (IconStyle::NerdFont, TableType::View) => "󰈈",      // eye icon (view)
+            (IconStyle::NerdFont, TableType::MaterializedView) => "󱁉", // cached/materialized icon
+            (IconStyle::NerdFont, TableType::ForeignTable) => "󰌷", // link/external icon
+            (IconStyle::NerdFont, TableType::Temporary) => "󰔛", // clock/temporary icon
+            (IconStyle::Unicode, TableType::BaseTable) => "▤",
+            (IconStyle::Unicode, TableType::View) => "◎",
+            (IconStyle::Unicode, TableType::MaterializedView) => "⟳",
+            (IconStyle::Unicode, TableType::ForeignTable) => "⇄",
+            (IconStyle::Unicode, TableType::Temporary) => "◌",
+            (IconStyle::Ascii, TableType::BaseTable) => "T",
+            (IconStyle::Ascii, TableType::View) => "V",
+            (IconStyle::Ascii, TableType::MaterializedView) => "M",
+            (IconStyle::Ascii, TableType::ForeignTable) => "F",
+            (IconStyle::Ascii, TableType::Temporary) => "t",
         }
     }
 
@@ -56,6 +86,9 @@ pub struct Table {
     pub constraints: Vec<Constraint>,
     pub triggers: Vec<Trigger>,
     pub row_count: usize,
+    /// Whether `row_count` was counted with `COUNT(*)` rather than estimated
+    /// from the statistics collector
+    pub row_count_exact: bool,
     pub size_bytes: u64,
     pub comment: Option<String>,
     /// Whether detailed schema information has been loaded
@@ -76,6 +109,7 @@ impl Table {
             constraints: Vec::new(),
             triggers: Vec::new(),
             row_count: 0,
+            row_count_exact: false,
             size_bytes: 0,
             comment: None,
             details_loaded: false,
@@ -321,6 +355,32 @@ mod tests {
         assert_eq!(TableType::Temporary.icon(), "󰔛");
     }
 
+    #[test]
+    fn test_table_type_icon_with_fallback_styles() {
+        assert_eq!(TableType::BaseTable.icon_with(IconStyle::Unicode), "▤");
+        assert_eq!(TableType::View.icon_with(IconStyle::Unicode), "◎");
+        assert_eq!(
+            TableType::MaterializedView.icon_with(IconStyle::Unicode),
+            "⟳"
+        );
+        assert_eq!(TableType::View.icon_with(IconStyle::Ascii), "V");
+        assert_eq!(
+            TableType::BaseTable.icon_with(IconStyle::NerdFont),
+            TableType::BaseTable.icon()
+        );
+
+        // ASCII icons fit in a single terminal cell
+        for table_type in [
+            TableType::BaseTable,
+            TableType::View,
+            TableType::MaterializedView,
+            TableType::ForeignTable,
+            TableType::Temporary,
+        ] {
+            assert!(table_type.icon_with(IconStyle::Ascii).is_ascii());
+        }
+    }
+
     // ===== TableType is_view tests =====
 
     #[test]
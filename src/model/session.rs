@@ -0,0 +1,58 @@
+//! Server sessions (backends) connected to a database
+
+/// A client session connected to the database, as seen by the server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveSession {
+    /// Server process ID of the backend
+    pub pid: i32,
+    pub user: Option<String>,
+    /// `active`, `idle`, `idle in transaction`, ...
+    pub state: Option<String>,
+    /// What the backend is waiting for (`type:event`), if anything
+    pub wait_event: Option<String>,
+    /// Time spent in the current state, in milliseconds
+    pub duration_ms: Option<u64>,
+    /// Current query, or the last one for idle sessions
+    pub query: String,
+}
+
+impl ActiveSession {
+    /// Whether the session is currently running a query
+    pub fn is_active(&self) -> bool {
+        self.state.as_deref() == Some("active")
+    }
+
+    /// The query collapsed onto a single line for list display
+    pub fn query_line(&self) -> String {
+        self.query.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(state: Option<&str>, query: &str) -> ActiveSession {
+        ActiveSession {
+            pid: 42,
+            user: Some("app".to_string()),
+            state: state.map(str::to_string),
+            wait_event: None,
+            duration_ms: Some(1500),
+            query: query.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_is_active() {
+        assert!(session(Some("active"), "SELECT 1").is_active());
+        assert!(!session(Some("idle in transaction"), "SELECT 1").is_active());
+        assert!(!session(None, "").is_active());
+    }
+
+    #[test]
+    fn test_query_line_collapses_whitespace() {
+        let s = session(Some("active"), "SELECT *\n  FROM users\n\tWHERE id = 1");
+        assert_eq!(s.query_line(), "SELECT * FROM users WHERE id = 1");
+    }
+}
@@ -0,0 +1,10 @@
+//! Server configuration parameters
+
+/// A run-time parameter of the current session, as listed by `SHOW ALL`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerSetting {
+    pub name: String,
+    /// Current value, including its unit (`30s`, `128MB`, ...)
+    pub setting: String,
+    pub description: String,
+}
@@ -0,0 +1,45 @@
+//! Snapshot of where the user left off, restored on the next launch
+
+use serde::{Deserialize, Serialize};
+
+/// Open project, connections and query, identified by names so it survives restarts
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    /// Project whose connections were shown (`None` when the project list was shown)
+    #[serde(default)]
+    pub project: Option<String>,
+
+    /// Selected connection within the project
+    #[serde(default)]
+    pub connection: Option<String>,
+
+    /// Connections of the project whose table lists were expanded
+    #[serde(default)]
+    pub expanded: Vec<String>,
+
+    /// Selected table of the selected connection
+    #[serde(default)]
+    pub table: Option<String>,
+
+    /// Contents of the query editor
+    #[serde(default)]
+    pub query: String,
+
+    /// Active main panel tab (`schema`, `data` or `relations`)
+    #[serde(default)]
+    pub tab: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_fields_default() {
+        let snapshot: SessionSnapshot = serde_norway::from_str("project: main").unwrap();
+        assert_eq!(snapshot.project.as_deref(), Some("main"));
+        assert!(snapshot.connection.is_none());
+        assert!(snapshot.expanded.is_empty());
+        assert!(snapshot.query.is_empty());
+    }
+}
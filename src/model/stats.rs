@@ -0,0 +1,210 @@
+//! Database-wide statistics and per-column statistics of query results
+
+use std::collections::HashSet;
+
+use super::QueryResult;
+
+/// How providers render SQL `NULL` in a [`QueryResult`]
+const NULL_VALUE: &str = "NULL";
+
+/// Total on-disk size of a single table, including indexes and TOAST data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableSize {
+    pub schema: String,
+    pub name: String,
+    pub total_bytes: u64,
+}
+
+impl TableSize {
+    /// Returns the schema-qualified table name
+    pub fn full_name(&self) -> String {
+        format!("{}.{}", self.schema, self.name)
+    }
+}
+
+/// Size summary of a database
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DatabaseStats {
+    /// Total size of the database in bytes
+    pub database_size: u64,
+    /// Largest tables, biggest first
+    pub largest_tables: Vec<TableSize>,
+}
+
+impl DatabaseStats {
+    /// Combined size of the listed tables
+    pub fn listed_tables_size(&self) -> u64 {
+        self.largest_tables.iter().map(|t| t.total_bytes).sum()
+    }
+
+    /// Share of the database taken by a table, in percent
+    pub fn share_of_database(&self, table: &TableSize) -> f64 {
+        if self.database_size == 0 {
+            0.0
+        } else {
+            table.total_bytes as f64 * 100.0 / self.database_size as f64
+        }
+    }
+}
+
+/// Min / max / average of a numeric column
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericSummary {
+    pub min: f64,
+    pub max: f64,
+    pub avg: f64,
+}
+
+/// Summary of one column of a query result
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStats {
+    pub column: String,
+    /// Number of rows
+    pub count: usize,
+    /// Number of distinct non-null values
+    pub distinct: usize,
+    /// Number of NULL values
+    pub nulls: usize,
+    /// Set when there are non-null values and all of them are numbers
+    pub numeric: Option<NumericSummary>,
+}
+
+impl ColumnStats {
+    /// Compute statistics for the column at `column`, or `None` if it is out of range
+    pub fn compute(result: &QueryResult, column: usize) -> Option<Self> {
+        let name = result.columns.get(column)?;
+        let values: Vec<&str> = result
+            .rows
+            .iter()
+            .map(|row| row.get(column).map(String::as_str).unwrap_or(NULL_VALUE))
+            .collect();
+        let non_null: Vec<&str> = values
+            .iter()
+            .copied()
+            .filter(|v| *v != NULL_VALUE)
+            .collect();
+
+        let numbers: Option<Vec<f64>> = non_null
+            .iter()
+            .map(|v| v.trim().parse::<f64>().ok().filter(|n| n.is_finite()))
+            .collect();
+        let numeric = numbers
+            .filter(|numbers| !numbers.is_empty())
+            .map(|numbers| NumericSummary {
+                min: numbers.iter().copied().fold(f64::INFINITY, f64::min),
+                max: numbers.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                avg: numbers.iter().sum::<f64>() / numbers.len() as f64,
+            });
+
+        Some(Self {
+            column: name.clone(),
+            count: values.len(),
+            distinct: non_null.iter().collect::<HashSet<_>>().len(),
+            nulls: values.len() - non_null.len(),
+            numeric,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result_with_column(values: &[&str]) -> QueryResult {
+        QueryResult {
+            columns: vec!["id".to_string(), "value".to_string()],
+            column_types: Vec::new(),
+            rows: values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| vec![i.to_string(), v.to_string()])
+                .collect(),
+            execution_time_ms: 0,
+            total_rows: values.len(),
+            rows_affected: None,
+        }
+    }
+
+    fn table(name: &str, total_bytes: u64) -> TableSize {
+        TableSize {
+            schema: "public".to_string(),
+            name: name.to_string(),
+            total_bytes,
+        }
+    }
+
+    #[test]
+    fn test_listed_tables_size() {
+        let stats = DatabaseStats {
+            database_size: 1000,
+            largest_tables: vec![table("a", 300), table("b", 200)],
+        };
+        assert_eq!(stats.listed_tables_size(), 500);
+    }
+
+    #[test]
+    fn test_share_of_database() {
+        let stats = DatabaseStats {
+            database_size: 1000,
+            largest_tables: vec![table("a", 250)],
+        };
+        assert_eq!(stats.share_of_database(&stats.largest_tables[0]), 25.0);
+    }
+
+    #[test]
+    fn test_share_of_empty_database() {
+        let stats = DatabaseStats::default();
+        assert_eq!(stats.share_of_database(&table("a", 10)), 0.0);
+    }
+
+    #[test]
+    fn test_table_size_full_name() {
+        assert_eq!(table("users", 0).full_name(), "public.users");
+    }
+
+    #[test]
+    fn test_column_stats_numeric() {
+        let result = result_with_column(&["3", "1.5", "NULL", "3", "-2"]);
+        let stats = ColumnStats::compute(&result, 1).unwrap();
+
+        assert_eq!(stats.column, "value");
+        assert_eq!(stats.count, 5);
+        assert_eq!(stats.distinct, 3);
+        assert_eq!(stats.nulls, 1);
+        assert_eq!(
+            stats.numeric,
+            Some(NumericSummary {
+                min: -2.0,
+                max: 3.0,
+                avg: 1.375,
+            })
+        );
+    }
+
+    #[test]
+    fn test_column_stats_non_numeric() {
+        let result = result_with_column(&["10", "abc", "abc", "NULL", "NULL"]);
+        let stats = ColumnStats::compute(&result, 1).unwrap();
+
+        assert_eq!(stats.count, 5);
+        assert_eq!(stats.distinct, 2);
+        assert_eq!(stats.nulls, 2);
+        assert_eq!(stats.numeric, None);
+    }
+
+    #[test]
+    fn test_column_stats_all_null() {
+        let result = result_with_column(&["NULL", "NULL"]);
+        let stats = ColumnStats::compute(&result, 1).unwrap();
+
+        assert_eq!(stats.distinct, 0);
+        assert_eq!(stats.nulls, 2);
+        assert_eq!(stats.numeric, None);
+    }
+
+    #[test]
+    fn test_column_stats_out_of_range() {
+        let result = result_with_column(&["1"]);
+        assert!(ColumnStats::compute(&result, 2).is_none());
+    }
+}
@@ -6,28 +6,214 @@ use ratatui::{
     Frame,
 };
 
+use crate::app::{App, Focus, MainPanelTab, ModalState, SidebarMode};
+
+use super::icons::Icons;
 use super::theme;
 
-pub fn draw_help_bar(frame: &mut Frame, area: Rect) {
-    let help_items = [
-        ("q", "Quit"),
-        ("↑/k", "Up"),
-        ("↓/j", "Down"),
-        ("Tab", "Focus"),
-        ("S-hjkl", "Pane"),
-        ("Enter", "Select"),
-        ("BS", "Back"),
-        ("a", "Add"),
-        ("s/d/r", "Schema/Data/Relations"),
-        ("1-4", "SubTab"),
-    ];
-
-    let spans: Vec<Span> = help_items
+/// (key, description) pairs shown in the help bar
+type Hints = &'static [(&'static str, &'static str)];
+
+const PROJECTS_HINTS: Hints = &[
+    ("↑↓/jk", "Move"),
+    ("Enter", "Open"),
+    ("a", "Add"),
+    ("e", "Edit"),
+    ("d", "Delete"),
+    ("/", "Search"),
+    ("Tab", "Focus"),
+    ("?", "Help"),
+    ("q", "Quit"),
+];
+
+const CONNECTIONS_HINTS: Hints = &[
+    ("↑↓/jk", "Move"),
+    ("Enter", "Expand/Open"),
+    ("BS", "Back"),
+    ("a", "Add"),
+    ("/", "Search"),
+    ("i", "Stats"),
+    ("y", "Copy name"),
+    ("A", "Sessions"),
+    ("U", "Roles"),
+    ("V", "Settings"),
+    ("P", "search_path"),
+    ("N", "Snippets"),
+    ("c", "Reconnect"),
+    ("R", "Refresh all"),
+    ("zM/zR", "Fold all"),
+    ("S", "System"),
+    ("Tab", "Focus"),
+    ("?", "Help"),
+    ("q", "Quit"),
+];
+
+const EDITOR_HINTS: Hints = &[
+    ("Enter", "Run"),
+    ("v", "Validate"),
+    ("t/]/w", "New/Next/Close tab"),
+    ("C-r", "History"),
+    ("Tab", "Focus"),
+    ("S-hjkl", "Pane"),
+    ("?", "Help"),
+    ("q", "Quit"),
+];
+
+const SCHEMA_HINTS: Hints = &[
+    ("1-6", "SubTab"),
+    ("/", "Filter"),
+    ("y", "Copy"),
+    ("g", "SELECT"),
+    ("Enter", "Detail"),
+    ("c", "Columns"),
+    ("d/r", "Data/Relations"),
+    ("Tab", "Focus"),
+    ("S-hjkl", "Pane"),
+    ("?", "Help"),
+    ("q", "Quit"),
+];
+
+const DATA_HINTS: Hints = &[
+    ("↑↓/jk", "Row"),
+    ("←→/hl", "Column"),
+    ("i", "Stats"),
+    ("t", "Types"),
+    ("g/G", "First/Last"),
+    ("n/p", "Page"),
+    ("z", "PageSize"),
+    ("/", "Go to column"),
+    ("f/o", "Filter/Order"),
+    ("R", "Refresh"),
+    ("x/X", "CSV/JSON"),
+    ("Y/C-y", "Copy CSV/JSON"),
+    ("s/r", "Schema/Relations"),
+    ("?", "Help"),
+];
+
+const RELATIONS_HINTS: Hints = &[
+    ("s/d", "Schema/Data"),
+    ("Tab", "Focus"),
+    ("S-hjkl", "Pane"),
+    ("?", "Help"),
+    ("q", "Quit"),
+];
+
+const COLUMN_SEARCH_HINTS: Hints = &[("↑↓", "Move"), ("Enter", "Keep filter"), ("Esc", "Clear")];
+
+const FORM_MODAL_HINTS: Hints = &[
+    ("Tab/S-Tab", "Field"),
+    ("Enter", "Confirm"),
+    ("Esc", "Cancel"),
+];
+
+const PICKER_MODAL_HINTS: Hints = &[
+    ("Tab/S-Tab", "Field"),
+    ("←→", "Choose"),
+    ("Enter", "Apply"),
+    ("Esc", "Cancel"),
+];
+
+const LIST_MODAL_HINTS: Hints = &[("↑↓", "Move"), ("Enter", "Select"), ("Esc", "Close")];
+
+const CONFIRM_MODAL_HINTS: Hints = &[
+    ("←→/Tab", "Button"),
+    ("Enter", "Confirm"),
+    ("Esc", "Cancel"),
+];
+
+const VIEW_MODAL_HINTS: Hints = &[("↑↓/jk", "Scroll"), ("Esc", "Close")];
+
+const SESSIONS_MODAL_HINTS: Hints = &[
+    ("↑↓/jk", "Move"),
+    ("x", "Terminate"),
+    ("r", "Refresh"),
+    ("Esc", "Close"),
+];
+
+const HISTORY_MODAL_HINTS: Hints = &[
+    ("↑↓", "Move"),
+    ("d", "Detail"),
+    ("f", "Filter"),
+    ("Enter", "Select"),
+    ("x", "Re-run"),
+    ("Esc", "Close"),
+];
+
+const ROLES_MODAL_HINTS: Hints = &[("↑↓/jk", "Move"), ("Esc", "Close")];
+
+const SETTINGS_MODAL_HINTS: Hints = &[("Type", "Search"), ("↑↓", "Move"), ("Esc", "Close")];
+
+const TERMINATE_CONFIRM_HINTS: Hints = &[("y", "Terminate"), ("n/Esc", "Cancel")];
+
+/// Hint set for the current focus, tab and modal
+fn hints_for(app: &App) -> Hints {
+    match &app.modal_state {
+        ModalState::None => {}
+        ModalState::AddConnection(_)
+        | ModalState::CloneConnection(_)
+        | ModalState::AddProject(_)
+        | ModalState::EditProject(..)
+        | ModalState::ExportProject(..)
+        | ModalState::ImportProject(_)
+        | ModalState::ImportPassword(_)
+        | ModalState::SearchPath(_) => return FORM_MODAL_HINTS,
+        ModalState::Filter(_) | ModalState::Order(_) => return PICKER_MODAL_HINTS,
+        ModalState::SearchProject(_)
+        | ModalState::SearchConnection(_)
+        | ModalState::SearchTable(_)
+        | ModalState::UnifiedSearch(_)
+        | ModalState::GlobalSearch(_)
+        | ModalState::GoToColumn(_)
+        | ModalState::RecentTables(_)
+        | ModalState::ColumnVisibility(_)
+        | ModalState::ImportConnections(_)
+        | ModalState::Snippets(_) => return LIST_MODAL_HINTS,
+        ModalState::History(_) => return HISTORY_MODAL_HINTS,
+        ModalState::Roles(_) => return ROLES_MODAL_HINTS,
+        ModalState::ServerSettings(_) => return SETTINGS_MODAL_HINTS,
+        ModalState::DeleteProject(_)
+        | ModalState::ConfirmQuit(_)
+        | ModalState::ConfirmCopy(_)
+        | ModalState::ConfirmQuery(_) => return CONFIRM_MODAL_HINTS,
+        ModalState::DatabaseStats(_)
+        | ModalState::ColumnStats(_)
+        | ModalState::ConstraintDetail(_)
+        | ModalState::LoadErrors(_)
+        | ModalState::Help(_) => return VIEW_MODAL_HINTS,
+        ModalState::ActiveSessions(modal) => {
+            return match modal.confirm_pid {
+                Some(_) => TERMINATE_CONFIRM_HINTS,
+                None => SESSIONS_MODAL_HINTS,
+            }
+        }
+    }
+
+    if app.column_search.editing {
+        return COLUMN_SEARCH_HINTS;
+    }
+
+    match app.focus {
+        Focus::Sidebar => match app.sidebar_mode {
+            SidebarMode::Projects => PROJECTS_HINTS,
+            SidebarMode::Connections(_) => CONNECTIONS_HINTS,
+        },
+        Focus::QueryEditor => EDITOR_HINTS,
+        Focus::MainPanel => match app.panel_tab {
+            MainPanelTab::Schema => SCHEMA_HINTS,
+            MainPanelTab::Data => DATA_HINTS,
+            MainPanelTab::Relations => RELATIONS_HINTS,
+        },
+    }
+}
+
+pub fn draw_help_bar(frame: &mut Frame, app: &App, area: Rect) {
+    let icons = Icons::from_app(app);
+    let spans: Vec<Span> = hints_for(app)
         .iter()
         .flat_map(|(key, desc)| {
             vec![
                 Span::styled(
-                    format!(" {} ", key),
+                    format!(" {} ", icons.text(key)),
                     Style::default().fg(theme::BG).bg(theme::MUTED),
                 ),
                 Span::styled(format!(" {} ", desc), theme::muted()),
@@ -39,3 +225,32 @@ pub fn draw_help_bar(frame: &mut Frame, area: Rect) {
 
     frame.render_widget(help, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::HelpModal;
+
+    #[test]
+    fn test_hints_follow_focus_and_tab() {
+        let mut app = App::new(vec![]);
+        assert_eq!(hints_for(&app), PROJECTS_HINTS);
+
+        app.sidebar_mode = SidebarMode::Connections(0);
+        assert_eq!(hints_for(&app), CONNECTIONS_HINTS);
+
+        app.focus = Focus::QueryEditor;
+        assert_eq!(hints_for(&app), EDITOR_HINTS);
+
+        app.focus = Focus::MainPanel;
+        app.panel_tab = MainPanelTab::Data;
+        assert_eq!(hints_for(&app), DATA_HINTS);
+    }
+
+    #[test]
+    fn test_modal_hints_take_precedence() {
+        let mut app = App::new(vec![]);
+        app.modal_state = ModalState::Help(HelpModal::default());
+        assert_eq!(hints_for(&app), VIEW_MODAL_HINTS);
+    }
+}
@@ -0,0 +1,162 @@
+//! Glyphs used by the UI, with ASCII fallbacks for limited terminals
+//!
+//! Some terminals and SSH sessions render emoji, arrows and box-drawing
+//! characters as mojibake. With `ascii_mode` enabled in the settings every
+//! glyph drawn through [`Icons`] is replaced with plain ASCII.
+
+use std::borrow::Cow;
+
+use crate::app::App;
+use crate::model::schema::TableType;
+use crate::model::IconStyle;
+
+/// Marker for primary key columns (U+1F511 KEY)
+pub const PRIMARY_KEY_ICON: &str = "\u{1F511}";
+
+/// Marker for unique columns (U+26A1 HIGH VOLTAGE SIGN)
+pub const UNIQUE_ICON: &str = "\u{26A1}";
+
+/// Glyph choices for the current settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Icons {
+    ascii: bool,
+    table_icons: IconStyle,
+}
+
+impl Icons {
+    pub fn new(ascii: bool, table_icons: IconStyle) -> Self {
+        Self { ascii, table_icons }
+    }
+
+    pub fn from_app(app: &App) -> Self {
+        Self::new(app.ascii_mode, app.table_icons)
+    }
+
+    /// Marker for primary key columns
+    pub fn primary_key(self) -> &'static str {
+        if self.ascii {
+            "PK"
+        } else {
+            PRIMARY_KEY_ICON
+        }
+    }
+
+    /// Marker for unique columns
+    pub fn unique(self) -> &'static str {
+        if self.ascii {
+            "*"
+        } else {
+            UNIQUE_ICON
+        }
+    }
+
+    /// Icon for a table type; ASCII mode overrides the `table_icons` setting
+    pub fn table(self, table_type: &TableType) -> &'static str {
+        let style = if self.ascii {
+            IconStyle::Ascii
+        } else {
+            self.table_icons
+        };
+        table_type.icon_with(style)
+    }
+
+    /// A single-character glyph, converted to ASCII in ASCII mode
+    pub fn glyph(self, glyph: &'static str) -> &'static str {
+        match glyph.chars().next() {
+            Some(c) if self.ascii => ascii_glyph(c).unwrap_or(glyph),
+            _ => glyph,
+        }
+    }
+
+    /// Text with arrows, markers and box-drawing characters, converted to
+    /// ASCII in ASCII mode. Characters without a replacement (such as
+    /// non-ASCII table names) are kept as they are.
+    pub fn text<'a>(self, text: &'a str) -> Cow<'a, str> {
+        if !self.ascii || text.is_ascii() {
+            return Cow::Borrowed(text);
+        }
+        let mut converted = String::with_capacity(text.len());
+        for c in text.chars() {
+            match ascii_glyph(c) {
+                Some(replacement) => converted.push_str(replacement),
+                None => converted.push(c),
+            }
+        }
+        Cow::Owned(converted)
+    }
+}
+
+/// ASCII replacement for a glyph used by the UI
+fn ascii_glyph(c: char) -> Option<&'static str> {
+    let replacement = match c {
+        '↑' | '▲' => "^",
+        '↓' | '▼' => "v",
+        '←' | '◀' => "<",
+        '→' | '▶' => ">",
+        '│' => "|",
+        '─' => "-",
+        '┌' | '┐' | '└' | '┘' | '├' | '┤' => "+",
+        '█' => "#",
+        '✓' => "OK",
+        '✗' => "X",
+        '◆' | '●' => "*",
+        '⚠' => "!",
+        '🔑' => "PK",
+        '⚡' => "*",
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unicode_mode_keeps_glyphs() {
+        let icons = Icons::new(false, IconStyle::NerdFont);
+        assert_eq!(icons.text("↑↓/jk"), "↑↓/jk");
+        assert_eq!(icons.primary_key(), "🔑");
+        assert_eq!(icons.table(&TableType::View), TableType::View.icon());
+    }
+
+    #[test]
+    fn test_key_markers_are_single_code_points() {
+        // Guards against the markers being saved double-encoded (e.g. "ðŸ”‘")
+        assert_eq!(PRIMARY_KEY_ICON.chars().collect::<Vec<_>>(), ['🔑']);
+        assert_eq!(UNIQUE_ICON.chars().collect::<Vec<_>>(), ['⚡']);
+
+        let icons = Icons::new(false, IconStyle::NerdFont);
+        assert_eq!(icons.primary_key(), PRIMARY_KEY_ICON);
+        assert_eq!(icons.unique(), UNIQUE_ICON);
+    }
+
+    #[test]
+    fn test_ascii_mode_returns_only_ascii() {
+        let icons = Icons::new(true, IconStyle::NerdFont);
+        assert_eq!(icons.text("↑↓/jk"), "^v/jk");
+        assert_eq!(icons.text("←→/hl"), "<>/hl");
+        assert_eq!(icons.text("  └─ "), "  +- ");
+        assert_eq!(icons.primary_key(), "PK");
+        assert_eq!(icons.glyph("█"), "#");
+        // Data such as table names is not a glyph and stays readable
+        assert_eq!(icons.text("ユーザー"), "ユーザー");
+
+        let samples = ["◀ ▶ ▲ ▼ │ ─ ┌┐└┘├┤ █ ✓ ◆ 🔑 ⚡", "Shift+k / Shift+↑"];
+        for text in samples {
+            assert!(icons.text(text).is_ascii(), "{:?}", icons.text(text));
+        }
+        for icon in [icons.primary_key(), icons.unique()] {
+            assert!(icon.is_ascii());
+        }
+        for table_type in [
+            TableType::BaseTable,
+            TableType::View,
+            TableType::MaterializedView,
+            TableType::ForeignTable,
+            TableType::Temporary,
+        ] {
+            assert!(icons.table(&table_type).is_ascii());
+        }
+    }
+}
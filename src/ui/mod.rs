@@ -18,6 +18,9 @@
 //! └─────────────────────────────────────────────────────────┘
 //! ```
 //!
+//! While the Data panel is maximized (`Shift+f`), the main panel and status
+//! bar take the whole content area.
+//!
 //! # Modules
 //!
 //! - [`sidebar`] - Left sidebar with project/connection tree
@@ -25,8 +28,10 @@
 //! - [`modal`] - Modal dialogs for user input
 //! - [`help_bar`] - Context-sensitive keybinding hints
 //! - [`status_bar`] - Status messages and current state info
+//! - [`icons`] - Glyphs with ASCII fallbacks
 
 mod help_bar;
+mod icons;
 mod modal;
 mod panel;
 mod sidebar;
@@ -36,14 +41,15 @@ pub mod utils;
 
 use crate::app::{App, SidebarMode};
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     Frame,
 };
 
 use help_bar::draw_help_bar;
+use icons::Icons;
 use modal::draw_modal;
 use panel::{draw_panel, draw_query_editor};
-use sidebar::{draw_sidebar, draw_table_summary};
+use sidebar::{draw_sidebar, draw_table_summary, table_summary_height};
 use status_bar::draw_status_bar;
 
 pub fn draw(frame: &mut Frame, app: &mut App) {
@@ -60,6 +66,47 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
 
     let content_area = outer_chunks[0];
 
+    if app.fullscreen_data {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(10),   // Main panel
+                Constraint::Length(3), // Status bar
+            ])
+            .split(content_area);
+        draw_panel(frame, app, chunks[0]);
+        draw_status_bar(frame, app, chunks[1]);
+    } else {
+        draw_panes(frame, app, content_area);
+    }
+    draw_help_bar(frame, app, outer_chunks[1]);
+
+    // Draw modal on top if open
+    // Get current project's connections for SearchConnection modal
+    let connections = match app.sidebar_mode {
+        SidebarMode::Connections(proj_idx) => app
+            .projects
+            .get(proj_idx)
+            .map(|p| p.connections.as_slice())
+            .unwrap_or(&[]),
+        SidebarMode::Projects => &[],
+    };
+    let tables = app.current_connection_tables();
+    draw_modal(
+        frame,
+        &app.modal_state,
+        &app.projects,
+        connections,
+        tables,
+        &app.query_history,
+        &app.recent_tables,
+        &app.column_visibility,
+        Icons::from_app(app),
+    );
+}
+
+/// Sidebar, query editor, main panel and status bar
+fn draw_panes(frame: &mut Frame, app: &mut App, content_area: Rect) {
     // Main layout: Sidebar | Main area
     let main_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -73,8 +120,8 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     let sidebar_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(8),    // Connections tree
-            Constraint::Length(7), // Table info summary (compact)
+            Constraint::Min(8),                            // Connections tree
+            Constraint::Length(table_summary_height(app)), // Table info summary (compact)
         ])
         .split(main_chunks[0]);
 
@@ -82,7 +129,7 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     let right_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(5), // Query editor
+            Constraint::Length(6), // Query tab strip and editor
             Constraint::Min(10),   // Main panel (Schema/Data)
             Constraint::Length(3), // Status bar
         ])
@@ -93,26 +140,4 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     draw_query_editor(frame, app, right_chunks[0]);
     draw_panel(frame, app, right_chunks[1]);
     draw_status_bar(frame, app, right_chunks[2]);
-    draw_help_bar(frame, outer_chunks[1]);
-
-    // Draw modal on top if open
-    // Get current project's connections for SearchConnection modal
-    let connections = match app.sidebar_mode {
-        SidebarMode::Connections(proj_idx) => app
-            .projects
-            .get(proj_idx)
-            .map(|p| p.connections.as_slice())
-            .unwrap_or(&[]),
-        SidebarMode::Projects => &[],
-    };
-    let tables = app.current_connection_tables();
-    draw_modal(
-        frame,
-        &app.modal_state,
-        &app.projects,
-        connections,
-        tables,
-        &app.query_history,
-        &app.column_visibility,
-    );
 }
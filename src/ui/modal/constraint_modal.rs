@@ -0,0 +1,85 @@
+//! Constraint detail modal rendering
+
+use crate::app::ConstraintDetailModal;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+
+/// Popup width: the wrapped definition plus borders and padding
+const MODAL_WIDTH: u16 = crate::app::DEFINITION_WRAP_WIDTH as u16 + 4;
+
+pub fn draw_constraint_detail_modal(frame: &mut Frame, modal: &ConstraintDetailModal) {
+    let mut area = centered_rect(100, 60, frame.area());
+    if area.width > MODAL_WIDTH {
+        area.x += (area.width - MODAL_WIDTH) / 2;
+        area.width = MODAL_WIDTH;
+    }
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Constraint: {} ", modal.name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Type and columns
+            Constraint::Min(1),    // Definition
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner);
+
+    let columns = if modal.columns.is_empty() {
+        "-".to_string()
+    } else {
+        modal.columns.join(", ")
+    };
+    let summary = vec![
+        Line::from(vec![
+            Span::styled("Type     ", theme::header()),
+            Span::styled(modal.constraint_type.to_string(), theme::text()),
+        ]),
+        Line::from(vec![
+            Span::styled("Columns  ", theme::header()),
+            Span::styled(columns, theme::text()),
+        ]),
+    ];
+    frame.render_widget(Paragraph::new(summary), chunks[0]);
+
+    let lines: Vec<Line> = modal
+        .lines
+        .iter()
+        .map(|line| Line::from(Span::styled(line.as_str(), theme::text())))
+        .collect();
+    frame.render_widget(Paragraph::new(lines).scroll((modal.scroll, 0)), chunks[1]);
+
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("j/k", theme::header()),
+        Span::raw(": scroll  "),
+        Span::styled("Esc/q", theme::header()),
+        Span::raw(": close"),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[2]);
+}
@@ -0,0 +1,71 @@
+//! Clipboard copy confirmation modal rendering
+
+use crate::app::{ConfirmCopyModal, CopyKind};
+use crate::ui::theme;
+use crate::ui::utils::format_number;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+use super::project_modal::draw_confirm_buttons;
+
+pub fn draw_confirm_copy_modal(frame: &mut Frame, modal: &ConfirmCopyModal) {
+    let area = centered_rect(50, 25, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let title = match modal.kind {
+        CopyKind::Json => " Copy as JSON ",
+        CopyKind::Csv => " Copy as CSV ",
+    };
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Question
+            Constraint::Min(1),    // Hint
+            Constraint::Length(3), // Buttons
+        ])
+        .split(inner);
+
+    let question = Paragraph::new(Line::from(Span::styled(
+        format!(
+            "Copy {}{} rows to the clipboard?",
+            if modal.more_rows { "more than " } else { "" },
+            format_number(modal.rows)
+        ),
+        theme::header(),
+    )))
+    .alignment(Alignment::Center);
+    frame.render_widget(question, chunks[0]);
+
+    let hint = Paragraph::new(Line::from(Span::styled(
+        "Large clipboard contents can be slow to paste",
+        theme::muted(),
+    )))
+    .alignment(Alignment::Center);
+    frame.render_widget(hint, chunks[1]);
+
+    draw_confirm_buttons(frame, chunks[2], modal.focused_field, "Copy");
+}
@@ -0,0 +1,93 @@
+//! WHERE clause builder modal rendering
+
+use crate::app::{FilterModal, FilterModalField};
+use crate::ui::icons::Icons;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::helpers::{centered_rect, draw_input_field, draw_ok_cancel_buttons};
+
+pub fn draw_filter_modal(frame: &mut Frame, modal: &FilterModal, icons: Icons) {
+    let area = centered_rect(50, 60, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Filter {} ", modal.table_name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Column
+            Constraint::Length(3), // Operator
+            Constraint::Length(3), // Value
+            Constraint::Length(2), // Preview
+            Constraint::Length(3), // Buttons
+        ])
+        .split(inner);
+
+    let selector = |value: &str| format!("{} {} {}", icons.glyph("◀"), value, icons.glyph("▶"));
+    draw_input_field(
+        frame,
+        chunks[0],
+        &icons.text("Column (←/→ to change)"),
+        &selector(modal.selected_column().unwrap_or("-")),
+        modal.focused_field == FilterModalField::Column,
+        false,
+    );
+    draw_input_field(
+        frame,
+        chunks[1],
+        &icons.text("Operator (←/→ to change)"),
+        &selector(modal.operator.sql()),
+        modal.focused_field == FilterModalField::Operator,
+        false,
+    );
+    let value_label = if modal.operator.takes_value() {
+        "Value"
+    } else {
+        "Value (not used)"
+    };
+    draw_input_field(
+        frame,
+        chunks[2],
+        value_label,
+        &modal.value,
+        modal.focused_field == FilterModalField::Value,
+        false,
+    );
+
+    // Preview of the condition, or why it cannot be built yet
+    let preview = match modal.clause() {
+        Ok(clause) => Span::styled(format!("WHERE {}", clause), theme::text()),
+        Err(e) => Span::styled(e, theme::muted()),
+    };
+    frame.render_widget(Paragraph::new(Line::from(preview)), chunks[3]);
+
+    draw_ok_cancel_buttons(
+        frame,
+        chunks[4],
+        modal.focused_field == FilterModalField::ButtonOk,
+        modal.focused_field == FilterModalField::ButtonCancel,
+    );
+}
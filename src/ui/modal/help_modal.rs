@@ -0,0 +1,75 @@
+//! Help overlay rendering
+
+use crate::app::HelpModal;
+use crate::event::keymap::{bindings_for, KeyContext};
+use crate::ui::icons::Icons;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+
+/// Width of the key column
+const KEY_WIDTH: usize = 20;
+
+pub fn draw_help_modal(frame: &mut Frame, modal: &HelpModal, icons: Icons) {
+    let area = centered_rect(60, 80, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(" Keybindings ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Keybinding list
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner);
+
+    let mut lines: Vec<Line> = Vec::new();
+    for context in KeyContext::ALL {
+        lines.push(Line::from(Span::styled(context.label(), theme::header())));
+        for binding in bindings_for(context) {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<width$}", icons.text(binding.keys), width = KEY_WIDTH),
+                    theme::focused(),
+                ),
+                Span::styled(binding.description, theme::text()),
+            ]));
+        }
+        lines.push(Line::default());
+    }
+
+    frame.render_widget(Paragraph::new(lines).scroll((modal.scroll, 0)), chunks[0]);
+
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("j/k", theme::header()),
+        Span::raw(": scroll  "),
+        Span::styled("Esc/?", theme::header()),
+        Span::raw(": close"),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
@@ -2,11 +2,13 @@
 
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
+use crate::fuzzy;
 use crate::ui::theme;
 
 /// Create a centered rectangle with given percentage of width and height
@@ -39,13 +41,31 @@ pub fn draw_input_field(
     focused: bool,
     is_password: bool,
 ) {
-    let style = if focused {
+    let cursor = focused.then(|| value.chars().count());
+    draw_validated_input_field(frame, area, label, value, cursor, is_password, None);
+}
+
+/// Draw a text input field with label and, while focused, the cursor at the
+/// given char position. Invalid input is outlined in red with `error` as a
+/// hint under the value.
+pub fn draw_validated_input_field(
+    frame: &mut Frame,
+    area: Rect,
+    label: &str,
+    value: &str,
+    cursor: Option<usize>,
+    is_password: bool,
+    error: Option<&str>,
+) {
+    let style = if cursor.is_some() {
         theme::input_focused()
     } else {
         theme::text()
     };
 
-    let border_style = if focused {
+    let border_style = if error.is_some() {
+        theme::error()
+    } else if cursor.is_some() {
         theme::input_border_focused()
     } else {
         theme::input_border_inactive()
@@ -53,33 +73,42 @@ pub fn draw_input_field(
 
     // Mask password field
     let masked_value = if is_password {
-        "*".repeat(value.len())
+        "*".repeat(value.chars().count())
     } else {
         value.to_string()
     };
 
-    // Display value with cursor if focused
-    let display_value = if focused {
-        format!("{}_", masked_value)
-    } else {
-        masked_value
+    // Display value with cursor if focused: `_` past the end, otherwise the
+    // char under the cursor in reverse video
+    let display_value = match cursor {
+        Some(pos) if pos < masked_value.chars().count() => {
+            let before: String = masked_value.chars().take(pos).collect();
+            let mut rest = masked_value.chars().skip(pos);
+            let under: String = rest.next().into_iter().collect();
+            Line::from(vec![
+                Span::raw(before),
+                Span::styled(under, style.add_modifier(Modifier::REVERSED)),
+                Span::raw(rest.collect::<String>()),
+            ])
+        }
+        Some(_) => Line::raw(format!("{}_", masked_value)),
+        None => Line::raw(masked_value),
     };
 
-    let input = Paragraph::new(display_value).style(style).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(border_style)
-            .title(format!(" {} ", label)),
-    );
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(border_style)
+        .title(format!(" {} ", label));
+    if let Some(error) = error {
+        block = block.title_bottom(Line::styled(format!(" {} ", error), theme::error()));
+    }
+    let input = Paragraph::new(display_value).style(style).block(block);
 
     frame.render_widget(input, area);
 }
 
-/// Highlight matching substring in text
+/// Highlight the characters of `text` matched by a fuzzy `query`
 pub fn highlight_match(text: &str, query: &str, is_selected: bool) -> Line<'static> {
-    let text_lower = text.to_lowercase();
-    let query_lower = query.to_lowercase();
-
     let base_style = if is_selected {
         theme::focused()
     } else {
@@ -92,24 +121,41 @@ pub fn highlight_match(text: &str, query: &str, is_selected: bool) -> Line<'stat
         theme::highlight_match()
     };
 
-    if let Some(start) = text_lower.find(&query_lower) {
-        let end = start + query.len();
-        let before = &text[..start];
-        let matched = &text[start..end];
-        let after = &text[end..];
+    let positions = match fuzzy::fuzzy_match_positions(query, text) {
+        Some(m) if !m.positions.is_empty() => m.positions,
+        _ => return Line::from(Span::styled(text.to_string(), base_style)),
+    };
 
-        Line::from(vec![
-            Span::styled(before.to_string(), base_style),
-            Span::styled(matched.to_string(), highlight_style),
-            Span::styled(after.to_string(), base_style),
-        ])
-    } else {
-        Line::from(Span::styled(text.to_string(), base_style))
+    // Group consecutive chars with the same highlight state into spans
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut current = String::new();
+    let mut current_matched = false;
+    for (idx, c) in text.chars().enumerate() {
+        let matched = positions.binary_search(&idx).is_ok();
+        if matched != current_matched && !current.is_empty() {
+            let style = if current_matched {
+                highlight_style
+            } else {
+                base_style
+            };
+            spans.push(Span::styled(std::mem::take(&mut current), style));
+        }
+        current_matched = matched;
+        current.push(c);
     }
+    if !current.is_empty() {
+        let style = if current_matched {
+            highlight_style
+        } else {
+            base_style
+        };
+        spans.push(Span::styled(current, style));
+    }
+
+    Line::from(spans)
 }
 
 /// Draw standard OK/Cancel buttons
-#[allow(dead_code)]
 pub fn draw_ok_cancel_buttons(
     frame: &mut Frame,
     area: Rect,
@@ -154,3 +200,22 @@ pub fn draw_ok_cancel_buttons(
     frame.render_widget(ok_button, button_chunks[0]);
     frame.render_widget(cancel_button, button_chunks[1]);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_match_splits_matched_chars() {
+        let line = highlight_match("user_sessions", "uss", false);
+        let parts: Vec<&str> = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(parts.concat(), "user_sessions");
+        assert_eq!(parts, vec!["us", "er_", "s", "essions"]);
+    }
+
+    #[test]
+    fn test_highlight_match_no_match_is_plain() {
+        let line = highlight_match("orders", "xyz", false);
+        assert_eq!(line.spans.len(), 1);
+    }
+}
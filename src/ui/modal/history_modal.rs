@@ -1,12 +1,16 @@
 //! History modal rendering
 
+use chrono::Utc;
+
 use crate::app::HistoryModal;
-use crate::model::QueryHistory;
+use crate::model::history::QueryStatus;
+use crate::model::{HistoryEntry, QueryHistory};
 use crate::ui::theme;
+use crate::ui::utils::{format_duration_ms, format_relative_time};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
@@ -44,19 +48,43 @@ pub fn draw_history_modal(frame: &mut Frame, modal: &HistoryModal, history: &Que
         ])
         .split(inner);
 
+    let indices = modal.filtered_indices(history);
+
+    // The detail pane of the selected entry takes the lower half of the list area
+    let detail_entry = indices
+        .get(modal.selected_idx)
+        .and_then(|&idx| history.get(idx))
+        .filter(|_| modal.show_detail);
+    let (list_area, detail_area) = match detail_entry {
+        Some(_) => {
+            let areas = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(45), Constraint::Min(6)])
+                .split(chunks[0]);
+            (areas[0], Some(areas[1]))
+        }
+        None => (chunks[0], None),
+    };
+
     // Create list items from history entries
-    let items: Vec<ListItem> = history
-        .entries
+    let now = Utc::now();
+    let items: Vec<ListItem> = indices
         .iter()
+        .filter_map(|&idx| history.get(idx))
         .enumerate()
         .map(|(idx, entry)| {
             let status_icon = if entry.is_success() { "+" } else { "x" };
-            let time_str = entry.executed_at.format("%m/%d %H:%M").to_string();
+            // Relative times are padded to the width of the absolute ones
+            let time_str = if modal.relative_times {
+                format!("{:>11}", format_relative_time(entry.executed_at, now))
+            } else {
+                entry.executed_at.format("%m/%d %H:%M").to_string()
+            };
 
             // Truncate query if too long (use chars for UTF-8 safety)
             let min_query_len = 10;
             let max_query_len =
-                std::cmp::max((chunks[0].width as usize).saturating_sub(30), min_query_len);
+                std::cmp::max((list_area.width as usize).saturating_sub(30), min_query_len);
             let query_display = if entry.query.chars().count() > max_query_len {
                 let safe_trunc: String = entry
                     .query
@@ -88,20 +116,38 @@ pub fn draw_history_modal(frame: &mut Frame, modal: &HistoryModal, history: &Que
         Block::default()
             .borders(Borders::ALL)
             .border_style(theme::border_inactive())
-            .title(format!(" {} queries ", history.len())),
+            .title(list_title(modal, history)),
     );
 
     // Render with stateful list to show selection
     let mut list_state = ListState::default();
     list_state.select(Some(modal.selected_idx));
-    frame.render_stateful_widget(list, chunks[0], &mut list_state);
+    frame.render_stateful_widget(list, list_area, &mut list_state);
+
+    if let (Some(entry), Some(area)) = (detail_entry, detail_area) {
+        let detail = Paragraph::new(detail_lines(entry))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme::border_inactive())
+                    .title(" Detail "),
+            )
+            .wrap(Wrap { trim: false });
+        frame.render_widget(detail, area);
+    }
 
     // Help text
     let help = Paragraph::new(Line::from(vec![
         Span::styled("Enter", theme::header()),
         Span::raw(": select  "),
+        Span::styled("x", theme::header()),
+        Span::raw(": re-run  "),
         Span::styled("j/k", theme::header()),
         Span::raw(": navigate  "),
+        Span::styled("f", theme::header()),
+        Span::raw(": filter  "),
+        Span::styled("d", theme::header()),
+        Span::raw(": detail  "),
         Span::styled("c", theme::header()),
         Span::raw(": clear  "),
         Span::styled("Esc/q", theme::header()),
@@ -110,3 +156,105 @@ pub fn draw_history_modal(frame: &mut Frame, modal: &HistoryModal, history: &Que
     .alignment(Alignment::Center);
     frame.render_widget(help, chunks[1]);
 }
+
+/// Entry counts, e.g. ` 12 total, 3 failed (failed only) `
+fn list_title(modal: &HistoryModal, history: &QueryHistory) -> String {
+    let failed = history.entries.iter().filter(|e| !e.is_success()).count();
+    match modal.filter.label() {
+        Some(label) => format!(" {} total, {} failed ({}) ", history.len(), failed, label),
+        None => format!(" {} total, {} failed ", history.len(), failed),
+    }
+}
+
+/// Connection, timing, outcome and the full query text of a history entry
+fn detail_lines(entry: &HistoryEntry) -> Vec<Line<'static>> {
+    let field = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<12}", label), theme::muted()),
+            Span::styled(value, theme::text()),
+        ])
+    };
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+    let status = match &entry.status {
+        QueryStatus::Success => Line::from(vec![
+            Span::styled(format!("{:<12}", "Status"), theme::muted()),
+            Span::styled("OK", theme::selected()),
+        ]),
+        QueryStatus::Error(e) => Line::from(vec![
+            Span::styled(format!("{:<12}", "Status"), theme::muted()),
+            Span::styled(format!("Error: {}", e), theme::error()),
+        ]),
+    };
+
+    let mut lines = vec![
+        field(
+            "Connection",
+            format!("{} ({})", entry.connection_name, entry.database),
+        ),
+        field(
+            "Executed",
+            entry.executed_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        ),
+        field(
+            "Duration",
+            or_dash(entry.execution_time_ms.map(format_duration_ms)),
+        ),
+        field("Rows", or_dash(entry.row_count.map(|n| n.to_string()))),
+        status,
+        Line::default(),
+    ];
+    lines.extend(
+        entry
+            .query
+            .lines()
+            .map(|line| Line::styled(line.to_string(), theme::text())),
+    );
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_list_title_counts_failed_entries() {
+        let mut history = QueryHistory::new();
+        history.add(HistoryEntry::success("SELECT 1", "local", "app", 1, 1));
+        history.add(HistoryEntry::error(
+            "SELEC 2",
+            "local",
+            "app",
+            "syntax error",
+        ));
+        let mut modal = HistoryModal::default();
+
+        assert_eq!(list_title(&modal, &history), " 2 total, 1 failed ");
+        modal.filter = modal.filter.next();
+        assert_eq!(
+            list_title(&modal, &history),
+            " 2 total, 1 failed (failed only) "
+        );
+    }
+
+    #[test]
+    fn test_detail_lines_show_full_query_and_outcome() {
+        let query = format!("SELECT {}\nFROM users", "id, ".repeat(40));
+        let entry = HistoryEntry::success(&query, "local", "app", 12, 3);
+        let lines: Vec<String> = detail_lines(&entry).iter().map(line_text).collect();
+
+        assert_eq!(lines[0], "Connection  local (app)");
+        assert_eq!(lines[3], "Rows        3");
+        assert_eq!(lines[4], "Status      OK");
+        assert_eq!(lines[6..].join("\n"), query);
+
+        let failed = HistoryEntry::error("SELEC 1", "local", "app", "syntax error");
+        let lines: Vec<String> = detail_lines(&failed).iter().map(line_text).collect();
+        assert_eq!(lines[2], "Duration    -");
+        assert_eq!(lines[4], "Status      Error: syntax error");
+    }
+}
@@ -0,0 +1,101 @@
+//! Connection import modal rendering
+
+use crate::app::ImportConnectionsModal;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+
+pub fn draw_import_connections_modal(frame: &mut Frame, modal: &ImportConnectionsModal) {
+    let area = centered_rect(70, 70, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Import {} Connections ", modal.connections.len()))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Sources
+            Constraint::Min(3),    // Connections
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner);
+
+    // Source files
+    let sources = Paragraph::new(Line::from(vec![
+        Span::styled(" From: ", theme::muted()),
+        Span::styled(modal.sources.join(", "), theme::text()),
+    ]));
+    frame.render_widget(sources, chunks[0]);
+
+    // Connections to import
+    let header = Row::new(
+        ["Name", "Host", "Port", "Database", "User"]
+            .into_iter()
+            .map(|h| Cell::from(h).style(theme::header())),
+    );
+
+    let rows: Vec<Row> = modal
+        .connections
+        .iter()
+        .map(|conn| {
+            Row::new(vec![
+                Cell::from(conn.name.clone()).style(theme::text()),
+                Cell::from(conn.host.clone()).style(theme::text()),
+                Cell::from(conn.port.to_string()).style(theme::muted()),
+                Cell::from(conn.database.clone()).style(theme::text()),
+                Cell::from(conn.username.clone()).style(theme::text()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(20),
+            Constraint::Min(15),
+            Constraint::Length(6),
+            Constraint::Min(10),
+            Constraint::Min(10),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme::border_inactive()),
+    );
+    frame.render_widget(table, chunks[1]);
+
+    // Help text
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("Enter", theme::header()),
+        Span::raw(": import  "),
+        Span::styled("Esc/q", theme::header()),
+        Span::raw(": cancel"),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[2]);
+}
@@ -0,0 +1,78 @@
+//! Project load errors modal rendering
+
+use crate::app::LoadErrorsModal;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+
+/// Popup width: the wrapped errors plus borders and padding
+const MODAL_WIDTH: u16 = crate::app::DEFINITION_WRAP_WIDTH as u16 + 4;
+
+pub fn draw_load_errors_modal(frame: &mut Frame, modal: &LoadErrorsModal) {
+    let mut area = centered_rect(100, 60, frame.area());
+    if area.width > MODAL_WIDTH {
+        area.x += (area.width - MODAL_WIDTH) / 2;
+        area.width = MODAL_WIDTH;
+    }
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(
+            " Project files that failed to load ({}) ",
+            modal.entries.len()
+        ))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::error());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),    // Files and errors
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner);
+
+    let mut lines = Vec::with_capacity(modal.line_count());
+    for (idx, (path, error)) in modal.entries.iter().enumerate() {
+        if idx > 0 {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(Span::styled(path.as_str(), theme::header())));
+        lines.extend(error.iter().map(|line| {
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled(line.as_str(), theme::text()),
+            ])
+        }));
+    }
+    frame.render_widget(Paragraph::new(lines).scroll((modal.scroll, 0)), chunks[0]);
+
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("j/k", theme::header()),
+        Span::raw(": scroll  "),
+        Span::styled("Esc/q", theme::header()),
+        Span::raw(": close"),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
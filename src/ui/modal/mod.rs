@@ -3,20 +3,39 @@
 //! This module contains all modal dialog rendering functions, organized by type.
 
 mod connection_modal;
+mod constraint_modal;
+mod copy_modal;
+mod filter_modal;
+mod help_modal;
 mod helpers;
 mod history_modal;
+mod import_modal;
+mod load_errors_modal;
+mod order_modal;
 mod project_modal;
+mod query_modal;
+mod quit_modal;
+mod recent_modal;
+mod roles_modal;
 mod search;
+mod search_path_modal;
+mod sessions_modal;
+mod settings_modal;
+mod share_modal;
+mod snippets_modal;
+mod stats_modal;
 mod visibility_modal;
 
 use crate::app::{ColumnVisibilitySettings, ModalState};
-use crate::model::{Connection, Project, QueryHistory, Table};
+use crate::model::{Connection, Project, QueryHistory, RecentTables, Table};
+use crate::ui::icons::Icons;
 use ratatui::Frame;
 
 // Re-export for potential external use
 #[allow(unused_imports)]
 pub use helpers::{centered_rect, draw_input_field, highlight_match};
 
+#[allow(clippy::too_many_arguments)]
 pub fn draw_modal(
     frame: &mut Frame,
     modal_state: &ModalState,
@@ -24,12 +43,17 @@ pub fn draw_modal(
     connections: &[Connection],
     tables: Option<&[Table]>,
     history: &QueryHistory,
+    recent_tables: &RecentTables,
     column_visibility: &ColumnVisibilitySettings,
+    icons: Icons,
 ) {
     match modal_state {
         ModalState::None => {}
         ModalState::AddConnection(modal) => {
-            connection_modal::draw_add_connection_modal(frame, modal);
+            connection_modal::draw_connection_modal(frame, modal, " Add Connection ", icons);
+        }
+        ModalState::CloneConnection(modal) => {
+            connection_modal::draw_connection_modal(frame, modal, " Clone Connection ", icons);
         }
         ModalState::AddProject(modal) => {
             project_modal::draw_project_modal(frame, modal, " Add Project ");
@@ -40,25 +64,107 @@ pub fn draw_modal(
         ModalState::DeleteProject(modal) => {
             project_modal::draw_delete_project_modal(frame, modal);
         }
+        ModalState::Help(modal) => {
+            help_modal::draw_help_modal(frame, modal, icons);
+        }
+        ModalState::ConfirmQuit(modal) => {
+            quit_modal::draw_confirm_quit_modal(frame, modal);
+        }
+        ModalState::ConfirmCopy(modal) => {
+            copy_modal::draw_confirm_copy_modal(frame, modal);
+        }
+        ModalState::ConfirmQuery(modal) => {
+            query_modal::draw_confirm_query_modal(frame, modal);
+        }
+        ModalState::Filter(modal) => {
+            filter_modal::draw_filter_modal(frame, modal, icons);
+        }
+        ModalState::Order(modal) => {
+            order_modal::draw_order_modal(frame, modal, icons);
+        }
         ModalState::SearchProject(modal) => {
-            search::draw_search_project_modal(frame, modal, projects);
+            search::draw_search_project_modal(frame, modal, projects, icons);
         }
         ModalState::SearchConnection(modal) => {
-            search::draw_search_connection_modal(frame, modal, connections);
+            search::draw_search_connection_modal(frame, modal, connections, icons);
         }
         ModalState::SearchTable(modal) => {
             if let Some(tables) = tables {
-                search::draw_search_table_modal(frame, modal, tables);
+                search::draw_search_table_modal(frame, modal, tables, icons);
             }
         }
         ModalState::UnifiedSearch(modal) => {
-            search::draw_unified_search_modal(frame, modal, connections, tables.unwrap_or(&[]));
+            search::draw_unified_search_modal(
+                frame,
+                modal,
+                connections,
+                tables.unwrap_or(&[]),
+                icons,
+            );
+        }
+        ModalState::GlobalSearch(modal) => {
+            search::draw_global_search_modal(frame, modal, icons);
+        }
+        ModalState::GoToColumn(modal) => {
+            search::draw_go_to_column_modal(frame, modal, icons);
         }
         ModalState::History(modal) => {
             history_modal::draw_history_modal(frame, modal, history);
         }
+        ModalState::RecentTables(modal) => {
+            recent_modal::draw_recent_tables_modal(frame, modal, recent_tables);
+        }
         ModalState::ColumnVisibility(modal) => {
             visibility_modal::draw_column_visibility_modal(frame, modal, column_visibility);
         }
+        ModalState::DatabaseStats(modal) => {
+            stats_modal::draw_database_stats_modal(frame, modal, icons);
+        }
+        ModalState::ColumnStats(modal) => {
+            stats_modal::draw_column_stats_modal(frame, modal);
+        }
+        ModalState::ConstraintDetail(modal) => {
+            constraint_modal::draw_constraint_detail_modal(frame, modal);
+        }
+        ModalState::LoadErrors(modal) => {
+            load_errors_modal::draw_load_errors_modal(frame, modal);
+        }
+        ModalState::ServerSettings(modal) => {
+            settings_modal::draw_server_settings_modal(frame, modal, icons);
+        }
+        ModalState::Roles(modal) => {
+            roles_modal::draw_roles_modal(frame, modal, icons);
+        }
+        ModalState::Snippets(modal) => {
+            snippets_modal::draw_snippets_modal(frame, modal, icons);
+        }
+        ModalState::ActiveSessions(modal) => {
+            sessions_modal::draw_active_sessions_modal(frame, modal, icons);
+        }
+        ModalState::ExportProject(_, modal) => {
+            share_modal::draw_file_path_modal(
+                frame,
+                modal,
+                " Export Project ",
+                "Passwords are not written to the exported file",
+            );
+        }
+        ModalState::SearchPath(modal) => {
+            search_path_modal::draw_search_path_modal(frame, modal);
+        }
+        ModalState::ImportProject(modal) => {
+            share_modal::draw_file_path_modal(
+                frame,
+                modal,
+                " Import Project ",
+                "The project is copied into the config directory",
+            );
+        }
+        ModalState::ImportPassword(modal) => {
+            share_modal::draw_import_password_modal(frame, modal, projects);
+        }
+        ModalState::ImportConnections(modal) => {
+            import_modal::draw_import_connections_modal(frame, modal);
+        }
     }
 }
@@ -0,0 +1,85 @@
+//! Server-side order modal rendering
+
+use crate::app::{OrderModal, OrderModalField};
+use crate::ui::icons::Icons;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::helpers::{centered_rect, draw_input_field, draw_ok_cancel_buttons};
+
+pub fn draw_order_modal(frame: &mut Frame, modal: &OrderModal, icons: Icons) {
+    let area = centered_rect(50, 50, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Order {} ", modal.table_name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Column
+            Constraint::Length(3), // Direction
+            Constraint::Length(2), // Preview
+            Constraint::Length(3), // Buttons
+        ])
+        .split(inner);
+
+    let selector = |value: &str| format!("{} {} {}", icons.glyph("◀"), value, icons.glyph("▶"));
+    draw_input_field(
+        frame,
+        chunks[0],
+        &icons.text("Column (←/→ to change)"),
+        &selector(modal.selected_column().unwrap_or("(table order)")),
+        modal.focused_field == OrderModalField::Column,
+        false,
+    );
+    draw_input_field(
+        frame,
+        chunks[1],
+        &icons.text("Direction (←/→ to change)"),
+        &selector(&modal.direction.to_string()),
+        modal.focused_field == OrderModalField::Direction,
+        false,
+    );
+
+    let preview = match modal.order() {
+        Some((column, direction)) => Span::styled(
+            format!(
+                "ORDER BY {} {}",
+                modal.database_type.quote_identifier(&column),
+                direction
+            ),
+            theme::text(),
+        ),
+        None => Span::styled("Rows are returned in table order", theme::muted()),
+    };
+    frame.render_widget(Paragraph::new(Line::from(preview)), chunks[2]);
+
+    draw_ok_cancel_buttons(
+        frame,
+        chunks[3],
+        modal.focused_field == OrderModalField::ButtonOk,
+        modal.focused_field == OrderModalField::ButtonCancel,
+    );
+}
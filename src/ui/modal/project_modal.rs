@@ -10,7 +10,7 @@ use ratatui::{
     Frame,
 };
 
-use super::helpers::{centered_rect, draw_input_field};
+use super::helpers::{centered_rect, draw_validated_input_field};
 
 pub fn draw_project_modal(frame: &mut Frame, modal: &ProjectModal, title: &str) {
     let area = centered_rect(40, 30, frame.area());
@@ -46,13 +46,15 @@ pub fn draw_project_modal(frame: &mut Frame, modal: &ProjectModal, title: &str)
         .split(inner);
 
     // Draw Name field
-    draw_input_field(
+    draw_validated_input_field(
         frame,
         chunks[0],
         "Name",
         &modal.name,
-        modal.focused_field == ProjectModalField::Name,
+        (modal.focused_field == ProjectModalField::Name)
+            .then(|| modal.cursor.position(&modal.name)),
         false,
+        None,
     );
 
     // Draw buttons
@@ -152,17 +154,23 @@ pub fn draw_delete_project_modal(frame: &mut Frame, modal: &DeleteProjectModal)
     frame.render_widget(project_name, chunks[1]);
 
     // Draw buttons
-    draw_confirm_buttons(frame, chunks[3], modal.focused_field);
+    draw_confirm_buttons(frame, chunks[3], modal.focused_field, "Delete");
 }
 
-fn draw_confirm_buttons(frame: &mut Frame, area: Rect, focused_field: ConfirmModalField) {
+/// Draw Cancel / `ok_label` buttons for a confirmation modal
+pub(super) fn draw_confirm_buttons(
+    frame: &mut Frame,
+    area: Rect,
+    focused_field: ConfirmModalField,
+    ok_label: &str,
+) {
     let button_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    // Delete button (using accent for emphasis)
-    let delete_style = if focused_field == ConfirmModalField::ButtonOk {
+    // Confirm button (using accent for emphasis)
+    let ok_style = if focused_field == ConfirmModalField::ButtonOk {
         Style::default()
             .fg(theme::BG)
             .bg(theme::ACCENT)
@@ -171,9 +179,9 @@ fn draw_confirm_buttons(frame: &mut Frame, area: Rect, focused_field: ConfirmMod
         theme::header()
     };
 
-    let delete_button = Paragraph::new(Line::from(vec![
+    let ok_button = Paragraph::new(Line::from(vec![
         Span::raw(" "),
-        Span::styled("[ Delete ]", delete_style),
+        Span::styled(format!("[ {} ]", ok_label), ok_style),
         Span::raw(" "),
     ]))
     .alignment(Alignment::Center)
@@ -195,5 +203,5 @@ fn draw_confirm_buttons(frame: &mut Frame, area: Rect, focused_field: ConfirmMod
     .block(Block::default().borders(Borders::NONE));
 
     frame.render_widget(cancel_button, button_chunks[0]);
-    frame.render_widget(delete_button, button_chunks[1]);
+    frame.render_widget(ok_button, button_chunks[1]);
 }
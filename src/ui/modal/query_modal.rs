@@ -0,0 +1,61 @@
+//! Production write confirmation modal rendering
+
+use crate::app::ConfirmQueryModal;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+use super::project_modal::draw_confirm_buttons;
+
+pub fn draw_confirm_query_modal(frame: &mut Frame, modal: &ConfirmQueryModal) {
+    let area = centered_rect(60, 35, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(" PRODUCTION ")
+        .title_alignment(Alignment::Center)
+        .title_style(theme::production_banner())
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Question
+            Constraint::Min(1),    // Statement
+            Constraint::Length(3), // Buttons
+        ])
+        .split(inner);
+
+    let question = Paragraph::new(Line::from(Span::styled(
+        format!("Run this statement on {}?", modal.connection),
+        theme::header(),
+    )))
+    .alignment(Alignment::Center);
+    frame.render_widget(question, chunks[0]);
+
+    let statement = Paragraph::new(modal.query.as_str())
+        .style(theme::muted())
+        .wrap(Wrap { trim: false });
+    frame.render_widget(statement, chunks[1]);
+
+    draw_confirm_buttons(frame, chunks[2], modal.focused_field, "Run");
+}
@@ -0,0 +1,73 @@
+//! Quit confirmation modal rendering
+
+use crate::app::ConfirmQuitModal;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+use super::project_modal::draw_confirm_buttons;
+
+pub fn draw_confirm_quit_modal(frame: &mut Frame, modal: &ConfirmQuitModal) {
+    let area = centered_rect(50, 30, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(" Quit ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),                          // Question
+            Constraint::Length(modal.reasons.len() as u16), // Reasons
+            Constraint::Min(1),                             // Hint
+            Constraint::Length(3),                          // Buttons
+        ])
+        .split(inner);
+
+    let question = Paragraph::new(Line::from(Span::styled(
+        "Quit lazydb anyway?",
+        theme::header(),
+    )))
+    .alignment(Alignment::Center);
+    frame.render_widget(question, chunks[0]);
+
+    let reasons: Vec<Line> = modal
+        .reasons
+        .iter()
+        .map(|reason| Line::from(Span::styled(reason.clone(), theme::text())))
+        .collect();
+    frame.render_widget(
+        Paragraph::new(reasons).alignment(Alignment::Center),
+        chunks[1],
+    );
+
+    let hint = Paragraph::new(Line::from(Span::styled(
+        "Press Ctrl+c again to quit immediately",
+        theme::muted(),
+    )))
+    .alignment(Alignment::Center);
+    frame.render_widget(hint, chunks[2]);
+
+    draw_confirm_buttons(frame, chunks[3], modal.focused_field, "Quit");
+}
@@ -0,0 +1,93 @@
+//! Recent tables modal rendering
+
+use crate::app::RecentTablesModal;
+use crate::model::RecentTables;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+
+pub fn draw_recent_tables_modal(
+    frame: &mut Frame,
+    modal: &RecentTablesModal,
+    recent: &RecentTables,
+) {
+    let area = centered_rect(60, 60, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(" Recent Tables ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Recent tables list
+            Constraint::Length(2), // Help text
+        ])
+        .split(inner);
+
+    let items: Vec<ListItem> = recent
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            let (name_style, path_style) = if idx == modal.selected_idx {
+                (theme::focused(), theme::focused())
+            } else {
+                (theme::text(), theme::muted())
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(entry.table.clone(), name_style),
+                Span::styled(
+                    format!("  {} / {}", entry.project, entry.connection),
+                    path_style,
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme::border_inactive())
+            .title(format!(" {} tables ", recent.len())),
+    );
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(modal.selected_idx));
+    frame.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    // Help text
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("Enter", theme::header()),
+        Span::raw(": open  "),
+        Span::styled("j/k", theme::header()),
+        Span::raw(": navigate  "),
+        Span::styled("Esc/q", theme::header()),
+        Span::raw(": close"),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
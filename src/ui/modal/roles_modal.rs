@@ -0,0 +1,122 @@
+//! Database roles modal rendering
+
+use crate::app::RolesModal;
+use crate::ui::icons::Icons;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+
+pub fn draw_roles_modal(frame: &mut Frame, modal: &RolesModal, icons: Icons) {
+    let area = centered_rect(70, 70, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Roles: {} ", modal.connection_name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Summary
+            Constraint::Min(3),    // Roles
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner);
+
+    let superusers = modal
+        .roles
+        .iter()
+        .filter(|r| r.superuser == Some(true))
+        .count();
+    let mut summary = vec![
+        Span::styled(format!(" {} roles ", modal.roles.len()), theme::text()),
+        Span::styled(icons.glyph("│"), theme::muted()),
+        Span::styled(format!(" {} superusers ", superusers), theme::header()),
+    ];
+    if modal.is_limited() {
+        summary.push(Span::styled(icons.glyph("│"), theme::muted()));
+        summary.push(Span::styled(
+            " Some attributes are hidden for this user ",
+            theme::muted(),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(summary)), chunks[0]);
+
+    let header = Row::new(
+        ["Name", "Superuser", "Login", "Valid until"]
+            .into_iter()
+            .map(|h| Cell::from(h).style(theme::header())),
+    );
+
+    let flag = |value: Option<bool>| match value {
+        Some(true) => "yes",
+        Some(false) => "no",
+        None => "?",
+    };
+    let rows: Vec<Row> = modal
+        .roles
+        .iter()
+        .map(|role| {
+            let name_style = if role.superuser == Some(true) {
+                theme::header()
+            } else {
+                theme::text()
+            };
+            Row::new(vec![
+                Cell::from(role.name.clone()).style(name_style),
+                Cell::from(flag(role.superuser)).style(theme::text()),
+                Cell::from(flag(role.can_login)).style(theme::text()),
+                Cell::from(role.valid_until.clone().unwrap_or_default()).style(theme::muted()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(20),
+            Constraint::Length(10),
+            Constraint::Length(6),
+            Constraint::Length(24),
+        ],
+    )
+    .header(header)
+    .row_highlight_style(theme::row_highlight())
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme::border_inactive()),
+    );
+    let mut state = TableState::default()
+        .with_selected((!modal.roles.is_empty()).then_some(modal.selected_idx));
+    frame.render_stateful_widget(table, chunks[1], &mut state);
+
+    let help = Line::from(vec![
+        Span::styled(icons.text("↑↓/jk"), theme::header()),
+        Span::raw(": select  "),
+        Span::styled("Esc/q", theme::header()),
+        Span::raw(": close"),
+    ]);
+    frame.render_widget(Paragraph::new(help).alignment(Alignment::Center), chunks[2]);
+}
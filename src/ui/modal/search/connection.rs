@@ -2,6 +2,7 @@
 
 use crate::app::SearchConnectionModal;
 use crate::model::Connection;
+use crate::ui::icons::Icons;
 use crate::ui::modal::helpers::{centered_rect, highlight_match};
 use crate::ui::theme;
 use ratatui::{
@@ -17,6 +18,7 @@ pub fn draw_search_connection_modal(
     frame: &mut Frame,
     modal: &SearchConnectionModal,
     connections: &[Connection],
+    icons: Icons,
 ) {
     let area = centered_rect(50, 60, frame.area());
 
@@ -128,5 +130,5 @@ pub fn draw_search_connection_modal(
     }
 
     // Draw help text
-    draw_search_help(frame, chunks[3]);
+    draw_search_help(frame, chunks[3], icons);
 }
@@ -0,0 +1,138 @@
+//! Global search modal rendering (all projects)
+
+use crate::app::GlobalSearchModal;
+use crate::ui::icons::Icons;
+use crate::ui::modal::helpers::{centered_rect, highlight_match};
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::draw_search_help;
+
+pub fn draw_global_search_modal(frame: &mut Frame, modal: &GlobalSearchModal, icons: Icons) {
+    let area = centered_rect(60, 70, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(" Search Everywhere ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    // Layout for search input and results
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Search input
+            Constraint::Length(1), // Results count
+            Constraint::Min(5),    // Results list
+            Constraint::Length(2), // Help text
+        ])
+        .split(inner);
+
+    // Draw search input field
+    let search_display = format!("{}_", modal.query);
+    let search_input = Paragraph::new(search_display)
+        .style(theme::input_focused())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme::input_border_focused())
+                .title(" Search "),
+        );
+    frame.render_widget(search_input, chunks[0]);
+
+    // Draw results count
+    let count_text = format!(
+        " {} of {} connections and tables ",
+        modal.filtered_indices.len(),
+        modal.entries.len()
+    );
+    let count_paragraph = Paragraph::new(count_text).style(theme::muted());
+    frame.render_widget(count_paragraph, chunks[1]);
+
+    // Draw results list
+    let results_area = chunks[2];
+    let results_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(theme::border_inactive())
+        .title(" Results ");
+    let results_inner = results_block.inner(results_area);
+    frame.render_widget(results_block, results_area);
+
+    let visible_height = results_inner.height as usize;
+
+    // Calculate scroll offset to keep selected item visible
+    let scroll_offset = if modal.selected_idx >= visible_height {
+        modal.selected_idx - visible_height + 1
+    } else {
+        0
+    };
+
+    // Render each visible result
+    for (display_idx, &entry_idx) in modal
+        .filtered_indices
+        .iter()
+        .skip(scroll_offset)
+        .take(visible_height)
+        .enumerate()
+    {
+        let Some(entry) = modal.entries.get(entry_idx) else {
+            continue;
+        };
+        let actual_idx = scroll_offset + display_idx;
+        let is_selected = actual_idx == modal.selected_idx;
+
+        let style = if is_selected {
+            theme::focused()
+        } else {
+            theme::text()
+        };
+
+        let kind = if entry.target.table_idx.is_some() {
+            "  ".into()
+        } else {
+            icons.text("◆ ")
+        };
+
+        // Highlight matching text, followed by where the entry lives
+        let mut line = if !modal.query.is_empty() {
+            highlight_match(&entry.name, &modal.query, is_selected)
+        } else {
+            Line::from(Span::styled(entry.name.clone(), style))
+        };
+        line.spans.insert(0, Span::styled(kind, style));
+        line.spans
+            .push(Span::styled(format!("  {}", entry.path), theme::muted()));
+
+        let item_area = Rect {
+            x: results_inner.x,
+            y: results_inner.y + display_idx as u16,
+            width: results_inner.width,
+            height: 1,
+        };
+
+        let paragraph = Paragraph::new(line).style(style);
+        frame.render_widget(paragraph, item_area);
+    }
+
+    // Draw help text
+    draw_search_help(frame, chunks[3], icons);
+}
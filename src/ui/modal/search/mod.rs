@@ -1,15 +1,20 @@
-//! Search modal rendering (projects, connections, tables, unified)
+//! Search modal rendering (projects, connections, tables, unified, global, columns)
 
+mod column;
 mod connection;
+mod global;
 mod project;
 mod table;
 mod unified;
 
+pub use column::draw_go_to_column_modal;
 pub use connection::draw_search_connection_modal;
+pub use global::draw_global_search_modal;
 pub use project::draw_search_project_modal;
 pub use table::draw_search_table_modal;
 pub use unified::draw_unified_search_modal;
 
+use crate::ui::icons::Icons;
 use crate::ui::theme;
 use ratatui::{
     layout::{Alignment, Rect},
@@ -19,13 +24,13 @@ use ratatui::{
 };
 
 /// Draw help text for search modals
-pub(super) fn draw_search_help(frame: &mut Frame, area: Rect) {
+pub(super) fn draw_search_help(frame: &mut Frame, area: Rect, icons: Icons) {
     let help_text = Line::from(vec![
         Span::styled("Enter", theme::header()),
         Span::raw(": select  "),
         Span::styled("Esc", theme::header()),
         Span::raw(": cancel  "),
-        Span::styled("↑/↓", theme::selected()),
+        Span::styled(icons.text("↑/↓"), theme::selected()),
         Span::raw(": navigate"),
     ]);
     let help = Paragraph::new(help_text).alignment(Alignment::Center);
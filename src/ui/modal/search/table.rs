@@ -2,6 +2,7 @@
 
 use crate::app::SearchTableModal;
 use crate::model::Table;
+use crate::ui::icons::Icons;
 use crate::ui::modal::helpers::{centered_rect, highlight_match};
 use crate::ui::theme;
 use ratatui::{
@@ -13,7 +14,12 @@ use ratatui::{
 
 use super::draw_search_help;
 
-pub fn draw_search_table_modal(frame: &mut Frame, modal: &SearchTableModal, tables: &[Table]) {
+pub fn draw_search_table_modal(
+    frame: &mut Frame,
+    modal: &SearchTableModal,
+    tables: &[Table],
+    icons: Icons,
+) {
     let area = centered_rect(50, 60, frame.area());
 
     // Clear the area behind the modal
@@ -124,5 +130,5 @@ pub fn draw_search_table_modal(frame: &mut Frame, modal: &SearchTableModal, tabl
     }
 
     // Draw help text
-    draw_search_help(frame, chunks[3]);
+    draw_search_help(frame, chunks[3], icons);
 }
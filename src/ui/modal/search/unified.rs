@@ -2,6 +2,7 @@
 
 use crate::app::{UnifiedSearchModal, UnifiedSearchSection};
 use crate::model::{Connection, Table};
+use crate::ui::icons::Icons;
 use crate::ui::modal::helpers::{centered_rect, highlight_match};
 use crate::ui::theme;
 use ratatui::{
@@ -16,6 +17,7 @@ pub fn draw_unified_search_modal(
     modal: &UnifiedSearchModal,
     connections: &[Connection],
     tables: &[Table],
+    icons: Icons,
 ) {
     let area = centered_rect(55, 70, frame.area());
 
@@ -114,7 +116,7 @@ pub fn draw_unified_search_modal(
         Span::raw(": switch  "),
         Span::styled("Esc", theme::header()),
         Span::raw(": cancel  "),
-        Span::styled("↑/↓", theme::header()),
+        Span::styled(icons.text("↑/↓"), theme::header()),
         Span::raw(": navigate"),
     ]);
     let help = Paragraph::new(help_text).alignment(Alignment::Center);
@@ -0,0 +1,29 @@
+//! Session search_path modal rendering
+
+use crate::app::SearchPathModal;
+use crate::ui::theme;
+use ratatui::{text::Span, widgets::Paragraph, Frame};
+
+use super::helpers::draw_input_field;
+use super::share_modal::{draw_help, draw_prompt_frame};
+
+pub fn draw_search_path_modal(frame: &mut Frame, modal: &SearchPathModal) {
+    let chunks = draw_prompt_frame(frame, " Session search_path ");
+
+    draw_input_field(
+        frame,
+        chunks[0],
+        "Schemas (comma separated)",
+        &modal.value,
+        true,
+        false,
+    );
+
+    let note = Paragraph::new(Span::styled(
+        "Applies until lazydb exits; leave empty to reset",
+        theme::muted(),
+    ));
+    frame.render_widget(note, chunks[1]);
+
+    draw_help(frame, chunks[2], "apply");
+}
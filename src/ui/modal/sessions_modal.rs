@@ -0,0 +1,135 @@
+//! Active sessions modal rendering
+
+use crate::app::ActiveSessionsModal;
+use crate::ui::icons::Icons;
+use crate::ui::theme;
+use crate::ui::utils::format_duration_ms;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+
+pub fn draw_active_sessions_modal(frame: &mut Frame, modal: &ActiveSessionsModal, icons: Icons) {
+    let area = centered_rect(90, 70, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Active Sessions: {} ", modal.connection_name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Summary
+            Constraint::Min(3),    // Sessions
+            Constraint::Length(1), // Help text or confirmation
+        ])
+        .split(inner);
+
+    let active = modal.sessions.iter().filter(|s| s.is_active()).count();
+    let summary = Paragraph::new(Line::from(vec![
+        Span::styled(format!(" {} ", modal.database), theme::header()),
+        Span::styled(icons.glyph("│"), theme::muted()),
+        Span::styled(
+            format!(" {} sessions ", modal.sessions.len()),
+            theme::text(),
+        ),
+        Span::styled(icons.glyph("│"), theme::muted()),
+        Span::styled(format!(" {} active ", active), theme::selected()),
+    ]));
+    frame.render_widget(summary, chunks[0]);
+
+    let header = Row::new(
+        ["PID", "User", "State", "Wait", "Duration", "Query"]
+            .into_iter()
+            .map(|h| Cell::from(h).style(theme::header())),
+    );
+
+    let rows: Vec<Row> = modal
+        .sessions
+        .iter()
+        .map(|session| {
+            let state_style = if session.is_active() {
+                theme::selected()
+            } else {
+                theme::muted()
+            };
+            Row::new(vec![
+                Cell::from(session.pid.to_string()).style(theme::text()),
+                Cell::from(session.user.clone().unwrap_or_default()).style(theme::text()),
+                Cell::from(session.state.clone().unwrap_or_default()).style(state_style),
+                Cell::from(session.wait_event.clone().unwrap_or_default()).style(theme::muted()),
+                Cell::from(
+                    session
+                        .duration_ms
+                        .map(format_duration_ms)
+                        .unwrap_or_default(),
+                )
+                .style(theme::text()),
+                Cell::from(session.query_line()).style(theme::text()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Length(20),
+            Constraint::Length(18),
+            Constraint::Length(10),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header)
+    .row_highlight_style(theme::row_highlight())
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme::border_inactive()),
+    );
+    let mut state = TableState::default()
+        .with_selected((!modal.sessions.is_empty()).then_some(modal.selected_idx));
+    frame.render_stateful_widget(table, chunks[1], &mut state);
+
+    let help = match modal.confirm_pid {
+        Some(pid) => Line::from(vec![
+            Span::styled(format!("Terminate session {}? ", pid), theme::header()),
+            Span::styled("y", theme::header()),
+            Span::raw(": terminate  "),
+            Span::styled("n/Esc", theme::header()),
+            Span::raw(": cancel"),
+        ]),
+        None => Line::from(vec![
+            Span::styled(icons.text("↑↓/jk"), theme::header()),
+            Span::raw(": select  "),
+            Span::styled("x", theme::header()),
+            Span::raw(": terminate  "),
+            Span::styled("r", theme::header()),
+            Span::raw(": refresh  "),
+            Span::styled("Esc/q", theme::header()),
+            Span::raw(": close"),
+        ]),
+    };
+    frame.render_widget(Paragraph::new(help).alignment(Alignment::Center), chunks[2]);
+}
@@ -0,0 +1,136 @@
+//! Server settings modal rendering
+
+use crate::app::ServerSettingsModal;
+use crate::ui::icons::Icons;
+use crate::ui::modal::helpers::{centered_rect, highlight_match};
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Width of the name column in the settings list
+const NAME_WIDTH: usize = 36;
+
+pub fn draw_server_settings_modal(frame: &mut Frame, modal: &ServerSettingsModal, icons: Icons) {
+    let area = centered_rect(80, 80, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Server Settings: {} ", modal.connection_name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Search input
+            Constraint::Length(1), // Results count
+            Constraint::Min(5),    // Settings list
+            Constraint::Length(3), // Description of the selected setting
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner);
+
+    let search_input = Paragraph::new(format!("{}_", modal.query))
+        .style(theme::input_focused())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme::input_border_focused())
+                .title(" Search "),
+        );
+    frame.render_widget(search_input, chunks[0]);
+
+    let count_text = format!(
+        " {} of {} settings ",
+        modal.filtered_indices.len(),
+        modal.settings.len()
+    );
+    frame.render_widget(Paragraph::new(count_text).style(theme::muted()), chunks[1]);
+
+    let results_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(theme::border_inactive());
+    let results_inner = results_block.inner(chunks[2]);
+    frame.render_widget(results_block, chunks[2]);
+
+    let visible_height = results_inner.height as usize;
+
+    // Calculate scroll offset to keep selected item visible
+    let scroll_offset = if modal.selected_idx >= visible_height {
+        modal.selected_idx - visible_height + 1
+    } else {
+        0
+    };
+
+    for (display_idx, &setting_idx) in modal
+        .filtered_indices
+        .iter()
+        .skip(scroll_offset)
+        .take(visible_height)
+        .enumerate()
+    {
+        let Some(setting) = modal.settings.get(setting_idx) else {
+            continue;
+        };
+        let is_selected = scroll_offset + display_idx == modal.selected_idx;
+        let style = if is_selected {
+            theme::focused()
+        } else {
+            theme::text()
+        };
+
+        let name = format!("{:<width$}", setting.name, width = NAME_WIDTH);
+        let mut line = if modal.query.is_empty() {
+            Line::from(Span::styled(name, style))
+        } else {
+            highlight_match(&name, &modal.query, is_selected)
+        };
+        line.spans
+            .push(Span::styled(format!(" {}", setting.setting), style));
+
+        let item_area = Rect {
+            x: results_inner.x,
+            y: results_inner.y + display_idx as u16,
+            width: results_inner.width,
+            height: 1,
+        };
+        frame.render_widget(Paragraph::new(line).style(style), item_area);
+    }
+
+    let description = modal
+        .selected_setting()
+        .map(|s| s.description.as_str())
+        .unwrap_or_default();
+    let description = Paragraph::new(description)
+        .style(theme::muted())
+        .wrap(Wrap { trim: true });
+    frame.render_widget(description, chunks[3]);
+
+    let help = Line::from(vec![
+        Span::styled("Type", theme::header()),
+        Span::raw(": search  "),
+        Span::styled(icons.text("↑/↓"), theme::selected()),
+        Span::raw(": navigate  "),
+        Span::styled("Esc", theme::header()),
+        Span::raw(": close"),
+    ]);
+    frame.render_widget(Paragraph::new(help).alignment(Alignment::Center), chunks[4]);
+}
@@ -0,0 +1,102 @@
+//! Project export/import modal rendering
+
+use crate::app::{FilePathModal, ImportPasswordModal};
+use crate::model::Project;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::helpers::{centered_rect, draw_input_field};
+
+pub fn draw_file_path_modal(frame: &mut Frame, modal: &FilePathModal, title: &str, note: &str) {
+    let chunks = draw_prompt_frame(frame, title);
+
+    draw_input_field(frame, chunks[0], "Path", &modal.path, true, false);
+
+    let note = Paragraph::new(Span::styled(note.to_string(), theme::muted()));
+    frame.render_widget(note, chunks[1]);
+
+    draw_help(frame, chunks[2], "save/load");
+}
+
+pub fn draw_import_password_modal(
+    frame: &mut Frame,
+    modal: &ImportPasswordModal,
+    projects: &[Project],
+) {
+    let connection_name = modal
+        .current()
+        .and_then(|conn_idx| {
+            projects
+                .get(modal.project_idx)?
+                .connections
+                .get(conn_idx)
+                .map(|conn| format!("{} ({}@{})", conn.name, conn.username, conn.host))
+        })
+        .unwrap_or_default();
+
+    let chunks = draw_prompt_frame(frame, " Imported Connection Password ");
+
+    draw_input_field(frame, chunks[0], "Password", &modal.password, true, true);
+
+    let note = Paragraph::new(Line::from(vec![
+        Span::styled(connection_name, theme::text()),
+        Span::styled(
+            format!("  ({} remaining)", modal.pending.len()),
+            theme::muted(),
+        ),
+    ]));
+    frame.render_widget(note, chunks[1]);
+
+    draw_help(frame, chunks[2], "next");
+}
+
+/// Draw the modal container and return areas for input, note and help text
+pub(super) fn draw_prompt_frame(frame: &mut Frame, title: &str) -> Vec<Rect> {
+    let area = centered_rect(50, 30, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(title.to_string())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Input
+            Constraint::Length(1), // Note
+            Constraint::Min(1),    // Help text
+        ])
+        .split(inner)
+        .to_vec()
+}
+
+pub(super) fn draw_help(frame: &mut Frame, area: Rect, action: &str) {
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("Enter", theme::header()),
+        Span::raw(format!(": {}  ", action)),
+        Span::styled("Esc", theme::header()),
+        Span::raw(": cancel"),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(help, area);
+}
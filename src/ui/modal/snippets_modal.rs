@@ -0,0 +1,87 @@
+//! Connection snippets modal rendering
+
+use crate::app::SnippetsModal;
+use crate::ui::icons::Icons;
+use crate::ui::theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+
+pub fn draw_snippets_modal(frame: &mut Frame, modal: &SnippetsModal, icons: Icons) {
+    let area = centered_rect(70, 70, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Snippets: {} ", modal.connection_name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Snippet names
+            Constraint::Length(8), // Query of the selected snippet
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner);
+
+    let items: Vec<ListItem> = modal
+        .snippets
+        .iter()
+        .map(|snippet| ListItem::new(Line::from(Span::styled(&snippet.name, theme::text()))))
+        .collect();
+    let list = List::new(items)
+        .highlight_style(theme::row_highlight())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme::border_inactive()),
+        );
+    let mut state = ListState::default()
+        .with_selected((!modal.snippets.is_empty()).then_some(modal.selected_idx));
+    frame.render_stateful_widget(list, chunks[0], &mut state);
+
+    let query = modal
+        .selected_snippet()
+        .map(|snippet| snippet.query.as_str())
+        .unwrap_or_default();
+    let preview = Paragraph::new(query)
+        .style(theme::muted())
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .title(" Query ")
+                .borders(Borders::ALL)
+                .border_style(theme::border_inactive()),
+        );
+    frame.render_widget(preview, chunks[1]);
+
+    let help = Line::from(vec![
+        Span::styled(icons.text("↑↓/jk"), theme::header()),
+        Span::raw(": select  "),
+        Span::styled("Enter", theme::header()),
+        Span::raw(": insert into editor  "),
+        Span::styled("Esc/q", theme::header()),
+        Span::raw(": close"),
+    ]);
+    frame.render_widget(Paragraph::new(help).alignment(Alignment::Center), chunks[2]);
+}
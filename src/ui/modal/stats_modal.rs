@@ -0,0 +1,186 @@
+//! Database and column stats modal rendering
+
+use crate::app::{ColumnStatsModal, DatabaseStatsModal};
+use crate::ui::icons::Icons;
+use crate::ui::theme;
+use crate::ui::utils::format_size;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+
+use super::helpers::centered_rect;
+
+pub fn draw_database_stats_modal(frame: &mut Frame, modal: &DatabaseStatsModal, icons: Icons) {
+    let area = centered_rect(60, 70, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Database Size: {} ", modal.connection_name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Summary
+            Constraint::Min(3),    // Largest tables
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner);
+
+    let stats = &modal.stats;
+
+    // Summary line
+    let summary = Paragraph::new(Line::from(vec![
+        Span::styled(format!(" {} ", modal.database), theme::header()),
+        Span::styled(icons.glyph("│"), theme::muted()),
+        Span::styled(
+            format!(" Total: {} ", format_size(stats.database_size)),
+            theme::selected(),
+        ),
+        Span::styled(icons.glyph("│"), theme::muted()),
+        Span::styled(
+            format!(
+                " Top {}: {} ",
+                stats.largest_tables.len(),
+                format_size(stats.listed_tables_size())
+            ),
+            theme::text(),
+        ),
+    ]));
+    frame.render_widget(summary, chunks[0]);
+
+    // Largest tables
+    let header = Row::new(
+        ["Table", "Size", "Share"]
+            .into_iter()
+            .map(|h| Cell::from(h).style(theme::header())),
+    );
+
+    let rows: Vec<Row> = stats
+        .largest_tables
+        .iter()
+        .map(|table| {
+            Row::new(vec![
+                Cell::from(table.full_name()).style(theme::text()),
+                Cell::from(format_size(table.total_bytes)).style(theme::text()),
+                Cell::from(format!("{:.1}%", stats.share_of_database(table))).style(theme::muted()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(20),
+            Constraint::Length(12),
+            Constraint::Length(8),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme::border_inactive())
+            .title(" Largest tables "),
+    );
+    frame.render_widget(table, chunks[1]);
+
+    // Help text
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("Esc/q", theme::header()),
+        Span::raw(": close"),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[2]);
+}
+
+pub fn draw_column_stats_modal(frame: &mut Frame, modal: &ColumnStatsModal) {
+    let area = centered_rect(40, 40, frame.area());
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, area);
+
+    let stats = &modal.stats;
+
+    // Modal container
+    let block = Block::default()
+        .title(format!(" Column: {} ", stats.column))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(theme::border_focused());
+
+    frame.render_widget(block, area);
+
+    // Inner area for content
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Statistics
+            Constraint::Length(1), // Note on partial results
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner);
+
+    let mut items = vec![
+        ("Count", stats.count.to_string()),
+        ("Distinct", stats.distinct.to_string()),
+        ("Nulls", stats.nulls.to_string()),
+    ];
+    if let Some(numeric) = &stats.numeric {
+        items.push(("Min", numeric.min.to_string()));
+        items.push(("Max", numeric.max.to_string()));
+        items.push(("Avg", format!("{:.4}", numeric.avg)));
+    }
+
+    let rows: Vec<Row> = items
+        .into_iter()
+        .map(|(label, value)| {
+            Row::new(vec![
+                Cell::from(label).style(theme::header()),
+                Cell::from(value).style(theme::text()),
+            ])
+        })
+        .collect();
+    let table = Table::new(rows, [Constraint::Length(10), Constraint::Min(10)]);
+    frame.render_widget(table, chunks[0]);
+
+    if modal.partial {
+        let note = Paragraph::new("Only the rows held in memory are included")
+            .style(theme::muted())
+            .alignment(Alignment::Center);
+        frame.render_widget(note, chunks[1]);
+    }
+
+    // Help text
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("Esc/q", theme::header()),
+        Span::raw(": close"),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[2]);
+}
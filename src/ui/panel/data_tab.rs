@@ -1,21 +1,39 @@
 //! Data tab rendering with pagination
 
 use crate::app::App;
+use crate::model::PAGE_SIZES;
+use crate::ui::icons::Icons;
 use crate::ui::theme;
+use crate::ui::utils::{format_duration_ms, format_number};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    text::{Line, Span},
+    text::{Line, Span, Text},
     widgets::{
         Block, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState,
-        Table as RatatuiTable,
+        Table as RatatuiTable, Wrap,
     },
     Frame,
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Most lines the executed SQL may take above the grid
+const EXECUTED_QUERY_MAX_LINES: u16 = 3;
 
 pub fn draw_data_content(frame: &mut Frame, app: &mut App, area: Rect) {
+    let icons = Icons::from_app(app);
     if let Some(result) = &app.result {
+        // Executed SQL above the grid (toggled with `e`)
+        let area = match app.executed_query.as_deref() {
+            Some(sql) if app.show_executed_query => draw_executed_query(frame, sql, area),
+            _ => area,
+        };
+
         if result.rows.is_empty() {
-            let empty = Paragraph::new("Query returned no rows").style(theme::muted());
+            let empty = Paragraph::new(format!(
+                "Query returned no rows ({})",
+                format_duration_ms(result.execution_time_ms)
+            ))
+            .style(theme::muted());
             frame.render_widget(empty, area);
             return;
         }
@@ -52,40 +70,86 @@ pub fn draw_data_content(frame: &mut Frame, app: &mut App, area: Rect) {
             ])
             .split(chunks[0]);
 
-        // Create header row
-        let header_cells = result
-            .columns
+        // Shown columns in display order (moved with < / >, hidden with -)
+        let order = app.visible_data_columns();
+
+        // Create header row, marking the column selected with h/l
+        let selected_position = app.selected_data_position();
+        let show_types = app.show_column_types && !result.column_types.is_empty();
+        let header_cells = order.iter().enumerate().map(|(position, &i)| {
+            let style = if selected_position == Some(position) {
+                theme::selected()
+            } else {
+                theme::header()
+            };
+            let mut lines = vec![Line::from(result.columns[i].clone())];
+            if show_types {
+                let type_name = result.column_types.get(i).cloned().unwrap_or_default();
+                lines.push(Line::styled(type_name, theme::muted()));
+            }
+            Cell::from(Text::from(lines)).style(style)
+        });
+        let header = Row::new(header_cells).height(if show_types { 2 } else { 1 });
+
+        // Calculate column widths using Ratio for accurate distribution
+        // (Percentage would result in 0% width when columns > 100)
+        let widths: Vec<Constraint> = order
             .iter()
-            .map(|col| Cell::from(col.clone()).style(theme::header()));
-        let header = Row::new(header_cells).height(1);
+            .map(|_| Constraint::Ratio(1, order.len() as u32))
+            .collect();
+        let highlight_symbol = icons.text("▶ ");
+
+        // Width each column is drawn at, to wrap long values (toggled with `w`)
+        let wrap_widths: Option<Vec<usize>> = app.wrap_cells.then(|| {
+            let symbol_width = highlight_symbol.width() as u16;
+            let cells_area = Rect {
+                width: table_chunks[0].width.saturating_sub(symbol_width),
+                ..table_chunks[0]
+            };
+            Layout::horizontal(widths.iter().copied())
+                .spacing(1)
+                .split(cells_area)
+                .iter()
+                .map(|rect| usize::from(rect.width))
+                .collect()
+        });
+        let max_lines = usize::from(app.wrap_row_height);
 
         // Create data rows (paginated)
         let rows: Vec<Row> = page_rows
             .iter()
             .map(|row_data| {
-                let cells = row_data
+                let values = order
                     .iter()
-                    .map(|cell| Cell::from(cell.clone()).style(theme::text()));
-                Row::new(cells).height(1)
+                    .map(|&i| row_data.get(i).map(String::as_str).unwrap_or_default());
+                match &wrap_widths {
+                    Some(column_widths) => {
+                        let cells: Vec<Vec<String>> = values
+                            .zip(column_widths)
+                            .map(|(value, &width)| wrap_cell(value, width, max_lines))
+                            .collect();
+                        let height = cells.iter().map(Vec::len).max().unwrap_or(1);
+                        let cells = cells.into_iter().map(|lines| {
+                            Cell::from(Text::from(
+                                lines.into_iter().map(Line::from).collect::<Vec<_>>(),
+                            ))
+                            .style(theme::text())
+                        });
+                        Row::new(cells).height(height as u16)
+                    }
+                    None => {
+                        let cells =
+                            values.map(|value| Cell::from(value.to_string()).style(theme::text()));
+                        Row::new(cells).height(1)
+                    }
+                }
             })
             .collect();
 
-        // Calculate column widths using Ratio for accurate distribution
-        // (Percentage would result in 0% width when columns > 100)
-        let widths: Vec<Constraint> = if result.columns.is_empty() {
-            vec![]
-        } else {
-            result
-                .columns
-                .iter()
-                .map(|_| Constraint::Ratio(1, result.columns.len() as u32))
-                .collect()
-        };
-
         let table = RatatuiTable::new(rows, widths)
             .header(header)
             .row_highlight_style(theme::row_highlight())
-            .highlight_symbol("▶ ");
+            .highlight_symbol(highlight_symbol);
 
         // Render table with state for scrolling
         frame.render_stateful_widget(table, table_chunks[0], &mut app.data_table_state);
@@ -94,16 +158,35 @@ pub fn draw_data_content(frame: &mut Frame, app: &mut App, area: Rect) {
         let page_relative_idx = page_relative_index(selected_idx, start);
         let mut scrollbar_state = ScrollbarState::new(page_row_count).position(page_relative_idx);
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-            .begin_symbol(Some("▲"))
-            .end_symbol(Some("▼"))
-            .track_symbol(Some("│"))
-            .thumb_symbol("█");
+            .begin_symbol(Some(icons.glyph("▲")))
+            .end_symbol(Some(icons.glyph("▼")))
+            .track_symbol(Some(icons.glyph("│")))
+            .thumb_symbol(icons.glyph("█"));
         frame.render_stateful_widget(scrollbar, table_chunks[1], &mut scrollbar_state);
 
-        // Render info bar showing row position
-        let info_text = format_info_bar_text(selected_idx, start, page_row_count);
-        let info_bar = Paragraph::new(info_text).style(theme::muted());
-        frame.render_widget(info_bar, chunks[1]);
+        // Render info bar: row position on the left, result summary on the right
+        let summary = format_result_summary(
+            result.total_rows,
+            result.execution_time_ms,
+            app.result_limit,
+        );
+        let info_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Length(summary.chars().count() as u16),
+            ])
+            .split(chunks[1]);
+        let mut info_text = format_info_bar_text(selected_idx, start, page_row_count);
+        if !app.hidden_columns.is_empty() {
+            info_text.push_str(&format!("│ {} hidden (+: show) ", app.hidden_columns.len()));
+        }
+        if app.result_truncated {
+            info_text.push_str("│ partial result (R: reload) ");
+        }
+        let info_bar = Paragraph::new(icons.text(&info_text).into_owned()).style(theme::muted());
+        frame.render_widget(info_bar, info_chunks[0]);
+        frame.render_widget(Paragraph::new(summary).style(theme::text()), info_chunks[1]);
 
         // Draw pagination bar
         draw_pagination_bar(frame, app, chunks[2]);
@@ -113,6 +196,73 @@ pub fn draw_data_content(frame: &mut Frame, app: &mut App, area: Rect) {
     }
 }
 
+/// Draw the SQL behind the result at the top of `area`; returns the rest
+fn draw_executed_query(frame: &mut Frame, sql: &str, area: Rect) -> Rect {
+    let text = format!("SQL: {}", single_line(sql));
+    let height = wrapped_height(&text, area.width).min(EXECUTED_QUERY_MAX_LINES);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(height), Constraint::Min(1)])
+        .split(area);
+    let paragraph = Paragraph::new(text)
+        .style(theme::muted())
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, chunks[0]);
+    chunks[1]
+}
+
+/// Collapse the query's line breaks and indentation into single spaces
+fn single_line(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Lines `text` takes when wrapped at `width` columns (at least one)
+fn wrapped_height(text: &str, width: u16) -> u16 {
+    let width = usize::from(width.max(1));
+    let lines = text.chars().count().div_ceil(width).max(1);
+    u16::try_from(lines).unwrap_or(u16::MAX)
+}
+
+/// Split a value into lines of at most `width` display columns, keeping at most
+/// `max_lines` of them. Line breaks in the value start a new line; a value cut
+/// short ends with `…`.
+fn wrap_cell(value: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let width = width.max(1);
+    let max_lines = max_lines.max(1);
+    let mut lines = vec![String::new()];
+    let mut line_width = 0;
+    for c in value.chars() {
+        if c == '\n' {
+            lines.push(String::new());
+            line_width = 0;
+        } else if c != '\r' {
+            let char_width = c.width().unwrap_or(0);
+            if line_width + char_width > width && line_width > 0 {
+                lines.push(String::new());
+                line_width = 0;
+            }
+            if let Some(line) = lines.last_mut() {
+                line.push(c);
+            }
+            line_width += char_width;
+        }
+        // Keep one line past the limit to know the value was cut
+        if lines.len() > max_lines {
+            break;
+        }
+    }
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            while !last.is_empty() && last.width() + 1 > width {
+                last.pop();
+            }
+            last.push('…');
+        }
+    }
+    lines
+}
+
 /// Calculate page-relative index for scrollbar position.
 ///
 /// The scrollbar should show position within the current page, not the absolute
@@ -140,59 +290,112 @@ fn format_info_bar_text(selected_idx: usize, start: usize, page_row_count: usize
     )
 }
 
+/// Formats the result summary, e.g. " 587 rows in 42 ms ".
+///
+/// When the browse limit cut the result short it is noted, e.g.
+/// " 1,000 rows in 42 ms (LIMIT 1,000) ".
+fn format_result_summary(
+    total_rows: usize,
+    execution_time_ms: u64,
+    limit: Option<usize>,
+) -> String {
+    let noun = if total_rows == 1 { "row" } else { "rows" };
+    let limited = match limit {
+        Some(limit) if total_rows >= limit => format!("(LIMIT {}) ", format_number(limit)),
+        _ => String::new(),
+    };
+    format!(
+        " {} {} in {} {}",
+        format_number(total_rows),
+        noun,
+        format_duration_ms(execution_time_ms),
+        limited
+    )
+}
+
+/// Available page sizes with the active one highlighted (" 50 [100] 500")
+fn page_size_spans(active: usize) -> Vec<Span<'static>> {
+    PAGE_SIZES
+        .iter()
+        .map(|&size| {
+            if size == active {
+                Span::styled(format!(" [{}]", size), theme::selected())
+            } else {
+                Span::styled(format!(" {}", size), theme::muted())
+            }
+        })
+        .collect()
+}
+
 fn draw_pagination_bar(frame: &mut Frame, app: &App, area: Rect) {
     let pagination = &app.pagination;
+    let icons = Icons::from_app(app);
+    let separator = || Span::styled(icons.glyph("│"), theme::muted());
 
-    // Format: "< [p] Prev | Page 1/10 | Next [n] > | 50 rows | Total: 500 | Size: 50 [z]"
-    let prev_style = if pagination.has_prev() {
+    // Format: "< [p] Prev | Page 1/10 | Next [n] > | Rows 1-50 | Total: 500 | Size: [50] 100 500 [z]"
+    // Buttons are grayed out when pressing them would not change the page
+    let prev_style = if pagination.can_prev() || (app.result_offset > 0 && app.cursor_open) {
         theme::selected()
     } else {
         theme::muted()
     };
 
-    let next_style = if pagination.has_next() {
+    // More rows may still be waiting on the server-side cursor
+    let more_on_server = app.cursor_has_more;
+
+    let next_style = if pagination.can_next() || more_on_server {
         theme::selected()
     } else {
         theme::muted()
     };
 
-    let current_page = pagination.current_page + 1;
-    let total_pages = pagination.total_pages();
-    let start_row = pagination.start_index() + 1;
-    let end_row = pagination.end_index();
-
-    let spans = vec![
-        Span::styled(" ◀ ", prev_style),
+    // Pages and rows count from the start of the whole result, including
+    // rows dropped from the browsing window
+    let offset = app.result_offset;
+    let total_rows = app.result.as_ref().map_or(pagination.total_rows, |r| {
+        r.total_rows.max(offset + pagination.total_rows)
+    });
+    let current_page = offset / pagination.page_size + pagination.current_page + 1;
+    let total_pages = total_rows.div_ceil(pagination.page_size).max(1);
+    let more_suffix = if more_on_server { "+" } else { "" };
+    let start_row = offset + pagination.start_index() + 1;
+    let end_row = offset + pagination.end_index();
+
+    let mut spans = vec![
+        Span::styled(icons.text(" ◀ "), prev_style),
         Span::styled("[p]", theme::muted()),
         Span::styled(" Prev ", prev_style),
-        Span::styled("│", theme::muted()),
+        separator(),
         Span::styled(
-            format!(" Page {}/{} ", current_page, total_pages),
+            format!(" Page {}/{}{} ", current_page, total_pages, more_suffix),
             theme::text(),
         ),
-        Span::styled("│", theme::muted()),
+        separator(),
         Span::styled(" Next ", next_style),
         Span::styled("[n]", theme::muted()),
-        Span::styled(" ▶ ", next_style),
-        Span::styled("│", theme::muted()),
+        Span::styled(icons.text(" ▶ "), next_style),
+        separator(),
         Span::styled(
             format!(" Rows {}-{} ", start_row, end_row),
             theme::selected(),
         ),
-        Span::styled("│", theme::muted()),
+        separator(),
         Span::styled(
-            format!(" Total: {} ", pagination.total_rows),
+            format!(" Total: {}{} ", total_rows, more_suffix),
             theme::header(),
         ),
-        Span::styled("│", theme::muted()),
-        Span::styled(format!(" Size: {} ", pagination.page_size), theme::muted()),
-        Span::styled("[z]", theme::muted()),
-        Span::styled("│", theme::muted()),
+        separator(),
+        Span::styled(" Size:", theme::muted()),
+    ];
+    spans.extend(page_size_spans(pagination.page_size));
+    spans.extend([
+        Span::styled(" [z]", theme::muted()),
+        separator(),
         Span::styled(" [g]", theme::muted()),
         Span::styled(" First ", theme::selected()),
         Span::styled("[G]", theme::muted()),
         Span::styled(" Last ", theme::selected()),
-    ];
+    ]);
 
     let line = Line::from(spans);
     let paragraph = Paragraph::new(line).block(
@@ -208,6 +411,44 @@ fn draw_pagination_bar(frame: &mut Frame, app: &App, area: Rect) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_wrap_cell() {
+        assert_eq!(wrap_cell("short", 10, 3), vec!["short"]);
+        assert_eq!(wrap_cell("", 10, 3), vec![""]);
+        assert_eq!(wrap_cell("abcdefgh", 3, 3), vec!["abc", "def", "gh"]);
+        // Values longer than the row end with an ellipsis
+        assert_eq!(wrap_cell("abcdefghijk", 3, 2), vec!["abc", "de…"]);
+        // Line breaks in the value start a new line
+        assert_eq!(wrap_cell("a\r\nb", 10, 3), vec!["a", "b"]);
+        // Wide characters take two columns each
+        assert_eq!(
+            wrap_cell("日本語データ", 5, 3),
+            vec!["日本", "語デ", "ータ"]
+        );
+        assert_eq!(wrap_cell("日本語データ", 4, 2), vec!["日本", "語…"]);
+    }
+
+    #[test]
+    fn test_executed_query_is_shown_on_one_line() {
+        let sql = "SELECT *\nFROM users\n    WHERE id > 10\nLIMIT 1000";
+        assert_eq!(
+            single_line(sql),
+            "SELECT * FROM users WHERE id > 10 LIMIT 1000"
+        );
+        assert_eq!(wrapped_height("SQL: SELECT 1", 80), 1);
+        assert_eq!(wrapped_height(&"x".repeat(81), 80), 2);
+        assert_eq!(wrapped_height("", 80), 1);
+    }
+
+    #[test]
+    fn test_page_size_spans_highlight_active_size() {
+        let text: String = page_size_spans(100)
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(text, " 50 [100] 500");
+    }
+
     #[test]
     fn test_page_relative_index_first_page() {
         // Page 1: start=0, selected=0 -> relative=0
@@ -299,4 +540,36 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_format_result_summary() {
+        assert_eq!(format_result_summary(587, 42, None), " 587 rows in 42 ms ");
+        assert_eq!(format_result_summary(1, 3, None), " 1 row in 3 ms ");
+        assert_eq!(
+            format_result_summary(12345, 0, None),
+            " 12,345 rows in <1 ms "
+        );
+    }
+
+    #[test]
+    fn test_format_result_summary_notes_reached_limit() {
+        assert_eq!(
+            format_result_summary(1000, 42, Some(1000)),
+            " 1,000 rows in 42 ms (LIMIT 1,000) "
+        );
+        // Fewer rows than the limit means nothing was cut off
+        assert_eq!(
+            format_result_summary(10, 42, Some(1000)),
+            " 10 rows in 42 ms "
+        );
+    }
+
+    #[test]
+    fn test_format_result_summary_slow_queries() {
+        assert_eq!(format_result_summary(10, 1250, None), " 10 rows in 1.25 s ");
+        assert_eq!(
+            format_result_summary(10, 125_000, None),
+            " 10 rows in 2m 05s "
+        );
+    }
 }
@@ -6,6 +6,7 @@ mod data_tab;
 mod query_editor;
 mod relations_tab;
 mod schema_tab;
+mod welcome;
 
 use crate::app::{App, Focus, MainPanelTab};
 use crate::ui::theme;
@@ -19,6 +20,12 @@ use ratatui::{
 pub use query_editor::draw_query_editor;
 
 pub fn draw_panel(frame: &mut Frame, app: &mut App, area: Rect) {
+    // Nothing to browse yet: explain how to get started instead
+    if app.projects.is_empty() {
+        welcome::draw_welcome(frame, app, area);
+        return;
+    }
+
     let is_focused = app.focus == Focus::MainPanel;
     let border_style = if is_focused {
         theme::border_focused()
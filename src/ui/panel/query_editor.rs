@@ -3,8 +3,8 @@
 use crate::app::{App, Focus};
 use crate::ui::theme;
 use ratatui::{
-    layout::Rect,
-    widgets::{Block, Borders, Paragraph, Wrap},
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders, Paragraph, Tabs, Wrap},
     Frame,
 };
 
@@ -16,6 +16,23 @@ pub fn draw_query_editor(frame: &mut Frame, app: &App, area: Rect) {
         theme::border_inactive()
     };
 
+    // Split area for the tab strip and the editor
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Tabs
+            Constraint::Min(1),    // Editor
+        ])
+        .split(area);
+
+    let tabs = Tabs::new(app.query_tab_titles())
+        .select(app.active_query_tab)
+        .style(theme::muted())
+        .highlight_style(theme::header())
+        .divider("|");
+
+    frame.render_widget(tabs, chunks[0]);
+
     let block = Block::default()
         .title(" SQL Query ")
         .borders(Borders::ALL)
@@ -26,5 +43,5 @@ pub fn draw_query_editor(frame: &mut Frame, app: &App, area: Rect) {
         .style(theme::text())
         .wrap(Wrap { trim: false });
 
-    frame.render_widget(query_text, area);
+    frame.render_widget(query_text, chunks[1]);
 }
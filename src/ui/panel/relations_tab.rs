@@ -2,6 +2,7 @@
 
 use crate::app::App;
 use crate::model::{ForeignKey, Table};
+use crate::ui::icons::Icons;
 use crate::ui::theme;
 use ratatui::{
     layout::Rect,
@@ -13,6 +14,13 @@ use ratatui::{
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 pub fn draw_relations_content(frame: &mut Frame, app: &App, area: Rect) {
+    if !app.capabilities().supports_foreign_keys {
+        let empty =
+            Paragraph::new("Relations are not available for this database").style(theme::muted());
+        frame.render_widget(empty, area);
+        return;
+    }
+
     if let Some(tables) = app.current_connection_tables() {
         if tables.is_empty() {
             let empty = Paragraph::new("No tables in this connection").style(theme::muted());
@@ -21,7 +29,7 @@ pub fn draw_relations_content(frame: &mut Frame, app: &App, area: Rect) {
         }
 
         // Build ER diagram visualization
-        let lines = build_er_diagram(tables);
+        let lines = build_er_diagram(tables, Icons::from_app(app));
         let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
         frame.render_widget(paragraph, area);
     } else {
@@ -98,7 +106,7 @@ mod er_layout {
 }
 
 /// Build a text-based ER diagram showing table relationships
-fn build_er_diagram(tables: &[Table]) -> Vec<Line<'static>> {
+fn build_er_diagram(tables: &[Table], icons: Icons) -> Vec<Line<'static>> {
     use er_layout::*;
 
     let mut lines: Vec<Line<'static>> = Vec::new();
@@ -141,14 +149,14 @@ fn build_er_diagram(tables: &[Table]) -> Vec<Line<'static>> {
 
         lines.push(Line::from(vec![
             Span::raw("  "),
-            Span::styled("┌─ ", theme::selected()),
+            Span::styled(icons.text("┌─ "), theme::selected()),
             Span::styled(
                 table_name_display,
                 theme::selected().add_modifier(Modifier::BOLD),
             ),
             Span::styled(" ", theme::selected()),
-            Span::styled("─".repeat(header_dashes), theme::selected()),
-            Span::styled("┐", theme::selected()),
+            Span::styled(icons.glyph("─").repeat(header_dashes), theme::selected()),
+            Span::styled(icons.glyph("┐"), theme::selected()),
         ]));
 
         // Columns (limited to first 5 for brevity)
@@ -180,12 +188,12 @@ fn build_er_diagram(tables: &[Table]) -> Vec<Line<'static>> {
 
             lines.push(Line::from(vec![
                 Span::raw("  "),
-                Span::styled("│ ", theme::selected()),
+                Span::styled(icons.text("│ "), theme::selected()),
                 Span::raw(marker),
                 Span::raw(" "),
                 Span::styled(col_name_padded, col_style),
                 Span::styled(col_type_padded, theme::muted()),
-                Span::styled("│", theme::selected()),
+                Span::styled(icons.glyph("│"), theme::selected()),
             ]));
         }
 
@@ -195,10 +203,10 @@ fn build_er_diagram(tables: &[Table]) -> Vec<Line<'static>> {
             let padding = BOX_CONTENT_WIDTH.saturating_sub(display_width(&more_text));
             lines.push(Line::from(vec![
                 Span::raw("  "),
-                Span::styled("│ ", theme::selected()),
+                Span::styled(icons.text("│ "), theme::selected()),
                 Span::styled(more_text, theme::muted()),
                 Span::raw(" ".repeat(padding)),
-                Span::styled("│", theme::selected()),
+                Span::styled(icons.glyph("│"), theme::selected()),
             ]));
         }
 
@@ -208,7 +216,12 @@ fn build_er_diagram(tables: &[Table]) -> Vec<Line<'static>> {
         lines.push(Line::from(vec![
             Span::raw("  "),
             Span::styled(
-                format!("└{}┘", "─".repeat(BOX_TOTAL_WIDTH - 2)),
+                format!(
+                    "{}{}{}",
+                    icons.glyph("└"),
+                    icons.glyph("─").repeat(BOX_TOTAL_WIDTH - 2),
+                    icons.glyph("┘")
+                ),
                 theme::selected(),
             ),
         ]));
@@ -217,13 +230,14 @@ fn build_er_diagram(tables: &[Table]) -> Vec<Line<'static>> {
         for fk in &table.foreign_keys {
             lines.push(Line::from(vec![
                 Span::raw("        "),
-                Span::styled("│", theme::selected()),
+                Span::styled(icons.glyph("│"), theme::selected()),
             ]));
             lines.push(Line::from(vec![
                 Span::raw("        "),
                 Span::styled(
                     format!(
-                        "└──> {}.{}",
+                        "{}> {}.{}",
+                        icons.text("└──"),
                         truncate_to_width(&fk.referenced_table, REF_TABLE_MAX_WIDTH),
                         fk.referenced_columns.join(", ")
                     ),
@@ -1,19 +1,39 @@
 //! Columns sub-tab rendering
 
-use crate::app::App;
+use crate::app::{App, SchemaSubTab};
+use crate::model::schema::PlannerStats;
+use crate::ui::icons::Icons;
 use crate::ui::theme;
+use crate::ui::utils::format_number;
 use ratatui::{
-    layout::{Constraint, Rect},
-    widgets::{Cell, Paragraph, Row, Table as RatatuiTable},
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Cell, Paragraph, Row, Table as RatatuiTable, TableState},
     Frame,
 };
 
 pub fn draw_columns_content(frame: &mut Frame, app: &App, area: Rect) {
     if let Some(table) = app.selected_table_info() {
         let vis = &app.column_visibility.columns;
+        let search = &app.column_search;
+        let icons = Icons::from_app(app);
+        let filtered = search.filtered_indices(&table.columns);
+
+        // Reserve a line for the filter while it is in use
+        let (table_area, filter_area) = if search.is_active() {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(1)])
+                .split(area);
+            (chunks[0], Some(chunks[1]))
+        } else {
+            (area, None)
+        };
 
         // Build visible header cells
-        let all_headers = ["", "Name", "Type", "Null", "Default", "Key"];
+        let all_headers = [
+            "", "Name", "Type", "Null", "Default", "Key", "Distinct", "Null %", "Width",
+        ];
         let visibility_flags = [
             vis.show_icon,
             vis.show_name,
@@ -21,6 +41,9 @@ pub fn draw_columns_content(frame: &mut Frame, app: &App, area: Rect) {
             vis.show_nullable,
             vis.show_default,
             vis.show_key,
+            vis.show_distinct,
+            vis.show_null_frac,
+            vis.show_avg_width,
         ];
 
         let header_cells: Vec<Cell> = all_headers
@@ -32,14 +55,14 @@ pub fn draw_columns_content(frame: &mut Frame, app: &App, area: Rect) {
         let header = Row::new(header_cells).height(1);
 
         // Create rows with visibility filtering
-        let rows: Vec<Row> = table
-            .columns
+        let rows: Vec<Row> = filtered
             .iter()
+            .map(|&idx| &table.columns[idx])
             .map(|col| {
                 let pk_marker = if col.is_primary_key {
-                    "🔑"
+                    icons.primary_key()
                 } else if col.is_unique {
-                    "⚡"
+                    icons.unique()
                 } else {
                     ""
                 };
@@ -55,6 +78,11 @@ pub fn draw_columns_content(frame: &mut Frame, app: &App, area: Rect) {
                 let null_str = if col.is_nullable { "YES" } else { "NO" };
                 let default_str = col.default_value.as_deref().unwrap_or("-");
 
+                // "-" until the table has been analyzed
+                let stat = |label: fn(&PlannerStats) -> String| {
+                    col.planner_stats.as_ref().map_or("-".to_string(), label)
+                };
+
                 let name_style = if col.is_primary_key {
                     theme::header()
                 } else {
@@ -84,6 +112,18 @@ pub fn draw_columns_content(frame: &mut Frame, app: &App, area: Rect) {
                         vis.show_default,
                     ),
                     (Cell::from(key_info).style(theme::selected()), vis.show_key),
+                    (
+                        Cell::from(stat(distinct_label)).style(theme::muted()),
+                        vis.show_distinct,
+                    ),
+                    (
+                        Cell::from(stat(|s| format_percent(s.null_frac))).style(theme::muted()),
+                        vis.show_null_frac,
+                    ),
+                    (
+                        Cell::from(stat(|s| format!("{} B", s.avg_width))).style(theme::muted()),
+                        vis.show_avg_width,
+                    ),
                 ];
 
                 let visible_cells: Vec<Cell> = all_cells
@@ -104,6 +144,9 @@ pub fn draw_columns_content(frame: &mut Frame, app: &App, area: Rect) {
             (Constraint::Length(5), vis.show_nullable),
             (Constraint::Percentage(25), vis.show_default),
             (Constraint::Length(5), vis.show_key),
+            (Constraint::Length(10), vis.show_distinct),
+            (Constraint::Length(8), vis.show_null_frac),
+            (Constraint::Length(8), vis.show_avg_width),
         ];
 
         let widths: Vec<Constraint> = all_widths
@@ -116,9 +159,66 @@ pub fn draw_columns_content(frame: &mut Frame, app: &App, area: Rect) {
             .header(header)
             .row_highlight_style(theme::row_highlight());
 
-        frame.render_widget(table_widget, area);
+        let mut state = TableState::default().with_selected(
+            app.schema_states
+                .selected(SchemaSubTab::Columns, filtered.len()),
+        );
+        frame.render_stateful_widget(table_widget, table_area, &mut state);
+
+        if let Some(filter_area) = filter_area {
+            let cursor = if search.editing { "_" } else { "" };
+            let filter_line = Line::from(vec![
+                Span::styled("/", theme::header()),
+                Span::styled(format!("{}{}", search.query, cursor), theme::text()),
+                Span::styled(
+                    format!("  {}/{} columns", filtered.len(), table.columns.len()),
+                    theme::muted(),
+                ),
+            ]);
+            frame.render_widget(Paragraph::new(filter_line), filter_area);
+        }
     } else {
         let empty = Paragraph::new("Select a table to view columns").style(theme::muted());
         frame.render_widget(empty, area);
     }
 }
+
+/// `n_distinct` for display: a count, or the share of rows for negative
+/// values (`-1` means every row is distinct)
+fn distinct_label(stats: &PlannerStats) -> String {
+    if stats.n_distinct < 0.0 {
+        format_percent(-stats.n_distinct)
+    } else {
+        format_number(stats.n_distinct.round() as usize)
+    }
+}
+
+/// Format a fraction as a percentage with at most one decimal place
+fn format_percent(fraction: f32) -> String {
+    let percent = format!("{:.1}", fraction * 100.0);
+    format!("{}%", percent.trim_end_matches(".0"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planner_stats_labels() {
+        let stats = PlannerStats {
+            null_frac: 0.125,
+            n_distinct: 12345.0,
+            avg_width: 8,
+        };
+        assert_eq!(format_percent(stats.null_frac), "12.5%");
+        assert_eq!(distinct_label(&stats), "12,345");
+
+        let unique = PlannerStats {
+            null_frac: 0.0,
+            n_distinct: -1.0,
+            avg_width: 4,
+        };
+        assert_eq!(format_percent(unique.null_frac), "0%");
+        assert_eq!(distinct_label(&unique), "100%");
+    }
+}
@@ -1,10 +1,10 @@
 //! Foreign Keys sub-tab rendering
 
-use crate::app::App;
+use crate::app::{App, SchemaSubTab};
 use crate::ui::theme;
 use ratatui::{
     layout::{Constraint, Rect},
-    widgets::{Cell, Paragraph, Row, Table as RatatuiTable},
+    widgets::{Cell, Paragraph, Row, Table as RatatuiTable, TableState},
     Frame,
 };
 
@@ -100,7 +100,11 @@ pub fn draw_foreign_keys_content(frame: &mut Frame, app: &App, area: Rect) {
             .header(header)
             .row_highlight_style(theme::row_highlight());
 
-        frame.render_widget(table_widget, area);
+        let mut state = TableState::default().with_selected(
+            app.schema_states
+                .selected(SchemaSubTab::ForeignKeys, table.foreign_keys.len()),
+        );
+        frame.render_stateful_widget(table_widget, area, &mut state);
     } else {
         let empty = Paragraph::new("Select a table to view foreign keys").style(theme::muted());
         frame.render_widget(empty, area);
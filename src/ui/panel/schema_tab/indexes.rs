@@ -1,10 +1,12 @@
 //! Indexes sub-tab rendering
 
-use crate::app::App;
+use crate::app::{App, IndexesVisibility, SchemaSubTab};
+use crate::model::schema::IndexUsage;
 use crate::ui::theme;
+use crate::ui::utils::format_number;
 use ratatui::{
     layout::{Constraint, Rect},
-    widgets::{Cell, Paragraph, Row, Table as RatatuiTable},
+    widgets::{Cell, Paragraph, Row, Table as RatatuiTable, TableState},
     Frame,
 };
 
@@ -19,12 +21,15 @@ pub fn draw_indexes_content(frame: &mut Frame, app: &App, area: Rect) {
         let vis = &app.column_visibility.indexes;
 
         // Build visible header cells
-        let all_headers = ["Name", "Type", "Method", "Columns"];
+        let all_headers = IndexesVisibility::all_columns();
         let visibility_flags = [
             vis.show_name,
             vis.show_type,
             vis.show_method,
             vis.show_columns,
+            vis.show_scans,
+            vis.show_tuples_read,
+            vis.show_tuples_fetched,
         ];
 
         let header_cells: Vec<Cell> = all_headers
@@ -60,6 +65,18 @@ pub fn draw_indexes_content(frame: &mut Frame, app: &App, area: Rect) {
                     _ => theme::text(),
                 };
 
+                // "-" when the database reported no statistics for the index
+                let usage = |value: fn(&IndexUsage) -> u64| {
+                    idx.usage
+                        .as_ref()
+                        .map_or("-".to_string(), |u| format_number(value(u) as usize))
+                };
+                // Indexes that were never scanned are candidates for removal
+                let scans_style = match idx.usage {
+                    Some(IndexUsage { scans: 0, .. }) => theme::header(),
+                    _ => theme::text(),
+                };
+
                 let all_cells = [
                     (
                         Cell::from(idx.name.clone()).style(theme::selected()),
@@ -77,6 +94,18 @@ pub fn draw_indexes_content(frame: &mut Frame, app: &App, area: Rect) {
                         Cell::from(columns_str).style(theme::text()),
                         vis.show_columns,
                     ),
+                    (
+                        Cell::from(usage(|u| u.scans)).style(scans_style),
+                        vis.show_scans,
+                    ),
+                    (
+                        Cell::from(usage(|u| u.tuples_read)).style(theme::muted()),
+                        vis.show_tuples_read,
+                    ),
+                    (
+                        Cell::from(usage(|u| u.tuples_fetched)).style(theme::muted()),
+                        vis.show_tuples_fetched,
+                    ),
                 ];
 
                 let visible_cells: Vec<Cell> = all_cells
@@ -95,6 +124,9 @@ pub fn draw_indexes_content(frame: &mut Frame, app: &App, area: Rect) {
             (Constraint::Percentage(15), vis.show_type),
             (Constraint::Percentage(15), vis.show_method),
             (Constraint::Percentage(40), vis.show_columns),
+            (Constraint::Length(10), vis.show_scans),
+            (Constraint::Length(10), vis.show_tuples_read),
+            (Constraint::Length(10), vis.show_tuples_fetched),
         ];
 
         let widths: Vec<Constraint> = all_widths
@@ -107,7 +139,11 @@ pub fn draw_indexes_content(frame: &mut Frame, app: &App, area: Rect) {
             .header(header)
             .row_highlight_style(theme::row_highlight());
 
-        frame.render_widget(table_widget, area);
+        let mut state = TableState::default().with_selected(
+            app.schema_states
+                .selected(SchemaSubTab::Indexes, table.indexes.len()),
+        );
+        frame.render_stateful_widget(table_widget, area, &mut state);
     } else {
         let empty = Paragraph::new("Select a table to view indexes").style(theme::muted());
         frame.render_widget(empty, area);
@@ -31,40 +31,20 @@ pub fn draw_schema_content(frame: &mut Frame, app: &App, area: Rect) {
         .map(|t| t.table_type.is_view())
         .unwrap_or(false);
 
-    // Draw sub-tabs (Definition tab only shown for views)
-    let sub_tab_titles: Vec<&str> = if is_view {
-        vec![
-            "Columns [1]",
-            "Indexes [2]",
-            "Foreign Keys [3]",
-            "Constraints [4]",
-            "Triggers [5]",
-            "Definition [6]",
-        ]
-    } else {
-        vec![
-            "Columns [1]",
-            "Indexes [2]",
-            "Foreign Keys [3]",
-            "Constraints [4]",
-            "Triggers [5]",
-        ]
-    };
+    // Draw sub-tabs (Definition only for views, Triggers only if the database has them)
+    let visible_tabs = SchemaSubTab::visible_tabs(is_view, &app.capabilities());
+    let sub_tab_titles: Vec<&str> = visible_tabs.iter().map(|tab| tab.title()).collect();
 
-    let selected_sub_tab = match app.schema_sub_tab {
-        SchemaSubTab::Columns => 0,
-        SchemaSubTab::Indexes => 1,
-        SchemaSubTab::ForeignKeys => 2,
-        SchemaSubTab::Constraints => 3,
-        SchemaSubTab::Triggers => 4,
-        SchemaSubTab::Definition => {
-            if is_view {
-                5
-            } else {
-                0
-            }
-        }
+    // Fall back to Columns if the selected sub-tab is hidden
+    let effective_sub_tab = if visible_tabs.contains(&app.schema_sub_tab) {
+        app.schema_sub_tab
+    } else {
+        SchemaSubTab::Columns
     };
+    let selected_sub_tab = visible_tabs
+        .iter()
+        .position(|tab| *tab == effective_sub_tab)
+        .unwrap_or(0);
 
     let sub_tabs = Tabs::new(sub_tab_titles)
         .select(selected_sub_tab)
@@ -75,13 +55,6 @@ pub fn draw_schema_content(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(sub_tabs, chunks[0]);
 
     // Draw content based on selected sub-tab
-    // For non-views with Definition tab selected, fall back to Columns content
-    let effective_sub_tab = if !is_view && app.schema_sub_tab == SchemaSubTab::Definition {
-        SchemaSubTab::Columns
-    } else {
-        app.schema_sub_tab
-    };
-
     match effective_sub_tab {
         SchemaSubTab::Columns => columns::draw_columns_content(frame, app, chunks[1]),
         SchemaSubTab::Indexes => indexes::draw_indexes_content(frame, app, chunks[1]),
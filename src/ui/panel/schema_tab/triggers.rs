@@ -1,10 +1,10 @@
 //! Triggers sub-tab rendering
 
-use crate::app::App;
+use crate::app::{App, SchemaSubTab};
 use crate::ui::theme;
 use ratatui::{
     layout::{Constraint, Rect},
-    widgets::{Cell, Paragraph, Row, Table as RatatuiTable},
+    widgets::{Cell, Paragraph, Row, Table as RatatuiTable, TableState},
     Frame,
 };
 
@@ -110,7 +110,11 @@ pub fn draw_triggers_content(frame: &mut Frame, app: &App, area: Rect) {
             .header(header)
             .row_highlight_style(theme::row_highlight());
 
-        frame.render_widget(table_widget, area);
+        let mut state = TableState::default().with_selected(
+            app.schema_states
+                .selected(SchemaSubTab::Triggers, table.triggers.len()),
+        );
+        frame.render_stateful_widget(table_widget, area, &mut state);
     } else {
         let empty = Paragraph::new("Select a table to view triggers").style(theme::muted());
         frame.render_widget(empty, area);
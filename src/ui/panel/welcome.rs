@@ -0,0 +1,70 @@
+//! First-run guide shown in the main panel while there are no projects
+
+use crate::app::{App, Focus};
+use crate::ui::theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_welcome(frame: &mut Frame, app: &App, area: Rect) {
+    let border_style = if app.focus == Focus::MainPanel {
+        theme::border_focused()
+    } else {
+        theme::border_inactive()
+    };
+    let block = Block::default()
+        .title(" Welcome to lazydb ")
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    let paragraph = Paragraph::new(welcome_lines())
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+/// A line of text where `key` is highlighted
+fn with_key(before: &'static str, key: &'static str, after: &'static str) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(before, theme::text()),
+        Span::styled(key, theme::header()),
+        Span::styled(after, theme::text()),
+    ])
+}
+
+fn welcome_lines() -> Vec<Line<'static>> {
+    vec![
+        Line::styled(
+            "No projects yet. A project groups the database connections you work with.",
+            theme::text(),
+        ),
+        Line::default(),
+        Line::styled("Getting started", theme::header()),
+        with_key("  1. Press ", "a", " in the sidebar to add a project"),
+        with_key(
+            "  2. Press ",
+            "Enter",
+            " on the project, then a to add a connection",
+        ),
+        with_key(
+            "  3. Press ",
+            "Enter",
+            " on the connection to list its tables",
+        ),
+        Line::default(),
+        with_key(
+            "Or press ",
+            "Shift+s",
+            " to create a sample project connecting to postgres@localhost:5432",
+        ),
+        Line::styled(
+            "with the password in $PGPASSWORD. Projects are saved to the config directory.",
+            theme::muted(),
+        ),
+        Line::default(),
+        with_key("Press ", "?", " at any time to list every key."),
+    ]
+}
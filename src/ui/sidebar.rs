@@ -1,4 +1,5 @@
-use crate::app::{App, Focus, SidebarMode};
+use crate::app::{App, Focus, SidebarMode, TablePreview};
+use crate::model::Table;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -7,6 +8,7 @@ use ratatui::{
     Frame,
 };
 
+use super::icons::Icons;
 use super::theme;
 use super::utils::{format_number, format_size};
 
@@ -46,9 +48,10 @@ fn draw_mode_indicator(frame: &mut Frame, app: &App, area: Rect) {
         theme::muted()
     };
 
+    let icons = Icons::from_app(app);
     let indicator = Line::from(vec![
         Span::styled(" Projects ", projects_style),
-        Span::styled("│", theme::muted()),
+        Span::styled(icons.text("│"), theme::muted()),
         Span::styled(" Connections ", connections_style),
     ]);
 
@@ -73,6 +76,7 @@ fn draw_projects_view(frame: &mut Frame, app: &App, area: Rect) {
     let inner_area = block.inner(area);
     frame.render_widget(block, area);
 
+    let icons = Icons::from_app(app);
     let mut lines: Vec<Line> = Vec::new();
 
     // Help hints for project operations
@@ -101,14 +105,18 @@ fn draw_projects_view(frame: &mut Frame, app: &App, area: Rect) {
         };
 
         lines.push(Line::from(vec![
-            Span::styled("▶ ", style),
+            Span::styled(icons.text("▶ "), style),
             Span::styled(&project.name, style),
             Span::styled(suffix, theme::muted()),
         ]));
     }
 
-    if lines.is_empty() {
-        lines.push(Line::from(Span::styled("No projects", theme::muted())));
+    if app.projects.is_empty() {
+        lines.push(Line::from(Span::styled("No projects yet", theme::muted())));
+        lines.push(Line::from(Span::styled(
+            "Press a to add one",
+            theme::muted(),
+        )));
     }
 
     let paragraph = Paragraph::new(lines);
@@ -138,11 +146,12 @@ fn draw_connections_view(frame: &mut Frame, app: &App, area: Rect, proj_idx: usi
     let inner_area = block.inner(area);
     frame.render_widget(block, area);
 
+    let icons = Icons::from_app(app);
     let mut lines: Vec<Line> = Vec::new();
 
     // Navigation and operation hints
     lines.push(Line::from(Span::styled(
-        "← Back  a: add connection",
+        icons.text("← Back  a: add connection"),
         theme::muted(),
     )));
     lines.push(Line::from(""));
@@ -166,10 +175,19 @@ fn draw_connections_view(frame: &mut Frame, app: &App, area: Rect, proj_idx: usi
             theme::text()
         };
 
-        lines.push(Line::from(vec![
-            Span::styled(format!("{} ", expand_icon), conn_style),
-            Span::styled(&conn.name, conn_style),
-        ]));
+        let mut conn_line = vec![Span::styled(
+            format!("{} ", icons.text(expand_icon)),
+            conn_style,
+        )];
+        // Colored marker for labelled connections (e.g. red for production)
+        if let Some(color) = conn.color.as_deref().and_then(theme::connection_color) {
+            conn_line.push(Span::styled(
+                icons.text("● ").into_owned(),
+                Style::default().fg(color),
+            ));
+        }
+        conn_line.push(Span::styled(&conn.name, conn_style));
+        lines.push(Line::from(conn_line));
 
         if conn.expanded {
             for (table_idx, table) in conn.tables.iter().enumerate() {
@@ -188,19 +206,28 @@ fn draw_connections_view(frame: &mut Frame, app: &App, area: Rect, proj_idx: usi
                 // Icon style matches table style for consistency
                 let icon_style = table_style;
 
-                let prefix = if table_idx == conn.tables.len() - 1 {
+                let prefix = icons.text(if table_idx == conn.tables.len() - 1 {
                     "  └─ "
                 } else {
                     "  ├─ "
-                };
+                });
+
+                let icon = icons.table(&table.table_type);
 
-                let icon = table.table_type.icon();
+                // System schema tables are listed after the connection's own schema
+                let name = if app.show_system_objects
+                    && table.schema.as_deref() != Some(conn.schema().as_str())
+                {
+                    table.full_name()
+                } else {
+                    table.name.clone()
+                };
 
                 // Apply style to entire line for proper background highlighting
                 let line = Line::from(vec![
                     Span::styled(prefix, theme::muted()),
                     Span::styled(format!("{} ", icon), icon_style),
-                    Span::styled(&table.name, table_style),
+                    Span::styled(name, table_style),
                 ]);
 
                 // If table is selected and focused, apply background to entire line
@@ -221,6 +248,20 @@ fn draw_connections_view(frame: &mut Frame, app: &App, area: Rect, proj_idx: usi
     frame.render_widget(paragraph, inner_area);
 }
 
+/// Characters shown of each value in the row preview
+const PREVIEW_CELL_WIDTH: usize = 8;
+
+/// Height of the info box without a row preview
+const SUMMARY_HEIGHT: u16 = 7;
+
+/// Height of the info box; it grows to fit the row preview of a selected table
+pub fn table_summary_height(app: &App) -> u16 {
+    if app.selected_table_preview().is_none() {
+        return SUMMARY_HEIGHT;
+    }
+    (summary_lines(app).len() as u16 + 2).max(SUMMARY_HEIGHT)
+}
+
 pub fn draw_table_summary(frame: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .title(" Info ")
@@ -230,7 +271,12 @@ pub fn draw_table_summary(frame: &mut Frame, app: &App, area: Rect) {
     let inner_area = block.inner(area);
     frame.render_widget(block, area);
 
-    let lines = if let Some(table) = app.selected_table_info() {
+    let paragraph = Paragraph::new(summary_lines(app));
+    frame.render_widget(paragraph, inner_area);
+}
+
+fn summary_lines(app: &App) -> Vec<Line<'_>> {
+    if let Some(table) = app.selected_table_info() {
         let pk_name = table
             .columns
             .iter()
@@ -242,7 +288,10 @@ pub fn draw_table_summary(frame: &mut Frame, app: &App, area: Rect) {
 
         let mut info_lines = vec![
             Line::from(vec![
-                Span::styled(format!("{} ", table.table_type.icon()), theme::header()),
+                Span::styled(
+                    format!("{} ", Icons::from_app(app).table(&table.table_type)),
+                    theme::header(),
+                ),
                 Span::styled(
                     &table.name,
                     Style::default()
@@ -263,7 +312,7 @@ pub fn draw_table_summary(frame: &mut Frame, app: &App, area: Rect) {
         // Only show row count and PK for non-view tables
         if !table.table_type.is_view() {
             info_lines.push(Line::from(vec![Span::styled(
-                format!("{} rows", format_number(table.row_count)),
+                format_row_count(table),
                 theme::muted(),
             )]));
             info_lines.push(Line::from(vec![
@@ -274,6 +323,10 @@ pub fn draw_table_summary(frame: &mut Frame, app: &App, area: Rect) {
 
         info_lines.push(Line::from(vec![Span::styled(size_str, theme::muted())]));
 
+        if let Some(preview) = app.selected_table_preview() {
+            info_lines.extend(preview_lines(preview));
+        }
+
         info_lines
     } else if let Some(conn) = app.selected_connection_info() {
         vec![
@@ -317,8 +370,59 @@ pub fn draw_table_summary(frame: &mut Frame, app: &App, area: Rect) {
         }
     } else {
         vec![Line::from(Span::styled("No selection", theme::muted()))]
+    }
+}
+
+/// Column names and first rows of a table, one line each
+fn preview_lines(preview: &TablePreview) -> Vec<Line<'static>> {
+    let result = match preview {
+        TablePreview::Loading => {
+            return vec![Line::from(Span::styled(
+                "Loading preview...",
+                theme::muted(),
+            ))]
+        }
+        TablePreview::Failed(e) => {
+            return vec![Line::from(Span::styled(
+                format!("No preview: {}", e),
+                theme::muted(),
+            ))]
+        }
+        TablePreview::Loaded(result) => result,
+    };
+    let row_line = |values: &[String]| {
+        values
+            .iter()
+            .map(|v| preview_cell(v))
+            .collect::<Vec<_>>()
+            .join(" ")
     };
 
-    let paragraph = Paragraph::new(lines);
-    frame.render_widget(paragraph, inner_area);
+    let mut lines = vec![Line::from(Span::styled(
+        row_line(&result.columns),
+        theme::header(),
+    ))];
+    if result.rows.is_empty() {
+        lines.push(Line::from(Span::styled("(no rows)", theme::muted())));
+    }
+    for row in &result.rows {
+        lines.push(Line::from(Span::styled(row_line(row), theme::text())));
+    }
+    lines
+}
+
+/// A value cut or padded to [`PREVIEW_CELL_WIDTH`] characters, on one line
+fn preview_cell(value: &str) -> String {
+    let value: String = value
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .take(PREVIEW_CELL_WIDTH)
+        .collect();
+    format!("{:<width$}", value, width = PREVIEW_CELL_WIDTH)
+}
+
+/// Row count line for the table info; estimates from statistics get a `~`
+fn format_row_count(table: &Table) -> String {
+    let approx = if table.row_count_exact { "" } else { "~" };
+    format!("{}{} rows", approx, format_number(table.row_count))
 }
@@ -1,32 +1,192 @@
 use crate::app::App;
+use crate::model::QueryOutcome;
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
+use super::icons::Icons;
 use super::theme;
+use super::utils::format_duration_ms;
 
 pub fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect) {
+    let mut context = context_line(app);
+
+    // A labelled connection colors the context; alert colors (red) also flag the border
+    let conn = app.selected_connection_info();
+    let label = conn
+        .and_then(|conn| conn.color.as_deref())
+        .and_then(theme::connection_color);
+    let is_production = conn.is_some_and(|conn| conn.is_production);
+    let icons = Icons::from_app(app);
+    let (title, mut border_style, context_style) = match label {
+        Some(color) if theme::is_alert_color(color) => (
+            icons.text(" ⚠ Context ").into_owned(),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+            Some(theme::connection_context(color)),
+        ),
+        Some(color) => (
+            " Context ".to_string(),
+            theme::border_inactive(),
+            Some(theme::connection_context(color)),
+        ),
+        None => (" Context ".to_string(), theme::border_inactive(), None),
+    };
+    if let Some(style) = context_style {
+        for span in &mut context.spans {
+            span.style = style;
+        }
+    }
+    if is_production {
+        context
+            .spans
+            .insert(0, Span::styled(" PRODUCTION ", theme::production_banner()));
+        context.spans.insert(1, Span::raw(" "));
+        border_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+    }
+
+    // Context takes what it needs (plus borders), but never more than half the bar
+    let context_width = (context.width() as u16 + 2).min(area.width / 2);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(context_width), Constraint::Min(0)])
+        .split(area);
+
+    let context_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    frame.render_widget(Paragraph::new(context).block(context_block), chunks[0]);
+
     let block = Block::default()
         .title(" Status ")
         .borders(Borders::ALL)
         .border_style(theme::border_inactive());
 
-    let status_parts = if let Some(result) = &app.result {
-        vec![
-            Span::styled("✓ ", theme::selected()),
-            Span::styled(format!("{} rows", result.rows.len()), theme::text()),
-            Span::styled(" │ ", theme::muted()),
-            Span::styled(format!("{}ms", result.execution_time_ms), theme::muted()),
-            Span::styled(" │ ", theme::muted()),
-            Span::styled(&app.status_message, theme::selected()),
-        ]
+    let status_parts = match app.query_outcome() {
+        Some(outcome) => {
+            let mut parts = outcome_spans(&outcome, icons);
+            parts.push(Span::styled(icons.text(" │ "), theme::muted()));
+            parts.push(Span::styled(&app.status_message, theme::selected()));
+            parts
+        }
+        None => vec![Span::styled(&app.status_message, theme::muted())],
+    };
+    let status_parts = if app.config_changed_on_disk {
+        let mut parts = vec![
+            Span::styled(" Config changed (F5) ", theme::production_banner()),
+            Span::raw(" "),
+        ];
+        parts.extend(status_parts);
+        parts
     } else {
-        vec![Span::styled(&app.status_message, theme::muted())]
+        status_parts
     };
 
     let status = Paragraph::new(Line::from(status_parts)).block(block);
-    frame.render_widget(status, area);
+    frame.render_widget(status, chunks[1]);
+}
+
+/// Summary of the last query, styled by how it ended
+fn outcome_spans(outcome: &QueryOutcome, icons: Icons) -> Vec<Span<'static>> {
+    let (icon, style) = match outcome {
+        QueryOutcome::Returned { .. } => ("✓ ", theme::selected()),
+        QueryOutcome::Affected { .. } => ("✓ ", theme::header()),
+        QueryOutcome::Failed(_) => ("✗ ", theme::error()),
+    };
+    let mut spans = vec![
+        Span::styled(icons.text(icon).into_owned(), style),
+        Span::styled(outcome.summary(), style),
+    ];
+    if let Some(ms) = outcome.execution_time_ms() {
+        spans.push(Span::styled(icons.text(" │ ").into_owned(), theme::muted()));
+        spans.push(Span::styled(format_duration_ms(ms), theme::muted()));
+    }
+    spans
+}
+
+/// Where a query would run: connection, database and schema
+fn context_line(app: &App) -> Line<'static> {
+    let separator = Icons::from_app(app).text(" │ ");
+    match app.selected_connection_info() {
+        Some(conn) => Line::from(vec![
+            Span::styled(conn.name.clone(), theme::header()),
+            Span::styled(separator.clone(), theme::muted()),
+            Span::styled(conn.database.clone(), theme::text()),
+            Span::styled(separator, theme::muted()),
+            Span::styled(conn.schema(), theme::text()),
+        ]),
+        None => Line::from(Span::styled("No connection", theme::muted())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::SidebarMode;
+    use crate::model::{Connection, IconStyle, Project};
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_outcome_spans_style_each_outcome() {
+        let icons = Icons::new(false, IconStyle::NerdFont);
+        let returned = QueryOutcome::Returned {
+            rows: 3,
+            execution_time_ms: 12,
+        };
+        let spans = outcome_spans(&returned, icons);
+        assert_eq!(
+            line_text(&Line::from(spans.clone())),
+            format!("✓ 3 rows returned │ {}", format_duration_ms(12))
+        );
+        assert_eq!(spans[1].style, theme::selected());
+
+        let affected = QueryOutcome::Affected {
+            rows: 1,
+            execution_time_ms: 12,
+        };
+        assert_eq!(outcome_spans(&affected, icons)[1].style, theme::header());
+
+        let spans = outcome_spans(&QueryOutcome::Failed("boom".to_string()), icons);
+        assert_eq!(line_text(&Line::from(spans.clone())), "✗ Error: boom");
+        assert_eq!(spans[1].style, theme::error());
+    }
+
+    #[test]
+    fn test_context_line_without_connection() {
+        let app = App::new(vec![]);
+        assert_eq!(line_text(&context_line(&app)), "No connection");
+    }
+
+    #[test]
+    fn test_context_line_shows_connection_database_and_schema() {
+        let mut project = Project::new("main");
+        project.connections.push(Connection {
+            name: "prod".to_string(),
+            host: "localhost".to_string(),
+            port: 5432,
+            database: "app".to_string(),
+            username: "user".to_string(),
+            password: String::new(),
+            password_env: None,
+            expanded: false,
+            tables: vec![],
+            default_schema: Some("billing".to_string()),
+            color: None,
+            is_production: false,
+            query_timeout_secs: None,
+            snippets: Vec::new(),
+            search_path: None,
+        });
+        let mut app = App::new(vec![project]);
+        app.sidebar_mode = SidebarMode::Connections(0);
+
+        assert_eq!(line_text(&context_line(&app)), "prod │ app │ billing");
+    }
 }
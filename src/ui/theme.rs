@@ -110,3 +110,65 @@ pub fn button_cancel_focused() -> Style {
         .bg(MUTED)
         .add_modifier(Modifier::BOLD)
 }
+
+/// Style for errors, e.g. a failed query in the status bar
+pub fn error() -> Style {
+    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+}
+
+// =============================================================================
+// Connection Labels
+// =============================================================================
+
+/// Color of a connection's label (`red`, `light-green`, `#ff8800`, ...).
+///
+/// Unknown names give `None`, so the connection is drawn without a label.
+pub fn connection_color(name: &str) -> Option<Color> {
+    name.trim().parse().ok()
+}
+
+/// Whether a label color marks a connection to be careful with (production)
+pub fn is_alert_color(color: Color) -> bool {
+    matches!(color, Color::Red | Color::LightRed)
+}
+
+/// Style for the status bar context of a labelled connection (label as background)
+pub fn connection_context(color: Color) -> Style {
+    Style::default()
+        .bg(color)
+        .fg(BG)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Banner flagging a connection marked as production
+pub fn production_banner() -> Style {
+    Style::default()
+        .bg(Color::Red)
+        .fg(TEXT)
+        .add_modifier(Modifier::BOLD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_color_parses_names_and_hex() {
+        assert_eq!(connection_color("red"), Some(Color::Red));
+        assert_eq!(connection_color(" Green "), Some(Color::Green));
+        assert_eq!(connection_color("light-red"), Some(Color::LightRed));
+        assert_eq!(
+            connection_color("#ff8800"),
+            Some(Color::Rgb(0xff, 0x88, 0x00))
+        );
+        assert_eq!(connection_color("prod-ish"), None);
+        assert_eq!(connection_color(""), None);
+    }
+
+    #[test]
+    fn test_red_labels_are_alerts() {
+        assert!(is_alert_color(Color::Red));
+        assert!(is_alert_color(Color::LightRed));
+        assert!(!is_alert_color(Color::Green));
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use itertools::Itertools;
 
 /// Format bytes to human readable size
@@ -30,3 +31,63 @@ pub fn format_number(n: usize) -> String {
         .rev()
         .collect()
 }
+
+/// Format a duration in milliseconds for display ("<1 ms", "42 ms", "1.25 s", "2m 05s")
+pub fn format_duration_ms(ms: u64) -> String {
+    if ms == 0 {
+        "<1 ms".to_string()
+    } else if ms < 1000 {
+        format!("{} ms", ms)
+    } else if ms < 60_000 {
+        format!("{:.2} s", ms as f64 / 1000.0)
+    } else {
+        let secs = ms / 1000;
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Format how long ago `then` was, as seen at `now` ("just now", "2m ago",
+/// "3h ago", "yesterday", "4d ago"). A week or more back gives the date.
+pub fn format_relative_time(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(then);
+    let minutes = elapsed.num_minutes();
+    let hours = elapsed.num_hours();
+    let days = elapsed.num_days();
+
+    if minutes < 1 {
+        // Includes clocks that moved backwards since the entry was written
+        "just now".to_string()
+    } else if hours < 1 {
+        format!("{}m ago", minutes)
+    } else if days < 1 {
+        format!("{}h ago", hours)
+    } else if days < 2 {
+        "yesterday".to_string()
+    } else if days < 7 {
+        format!("{}d ago", days)
+    } else {
+        then.format("%Y-%m-%d").to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_format_relative_time() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+        let ago = |d: Duration| format_relative_time(now - d, now);
+
+        assert_eq!(ago(Duration::seconds(30)), "just now");
+        assert_eq!(ago(Duration::seconds(-30)), "just now");
+        assert_eq!(ago(Duration::minutes(2)), "2m ago");
+        assert_eq!(ago(Duration::minutes(59)), "59m ago");
+        assert_eq!(ago(Duration::hours(3)), "3h ago");
+        assert_eq!(ago(Duration::hours(23)), "23h ago");
+        assert_eq!(ago(Duration::hours(30)), "yesterday");
+        assert_eq!(ago(Duration::days(4)), "4d ago");
+        assert_eq!(ago(Duration::days(10)), "2024-03-05");
+    }
+}